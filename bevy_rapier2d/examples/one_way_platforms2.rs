@@ -0,0 +1,104 @@
+use bevy::{ecs::system::SystemParam, prelude::*};
+use bevy_rapier2d::prelude::*;
+
+#[derive(Component)]
+struct OneWayPlatform;
+
+// The contact manifold user-data used to remember whether a body entered a platform from below.
+const SOLID: u32 = 1;
+const PASS_THROUGH: u32 = 2;
+
+// Lets the bodies moving upward go through the platforms, and the other ones land on them.
+#[derive(SystemParam)]
+struct OneWayPlatformHooks<'w, 's> {
+    platforms: Query<'w, 's, (), With<OneWayPlatform>>,
+}
+
+impl BevyPhysicsHooks for OneWayPlatformHooks<'_, '_> {
+    fn modify_solver_contacts(&self, mut context: ContactModificationContextView) {
+        let velocity = if self.platforms.contains(context.collider1()) {
+            context.velocity2().linvel - context.velocity1().linvel
+        } else if self.platforms.contains(context.collider2()) {
+            context.velocity1().linvel - context.velocity2().linvel
+        } else {
+            return;
+        };
+
+        // Only decide once per contact manifold, otherwise a body could fall through the
+        // platform when it bounces while halfway through it.
+        if context.user_data() == 0 {
+            context.set_user_data(if velocity.y > 0.0 {
+                PASS_THROUGH
+            } else {
+                SOLID
+            });
+        }
+
+        if context.user_data() == PASS_THROUGH {
+            context.clear_solver_contacts();
+        }
+    }
+}
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(
+            0xF9 as f32 / 255.0,
+            0xF9 as f32 / 255.0,
+            0xFF as f32 / 255.0,
+        )))
+        .add_plugins((
+            DefaultPlugins,
+            RapierPhysicsPlugin::<OneWayPlatformHooks>::pixels_per_meter(100.0),
+            RapierDebugRenderPlugin::default(),
+        ))
+        .add_systems(Startup, (setup_graphics, setup_physics))
+        .add_systems(Update, launch_balls)
+        .run();
+}
+
+fn setup_graphics(mut commands: Commands) {
+    commands.spawn(Camera2dBundle {
+        transform: Transform::from_xyz(0.0, 150.0, 0.0),
+        ..default()
+    });
+}
+
+pub fn setup_physics(mut commands: Commands) {
+    /*
+     * Ground
+     */
+    commands.spawn((
+        TransformBundle::from(Transform::from_xyz(0.0, -100.0, 0.0)),
+        Collider::cuboid(400.0, 10.0),
+    ));
+
+    /*
+     * One-way platforms
+     */
+    for (i, y) in [50.0, 200.0, 350.0].into_iter().enumerate() {
+        let x = if i % 2 == 0 { -100.0 } else { 100.0 };
+        commands.spawn((
+            TransformBundle::from(Transform::from_xyz(x, y, 0.0)),
+            Collider::cuboid(200.0, 5.0),
+            OneWayPlatform,
+            ActiveHooks::MODIFY_SOLVER_CONTACTS,
+            ColliderDebugColor(Color::hsl(220.0, 1.0, 0.3)),
+        ));
+    }
+}
+
+// Throws a ball upward from the ground every second.
+fn launch_balls(mut commands: Commands, time: Res<Time>, mut timer: Local<Option<Timer>>) {
+    let timer = timer.get_or_insert_with(|| Timer::from_seconds(1.0, TimerMode::Repeating));
+
+    if timer.tick(time.delta()).just_finished() {
+        let x = (time.elapsed_seconds() * 3.0).sin() * 250.0;
+        commands.spawn((
+            TransformBundle::from(Transform::from_xyz(x, -60.0, 0.0)),
+            RigidBody::Dynamic,
+            Collider::ball(20.0),
+            Velocity::linear(Vec2::Y * 1000.0),
+        ));
+    }
+}
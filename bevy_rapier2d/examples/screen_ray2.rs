@@ -0,0 +1,89 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy_rapier2d::prelude::*;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(
+            0xF9 as f32 / 255.0,
+            0xF9 as f32 / 255.0,
+            0xFF as f32 / 255.0,
+        )))
+        .add_plugins((
+            DefaultPlugins,
+            RapierPhysicsPlugin::<NoUserData>::pixels_per_meter(100.0),
+            RapierDebugRenderPlugin::default(),
+        ))
+        .add_systems(Startup, (setup_graphics, setup_physics))
+        .add_systems(Update, despawn_clicked)
+        .run();
+}
+
+pub fn setup_graphics(mut commands: Commands) {
+    commands.spawn(Camera2dBundle {
+        transform: Transform::from_xyz(0.0, 200.0, 0.0),
+        ..default()
+    });
+}
+
+pub fn setup_physics(mut commands: Commands) {
+    /*
+     * Ground
+     */
+    commands.spawn((
+        TransformBundle::from(Transform::from_xyz(0.0, -10.0, 0.0)),
+        Collider::cuboid(500.0, 10.0),
+    ));
+
+    /*
+     * A pile of boxes to click on.
+     */
+    for i in 0..10 {
+        for j in 0..5 {
+            commands.spawn((
+                TransformBundle::from(Transform::from_xyz(
+                    i as f32 * 45.0 - 200.0,
+                    j as f32 * 45.0 + 20.0,
+                    0.0,
+                )),
+                RigidBody::Dynamic,
+                Collider::cuboid(20.0, 20.0),
+            ));
+        }
+    }
+}
+
+/// Despawns the box under the cursor when the left mouse button is clicked.
+fn despawn_clicked(
+    mut commands: Commands,
+    buttons: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    rapier_context: Res<RapierContext>,
+) {
+    if !buttons.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    let Some(cursor_position) = windows.single().cursor_position() else {
+        return;
+    };
+
+    for (camera, camera_transform) in &cameras {
+        let hit = rapier_context
+            .cast_ray_from_screen(
+                DEFAULT_WORLD_ID,
+                camera,
+                camera_transform,
+                cursor_position,
+                f32::MAX,
+                true,
+                QueryFilter::only_dynamic(),
+            )
+            .expect("Default world should exist.");
+
+        if let Some((entity, _intersection)) = hit {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
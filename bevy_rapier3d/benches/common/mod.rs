@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+use bevy::scene::ScenePlugin;
+use bevy_rapier3d::prelude::*;
+
+/// An app simulating a fixed time step on every update, without rendering.
+pub fn bench_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        TransformPlugin,
+        AssetPlugin::default(),
+        ScenePlugin,
+        RapierPhysicsPlugin::<NoUserData>::default(),
+    ))
+    .init_asset::<Mesh>();
+    app.world
+        .resource_mut::<RapierConfiguration>()
+        .timestep_mode = TimestepMode::Fixed {
+        dt: 1.0 / 60.0,
+        substeps: 1,
+    };
+    app
+}
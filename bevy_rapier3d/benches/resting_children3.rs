@@ -0,0 +1,64 @@
+//! Steps 1k dynamic child rigid-bodies resting under a fixed parent.
+//!
+//! The children aren’t moved by the writeback while their parent doesn’t move, so they can fall
+//! asleep and the steps become cheap.
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+mod common;
+
+const NUM_CHILDREN: usize = 1_000;
+
+fn setup() -> App {
+    let mut app = common::bench_app();
+
+    let children: Vec<_> = (0..NUM_CHILDREN)
+        .map(|k| {
+            let x = (k % 32) as f32 * 2.0;
+            let z = (k / 32) as f32 * 2.0;
+            app.world
+                .spawn((
+                    TransformBundle::from(Transform::from_xyz(x, 0.0, z)),
+                    RigidBody::Dynamic,
+                    Collider::ball(0.5),
+                    GravityScale(0.0),
+                    Velocity::zero(),
+                ))
+                .id()
+        })
+        .collect();
+    app.world
+        .spawn((TransformBundle::default(), RigidBody::Fixed))
+        .push_children(&children);
+
+    // Rigid-bodies fall asleep after resting for two seconds.
+    for _ in 0..180 {
+        app.update();
+    }
+    app
+}
+
+fn sleeping_bodies(app: &App) -> usize {
+    let context = app.world.resource::<RapierContext>();
+    let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+    world
+        .bodies
+        .iter()
+        .filter(|(_, body)| body.is_dynamic() && body.is_sleeping())
+        .count()
+}
+
+fn resting_children(c: &mut Criterion) {
+    let mut app = setup();
+
+    let sleeping = sleeping_bodies(&app);
+    println!("Sleeping child rigid-bodies: {sleeping}/{NUM_CHILDREN}");
+    assert_eq!(sleeping, NUM_CHILDREN);
+
+    c.bench_function("step 1k resting child bodies", |b| b.iter(|| app.update()));
+}
+
+criterion_group!(benches, resting_children);
+criterion_main!(benches);
@@ -0,0 +1,91 @@
+//! Moves 1k non-uniformly scaled trimesh colliders every frame.
+//!
+//! Moving a collider doesn’t change its scale, so its scaled shape must neither be cloned nor
+//! recomputed.
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use bevy_rapier3d::rapier::geometry::SharedShape;
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::sync::Arc;
+
+mod common;
+
+const NUM_COLLIDERS: usize = 1_000;
+const GRID_SIZE: u32 = 16;
+
+/// A grid of `GRID_SIZE * GRID_SIZE` quads.
+fn grid_trimesh() -> Collider {
+    let vertices = (0..=GRID_SIZE)
+        .flat_map(|i| (0..=GRID_SIZE).map(move |j| Vec3::new(i as f32, 0.0, j as f32)))
+        .collect();
+    let index = |i: u32, j: u32| i * (GRID_SIZE + 1) + j;
+    let indices = (0..GRID_SIZE)
+        .flat_map(|i| (0..GRID_SIZE).map(move |j| (i, j)))
+        .flat_map(|(i, j)| {
+            [
+                [index(i, j), index(i + 1, j), index(i, j + 1)],
+                [index(i + 1, j), index(i + 1, j + 1), index(i, j + 1)],
+            ]
+        })
+        .collect();
+    Collider::trimesh(vertices, indices)
+}
+
+fn move_colliders(mut transforms: Query<&mut Transform, With<Collider>>, mut frame: Local<u32>) {
+    *frame += 1;
+    for mut transform in transforms.iter_mut() {
+        transform.translation.y = (*frame % 100) as f32;
+    }
+}
+
+fn setup() -> App {
+    let mut app = common::bench_app();
+    app.add_systems(Update, move_colliders);
+
+    let trimesh = grid_trimesh();
+    for k in 0..NUM_COLLIDERS {
+        let x = (k % 32) as f32 * 20.0;
+        let z = (k / 32) as f32 * 20.0;
+        app.world.spawn((
+            TransformBundle::from(
+                Transform::from_xyz(x, 0.0, z).with_scale(Vec3::new(1.0, 2.0, 0.5)),
+            ),
+            trimesh.clone(),
+        ));
+    }
+
+    // Let the colliders be scaled and created.
+    app.update();
+    app.update();
+    app
+}
+
+fn scaled_shapes(app: &mut App) -> Vec<SharedShape> {
+    app.world
+        .query::<&Collider>()
+        .iter(&app.world)
+        .map(|collider| collider.raw.clone())
+        .collect()
+}
+
+fn moving_scaled_trimeshes(c: &mut Criterion) {
+    let mut app = setup();
+
+    let before = scaled_shapes(&mut app);
+    for _ in 0..10 {
+        app.update();
+    }
+    let rescaled = scaled_shapes(&mut app)
+        .iter()
+        .zip(&before)
+        .filter(|(after, before)| !Arc::ptr_eq(&after.0, &before.0))
+        .count();
+    println!("Scaled shapes recomputed while moving: {rescaled}");
+    assert_eq!(rescaled, 0);
+
+    c.bench_function("move 1k scaled trimeshes", |b| b.iter(|| app.update()));
+}
+
+criterion_group!(benches, moving_scaled_trimeshes);
+criterion_main!(benches);
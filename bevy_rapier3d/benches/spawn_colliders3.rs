@@ -0,0 +1,49 @@
+//! Spawns 50k colliders in a single frame, with and without reserving the maps between entities
+//! and Rapier handles beforehand.
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+
+mod common;
+
+const NUM_COLLIDERS: usize = 50_000;
+
+fn setup(reserve: bool) -> App {
+    let mut app = common::bench_app();
+    if reserve {
+        app.world
+            .resource_mut::<RapierContext>()
+            .get_world_mut(DEFAULT_WORLD_ID)
+            .unwrap()
+            .reserve(0, NUM_COLLIDERS);
+    }
+
+    for k in 0..NUM_COLLIDERS {
+        let x = (k % 256) as f32 * 2.0;
+        let z = (k / 256) as f32 * 2.0;
+        app.world.spawn((
+            TransformBundle::from(Transform::from_xyz(x, 0.0, z)),
+            Collider::ball(0.5),
+        ));
+    }
+    app
+}
+
+fn spawn_colliders(c: &mut Criterion) {
+    let mut group = c.benchmark_group("spawn 50k colliders");
+    group.sample_size(10);
+    for (name, reserve) in [("default", false), ("reserved", true)] {
+        group.bench_function(name, |b| {
+            b.iter_batched(
+                || setup(reserve),
+                |mut app| app.update(),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, spawn_colliders);
+criterion_main!(benches);
@@ -0,0 +1,56 @@
+//! Steps worlds mixing moving rigid-bodies with sleeping ones.
+//!
+//! Only the moving rigid-bodies are written back, so the cost of a frame must scale with the
+//! number of active rigid-bodies rather than with the total number of rigid-bodies.
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+mod common;
+
+fn setup(num_active: usize, num_bodies: usize) -> App {
+    let mut app = common::bench_app();
+
+    for k in 0..num_bodies {
+        let x = (k % 128) as f32 * 2.0;
+        let z = (k / 128) as f32 * 2.0;
+        let (velocity, sleeping) = if k < num_active {
+            (Velocity::linear(Vec3::Y), Sleeping::disabled())
+        } else {
+            (
+                Velocity::zero(),
+                Sleeping {
+                    sleeping: true,
+                    ..default()
+                },
+            )
+        };
+        app.world.spawn((
+            TransformBundle::from(Transform::from_xyz(x, 0.0, z)),
+            RigidBody::Dynamic,
+            Collider::ball(0.5),
+            GravityScale(0.0),
+            velocity,
+            sleeping,
+        ));
+    }
+
+    app.update();
+    app
+}
+
+fn writeback(c: &mut Criterion) {
+    let mut group = c.benchmark_group("writeback");
+    for (num_active, num_bodies) in [(100, 1_000), (100, 20_000), (1_000, 20_000)] {
+        let mut app = setup(num_active, num_bodies);
+        group.bench_function(
+            BenchmarkId::from_parameter(format!("{num_active} active / {num_bodies} bodies")),
+            |b| b.iter(|| app.update()),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, writeback);
+criterion_main!(benches);
@@ -14,6 +14,7 @@ fn main() {
             RapierDebugRenderPlugin::default(),
         ))
         .add_systems(Startup, (setup_graphics, setup_physics))
+        .add_systems(Update, toggle_debug_render)
         .run();
 }
 
@@ -25,6 +26,34 @@ pub fn setup_graphics(mut commands: Commands) {
     });
 }
 
+/// Press C to show the contacts between the boxes, A to show their AABBs, and B to show the
+/// bounding volume hierarchy of the query pipeline.
+fn toggle_debug_render(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut render_context: ResMut<DebugRenderContext>,
+) {
+    if keys.just_pressed(KeyCode::KeyC) {
+        render_context
+            .pipeline
+            .mode
+            .toggle(DebugRenderMode::CONTACTS);
+    }
+
+    if keys.just_pressed(KeyCode::KeyA) {
+        render_context
+            .pipeline
+            .mode
+            .toggle(DebugRenderMode::COLLIDER_AABBS);
+    }
+
+    if keys.just_pressed(KeyCode::KeyB) {
+        render_context.bvh_max_depth = match render_context.bvh_max_depth {
+            Some(_) => None,
+            None => Some(3),
+        };
+    }
+}
+
 pub fn setup_physics(mut commands: Commands) {
     /*
      * Ground
@@ -0,0 +1,127 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(
+            0xF9 as f32 / 255.0,
+            0xF9 as f32 / 255.0,
+            0xFF as f32 / 255.0,
+        )))
+        .add_plugins((
+            DefaultPlugins,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+            RapierDebugRenderPlugin::default(),
+        ))
+        .add_systems(Startup, (setup_graphics, setup_physics))
+        .add_systems(Update, (character_movement, push_boxes))
+        .run();
+}
+
+/// The character controlled with the arrow keys.
+#[derive(Component)]
+pub struct Player;
+
+/// A box periodically pushed toward the character.
+#[derive(Component)]
+pub struct Pusher;
+
+pub fn setup_graphics(mut commands: Commands) {
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(0.0, 10.0, 15.0).looking_at(Vec3::ZERO, Vec3::Y),
+        ..Default::default()
+    });
+}
+
+pub fn setup_physics(mut commands: Commands) {
+    /*
+     * The ground
+     */
+    commands.spawn((
+        TransformBundle::from(Transform::from_xyz(0.0, -0.1, 0.0)),
+        Collider::cuboid(20.0, 0.1, 20.0),
+    ));
+
+    /*
+     * A few steps the character can climb with autostepping.
+     */
+    for i in 0..3 {
+        let height = 0.1 * (i + 1) as f32;
+        commands.spawn((
+            TransformBundle::from(Transform::from_xyz(-4.0 - i as f32, height, 0.0)),
+            Collider::cuboid(0.5, height, 2.0),
+        ));
+    }
+
+    /*
+     * The dynamic character.
+     */
+    commands.spawn((
+        TransformBundle::from(Transform::from_xyz(0.0, 1.0, 0.0)),
+        RigidBody::Dynamic,
+        Velocity::zero(),
+        LockedAxes::ROTATION_LOCKED,
+        Collider::capsule_y(0.3, 0.3),
+        KinematicCharacterController {
+            apply_mode: CharacterApplyMode::Velocity,
+            autostep: Some(CharacterAutostep {
+                max_height: CharacterLength::Absolute(0.3),
+                min_width: CharacterLength::Absolute(0.2),
+                include_dynamic_bodies: false,
+            }),
+            ..Default::default()
+        },
+        Player,
+    ));
+
+    /*
+     * Boxes pushing the character around.
+     */
+    for z in [-1.0, 1.0] {
+        commands.spawn((
+            TransformBundle::from(Transform::from_xyz(5.0, 0.5, z)),
+            RigidBody::Dynamic,
+            Collider::cuboid(0.5, 0.5, 0.5),
+            ExternalImpulse::default(),
+            Pusher,
+        ));
+    }
+}
+
+pub fn character_movement(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    mut controllers: Query<&mut KinematicCharacterController, With<Player>>,
+) {
+    let left = keyboard_input.pressed(KeyCode::ArrowLeft) as i8;
+    let right = keyboard_input.pressed(KeyCode::ArrowRight) as i8;
+    let forward = keyboard_input.pressed(KeyCode::ArrowUp) as i8;
+    let backward = keyboard_input.pressed(KeyCode::ArrowDown) as i8;
+
+    let input = Vec3::new((right - left) as f32, 0.0, (backward - forward) as f32);
+
+    for mut controller in controllers.iter_mut() {
+        // Gravity is applied by the physics engine since the character is dynamic.
+        controller.translation = Some(input * 3.0 * time.delta_seconds());
+    }
+}
+
+pub fn push_boxes(
+    time: Res<Time>,
+    mut pushers: Query<(&GlobalTransform, &mut ExternalImpulse), With<Pusher>>,
+    player: Query<&GlobalTransform, With<Player>>,
+) {
+    let Ok(player) = player.get_single() else {
+        return;
+    };
+
+    // Every three seconds, throw the boxes at the character.
+    if time.elapsed_seconds() % 3.0 > time.delta_seconds() {
+        return;
+    }
+
+    for (transform, mut impulse) in pushers.iter_mut() {
+        let direction = (player.translation() - transform.translation()).normalize_or_zero();
+        impulse.impulse = direction * 5.0;
+    }
+}
@@ -0,0 +1,111 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(
+            0xF9 as f32 / 255.0,
+            0xF9 as f32 / 255.0,
+            0xFF as f32 / 255.0,
+        )))
+        .add_plugins((
+            DefaultPlugins,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+            RapierDebugRenderPlugin::default(),
+        ))
+        .add_systems(Startup, (setup_graphics, setup_physics))
+        .add_systems(Update, (move_platforms, character_movement))
+        .run();
+}
+
+/// A platform going back and forth along the `x` axis.
+#[derive(Component)]
+pub struct TranslatingPlatform {
+    origin: Vec3,
+}
+
+/// The character controlled with the arrow keys.
+#[derive(Component)]
+pub struct Player;
+
+pub fn setup_graphics(mut commands: Commands) {
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(0.0, 12.0, 20.0).looking_at(Vec3::ZERO, Vec3::Y),
+        ..Default::default()
+    });
+}
+
+pub fn setup_physics(mut commands: Commands) {
+    /*
+     * The ground
+     */
+    commands.spawn((
+        TransformBundle::from(Transform::from_xyz(0.0, -0.1, 0.0)),
+        Collider::cuboid(20.0, 0.1, 20.0),
+    ));
+
+    /*
+     * A platform moved by setting its position.
+     */
+    let origin = Vec3::new(-5.0, 1.0, 0.0);
+    commands.spawn((
+        TransformBundle::from(Transform::from_translation(origin)),
+        RigidBody::KinematicPositionBased,
+        Collider::cuboid(2.0, 0.2, 2.0),
+        TranslatingPlatform { origin },
+    ));
+
+    /*
+     * A platform rotating around the `y` axis.
+     */
+    commands.spawn((
+        TransformBundle::from(Transform::from_xyz(5.0, 1.0, 0.0)),
+        RigidBody::KinematicVelocityBased,
+        Velocity::angular(Vec3::Y * 0.5),
+        Collider::cuboid(3.0, 0.2, 3.0),
+    ));
+
+    /*
+     * The characters, one standing on each platform.
+     */
+    for position in [Vec3::new(-5.0, 2.0, 0.0), Vec3::new(7.0, 2.0, 0.0)] {
+        commands.spawn((
+            TransformBundle::from(Transform::from_translation(position)),
+            Collider::capsule_y(0.3, 0.3),
+            KinematicCharacterController {
+                follow_ground: true,
+                ..Default::default()
+            },
+            Player,
+        ));
+    }
+}
+
+pub fn move_platforms(
+    time: Res<Time>,
+    mut platforms: Query<(&TranslatingPlatform, &mut Transform)>,
+) {
+    for (platform, mut transform) in platforms.iter_mut() {
+        transform.translation =
+            platform.origin + Vec3::X * (time.elapsed_seconds() * 0.5).sin() * 3.0;
+    }
+}
+
+pub fn character_movement(
+    time: Res<Time>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut controllers: Query<&mut KinematicCharacterController, With<Player>>,
+) {
+    let left = keyboard_input.pressed(KeyCode::ArrowLeft) as i8;
+    let right = keyboard_input.pressed(KeyCode::ArrowRight) as i8;
+    let forward = keyboard_input.pressed(KeyCode::ArrowUp) as i8;
+    let backward = keyboard_input.pressed(KeyCode::ArrowDown) as i8;
+
+    let input = Vec3::new((right - left) as f32, 0.0, (backward - forward) as f32);
+    // Apply some gravity so the characters stay on their platforms.
+    let movement = (input * 3.0 - Vec3::Y * 9.81) * time.delta_seconds();
+
+    for mut controller in controllers.iter_mut() {
+        controller.translation = Some(movement);
+    }
+}
@@ -0,0 +1,73 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(
+            0xF9 as f32 / 255.0,
+            0xF9 as f32 / 255.0,
+            0xFF as f32 / 255.0,
+        )))
+        .add_plugins((
+            DefaultPlugins,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+            RapierDebugRenderPlugin::default(),
+        ))
+        .add_systems(Startup, (setup_graphics, setup_physics))
+        .add_systems(Update, drop_boxes)
+        .run();
+}
+
+pub fn setup_graphics(mut commands: Commands) {
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(0.0, 15.0, 25.0).looking_at(Vec3::ZERO, Vec3::Y),
+        ..Default::default()
+    });
+}
+
+pub fn setup_physics(mut commands: Commands) {
+    /*
+     * Ground
+     */
+    commands.spawn((
+        TransformBundle::from(Transform::from_xyz(0.0, -2.0, 0.0)),
+        Collider::cuboid(20.0, 0.1, 20.0),
+    ));
+
+    /*
+     * Four belts forming a loop. Each belt moves its surface along its local `X` axis, so
+     * rotating the belt is enough to change the direction it transports the boxes to.
+     */
+    for i in 0..4 {
+        let rotation = Quat::from_rotation_y(i as f32 * std::f32::consts::FRAC_PI_2);
+        let position = rotation * Vec3::new(0.0, 0.0, 5.0);
+
+        commands.spawn((
+            TransformBundle::from(Transform::from_translation(position).with_rotation(rotation)),
+            Collider::cuboid(5.0, 0.1, 1.0),
+            ConveyorBelt {
+                velocity: Vec3::X * 3.0,
+            },
+            ColliderDebugColor(Color::hsl(i as f32 * 90.0, 0.7, 0.5)),
+        ));
+    }
+}
+
+// Drops a box on one of the belts every second, up to a maximum number of boxes.
+fn drop_boxes(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut timer: Local<Option<Timer>>,
+    mut count: Local<usize>,
+) {
+    let timer = timer.get_or_insert_with(|| Timer::from_seconds(1.0, TimerMode::Repeating));
+
+    if *count < 20 && timer.tick(time.delta()).just_finished() {
+        *count += 1;
+        commands.spawn((
+            TransformBundle::from(Transform::from_xyz(0.0, 3.0, 5.0)),
+            RigidBody::Dynamic,
+            Collider::cuboid(0.4, 0.4, 0.4),
+        ));
+    }
+}
@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(
+            0xF9 as f32 / 255.0,
+            0xF9 as f32 / 255.0,
+            0xFF as f32 / 255.0,
+        )))
+        // A very low tick rate, to make the difference with the interpolated ball obvious.
+        .insert_resource(Time::<Fixed>::from_hz(10.0))
+        .add_plugins((
+            DefaultPlugins,
+            RapierPhysicsPlugin::<NoUserData>::default().in_fixed_schedule(),
+        ))
+        .add_systems(Startup, (setup_graphics, setup_physics))
+        .run();
+}
+
+pub fn setup_graphics(mut commands: Commands) {
+    commands.spawn(PointLightBundle {
+        transform: Transform::from_xyz(4.0, 10.0, 8.0),
+        ..Default::default()
+    });
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(0.0, 3.0, 15.0)
+            .looking_at(Vec3::new(0.0, 3.0, 0.0), Vec3::Y),
+        ..Default::default()
+    });
+}
+
+pub fn setup_physics(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    /*
+     * Ground
+     */
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Cuboid::new(20.0, 0.2, 20.0)),
+            material: materials.add(Color::GRAY),
+            transform: Transform::from_xyz(0.0, -0.1, 0.0),
+            ..Default::default()
+        },
+        Collider::cuboid(10.0, 0.1, 10.0),
+        Restitution::coefficient(1.0),
+    ));
+
+    /*
+     * Two bouncing balls: the one on the left is interpolated between the fixed steps, the one
+     * on the right only moves when the physics is stepped.
+     */
+    let mesh = meshes.add(Sphere::new(0.5));
+
+    for (x, interpolated) in [(-2.0, true), (2.0, false)] {
+        let mut ball = commands.spawn((
+            PbrBundle {
+                mesh: mesh.clone(),
+                material: materials.add(if interpolated {
+                    Color::SEA_GREEN
+                } else {
+                    Color::ORANGE_RED
+                }),
+                transform: Transform::from_xyz(x, 6.0, 0.0),
+                ..Default::default()
+            },
+            RigidBody::Dynamic,
+            Collider::ball(0.5),
+            Restitution::coefficient(1.0),
+        ));
+
+        if interpolated {
+            ball.insert(TransformInterpolation::default());
+        }
+    }
+}
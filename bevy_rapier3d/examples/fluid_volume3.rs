@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(
+            0xF9 as f32 / 255.0,
+            0xF9 as f32 / 255.0,
+            0xFF as f32 / 255.0,
+        )))
+        .add_plugins((
+            DefaultPlugins,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+            RapierDebugRenderPlugin::default(),
+        ))
+        .add_systems(Startup, (setup_graphics, setup_physics))
+        .run();
+}
+
+pub fn setup_graphics(mut commands: Commands) {
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(0.0, 8.0, 20.0).looking_at(Vec3::ZERO, Vec3::Y),
+        ..Default::default()
+    });
+}
+
+pub fn setup_physics(mut commands: Commands) {
+    /*
+     * Ground, at the bottom of the pool.
+     */
+    commands.spawn((
+        TransformBundle::from(Transform::from_xyz(0.0, -6.1, 0.0)),
+        Collider::cuboid(10.0, 0.1, 10.0),
+    ));
+
+    /*
+     * The water, whose surface is at `y = 0`, slowly flowing along `X`.
+     */
+    commands.spawn((
+        TransformBundle::from(Transform::from_xyz(0.0, -3.0, 0.0)),
+        Collider::cuboid(8.0, 3.0, 8.0),
+        Sensor,
+        FluidVolume {
+            flow_velocity: Vec3::X * 0.5,
+            ..Default::default()
+        },
+        ColliderDebugColor(Color::rgb(0.2, 0.4, 0.9)),
+    ));
+
+    /*
+     * Boxes of increasing densities dropped into the water: the boxes lighter than the water
+     * bob at the surface, the others sink to the bottom.
+     */
+    for (i, density) in [0.2, 0.5, 0.8, 1.5, 3.0].into_iter().enumerate() {
+        commands.spawn((
+            TransformBundle::from(Transform::from_xyz(i as f32 * 2.5 - 5.0, 4.0, -2.0)),
+            RigidBody::Dynamic,
+            Collider::cuboid(0.5, 0.5, 0.5),
+            ColliderMassProperties::Density(density),
+        ));
+    }
+}
@@ -0,0 +1,54 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(
+            0xF9 as f32 / 255.0,
+            0xF9 as f32 / 255.0,
+            0xFF as f32 / 255.0,
+        )))
+        .add_plugins((
+            DefaultPlugins,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+            RapierDebugRenderPlugin::default(),
+        ))
+        .add_systems(Startup, (setup_graphics, setup_physics))
+        .run();
+}
+
+pub fn setup_graphics(mut commands: Commands) {
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(0.0, 0.0, 25.0).looking_at(Vec3::ZERO, Vec3::Y),
+        ..Default::default()
+    });
+}
+
+pub fn setup_physics(mut commands: Commands) {
+    /*
+     * A floor and a ceiling.
+     */
+    for y in [-8.0, 8.0] {
+        commands.spawn((
+            TransformBundle::from(Transform::from_xyz(0.0, y, 0.0)),
+            Collider::cuboid(10.0, 0.1, 10.0),
+        ));
+    }
+
+    /*
+     * A sphere affected by the world gravity, and a sphere falling upward.
+     */
+    commands.spawn((
+        TransformBundle::from(Transform::from_xyz(-2.0, 0.0, 0.0)),
+        RigidBody::Dynamic,
+        Collider::ball(1.0),
+    ));
+
+    commands.spawn((
+        TransformBundle::from(Transform::from_xyz(2.0, 0.0, 0.0)),
+        RigidBody::Dynamic,
+        Collider::ball(1.0),
+        ColliderMassProperties::Density(5.0),
+        GravityOverride(Vec3::Y * 9.81),
+    ));
+}
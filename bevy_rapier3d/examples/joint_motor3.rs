@@ -0,0 +1,127 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(
+            0xF9 as f32 / 255.0,
+            0xF9 as f32 / 255.0,
+            0xFF as f32 / 255.0,
+        )))
+        .add_plugins((
+            DefaultPlugins,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+            RapierDebugRenderPlugin::default(),
+        ))
+        .add_systems(Startup, (setup_graphics, setup_physics))
+        .add_systems(Update, (update_target_angle, display_target_angle).chain())
+        .run();
+}
+
+/// The door rotated by the joint motor.
+#[derive(Component)]
+pub struct Door;
+
+/// The text displaying the door’s target angle.
+#[derive(Component)]
+pub struct TargetAngleText;
+
+pub fn setup_graphics(mut commands: Commands) {
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(4.0, 4.0, 8.0).looking_at(Vec3::new(0.0, 1.5, 0.0), Vec3::Y),
+        ..Default::default()
+    });
+
+    commands.spawn((
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: 24.0,
+                color: Color::BLACK,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            left: Val::Px(10.0),
+            ..default()
+        }),
+        TargetAngleText,
+    ));
+}
+
+pub fn setup_physics(mut commands: Commands) {
+    /*
+     * The ground
+     */
+    commands.spawn((
+        TransformBundle::from(Transform::from_xyz(0.0, -0.1, 0.0)),
+        Collider::cuboid(10.0, 0.1, 10.0),
+    ));
+
+    /*
+     * The door frame, holding the hinge.
+     */
+    let hinge = commands
+        .spawn((
+            TransformBundle::from(Transform::from_xyz(0.0, 1.5, 0.0)),
+            RigidBody::Fixed,
+            Collider::cuboid(0.1, 1.5, 0.1),
+        ))
+        .id();
+
+    /*
+     * The door, rotating around the hinge with a position-based motor.
+     */
+    let joint = RevoluteJointBuilder::new(Vec3::Y)
+        .local_anchor1(Vec3::new(0.1, 0.0, 0.0))
+        .local_anchor2(Vec3::new(-1.0, 0.0, 0.0));
+    commands.spawn((
+        TransformBundle::from(Transform::from_xyz(1.1, 1.5, 0.0)),
+        RigidBody::Dynamic,
+        Collider::cuboid(1.0, 1.4, 0.05),
+        ImpulseJoint::new(hinge, joint),
+        JointMotor::revolute(AxisMotor::position(0.0, 50.0, 10.0).max_force(100.0)),
+        Door,
+    ));
+}
+
+/// Use the left and right arrow keys to slide the target angle of the door.
+pub fn update_target_angle(
+    time: Res<Time>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut doors: Query<&mut JointMotor, With<Door>>,
+) {
+    let left = keyboard_input.pressed(KeyCode::ArrowLeft) as i8;
+    let right = keyboard_input.pressed(KeyCode::ArrowRight) as i8;
+    let delta = (right - left) as f32 * time.delta_seconds();
+
+    if delta == 0.0 {
+        return;
+    }
+
+    for mut motor in doors.iter_mut() {
+        if let Some(motor) = motor.axis_mut(JointAxis::AngX) {
+            motor.target_position = (motor.target_position + delta).clamp(-1.5, 1.5);
+        }
+    }
+}
+
+pub fn display_target_angle(
+    doors: Query<&JointMotor, (With<Door>, Changed<JointMotor>)>,
+    mut texts: Query<&mut Text, With<TargetAngleText>>,
+) {
+    for motor in doors.iter() {
+        let Some(motor) = motor.axis(JointAxis::AngX) else {
+            continue;
+        };
+
+        for mut text in texts.iter_mut() {
+            text.sections[0].value = format!(
+                "Target angle: {:.0}° (use the arrow keys)",
+                motor.target_position.to_degrees()
+            );
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(
+            0xF9 as f32 / 255.0,
+            0xF9 as f32 / 255.0,
+            0xFF as f32 / 255.0,
+        )))
+        .add_plugins((
+            DefaultPlugins,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+            RapierDebugRenderPlugin::default(),
+        ))
+        .add_systems(Startup, (setup_graphics, setup_physics))
+        .run();
+}
+
+pub fn setup_graphics(mut commands: Commands) {
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(0.0, 5.0, 20.0)
+            .looking_at(Vec3::new(0.0, 5.0, 0.0), Vec3::Y),
+        ..Default::default()
+    });
+}
+
+pub fn setup_physics(mut commands: Commands) {
+    /*
+     * A kinematic velocity-based child, carried upward by its dynamic parent.
+     * Its velocity is relative to the parent, so it stays at the same local offset.
+     */
+    let child = commands
+        .spawn((
+            TransformBundle::from(Transform::from_xyz(0.0, 2.0, 0.0)),
+            RigidBody::KinematicVelocityBased,
+            Collider::cuboid(0.5, 0.5, 0.5),
+            Velocity::zero(),
+        ))
+        .id();
+
+    commands
+        .spawn((
+            TransformBundle::from(Transform::from_xyz(0.0, 0.0, 0.0)),
+            RigidBody::Dynamic,
+            Collider::cuboid(1.0, 0.5, 1.0),
+            GravityScale(0.0),
+            Velocity::linear(Vec3::Y),
+        ))
+        .push_children(&[child]);
+}
@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(
+            0xF9 as f32 / 255.0,
+            0xF9 as f32 / 255.0,
+            0xFF as f32 / 255.0,
+        )))
+        .insert_resource(RapierConfiguration {
+            timestep_mode: TimestepMode::Manual {
+                dt: 1.0 / 60.0,
+                substeps: 1,
+            },
+            ..RapierConfiguration::new()
+        })
+        .add_plugins((
+            DefaultPlugins,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+            RapierDebugRenderPlugin::default(),
+        ))
+        .add_systems(Startup, (setup_graphics, setup_physics))
+        .add_systems(Update, step_on_space)
+        .run();
+}
+
+pub fn setup_graphics(mut commands: Commands) {
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(0.0, 3.0, 15.0)
+            .looking_at(Vec3::new(0.0, 3.0, 0.0), Vec3::Y),
+        ..Default::default()
+    });
+}
+
+pub fn setup_physics(mut commands: Commands) {
+    /*
+     * Ground
+     */
+    commands.spawn((
+        TransformBundle::from(Transform::from_xyz(0.0, -0.1, 0.0)),
+        Collider::cuboid(10.0, 0.1, 10.0),
+    ));
+
+    /*
+     * A pile of cubes falling on the ground.
+     */
+    for i in 0..5 {
+        commands.spawn((
+            TransformBundle::from(Transform::from_xyz(
+                0.2 * i as f32,
+                1.0 + 1.5 * i as f32,
+                0.0,
+            )),
+            RigidBody::Dynamic,
+            Collider::cuboid(0.5, 0.5, 0.5),
+        ));
+    }
+}
+
+/// Advances the simulation by one step each time Space is pressed.
+pub fn step_on_space(keys: Res<ButtonInput<KeyCode>>, mut context: ResMut<RapierContext>) {
+    if keys.just_pressed(KeyCode::Space) {
+        context.request_step(1);
+    }
+}
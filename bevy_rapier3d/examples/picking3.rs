@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+use bevy_mod_picking::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(
+            0xF9 as f32 / 255.0,
+            0xF9 as f32 / 255.0,
+            0xFF as f32 / 255.0,
+        )))
+        .add_plugins((
+            DefaultPlugins,
+            DefaultPickingPlugins,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+            RapierDebugRenderPlugin::default(),
+            RapierPickingPlugin,
+        ))
+        // Only the cubes are pickable, not the ground.
+        .insert_resource(RapierPickingSettings {
+            require_markers: true,
+            ..Default::default()
+        })
+        .add_systems(Startup, (setup_graphics, setup_physics))
+        .add_systems(Update, toggle_clicked_cubes)
+        .run();
+}
+
+pub fn setup_graphics(mut commands: Commands) {
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(0.0, 8.0, 20.0)
+            .looking_at(Vec3::new(0.0, 2.0, 0.0), Vec3::Y),
+        ..Default::default()
+    });
+}
+
+pub fn setup_physics(mut commands: Commands) {
+    /*
+     * Ground
+     */
+    commands.spawn((
+        TransformBundle::from(Transform::from_xyz(0.0, -0.1, 0.0)),
+        Collider::cuboid(10.0, 0.1, 10.0),
+    ));
+
+    /*
+     * Clickable cubes
+     */
+    for i in 0..5 {
+        commands.spawn((
+            TransformBundle::from(Transform::from_xyz(i as f32 * 3.0 - 6.0, 4.0, 0.0)),
+            RigidBody::Dynamic,
+            Collider::cuboid(1.0, 1.0, 1.0),
+            RapierPickable,
+        ));
+    }
+}
+
+/// Clicking a cube toggles its color, and makes it jump.
+fn toggle_clicked_cubes(
+    mut commands: Commands,
+    mut clicks: EventReader<Pointer<Click>>,
+    colors: Query<(), With<ColliderDebugColor>>,
+) {
+    for click in clicks.read() {
+        let mut cube = commands.entity(click.target);
+
+        if colors.contains(click.target) {
+            cube.remove::<ColliderDebugColor>();
+        } else {
+            cube.insert(ColliderDebugColor(Color::BLUE));
+        }
+
+        cube.insert(ExternalImpulse {
+            impulse: Vec3::Y * 20.0,
+            ..Default::default()
+        });
+    }
+}
@@ -0,0 +1,104 @@
+use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+#[derive(Resource)]
+struct SpawnedRagdoll(Ragdoll);
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(
+            0xF9 as f32 / 255.0,
+            0xF9 as f32 / 255.0,
+            0xFF as f32 / 255.0,
+        )))
+        .add_plugins((
+            DefaultPlugins,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+            RapierDebugRenderPlugin::default(),
+        ))
+        .add_systems(Startup, (setup_graphics, setup_physics))
+        .add_systems(Update, toggle_kinematic)
+        .run();
+}
+
+pub fn setup_graphics(mut commands: Commands) {
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(0.0, 4.0, 8.0).looking_at(Vec3::new(0.0, 2.0, 0.0), Vec3::Y),
+        ..Default::default()
+    });
+}
+
+pub fn setup_physics(mut commands: Commands) {
+    /*
+     * Ground
+     */
+    commands.spawn((
+        TransformBundle::from(Transform::from_xyz(0.0, -0.1, 0.0)),
+        Collider::cuboid(10.0, 0.1, 10.0),
+    ));
+
+    /*
+     * A ragdoll made of five bones, each one a capsule extending along its local `Y` axis.
+     */
+    let bone_transforms = [
+        // Pelvis
+        Transform::from_xyz(0.0, 3.0, 0.0),
+        // Torso
+        Transform::from_xyz(0.0, 3.5, 0.0),
+        // Head
+        Transform::from_xyz(0.0, 4.5, 0.0),
+        // Right arm, pointing along `+X`.
+        Transform::from_xyz(0.4, 4.0, 0.0).with_rotation(Quat::from_rotation_z(-FRAC_PI_2)),
+        // Left arm, pointing along `-X`.
+        Transform::from_xyz(-0.4, 4.0, 0.0).with_rotation(Quat::from_rotation_z(FRAC_PI_2)),
+    ];
+    let [pelvis, torso, head, right_arm, left_arm] =
+        bone_transforms.map(|transform| commands.spawn(TransformBundle::from(transform)).id());
+    let bone = |entity, index: usize, length, radius| {
+        RagdollBone::new(entity, bone_transforms[index].into(), length, radius)
+    };
+
+    // The ragdoll starts above the ground and falls onto it.
+    let ragdoll = RagdollBuilder::new()
+        .bone(bone(pelvis, 0, 0.2, 0.25))
+        .bone(
+            bone(torso, 1, 0.6, 0.25)
+                .parent(pelvis)
+                .swing_limits([-FRAC_PI_4, FRAC_PI_4])
+                .twist_limits([-0.2, 0.2]),
+        )
+        .bone(
+            bone(head, 2, 0.1, 0.2)
+                .parent(torso)
+                .swing_limits([-0.5, 0.5])
+                .twist_limits([-0.5, 0.5]),
+        )
+        .bone(
+            bone(right_arm, 3, 0.7, 0.1)
+                .parent(torso)
+                .swing_limits([-FRAC_PI_2, FRAC_PI_2]),
+        )
+        .bone(
+            bone(left_arm, 4, 0.7, 0.1)
+                .parent(torso)
+                .swing_limits([-FRAC_PI_2, FRAC_PI_2]),
+        )
+        .spawn(&mut commands);
+
+    commands.insert_resource(SpawnedRagdoll(ragdoll));
+}
+
+/// Freezes the ragdoll while the space bar is held.
+fn toggle_kinematic(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    ragdoll: Res<SpawnedRagdoll>,
+) {
+    if keys.just_pressed(KeyCode::Space) {
+        ragdoll.0.set_kinematic(&mut commands);
+    } else if keys.just_released(KeyCode::Space) {
+        ragdoll.0.set_dynamic(&mut commands);
+    }
+}
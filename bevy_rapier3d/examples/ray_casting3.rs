@@ -76,6 +76,7 @@ pub fn cast_ray(
     mut commands: Commands,
     windows: Query<&Window, With<PrimaryWindow>>,
     rapier_context: Res<RapierContext>,
+    mut debug_casts: ResMut<DebugCastBuffer>,
     cameras: Query<(&Camera, &GlobalTransform)>,
 ) {
     let window = windows.single();
@@ -91,10 +92,11 @@ pub fn cast_ray(
             return;
         };
 
-        // Then cast the ray.
+        // Then cast the ray. The debug-renderer draws the hit point and normal during this frame.
         let hit = rapier_context
-            .cast_ray(
+            .cast_ray_debug(
                 DEFAULT_WORLD_ID,
+                &mut debug_casts,
                 ray.origin,
                 *ray.direction,
                 f32::MAX,
@@ -103,7 +105,7 @@ pub fn cast_ray(
             )
             .expect("Default world should exist.");
 
-        if let Some((entity, _toi)) = hit {
+        if let Some((entity, _intersection)) = hit {
             // Color in blue the entity we just hit.
             // Because of the query filter, only colliders attached to a dynamic body
             // will get an event.
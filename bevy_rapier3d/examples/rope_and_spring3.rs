@@ -0,0 +1,68 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(
+            0xF9 as f32 / 255.0,
+            0xF9 as f32 / 255.0,
+            0xFF as f32 / 255.0,
+        )))
+        .add_plugins((
+            DefaultPlugins,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+            RapierDebugRenderPlugin::default(),
+        ))
+        .add_systems(Startup, (setup_graphics, setup_physics))
+        .run();
+}
+
+pub fn setup_graphics(mut commands: Commands) {
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(0.0, 3.0, 12.0)
+            .looking_at(Vec3::new(0.0, 2.0, 0.0), Vec3::Y),
+        ..Default::default()
+    });
+}
+
+pub fn setup_physics(mut commands: Commands) {
+    let rad = 0.4;
+
+    /*
+     * A box dangling from a rope.
+     */
+    let rope_anchor = commands
+        .spawn((
+            TransformBundle::from(Transform::from_xyz(-3.0, 5.0, 0.0)),
+            RigidBody::Fixed,
+            Collider::cuboid(rad, rad, rad),
+        ))
+        .id();
+
+    let rope = RopeJointBuilder::new(3.0).local_anchor2(Vec3::new(0.0, rad, 0.0));
+    commands.spawn((
+        TransformBundle::from(Transform::from_xyz(-1.0, 5.0, 0.0)),
+        RigidBody::Dynamic,
+        Collider::cuboid(rad, rad, rad),
+        ImpulseJoint::new(rope_anchor, rope),
+    ));
+
+    /*
+     * A box oscillating on a spring.
+     */
+    let spring_anchor = commands
+        .spawn((
+            TransformBundle::from(Transform::from_xyz(3.0, 5.0, 0.0)),
+            RigidBody::Fixed,
+            Collider::cuboid(rad, rad, rad),
+        ))
+        .id();
+
+    let spring = SpringJointBuilder::new(2.0, 20.0, 0.1).local_anchor2(Vec3::new(0.0, rad, 0.0));
+    commands.spawn((
+        TransformBundle::from(Transform::from_xyz(3.0, 4.0, 0.0)),
+        RigidBody::Dynamic,
+        Collider::cuboid(rad, rad, rad),
+        ImpulseJoint::new(spring_anchor, spring),
+    ));
+}
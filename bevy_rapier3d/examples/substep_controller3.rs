@@ -0,0 +1,90 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+// A body pushed toward a target height by a PD controller running at each substep.
+#[derive(Component)]
+struct Hover {
+    height: f32,
+}
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(
+            0xF9 as f32 / 255.0,
+            0xF9 as f32 / 255.0,
+            0xFF as f32 / 255.0,
+        )))
+        .insert_resource(RapierConfiguration {
+            timestep_mode: TimestepMode::Fixed {
+                dt: 1.0 / 60.0,
+                substeps: 8,
+            },
+            ..RapierConfiguration::new()
+        })
+        .add_plugins((
+            DefaultPlugins,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+            RapierDebugRenderPlugin::default(),
+        ))
+        .add_systems(Startup, (setup_graphics, setup_physics))
+        .add_systems(PhysicsSubstepSchedule, hover_controller)
+        .run();
+}
+
+pub fn setup_graphics(mut commands: Commands) {
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(0.0, 4.0, 12.0)
+            .looking_at(Vec3::new(0.0, 2.0, 0.0), Vec3::Y),
+        ..Default::default()
+    });
+}
+
+pub fn setup_physics(mut commands: Commands) {
+    /*
+     * Ground
+     */
+    commands.spawn((
+        TransformBundle::from(Transform::from_xyz(0.0, -0.1, 0.0)),
+        Collider::cuboid(10.0, 0.1, 10.0),
+    ));
+
+    /*
+     * Hovering bodies, with different target heights.
+     */
+    for (i, height) in [1.0, 2.5, 4.0].into_iter().enumerate() {
+        commands.spawn((
+            TransformBundle::from(Transform::from_xyz(i as f32 * 3.0 - 3.0, 0.5, 0.0)),
+            RigidBody::Dynamic,
+            Collider::cuboid(0.5, 0.5, 0.5),
+            Hover { height },
+        ));
+    }
+}
+
+// Runs after each substep, so the thrust reacts to the state of the simulation at the rate of
+// the substeps rather than once per frame.
+fn hover_controller(
+    mut context: ResMut<RapierContext>,
+    bodies: Query<(&RapierRigidBodyHandle, &Hover)>,
+) {
+    const STIFFNESS: f32 = 40.0;
+    const DAMPING: f32 = 8.0;
+
+    let Ok(world) = context.get_world_mut(DEFAULT_WORLD_ID) else {
+        return;
+    };
+    let gravity = world.gravity;
+
+    for (handle, hover) in bodies.iter() {
+        let Some(body) = world.bodies.get_mut(handle.0) else {
+            continue;
+        };
+
+        let error = hover.height - body.translation().y;
+        let acceleration = STIFFNESS * error - DAMPING * body.linvel().y - gravity.y;
+        let thrust = Vec3::Y * acceleration * body.mass();
+
+        body.reset_forces(false);
+        body.add_force(thrust.into(), true);
+    }
+}
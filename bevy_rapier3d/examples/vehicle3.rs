@@ -0,0 +1,101 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::rgb(
+            0xF9 as f32 / 255.0,
+            0xF9 as f32 / 255.0,
+            0xFF as f32 / 255.0,
+        )))
+        .add_plugins((
+            DefaultPlugins,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+            RapierDebugRenderPlugin::default(),
+        ))
+        .add_systems(Startup, (setup_graphics, setup_physics))
+        .add_systems(Update, drive)
+        .run();
+}
+
+pub fn setup_graphics(mut commands: Commands) {
+    commands.spawn(Camera3dBundle {
+        transform: Transform::from_xyz(-10.0, 8.0, 10.0).looking_at(Vec3::ZERO, Vec3::Y),
+        ..Default::default()
+    });
+}
+
+pub fn setup_physics(mut commands: Commands) {
+    /*
+     * Ground
+     */
+    commands.spawn((
+        TransformBundle::from(Transform::from_xyz(0.0, -0.1, 0.0)),
+        Collider::cuboid(50.0, 0.1, 50.0),
+    ));
+
+    /*
+     * A few obstacles to drive into.
+     */
+    for i in 0..5 {
+        commands.spawn((
+            TransformBundle::from(Transform::from_xyz(8.0, 0.5, i as f32 * 1.5 - 3.0)),
+            RigidBody::Dynamic,
+            Collider::cuboid(0.5, 0.5, 0.5),
+        ));
+    }
+
+    /*
+     * The car: the chassis moves forward along its local `X` axis, and its wheels are its
+     * children.
+     */
+    commands
+        .spawn((
+            TransformBundle::from(Transform::from_xyz(0.0, 1.0, 0.0)),
+            RigidBody::Dynamic,
+            Collider::cuboid(1.0, 0.25, 0.5),
+            RaycastVehicle::default(),
+        ))
+        .with_children(|children| {
+            for (x, z) in [(0.8, 0.5), (0.8, -0.5), (-0.8, 0.5), (-0.8, -0.5)] {
+                let mut wheel =
+                    VehicleWheel::new(Vec3::new(x, -0.25, z), 0.3).suspension(0.3, 20.0, 2.0);
+                // Front-wheel steering, rear-wheel drive.
+                wheel = if x > 0.0 {
+                    wheel.steered()
+                } else {
+                    wheel.driven()
+                };
+                children.spawn((TransformBundle::default(), wheel));
+            }
+        });
+}
+
+/// Drives the car with the arrow keys, and brakes with the space bar.
+fn drive(keys: Res<ButtonInput<KeyCode>>, mut vehicles: Query<&mut RaycastVehicle>) {
+    for mut vehicle in vehicles.iter_mut() {
+        let mut engine_force = 0.0;
+        if keys.pressed(KeyCode::ArrowUp) {
+            engine_force += 20.0;
+        }
+        if keys.pressed(KeyCode::ArrowDown) {
+            engine_force -= 20.0;
+        }
+
+        let mut steering = 0.0;
+        if keys.pressed(KeyCode::ArrowLeft) {
+            steering += 0.5;
+        }
+        if keys.pressed(KeyCode::ArrowRight) {
+            steering -= 0.5;
+        }
+
+        vehicle.engine_force = engine_force;
+        vehicle.steering = steering;
+        vehicle.brake = if keys.pressed(KeyCode::Space) {
+            1.0
+        } else {
+            0.0
+        };
+    }
+}
@@ -1,11 +1,14 @@
 use crate::geometry::{Collider, CollisionGroups, ShapeCastHit};
 use crate::math::{Real, Rot, Vect};
 use crate::plugin::context::RapierWorld;
+use bevy::ecs::entity::{EntityMapper, MapEntities};
+use bevy::ecs::reflect::ReflectMapEntities;
 use bevy::prelude::*;
 
 pub use rapier::control::CharacterAutostep;
 pub use rapier::control::CharacterLength;
-use rapier::prelude::{ColliderSet, QueryFilterFlags};
+use rapier::control::EffectiveCharacterMovement;
+use rapier::prelude::{ColliderSet, QueryFilterFlags, Vector};
 
 /// A collision between the character and its environment during its movement.
 #[derive(Copy, Clone, PartialEq, Debug)]
@@ -106,8 +109,31 @@ impl Default for MoveShapeOptions {
     }
 }
 
+/// How the movement computed by a [`KinematicCharacterController`] is applied to the character.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Default, PartialEq)]
+pub enum CharacterApplyMode {
+    /// The movement is added to the character’s `Transform`. This is suitable for
+    /// kinematic rigid-bodies and free-standing colliders.
+    #[default]
+    Translation,
+    /// The movement is converted into the linear velocity of the character’s rigid-body.
+    ///
+    /// This lets a `RigidBody::Dynamic` character use the sliding, autostepping, and
+    /// snapping features of the character controller while still reacting to impulses.
+    /// While airborne, the velocity of the body along the `up` direction is preserved, and the
+    /// movement along `up` is ignored.
+    /// The entity of the character’s rigid-body needs a [`Velocity`](crate::dynamics::Velocity)
+    /// component.
+    Velocity,
+}
+
 /// A character controller for kinematic bodies and free-standing colliders.
-#[derive(Clone, Debug, Component)] // TODO: Reflect
+///
+/// The `offset`, `autostep`, `snap_to_ground`, and `filter_flags` fields hold Rapier types, so
+/// they aren’t reflected.
+#[derive(Clone, Debug, Component, Reflect)]
+#[reflect(Component, Default)]
 pub struct KinematicCharacterController {
     /// The translations we desire the character to move by if it doesn’t meet any obstacle.
     pub translation: Option<Vect>,
@@ -122,14 +148,19 @@ pub struct KinematicCharacterController {
     pub custom_mass: Option<Real>,
     /// The direction that goes "up". Used to determine where the floor is, and the floor’s angle.
     pub up: Vect,
+    /// If `true`, `up` is ignored and the opposite of the gravity of the [`RapierWorld`] the
+    /// character is part of is used instead. Nothing changes if that gravity is zero.
+    pub up_from_gravity: bool,
     /// A small gap to preserve between the character and its surroundings.
     ///
     /// This value should not be too large to avoid visual artifacts, but shouldn’t be too small
     /// (must not be zero) to improve numerical stability of the character controller.
+    #[reflect(ignore)]
     pub offset: CharacterLength,
     /// Should the character try to slide against the floor if it hits it?
     pub slide: bool,
     /// Should the character automatically step over small obstacles?
+    #[reflect(ignore)]
     pub autostep: Option<CharacterAutostep>,
     /// The maximum angle (radians) between the floor’s normal and the `up` vector that the
     /// character is able to climb.
@@ -141,9 +172,11 @@ pub struct KinematicCharacterController {
     pub apply_impulse_to_dynamic_bodies: bool,
     /// Should the character be automatically snapped to the ground if the distance between
     /// the ground and its feet are smaller than the specified threshold?
+    #[reflect(ignore)]
     pub snap_to_ground: Option<CharacterLength>,
     /// Flags for filtering-out some categories of entities from the environment seen by the
     /// character controller.
+    #[reflect(ignore)]
     pub filter_flags: QueryFilterFlags,
     /// Groups for filtering-out some colliders from the environment seen by the character
     /// controller.
@@ -157,6 +190,15 @@ pub struct KinematicCharacterController {
     /// This value should remain fairly small since it can introduce artificial "bumps" when sliding
     /// along a flat surface.
     pub normal_nudge_factor: Real,
+    /// Should the character be carried along by the rigid-body it is standing on?
+    ///
+    /// If `true`, the velocity of the ground (including its angular part) at the character’s
+    /// position is added to the character’s movement while it is grounded. This makes characters
+    /// follow moving platforms. The ground is detected every frame, so the character stops
+    /// inheriting the platform’s motion as soon as it steps off of it.
+    pub follow_ground: bool,
+    /// How the movement computed by the character controller is applied to the character.
+    pub apply_mode: CharacterApplyMode,
 }
 
 impl KinematicCharacterController {
@@ -188,6 +230,7 @@ impl Default for KinematicCharacterController {
             custom_shape: None,
             custom_mass: None,
             up: def.up.into(),
+            up_from_gravity: false,
             offset: def.offset,
             slide: def.slide,
             autostep: def.autostep,
@@ -198,16 +241,96 @@ impl Default for KinematicCharacterController {
             filter_flags: QueryFilterFlags::default() | QueryFilterFlags::EXCLUDE_SENSORS,
             filter_groups: None,
             normal_nudge_factor: def.normal_nudge_factor,
+            follow_ground: false,
+            apply_mode: CharacterApplyMode::Translation,
+        }
+    }
+}
+
+/// Filtering rules for the obstacles seen by a [`KinematicCharacterController`].
+///
+/// When this component is attached to the same entity as the character controller, it replaces
+/// the controller’s `filter_flags` and `filter_groups`. The collider and rigid-body of the
+/// character itself are always excluded.
+///
+/// The `flags` field holds a Rapier type, so it isn’t reflected. The `exclude` entities are
+/// remapped when the filter is spawned from a scene.
+#[derive(Clone, Debug, PartialEq, Component, Reflect)]
+#[reflect(Component, Default, MapEntities, PartialEq)]
+pub struct CharacterControllerFilter {
+    /// If set, only colliders with collision groups compatible with this one will
+    /// be considered as obstacles.
+    pub groups: Option<CollisionGroups>,
+    /// Flags indicating what particular type of colliders should be excluded.
+    #[reflect(ignore)]
+    pub flags: QueryFilterFlags,
+    /// Colliders, or rigid-bodies (with all their colliders), that are never considered as obstacles.
+    pub exclude: Vec<Entity>,
+    /// A predicate, registered in [`CharacterControllerPredicates`], returning `false`
+    /// for each collider entity that must not be considered as an obstacle.
+    pub predicate: Option<CharacterControllerPredicateId>,
+}
+
+impl Default for CharacterControllerFilter {
+    fn default() -> Self {
+        Self {
+            groups: None,
+            flags: QueryFilterFlags::default() | QueryFilterFlags::EXCLUDE_SENSORS,
+            exclude: vec![],
+            predicate: None,
+        }
+    }
+}
+
+impl MapEntities for CharacterControllerFilter {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        for entity in &mut self.exclude {
+            *entity = entity_mapper.map_entity(*entity);
         }
     }
 }
 
+/// The identifier of a predicate registered in [`CharacterControllerPredicates`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Hash, PartialEq)]
+pub struct CharacterControllerPredicateId(usize);
+
+/// Custom obstacle filters that can be referenced by a [`CharacterControllerFilter`].
+#[derive(Resource, Default)]
+pub struct CharacterControllerPredicates {
+    predicates: Vec<Box<dyn Fn(Entity) -> bool + Send + Sync>>,
+}
+
+impl CharacterControllerPredicates {
+    /// Registers a new predicate, returning the identifier to set as
+    /// [`CharacterControllerFilter::predicate`].
+    ///
+    /// The predicate is given the entity of a potential obstacle collider, and returns
+    /// `false` if it must be ignored by the character controller.
+    pub fn register(
+        &mut self,
+        predicate: impl Fn(Entity) -> bool + Send + Sync + 'static,
+    ) -> CharacterControllerPredicateId {
+        self.predicates.push(Box::new(predicate));
+        CharacterControllerPredicateId(self.predicates.len() - 1)
+    }
+
+    /// The predicate registered with the given identifier.
+    pub fn get(
+        &self,
+        id: CharacterControllerPredicateId,
+    ) -> Option<&(dyn Fn(Entity) -> bool + Send + Sync)> {
+        self.predicates.get(id.0).map(|p| &**p)
+    }
+}
+
 /// The output of a character control.
 ///
 /// This component is automatically added after the first execution of a character control
 /// based on the `KinematicCharacterController` component with its
 /// `KinematicCharacterController::translation` set to a value other than `None`.
-#[derive(Clone, PartialEq, Debug, Default, Component)]
+#[derive(Clone, PartialEq, Debug, Default, Component, Reflect)]
+#[reflect(Component, Default, PartialEq)]
 pub struct KinematicCharacterControllerOutput {
     /// Indicates whether the shape is grounded after its kinematic movement.
     pub grounded: bool,
@@ -216,9 +339,16 @@ pub struct KinematicCharacterControllerOutput {
     /// The translation calculated by the last character control step taking obstacles into account.
     pub effective_translation: Vect,
     /// Collisions between the character and obstacles found in its path.
+    ///
+    /// These hold Rapier types, so they aren’t reflected.
+    #[reflect(ignore)]
     pub collisions: Vec<CharacterCollision>,
     /// Indicates whether the shape is sliding down a slope after its kinematic movement.
     pub is_sliding_down_slope: bool,
+    /// The height climbed by autostepping over an obstacle during the last movement, if any.
+    pub stepped_up: Option<Real>,
+    /// The distance the character was snapped down to the ground during the last movement, if any.
+    pub snapped_to_ground: Option<Real>,
 }
 
 /// The allowed movement computed by `RapierContext::move_shape`.
@@ -229,4 +359,45 @@ pub struct MoveShapeOutput {
     pub effective_translation: Vect,
     /// Indicates whether the shape is sliding down a slope after its kinematic movement.
     pub is_sliding_down_slope: bool,
+    /// The height climbed by autostepping over an obstacle during the movement, if any.
+    pub stepped_up: Option<Real>,
+    /// The distance the shape was snapped down to the ground during the movement, if any.
+    pub snapped_to_ground: Option<Real>,
+}
+
+/// Computes the height climbed through autostepping, and the distance snapped down to the
+/// ground, by a character movement.
+///
+/// Rapier doesn’t report these directly so they are deduced from the difference between the
+/// desired and effective movements along the `up` direction:
+/// - the character stepped up if it actually moved up, higher than desired, while being blocked
+///   by an obstacle too steep to be climbed. Being pushed back up by the ground while walking
+///   into a wall isn’t a step.
+/// - the character snapped to the ground if it ended up lower than desired.
+pub(crate) fn step_and_snap_heights(
+    controller: &rapier::control::KinematicCharacterController,
+    desired_translation: &Vector<Real>,
+    movement: &EffectiveCharacterMovement,
+    collisions: &[rapier::control::CharacterCollision],
+    epsilon: Real,
+) -> (Option<Real>, Option<Real>) {
+    if !movement.grounded {
+        return (None, None);
+    }
+
+    let rise = movement.translation.dot(&controller.up);
+    let vertical_gain = rise - desired_translation.dot(&controller.up);
+    let blocked_by_obstacle = collisions
+        .iter()
+        .any(|c| c.hit.normal1.dot(&controller.up) < controller.max_slope_climb_angle.cos());
+
+    let stepped_up = (controller.autostep.is_some()
+        && rise > epsilon
+        && vertical_gain > epsilon
+        && blocked_by_obstacle)
+        .then_some(rise.min(vertical_gain));
+    let snapped_to_ground =
+        (controller.snap_to_ground.is_some() && vertical_gain < -epsilon).then_some(-vertical_gain);
+
+    (stepped_up, snapped_to_ground)
 }
@@ -1,6 +1,17 @@
 pub use self::character_controller::{
-    CharacterAutostep, CharacterCollision, CharacterLength, KinematicCharacterController,
-    KinematicCharacterControllerOutput, MoveShapeOptions, MoveShapeOutput,
+    CharacterApplyMode, CharacterAutostep, CharacterCollision, CharacterControllerFilter,
+    CharacterControllerPredicateId, CharacterControllerPredicates, CharacterLength,
+    KinematicCharacterController, KinematicCharacterControllerOutput, MoveShapeOptions,
+    MoveShapeOutput,
 };
 
+#[cfg(feature = "dim3")]
+pub use self::vehicle::{
+    RapierVehicleController, RaycastVehicle, RaycastVehicleOutput, VehicleWheel,
+};
+
+pub(crate) use self::character_controller::step_and_snap_heights;
+
 mod character_controller;
+#[cfg(feature = "dim3")]
+mod vehicle;
@@ -0,0 +1,193 @@
+use crate::math::{Real, Vect};
+use bevy::prelude::*;
+use rapier::control::{DynamicRayCastVehicleController, WheelTuning};
+use rapier::dynamics::RigidBodyHandle;
+
+/// A vehicle whose wheels are simulated with ray-casts, like a car.
+///
+/// This must be added to an entity with a [`RigidBody`](crate::dynamics::RigidBody), the chassis
+/// of the vehicle. Its wheels are its direct children with a [`VehicleWheel`] component, which
+/// shouldn’t have any collider. Before the simulation is stepped, the suspension, engine and
+/// friction forces of the wheels over all the steps of the frame are applied to the chassis, and
+/// the `Transform` of each wheel entity is set to the wheel’s pose relative to the chassis, so it
+/// can be rendered. Frames that don’t step the simulation don’t update the vehicle.
+///
+/// The axes of the chassis are identified by their index (`0` for `X`, `1` for `Y`, `2` for `Z`).
+#[derive(Copy, Clone, Debug, PartialEq, Component, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct RaycastVehicle {
+    /// The force applied by the engine to each driven wheel.
+    pub engine_force: Real,
+    /// The braking force applied to each wheel.
+    pub brake: Real,
+    /// The steering angle, in radians, of each steered wheel.
+    pub steering: Real,
+    /// The index of the up axis of the chassis.
+    pub up_axis: usize,
+    /// The index of the forward axis of the chassis.
+    pub forward_axis: usize,
+}
+
+impl Default for RaycastVehicle {
+    fn default() -> Self {
+        Self {
+            engine_force: 0.0,
+            brake: 0.0,
+            steering: 0.0,
+            up_axis: 1,
+            forward_axis: 0,
+        }
+    }
+}
+
+/// A wheel of a [`RaycastVehicle`], attached to a child entity of the vehicle’s chassis.
+///
+/// All the positions and directions are expressed in the local-space of the chassis.
+#[derive(Copy, Clone, Debug, PartialEq, Component, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct VehicleWheel {
+    /// The point where the suspension of the wheel is attached to the chassis.
+    pub connection_point: Vect,
+    /// The direction of the suspension, pointing from the chassis toward the ground.
+    pub direction: Vect,
+    /// The axis the wheel rotates around when rolling.
+    pub axle: Vect,
+    /// The length of the suspension when it is at rest.
+    pub suspension_rest_length: Real,
+    /// The maximum distance the suspension can travel from its rest length.
+    pub max_suspension_travel: Real,
+    /// The stiffness of the suspension spring.
+    pub suspension_stiffness: Real,
+    /// The damping of the suspension spring when it is compressed.
+    pub suspension_compression: Real,
+    /// The damping of the suspension spring when it is relaxed.
+    pub suspension_damping: Real,
+    /// The maximum force the suspension can apply.
+    pub max_suspension_force: Real,
+    /// The radius of the wheel.
+    pub radius: Real,
+    /// The friction coefficient of the wheel with the ground.
+    pub friction_slip: Real,
+    /// The multiplier of the friction applied perpendicularly to the rolling direction.
+    pub side_friction_stiffness: Real,
+    /// Whether [`RaycastVehicle::steering`] is applied to this wheel.
+    pub steered: bool,
+    /// Whether [`RaycastVehicle::engine_force`] is applied to this wheel.
+    pub driven: bool,
+}
+
+impl VehicleWheel {
+    /// A wheel of the given radius, whose suspension is attached to the given point of the
+    /// chassis.
+    pub fn new(connection_point: Vect, radius: Real) -> Self {
+        Self {
+            connection_point,
+            radius,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the rest length, stiffness and damping of the suspension.
+    #[must_use]
+    pub fn suspension(mut self, rest_length: Real, stiffness: Real, damping: Real) -> Self {
+        self.suspension_rest_length = rest_length;
+        self.suspension_stiffness = stiffness;
+        self.suspension_damping = damping;
+        self
+    }
+
+    /// Makes the wheel steered by the [`RaycastVehicle`].
+    #[must_use]
+    pub fn steered(mut self) -> Self {
+        self.steered = true;
+        self
+    }
+
+    /// Makes the wheel driven by the engine of the [`RaycastVehicle`].
+    #[must_use]
+    pub fn driven(mut self) -> Self {
+        self.driven = true;
+        self
+    }
+
+    pub(crate) fn tuning(&self) -> WheelTuning {
+        WheelTuning {
+            suspension_stiffness: self.suspension_stiffness,
+            suspension_compression: self.suspension_compression,
+            suspension_damping: self.suspension_damping,
+            max_suspension_travel: self.max_suspension_travel,
+            side_friction_stiffness: self.side_friction_stiffness,
+            friction_slip: self.friction_slip,
+            max_suspension_force: self.max_suspension_force,
+        }
+    }
+}
+
+impl Default for VehicleWheel {
+    fn default() -> Self {
+        let tuning = WheelTuning::default();
+        Self {
+            connection_point: Vect::ZERO,
+            direction: Vect::NEG_Y,
+            axle: Vect::Z,
+            suspension_rest_length: 0.3,
+            max_suspension_travel: tuning.max_suspension_travel,
+            suspension_stiffness: tuning.suspension_stiffness,
+            suspension_compression: tuning.suspension_compression,
+            suspension_damping: tuning.suspension_damping,
+            max_suspension_force: tuning.max_suspension_force,
+            radius: 0.5,
+            friction_slip: tuning.friction_slip,
+            side_friction_stiffness: tuning.side_friction_stiffness,
+            steered: false,
+            driven: false,
+        }
+    }
+}
+
+/// The state of a [`RaycastVehicle`] after the last update of its wheels.
+///
+/// This component is automatically added after the first update of the vehicle.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Component, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct RaycastVehicleOutput {
+    /// The speed of the vehicle along its forward axis.
+    pub current_speed: Real,
+    /// The number of wheels touching the ground.
+    pub wheels_in_contact: usize,
+}
+
+/// The Rapier controller simulating a [`RaycastVehicle`].
+///
+/// This component is automatically added to the vehicle, and rebuilt when its wheels change.
+#[derive(Component)]
+pub struct RapierVehicleController {
+    pub(crate) controller: DynamicRayCastVehicleController,
+    pub(crate) wheels: Vec<Entity>,
+}
+
+impl RapierVehicleController {
+    pub(crate) fn new(chassis: RigidBodyHandle, wheels: &[(Entity, VehicleWheel)]) -> Self {
+        let mut controller = DynamicRayCastVehicleController::new(chassis);
+        for (_, wheel) in wheels {
+            controller.add_wheel(
+                wheel.connection_point.into(),
+                wheel.direction.into(),
+                wheel.axle.into(),
+                wheel.suspension_rest_length,
+                wheel.radius,
+                &wheel.tuning(),
+            );
+        }
+
+        Self {
+            controller,
+            wheels: wheels.iter().map(|(entity, _)| *entity).collect(),
+        }
+    }
+
+    /// The Rapier vehicle controller.
+    pub fn controller(&self) -> &DynamicRayCastVehicleController {
+        &self.controller
+    }
+}
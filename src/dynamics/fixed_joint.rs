@@ -1,5 +1,6 @@
 use crate::dynamics::{GenericJoint, GenericJointBuilder};
 use crate::math::{Rot, Vect};
+use bevy::prelude::GlobalTransform;
 use rapier::dynamics::JointAxesMask;
 
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -99,6 +100,26 @@ impl FixedJointBuilder {
         Self(FixedJoint::new())
     }
 
+    /// Creates a new builder for fixed joints keeping the two rigid-bodies in their current
+    /// relative pose, given their current transforms.
+    pub fn from_world_transforms(
+        parent_transform: &GlobalTransform,
+        child_transform: &GlobalTransform,
+    ) -> Self {
+        let (_, rotation, translation) = child_transform.to_scale_rotation_translation();
+        #[cfg(feature = "dim2")]
+        let (anchor, basis) = (translation.truncate(), rotation.to_scaled_axis().z);
+        #[cfg(feature = "dim3")]
+        let (anchor, basis) = (translation, rotation);
+
+        let mut result = Self::new();
+        result
+            .0
+            .data
+            .set_world_frame(anchor, basis, parent_transform, child_transform);
+        result
+    }
+
     /// Sets the joint’s basis, expressed in the first rigid-body’s local-space.
     #[must_use]
     pub fn local_basis1(mut self, local_basis: Rot) -> Self {
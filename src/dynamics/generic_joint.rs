@@ -1,5 +1,7 @@
 use crate::dynamics::{FixedJoint, PrismaticJoint, RevoluteJoint, RopeJoint};
 use crate::math::{Real, Rot, Vect};
+use crate::utils::transform_to_iso;
+use bevy::prelude::{GlobalTransform, Quat, Reflect, Transform};
 use rapier::dynamics::{
     GenericJoint as RapierGenericJoint, JointAxesMask, JointAxis, JointLimits, JointMotor,
     MotorModel,
@@ -9,8 +11,13 @@ use rapier::dynamics::{
 use crate::dynamics::SphericalJoint;
 
 /// The description of any joint.
+///
+/// This is reflected as an opaque value, which can only be serialized (in a scene for example)
+/// if the `serde-serialize` feature is enabled.
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
-#[derive(Copy, Clone, Debug, PartialEq, Default)]
+#[derive(Copy, Clone, Debug, PartialEq, Default, Reflect)]
+#[reflect_value(Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde-serialize", reflect_value(Serialize, Deserialize))]
 #[repr(transparent)]
 pub struct GenericJoint {
     /// The raw Rapier description of the joint.
@@ -141,6 +148,52 @@ impl GenericJoint {
         self
     }
 
+    /// Sets both frames of this joint so that they match the given world-space frame, given the
+    /// current transforms of the two rigid-bodies attached to the joint.
+    ///
+    /// This way, the rigid-bodies keep their current relative pose when the joint is created.
+    /// The scale of the transforms is ignored, like it is for rigid-bodies.
+    pub fn set_world_frame(
+        &mut self,
+        anchor: Vect,
+        basis: Rot,
+        parent_transform: &GlobalTransform,
+        child_transform: &GlobalTransform,
+    ) -> &mut Self {
+        #[cfg(feature = "dim2")]
+        let frame = Transform::from_translation(anchor.extend(0.0))
+            .with_rotation(Quat::from_rotation_z(basis));
+        #[cfg(feature = "dim3")]
+        let frame = Transform::from_translation(anchor).with_rotation(basis);
+        let frame = transform_to_iso(&frame);
+
+        self.raw.local_frame1 =
+            transform_to_iso(&parent_transform.compute_transform()).inv_mul(&frame);
+        self.raw.local_frame2 =
+            transform_to_iso(&child_transform.compute_transform()).inv_mul(&frame);
+        self
+    }
+
+    /// Sets the anchors of this joint from world-space points, given the current transforms of
+    /// the two rigid-bodies attached to the joint.
+    ///
+    /// The scale of the transforms is ignored, like it is for rigid-bodies.
+    pub fn set_world_anchors(
+        &mut self,
+        anchor1: Vect,
+        anchor2: Vect,
+        parent_transform: &GlobalTransform,
+        child_transform: &GlobalTransform,
+    ) -> &mut Self {
+        let parent_pos = transform_to_iso(&parent_transform.compute_transform());
+        let child_pos = transform_to_iso(&child_transform.compute_transform());
+        self.raw.local_frame1.translation.vector =
+            parent_pos.inverse_transform_point(&anchor1.into()).coords;
+        self.raw.local_frame2.translation.vector =
+            child_pos.inverse_transform_point(&anchor2.into()).coords;
+        self
+    }
+
     /// Are contacts between the attached rigid-bodies enabled?
     pub fn contacts_enabled(&self) -> bool {
         self.raw.contacts_enabled
@@ -433,3 +486,11 @@ impl From<GenericJointBuilder> for GenericJoint {
         joint.0
     }
 }
+
+/// The joint basis whose first axis is aligned with the given world-space direction.
+pub(crate) fn basis_from_axis(axis: Vect) -> Rot {
+    #[cfg(feature = "dim2")]
+    return axis.y.atan2(axis.x);
+    #[cfg(feature = "dim3")]
+    return Quat::from_rotation_arc(Vect::X, axis.normalize());
+}
@@ -1,4 +1,8 @@
 use crate::dynamics::GenericJoint;
+use crate::math::{Real, Vect};
+use crate::plugin::WorldId;
+use bevy::ecs::entity::{EntityMapper, MapEntities};
+use bevy::ecs::reflect::ReflectMapEntities;
 use bevy::prelude::*;
 use rapier::dynamics::{ImpulseJointHandle, MultibodyJointHandle};
 
@@ -23,7 +27,10 @@ pub struct RapierMultibodyJointHandle(pub MultibodyJointHandle);
 /// joints can be added in the children of the entity containing that
 /// rigid-body (this is similar to the technique used to attach multiple
 /// colliders to the same rigid-body).
-#[derive(Copy, Clone, Debug, PartialEq, Component)]
+///
+/// The `parent` entity is remapped when the joint is spawned from a scene.
+#[derive(Copy, Clone, Debug, PartialEq, Component, Reflect)]
+#[reflect(Component, MapEntities, PartialEq)]
 pub struct ImpulseJoint {
     /// The entity containing the rigid-body used as the first endpoint of this joint.
     pub parent: Entity,
@@ -50,7 +57,10 @@ impl ImpulseJoint {
 /// Note that a set of multibody joints cannot form closed loops (for example a necklace).
 /// If a closed loop is detected, the last joint that closes the loop is ignored, and an
 /// error is printed to `stderr` (using `log::error!`).
-#[derive(Copy, Clone, Debug, PartialEq, Component)]
+///
+/// The `parent` entity is remapped when the joint is spawned from a scene.
+#[derive(Copy, Clone, Debug, PartialEq, Component, Reflect)]
+#[reflect(Component, MapEntities, PartialEq)]
 pub struct MultibodyJoint {
     /// The entity containing the rigid-body used as the first endpoint of this joint.
     pub parent: Entity,
@@ -58,6 +68,12 @@ pub struct MultibodyJoint {
     pub data: GenericJoint,
 }
 
+impl MapEntities for ImpulseJoint {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.parent = entity_mapper.map_entity(self.parent);
+    }
+}
+
 impl MultibodyJoint {
     /// Initializes an joint based on reduced coordinates from its first endpoint and
     /// the joint description.
@@ -68,3 +84,336 @@ impl MultibodyJoint {
         }
     }
 }
+
+impl MapEntities for MultibodyJoint {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.parent = entity_mapper.map_entity(self.parent);
+    }
+}
+
+/// Indicates whether or not the [`ImpulseJoint`] or [`MultibodyJoint`] is disabled explicitly by the user.
+///
+/// A disabled joint is kept in the physics scene but doesn’t constrain its rigid-bodies anymore.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component, PartialEq)]
+pub struct JointDisabled;
+
+/// Marker component welding a collider (or the colliders of a rigid-body) to the rigid-bodies
+/// it starts touching.
+///
+/// When a collider attached to this entity, or to the rigid-body of this entity, starts a contact
+/// with a collider attached to another rigid-body, the two rigid-bodies are attached by a fixed
+/// [`ImpulseJoint`] preserving their relative pose, as with
+/// [`RapierContext::weld_entities`](crate::plugin::RapierContext::weld_entities). Contacts with
+/// sensors, or with colliders without a rigid-body, are ignored.
+///
+/// The contacts are detected with the [`CollisionEvent`](crate::pipeline::CollisionEvent)s, so
+/// one of the colliders must have [`ActiveEvents::COLLISION_EVENTS`](crate::geometry::ActiveEvents::COLLISION_EVENTS)
+/// enabled.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component, PartialEq)]
+pub struct WeldOnContact;
+
+/// The maximum force and torque an [`ImpulseJoint`] can withstand before breaking.
+///
+/// After each simulation step, if the force (or torque) applied by the joint to hold its
+/// rigid-bodies together exceeds these thresholds, the [`ImpulseJoint`] component is removed
+/// from the entity and a [`JointBrokenEvent`] is emitted.
+///
+/// Forces and torques are expressed in the same units as the rest of the simulation (they are
+/// not affected by the `length_unit` of the physics plugin).
+#[derive(Copy, Clone, Debug, PartialEq, Component, Reflect)]
+#[reflect(Component, PartialEq)]
+pub struct ImpulseJointBreakForce {
+    /// The maximum linear force the joint can apply before breaking.
+    pub force: Real,
+    /// The maximum torque the joint can apply before breaking.
+    pub torque: Real,
+}
+
+impl Default for ImpulseJointBreakForce {
+    fn default() -> Self {
+        Self {
+            force: Real::MAX,
+            torque: Real::MAX,
+        }
+    }
+}
+
+impl ImpulseJointBreakForce {
+    /// A joint breaking if the given linear force is exceeded, regardless of the torque.
+    pub fn force(force: Real) -> Self {
+        Self {
+            force,
+            ..Default::default()
+        }
+    }
+
+    /// A joint breaking if the given torque is exceeded, regardless of the linear force.
+    pub fn torque(torque: Real) -> Self {
+        Self {
+            torque,
+            ..Default::default()
+        }
+    }
+}
+
+/// Event emitted when an [`ImpulseJoint`] breaks because of its [`ImpulseJointBreakForce`].
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct JointBrokenEvent {
+    /// The entity the broken [`ImpulseJoint`] was attached to.
+    pub entity: Entity,
+    /// The [`ImpulseJoint::parent`] of the broken joint.
+    pub parent: Entity,
+    /// The linear impulse applied by the joint during the step that broke it.
+    pub impulse: Vect,
+    /// The angular impulse applied by the joint during the step that broke it.
+    #[cfg(feature = "dim2")]
+    pub angular_impulse: Real,
+    /// The angular impulse applied by the joint during the step that broke it.
+    #[cfg(feature = "dim3")]
+    pub angular_impulse: Vect,
+}
+
+/// Event emitted when a joint couldn’t be created after several attempts.
+///
+/// Joints are created once the rigid-bodies they attach are registered in the same
+/// [`RapierWorld`](crate::plugin::RapierWorld). Creation is retried every frame, so
+/// this event doesn’t prevent the joint from being created later.
+///
+/// This is also emitted right away when a [`MultibodyJoint`] whose degrees of freedom
+/// were modified couldn’t be re-created.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct JointInitFailedEvent {
+    /// The entity with the [`ImpulseJoint`] or [`MultibodyJoint`] component.
+    pub entity: Entity,
+    /// Why the joint couldn’t be created.
+    pub reason: JointInitFailure,
+}
+
+/// The reason why a joint couldn’t be created.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum JointInitFailure {
+    /// The joint’s parent entity isn’t a rigid-body of the joint’s world.
+    MissingParentBody,
+    /// The joint’s entity (or any of its ancestors for impulse joints) isn’t a
+    /// rigid-body of the joint’s world.
+    MissingChildBody,
+    /// Neither end of the joint is a rigid-body of the joint’s world.
+    MissingBodies,
+    /// Inserting the multibody joint would create a loop in the multibody.
+    MultibodyLoop,
+    /// Both ends of the joint are rigid-bodies, but they belong to different worlds.
+    DifferentWorlds {
+        /// The world containing the rigid-body of the joint’s parent.
+        parent_world: WorldId,
+        /// The world containing the rigid-body of the joint’s entity.
+        child_world: WorldId,
+    },
+}
+
+impl std::fmt::Display for JointInitFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingParentBody => f.write_str("the parent rigid-body is missing"),
+            Self::MissingChildBody => f.write_str("the child rigid-body is missing"),
+            Self::MissingBodies => f.write_str("both rigid-bodies are missing"),
+            Self::MultibodyLoop => f.write_str("it would create a loop in the multibody"),
+            Self::DifferentWorlds {
+                parent_world,
+                child_world,
+            } => write!(
+                f,
+                "the parent rigid-body is in world {parent_world} but the child rigid-body is in world {child_world}"
+            ),
+        }
+    }
+}
+
+/// Add this component to an entity with an [`ImpulseJoint`] to read the forces the joint
+/// applied during the last simulation step.
+///
+/// The contents of this component are updated automatically after each step. Modifying
+/// them has no effect on the simulation.
+#[derive(Clone, Debug, Default, PartialEq, Component, Reflect)]
+#[reflect(Component, Default, PartialEq)]
+pub struct ReadImpulseJointForces {
+    /// The linear force applied by the joint, expressed in the local frame of its first anchor.
+    pub force: Vect,
+    /// The torque applied by the joint, expressed in the local frame of its first anchor.
+    #[cfg(feature = "dim2")]
+    pub torque: Real,
+    /// The torque applied by the joint, expressed in the local frame of its first anchor.
+    #[cfg(feature = "dim3")]
+    pub torque: Vect,
+    /// The state of each motorized axis of the joint.
+    ///
+    /// This isn’t reflected, since the [`JointAxis`] of each motor can’t be.
+    #[reflect(ignore)]
+    pub motors: Vec<JointMotorState>,
+}
+
+/// The target and actual state of a joint motor along one of its axes.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct JointMotorState {
+    /// The axis driven by this motor.
+    pub axis: JointAxis,
+    /// The position the motor is trying to reach.
+    pub target_position: Real,
+    /// The velocity the motor is trying to reach.
+    pub target_velocity: Real,
+    /// The current position of the joint along this axis.
+    ///
+    /// For angular axes of 3D joints, this is the component of the relative rotation’s
+    /// scaled axis, which is exact only if the joint rotates along a single axis.
+    pub position: Real,
+    /// The current relative velocity of the joint along this axis.
+    pub velocity: Real,
+    /// The force (or torque for angular axes) applied by the motor.
+    pub force: Real,
+}
+
+/// The settings of a joint motor along a single axis.
+#[derive(Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Default, PartialEq)]
+pub struct AxisMotor {
+    /// The position the motor tries to reach.
+    pub target_position: Real,
+    /// The velocity the motor tries to reach.
+    pub target_velocity: Real,
+    /// The stiffness coefficient of the motor’s spring-like equation.
+    pub stiffness: Real,
+    /// The damping coefficient of the motor’s spring-like equation.
+    pub damping: Real,
+    /// The maximum force (or torque for angular axes) the motor can deliver.
+    pub max_force: Real,
+}
+
+impl Default for AxisMotor {
+    fn default() -> Self {
+        Self {
+            target_position: 0.0,
+            target_velocity: 0.0,
+            stiffness: 0.0,
+            damping: 0.0,
+            max_force: Real::MAX,
+        }
+    }
+}
+
+impl AxisMotor {
+    /// A motor trying to reach the given velocity.
+    pub fn velocity(target_velocity: Real, factor: Real) -> Self {
+        Self {
+            target_velocity,
+            damping: factor,
+            ..Default::default()
+        }
+    }
+
+    /// A motor trying to reach the given position.
+    pub fn position(target_position: Real, stiffness: Real, damping: Real) -> Self {
+        Self {
+            target_position,
+            stiffness,
+            damping,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the maximum force (or torque for angular axes) the motor can deliver.
+    #[must_use]
+    pub fn max_force(mut self, max_force: Real) -> Self {
+        self.max_force = max_force;
+        self
+    }
+}
+
+/// Drives the motors of the [`ImpulseJoint`] or [`MultibodyJoint`] attached to the same entity.
+///
+/// Changes to this component are applied directly onto the existing physics joint, so the
+/// motor targets can be modified every frame without recreating the joint. The motor settings
+/// of the joint’s [`GenericJoint`] are overwritten for every axis listed in this component.
+///
+/// This component is reflected as an opaque value, since the [`JointAxis`] of each motor can’t
+/// be reflected.
+#[derive(Clone, Debug, Default, PartialEq, Component, Reflect)]
+#[reflect_value(Component, Debug, Default, PartialEq)]
+pub struct JointMotor {
+    /// The motorized axes of the joint, and their motor settings.
+    pub axes: Vec<(JointAxis, AxisMotor)>,
+}
+
+impl JointMotor {
+    /// A joint motor driving the given axis.
+    pub fn new(axis: JointAxis, motor: AxisMotor) -> Self {
+        Self {
+            axes: vec![(axis, motor)],
+        }
+    }
+
+    /// A joint motor driving the rotation of a [`RevoluteJoint`](crate::dynamics::RevoluteJoint).
+    pub fn revolute(motor: AxisMotor) -> Self {
+        Self::new(JointAxis::AngX, motor)
+    }
+
+    /// A joint motor driving the translation of a [`PrismaticJoint`](crate::dynamics::PrismaticJoint).
+    pub fn prismatic(motor: AxisMotor) -> Self {
+        Self::new(JointAxis::X, motor)
+    }
+
+    /// A joint motor driving the three rotations of a [`SphericalJoint`](crate::dynamics::SphericalJoint).
+    #[cfg(feature = "dim3")]
+    pub fn spherical(motor: AxisMotor) -> Self {
+        Self {
+            axes: vec![
+                (JointAxis::AngX, motor),
+                (JointAxis::AngY, motor),
+                (JointAxis::AngZ, motor),
+            ],
+        }
+    }
+
+    /// Drives an additional axis with the given motor, replacing its previous motor if any.
+    #[must_use]
+    pub fn with_axis(mut self, axis: JointAxis, motor: AxisMotor) -> Self {
+        self.set_axis(axis, motor);
+        self
+    }
+
+    /// Drives the given axis with the given motor, replacing its previous motor if any.
+    pub fn set_axis(&mut self, axis: JointAxis, motor: AxisMotor) -> &mut Self {
+        match self.axis_mut(axis) {
+            Some(existing) => *existing = motor,
+            None => self.axes.push((axis, motor)),
+        }
+        self
+    }
+
+    /// The motor driving the given axis, if any.
+    pub fn axis(&self, axis: JointAxis) -> Option<&AxisMotor> {
+        self.axes.iter().find(|(a, _)| *a == axis).map(|(_, m)| m)
+    }
+
+    /// A mutable reference to the motor driving the given axis, if any.
+    pub fn axis_mut(&mut self, axis: JointAxis) -> Option<&mut AxisMotor> {
+        self.axes
+            .iter_mut()
+            .find(|(a, _)| *a == axis)
+            .map(|(_, m)| m)
+    }
+
+    pub(crate) fn apply_to(&self, joint: &mut rapier::dynamics::GenericJoint) {
+        for (axis, motor) in &self.axes {
+            joint
+                .set_motor(
+                    *axis,
+                    motor.target_position,
+                    motor.target_velocity,
+                    motor.stiffness,
+                    motor.damping,
+                )
+                .set_motor_max_force(*axis, motor.max_force);
+        }
+    }
+}
@@ -1,5 +1,7 @@
 pub use self::generic_joint::*;
 pub use self::joint::*;
+pub use self::radial_impulse::{Falloff, RadialImpulseFlags};
+pub use self::ragdoll::{Ragdoll, RagdollBone, RagdollBuilder, RagdollJointType};
 pub use self::rigid_body::*;
 
 pub use self::fixed_joint::*;
@@ -16,6 +18,8 @@ pub use self::spherical_joint::*;
 
 mod generic_joint;
 mod joint;
+mod radial_impulse;
+mod ragdoll;
 mod rigid_body;
 
 mod fixed_joint;
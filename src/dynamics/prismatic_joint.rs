@@ -1,5 +1,6 @@
-use crate::dynamics::{GenericJoint, GenericJointBuilder};
+use crate::dynamics::{basis_from_axis, GenericJoint, GenericJointBuilder};
 use crate::math::{Real, Vect};
+use bevy::prelude::GlobalTransform;
 use rapier::dynamics::{JointAxesMask, JointAxis, JointLimits, JointMotor, MotorModel};
 
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
@@ -170,6 +171,25 @@ impl PrismaticJointBuilder {
         Self(PrismaticJoint::new(axis))
     }
 
+    /// Creates a new builder for prismatic joints sliding along the given world-space axis,
+    /// going through the given world-space anchor, given the current transforms of the two
+    /// rigid-bodies.
+    pub fn world_anchor(
+        anchor: Vect,
+        axis: Vect,
+        parent_transform: &GlobalTransform,
+        child_transform: &GlobalTransform,
+    ) -> Self {
+        let mut result = Self::new(axis);
+        result.0.data.set_world_frame(
+            anchor,
+            basis_from_axis(axis),
+            parent_transform,
+            child_transform,
+        );
+        result
+    }
+
     /// Sets the joint’s anchor, expressed in the local-space of the first rigid-body.
     #[must_use]
     pub fn local_anchor1(mut self, anchor1: Vect) -> Self {
@@ -0,0 +1,42 @@
+use crate::math::Real;
+
+/// How the strength of a radial impulse decreases with the distance to its center.
+///
+/// See [`RapierWorld::apply_radial_impulse`](crate::plugin::RapierWorld::apply_radial_impulse).
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum Falloff {
+    /// The impulse has the same strength at any distance within its radius.
+    None,
+    /// The strength decreases linearly, from its full value at the center to zero at the radius.
+    #[default]
+    Linear,
+    /// The strength is divided by `1 + distance²`, so it has its full value at the center.
+    InverseSquare,
+}
+
+impl Falloff {
+    /// The factor applied to the strength of an impulse of the given radius, at the given distance
+    /// from its center.
+    pub fn factor(self, distance: Real, radius: Real) -> Real {
+        match self {
+            Self::None => 1.0,
+            Self::Linear if radius > 0.0 => (1.0 - distance / radius).clamp(0.0, 1.0),
+            Self::Linear => 0.0,
+            Self::InverseSquare => 1.0 / (1.0 + distance * distance),
+        }
+    }
+}
+
+bitflags::bitflags! {
+    /// Flags modifying how a radial impulse is applied.
+    ///
+    /// See [`RapierWorld::apply_radial_impulse`](crate::plugin::RapierWorld::apply_radial_impulse).
+    #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+    pub struct RadialImpulseFlags: u8 {
+        /// Apply the impulse at the point of each rigid-body closest to the center, rather than at
+        /// its center-of-mass, so off-center hits make the rigid-body spin.
+        const AT_CLOSEST_POINT = 1 << 0;
+        /// Ignore the rigid-bodies hidden behind another collider, as seen from the center.
+        const OCCLUSION = 1 << 1;
+    }
+}
@@ -0,0 +1,279 @@
+use crate::dynamics::{
+    GenericJoint, ImpulseJoint, MultibodyJoint, PhysicsHierarchyMode, RigidBody,
+};
+use crate::geometry::Collider;
+use crate::math::{Real, Vect};
+use bevy::prelude::*;
+
+#[cfg(feature = "dim2")]
+use crate::dynamics::RevoluteJointBuilder;
+#[cfg(feature = "dim3")]
+use crate::dynamics::SphericalJointBuilder;
+#[cfg(feature = "dim3")]
+use rapier::dynamics::JointAxis;
+
+/// A bone of a ragdoll built with a [`RagdollBuilder`].
+///
+/// The bone is a capsule starting at the origin of the bone and extending along its local `Y`
+/// axis. It is attached to its parent bone, if any, by a joint located at its origin.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RagdollBone {
+    /// The entity of the bone, which receives the rigid-body, collider and joint components.
+    pub entity: Entity,
+    /// The current world-space transform of the bone.
+    pub transform: GlobalTransform,
+    /// The length of the segment of the bone’s capsule.
+    pub length: Real,
+    /// The radius of the bone’s capsule.
+    pub radius: Real,
+    /// The entity of the parent bone, if any.
+    pub parent: Option<Entity>,
+    /// The `[min, max]` angles, in radians, the bone can rotate relative to its parent away from
+    /// the bone’s axis. If `None`, the swing isn’t limited.
+    pub swing_limits: Option<[Real; 2]>,
+    /// The `[min, max]` angles, in radians, the bone can rotate relative to its parent around the
+    /// bone’s axis. If `None`, the twist isn’t limited.
+    #[cfg(feature = "dim3")]
+    pub twist_limits: Option<[Real; 2]>,
+}
+
+impl RagdollBone {
+    /// A bone with the given entity, current world-space transform and capsule dimensions,
+    /// without parent or joint limits.
+    pub fn new(entity: Entity, transform: GlobalTransform, length: Real, radius: Real) -> Self {
+        Self {
+            entity,
+            transform,
+            length,
+            radius,
+            parent: None,
+            swing_limits: None,
+            #[cfg(feature = "dim3")]
+            twist_limits: None,
+        }
+    }
+
+    /// Sets the parent bone of this bone.
+    #[must_use]
+    pub fn parent(mut self, parent: Entity) -> Self {
+        self.parent = Some(parent);
+        self
+    }
+
+    /// Sets the `[min, max]` angles the bone can rotate relative to its parent away from its axis.
+    #[must_use]
+    pub fn swing_limits(mut self, limits: [Real; 2]) -> Self {
+        self.swing_limits = Some(limits);
+        self
+    }
+
+    /// Sets the `[min, max]` angles the bone can rotate relative to its parent around its axis.
+    #[cfg(feature = "dim3")]
+    #[must_use]
+    pub fn twist_limits(mut self, limits: [Real; 2]) -> Self {
+        self.twist_limits = Some(limits);
+        self
+    }
+
+    /// The joint attaching this bone to the given parent bone, in their current pose.
+    fn joint(&self, parent: &RagdollBone) -> GenericJoint {
+        let anchor = self.transform.translation();
+
+        #[cfg(feature = "dim2")]
+        let mut joint: GenericJoint = {
+            let mut builder = RevoluteJointBuilder::world_anchor(
+                anchor.truncate(),
+                &parent.transform,
+                &self.transform,
+            );
+            if let Some(limits) = self.swing_limits {
+                builder = builder.limits(limits);
+            }
+            builder.into()
+        };
+        #[cfg(feature = "dim3")]
+        let mut joint: GenericJoint = {
+            let mut builder = SphericalJointBuilder::new();
+            if let Some(limits) = self.twist_limits {
+                builder = builder.limits(JointAxis::AngX, limits);
+            }
+            if let Some(limits) = self.swing_limits {
+                builder = builder
+                    .limits(JointAxis::AngY, limits)
+                    .limits(JointAxis::AngZ, limits);
+            }
+            let mut joint: GenericJoint = builder.into();
+            // The twist axis of the joint (its local `X` axis) is the axis of the bone.
+            let (_, rotation, _) = self.transform.to_scale_rotation_translation();
+            let basis = rotation * Quat::from_rotation_z(std::f32::consts::FRAC_PI_2);
+            joint.set_world_frame(anchor, basis, &parent.transform, &self.transform);
+            joint
+        };
+
+        // The capsules of adjacent bones overlap around their joint.
+        joint.set_contacts_enabled(false);
+        joint
+    }
+}
+
+/// The kind of joints attaching the bones of a ragdoll.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum RagdollJointType {
+    /// Attach the bones with [`MultibodyJoint`]s, which can’t drift apart.
+    #[default]
+    Multibody,
+    /// Attach the bones with [`ImpulseJoint`]s, which can be broken or disabled individually.
+    Impulse,
+}
+
+/// A builder adding the rigid-bodies, colliders and joints of a ragdoll to a set of bone
+/// entities.
+///
+/// The ragdoll is created in the current pose of the bones, given by their
+/// [`RagdollBone::transform`]: the joint anchors are computed in world-space so the bones keep
+/// their relative poses.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RagdollBuilder {
+    bones: Vec<RagdollBone>,
+    joint_type: RagdollJointType,
+}
+
+impl RagdollBuilder {
+    /// Creates a builder for a ragdoll without any bone.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a bone to the ragdoll.
+    #[must_use]
+    pub fn bone(mut self, bone: RagdollBone) -> Self {
+        self.bones.push(bone);
+        self
+    }
+
+    /// Sets the kind of joints attaching the bones.
+    #[must_use]
+    pub fn joint_type(mut self, joint_type: RagdollJointType) -> Self {
+        self.joint_type = joint_type;
+        self
+    }
+
+    /// Inserts the rigid-body, collider and joint components on each bone entity.
+    ///
+    /// The bones are dynamic rigid-bodies, independent from the Bevy hierarchy of their entities
+    /// (see [`PhysicsHierarchyMode::Independent`]). The joint of a bone whose parent isn’t part
+    /// of the ragdoll is ignored.
+    pub fn spawn(self, commands: &mut Commands) -> Ragdoll {
+        for bone in &self.bones {
+            let mut entity = commands.entity(bone.entity);
+            entity.insert((
+                RigidBody::Dynamic,
+                Collider::capsule(Vect::ZERO, Vect::Y * bone.length, bone.radius),
+                PhysicsHierarchyMode::Independent,
+            ));
+
+            let Some(parent) = bone
+                .parent
+                .and_then(|parent| self.bones.iter().find(|b| b.entity == parent))
+            else {
+                continue;
+            };
+            let joint = bone.joint(parent);
+            match self.joint_type {
+                RagdollJointType::Multibody => {
+                    entity.insert(MultibodyJoint::new(parent.entity, joint));
+                }
+                RagdollJointType::Impulse => {
+                    entity.insert(ImpulseJoint::new(parent.entity, joint));
+                }
+            }
+        }
+
+        Ragdoll {
+            bones: self.bones.iter().map(|bone| bone.entity).collect(),
+        }
+    }
+}
+
+/// A ragdoll spawned by a [`RagdollBuilder`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Ragdoll {
+    /// The bone entities of the ragdoll.
+    pub bones: Vec<Entity>,
+}
+
+impl Ragdoll {
+    /// Makes all the bones kinematic, so they follow their `Transform`, for example while they
+    /// are animated.
+    pub fn set_kinematic(&self, commands: &mut Commands) {
+        self.set_rigid_body(commands, RigidBody::KinematicPositionBased);
+    }
+
+    /// Makes all the bones dynamic, so the ragdoll is driven by the simulation.
+    pub fn set_dynamic(&self, commands: &mut Commands) {
+        self.set_rigid_body(commands, RigidBody::Dynamic);
+    }
+
+    fn set_rigid_body(&self, commands: &mut Commands, rigid_body: RigidBody) {
+        for bone in &self.bones {
+            commands.entity(*bone).insert(rigid_body);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use bevy::ecs::system::CommandQueue;
+
+    #[test]
+    fn joint_anchors_coincide_at_spawn() {
+        let mut world = World::new();
+
+        let torso_transform = Transform::from_xyz(0.0, 1.0, 0.0);
+        let arm_transform =
+            Transform::from_xyz(0.3, 1.5, 0.0).with_rotation(Quat::from_rotation_z(-1.2));
+        let forearm_transform =
+            Transform::from_xyz(0.9, 1.7, 0.0).with_rotation(Quat::from_rotation_z(-2.0));
+        let torso = world.spawn(TransformBundle::from(torso_transform)).id();
+        let arm = world.spawn(TransformBundle::from(arm_transform)).id();
+        let forearm = world.spawn(TransformBundle::from(forearm_transform)).id();
+
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        let ragdoll = RagdollBuilder::new()
+            .bone(RagdollBone::new(torso, torso_transform.into(), 0.5, 0.2))
+            .bone(
+                RagdollBone::new(arm, arm_transform.into(), 0.4, 0.1)
+                    .parent(torso)
+                    .swing_limits([-1.0, 1.0]),
+            )
+            .bone(RagdollBone::new(forearm, forearm_transform.into(), 0.4, 0.1).parent(arm))
+            .joint_type(RagdollJointType::Impulse)
+            .spawn(&mut commands);
+        queue.apply(&mut world);
+
+        assert_eq!(ragdoll.bones, vec![torso, arm, forearm]);
+        assert!(world.get::<ImpulseJoint>(torso).is_none());
+
+        for (child, child_transform, parent_transform) in [
+            (arm, arm_transform, torso_transform),
+            (forearm, forearm_transform, arm_transform),
+        ] {
+            let joint = world.get::<ImpulseJoint>(child).unwrap();
+
+            #[cfg(feature = "dim2")]
+            let (anchor1, anchor2) = (
+                joint.data.local_anchor1().extend(0.0),
+                joint.data.local_anchor2().extend(0.0),
+            );
+            #[cfg(feature = "dim3")]
+            let (anchor1, anchor2) = (joint.data.local_anchor1(), joint.data.local_anchor2());
+
+            let world_anchor1 = parent_transform.transform_point(anchor1);
+            let world_anchor2 = child_transform.transform_point(anchor2);
+            assert!(world_anchor1.abs_diff_eq(world_anchor2, 1.0e-5));
+            assert!(world_anchor2.abs_diff_eq(child_transform.translation, 1.0e-5));
+        }
+    }
+}
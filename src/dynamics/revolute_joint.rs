@@ -1,5 +1,9 @@
 use crate::dynamics::{GenericJoint, GenericJointBuilder};
 use crate::math::{Real, Vect};
+use bevy::prelude::GlobalTransform;
+
+#[cfg(feature = "dim3")]
+use crate::dynamics::basis_from_axis;
 use rapier::dynamics::{JointAxesMask, JointAxis, JointLimits, JointMotor, MotorModel};
 
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
@@ -175,6 +179,41 @@ impl RevoluteJointBuilder {
         Self(RevoluteJoint::new(axis))
     }
 
+    /// Creates a new revolute joint builder rotating around the given world-space anchor, given
+    /// the current transforms of the two rigid-bodies.
+    #[cfg(feature = "dim2")]
+    pub fn world_anchor(
+        anchor: Vect,
+        parent_transform: &GlobalTransform,
+        child_transform: &GlobalTransform,
+    ) -> Self {
+        let mut result = Self::new();
+        result
+            .0
+            .data
+            .set_world_frame(anchor, 0.0, parent_transform, child_transform);
+        result
+    }
+
+    /// Creates a new revolute joint builder rotating along the given world-space axis around the
+    /// given world-space anchor, given the current transforms of the two rigid-bodies.
+    #[cfg(feature = "dim3")]
+    pub fn world_anchor(
+        anchor: Vect,
+        axis: Vect,
+        parent_transform: &GlobalTransform,
+        child_transform: &GlobalTransform,
+    ) -> Self {
+        let mut result = Self::new(axis);
+        result.0.data.set_world_frame(
+            anchor,
+            basis_from_axis(axis),
+            parent_transform,
+            child_transform,
+        );
+        result
+    }
+
     /// Sets the joint’s anchor, expressed in the local-space of the first rigid-body.
     #[must_use]
     pub fn local_anchor1(mut self, anchor1: Vect) -> Self {
@@ -1,4 +1,4 @@
-use crate::math::Vect;
+use crate::math::{Rot, Vect};
 use crate::plugin::context::WorldId;
 use bevy::prelude::*;
 use rapier::prelude::{
@@ -26,6 +26,9 @@ pub struct RapierRigidBodyHandle(pub RigidBodyHandle);
 /// - [`LockedAxes`]
 /// - [`RigidBodyDisabled`]
 /// - [`GravityScale`]
+/// - [`GravityOverride`]
+/// - [`MaxVelocity`]
+/// - [`PhysicsHierarchyMode`]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Component, Reflect, Default)]
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 #[reflect(Component, PartialEq)]
@@ -177,18 +180,62 @@ impl Default for AdditionalMassProperties {
 /// and the `AdditionalMassProperties` should be modified instead).
 ///
 /// This only reads the mass from entities with a [`RigidBody`] component.
+///
+/// The local mass properties are updated whenever a [`MassModifiedEvent`] is sent, and the
+/// world-space quantities whenever the rigid-body moves.
 #[derive(Copy, Clone, Debug, Default, PartialEq, Component, Reflect)]
 #[reflect(Component, PartialEq)]
-pub struct ReadMassProperties(pub MassProperties);
+pub struct ReadMassProperties {
+    mass_properties: MassProperties,
+    /// The center-of-mass of the rigid-body, in world-space.
+    pub world_center_of_mass: Vect,
+    /// The inverse mass of the rigid-body along each world axis, set to zero along the
+    /// translations locked by its [`LockedAxes`].
+    pub effective_inv_mass: Vect,
+    /// The inverse angular inertia tensor of the rigid-body, in world-space, taking into
+    /// account the rotations locked by its [`LockedAxes`].
+    #[cfg(feature = "dim3")]
+    pub effective_inv_angular_inertia: Mat3,
+    /// The inverse angular inertia of the rigid-body, set to zero if its rotation is locked
+    /// by its [`LockedAxes`].
+    #[cfg(feature = "dim2")]
+    pub effective_inv_angular_inertia: f32,
+}
 
 impl ReadMassProperties {
     /// Get the [`MassProperties`] of this [`RigidBody`].
     pub fn get(&self) -> &MassProperties {
-        &self.0
+        &self.mass_properties
     }
 
-    pub(crate) fn set(&mut self, mass_props: MassProperties) {
-        self.0 = mass_props;
+    /// Reads the local and world-space mass properties of a Rapier rigid-body.
+    pub(crate) fn from_rapier(mprops: &rapier::dynamics::RigidBodyMassProps) -> Self {
+        let inv_inertia_sqrt = mprops.effective_world_inv_inertia_sqrt;
+        #[cfg(feature = "dim3")]
+        let inv_inertia_sqrt = Mat3::from_cols(
+            Vec3::new(
+                inv_inertia_sqrt.m11,
+                inv_inertia_sqrt.m12,
+                inv_inertia_sqrt.m13,
+            ),
+            Vec3::new(
+                inv_inertia_sqrt.m12,
+                inv_inertia_sqrt.m22,
+                inv_inertia_sqrt.m23,
+            ),
+            Vec3::new(
+                inv_inertia_sqrt.m13,
+                inv_inertia_sqrt.m23,
+                inv_inertia_sqrt.m33,
+            ),
+        );
+
+        Self {
+            mass_properties: MassProperties::from_rapier(mprops.local_mprops),
+            world_center_of_mass: mprops.world_com.coords.into(),
+            effective_inv_mass: mprops.effective_inv_mass.into(),
+            effective_inv_angular_inertia: inv_inertia_sqrt * inv_inertia_sqrt,
+        }
     }
 }
 
@@ -199,11 +246,56 @@ impl std::ops::Deref for ReadMassProperties {
     }
 }
 
-/// Entity that likely had their mass properties changed this frame.
+/// The modification that caused a [`MassModifiedEvent`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Reflect)]
+pub enum MassChangeCause {
+    /// A collider was attached to the rigid-body.
+    ColliderAdded,
+    /// A collider was removed from the rigid-body, or attached to another one.
+    ColliderRemoved,
+    /// The shape of a collider attached to the rigid-body changed.
+    ColliderShapeChanged,
+    /// The [`ColliderMassProperties`](crate::geometry::ColliderMassProperties) of a collider
+    /// attached to the rigid-body changed.
+    ColliderMassPropertiesChanged,
+    /// The [`AdditionalMassProperties`] of the rigid-body changed.
+    AdditionalMassPropertiesChanged,
+}
+
+/// Event sent when the mass properties of a rigid-body changed this frame.
+#[derive(Copy, Clone, Debug, PartialEq, Event)]
+pub struct MassModifiedEvent {
+    /// The rigid-body entity.
+    pub entity: Entity,
+    /// The world containing the rigid-body.
+    pub world_id: WorldId,
+    /// The mass properties of the rigid-body after the change, expressed in its local-space.
+    pub new_mass_properties: MassProperties,
+    /// The modification that caused the change.
+    pub cause: MassChangeCause,
+}
+
+/// Event waking up the given [`RigidBody`] entity before the next simulation step.
+///
+/// This is the preferred way of waking up a rigid-body: it acts directly on the physics
+/// rigid-body instead of relying on a change of the [`Sleeping`] component.
 #[derive(Deref, Copy, Clone, Debug, PartialEq, Event)]
-pub struct MassModifiedEvent(pub Entity);
+pub struct WakeUpBody(pub Entity);
 
-impl From<Entity> for MassModifiedEvent {
+impl From<Entity> for WakeUpBody {
+    fn from(entity: Entity) -> Self {
+        Self(entity)
+    }
+}
+
+/// Event putting the given [`RigidBody`] entity to sleep before the next simulation step.
+///
+/// The rigid-body stays asleep until it is woken up by a [`WakeUpBody`] event, by a
+/// modification of its components, or by a contact with an awake rigid-body.
+#[derive(Deref, Copy, Clone, Debug, PartialEq, Event)]
+pub struct PutBodyToSleep(pub Entity);
+
+impl From<Entity> for PutBodyToSleep {
     fn from(entity: Entity) -> Self {
         Self(entity)
     }
@@ -301,7 +393,8 @@ impl From<LockedAxes> for RapierLockedAxes {
 
 /// Constant external forces applied continuously to a [`RigidBody`].
 ///
-/// This force is applied at each timestep.
+/// This force is applied at each timestep, until the component is modified or removed. Use
+/// [`ForceMode::OneFrame`] for forces that must only be applied once.
 #[derive(Copy, Clone, Debug, Default, PartialEq, Component, Reflect)]
 #[reflect(Component, PartialEq)]
 pub struct ExternalForce {
@@ -370,6 +463,41 @@ impl SubAssign for ExternalForce {
     }
 }
 
+/// How long the [`ExternalForce`] and [`ExternalForceAtPoint`] of a [`RigidBody`] are applied.
+///
+/// A [`RigidBody`] without this component uses [`ForceMode::Persistent`].
+///
+/// A persistent force keeps pushing the rigid-body until it is modified, which suits
+/// continuous effects like thrusters or wind:
+/// ```ignore
+/// commands.spawn((
+///     RigidBody::Dynamic,
+///     ExternalForce { force: Vec3::Y * 20.0, ..default() },
+/// ));
+/// ```
+///
+/// A one-frame force is reset to zero once a simulation step applied it, like an
+/// [`ExternalImpulse`], which suits punctual effects like a jump pad. Setting the force again
+/// applies it again:
+/// ```ignore
+/// commands.spawn((
+///     RigidBody::Dynamic,
+///     ExternalForce { force: Vec3::Y * 2000.0, ..default() },
+///     ForceMode::OneFrame,
+/// ));
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Component, Reflect)]
+#[reflect(Component, Default, PartialEq, Hash)]
+pub enum ForceMode {
+    /// The forces are applied at each timestep, until they are modified or removed.
+    #[default]
+    Persistent,
+    /// The forces are applied during the simulation steps of the next frame where the
+    /// simulation is stepped (all of them, if several steps run during that frame). Both the
+    /// components and the forces of the Rapier rigid-body are then reset to zero.
+    OneFrame,
+}
+
 /// Instantaneous external impulse applied continuously to a [`RigidBody`].
 ///
 /// The impulse is only applied once, and whenever it it modified (based
@@ -447,6 +575,53 @@ impl SubAssign for ExternalImpulse {
     }
 }
 
+/// Constant external force applied continuously at a world-space point of a [`RigidBody`].
+///
+/// Unlike [`ExternalForce::at_point`], the torque resulting from this force is computed from
+/// the center-of-mass of the [`RigidBody`] whenever this component is added or modified. The
+/// torque isn’t updated when the rigid-body moves afterward.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Component, Reflect)]
+#[reflect(Component, PartialEq)]
+pub struct ExternalForceAtPoint {
+    /// The linear force applied to the [`RigidBody`].
+    pub force: Vect,
+    /// The world-space point where the force is applied.
+    pub point: Vect,
+}
+
+impl ExternalForceAtPoint {
+    /// A force applied at a world-space point of a [`RigidBody`].
+    pub fn new(force: Vect, point: Vect) -> Self {
+        Self { force, point }
+    }
+}
+
+/// Instantaneous external impulse applied at a world-space point of a [`RigidBody`].
+///
+/// Unlike [`ExternalImpulse::at_point`], the angular impulse resulting from this impulse is
+/// computed from the current center-of-mass of the [`RigidBody`]. The impulse is only applied
+/// once, and whenever it is modified (based on Bevy’s change detection).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Component, Reflect)]
+#[reflect(Component, PartialEq)]
+pub struct ExternalImpulseAtPoint {
+    /// The linear impulse applied to the [`RigidBody`].
+    pub impulse: Vect,
+    /// The world-space point where the impulse is applied.
+    pub point: Vect,
+}
+
+impl ExternalImpulseAtPoint {
+    /// An impulse applied at a world-space point of a [`RigidBody`].
+    pub fn new(impulse: Vect, point: Vect) -> Self {
+        Self { impulse, point }
+    }
+
+    /// Reset the external impulse to zero.
+    pub fn reset(&mut self) {
+        *self = Default::default();
+    }
+}
+
 /// Gravity is multiplied by this scaling factor before it's
 /// applied to this [`RigidBody`].
 #[derive(Copy, Clone, Debug, PartialEq, Component, Reflect)]
@@ -459,6 +634,15 @@ impl Default for GravityScale {
     }
 }
 
+/// Replaces the world gravity applied to this [`RigidBody`] by a custom acceleration.
+///
+/// While this component is present, the [`GravityScale`] of the rigid-body is ignored, and the
+/// force `mass * acceleration` is applied to it instead. This force is updated whenever the
+/// mass of the rigid-body changes.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Component, Reflect)]
+#[reflect(Component, PartialEq)]
+pub struct GravityOverride(pub Vect);
+
 /// Denotes which world this body is a part of. If omitted, the default world is assumed.
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Component, Reflect)]
 #[reflect(Component, PartialEq)]
@@ -525,8 +709,12 @@ impl Dominance {
 
 /// The activation status of a body.
 ///
-/// This controls whether a body is sleeping or not.
-/// If the threshold is negative, the body never sleeps.
+/// The thresholds are owned by the user: modifying them is applied to the physics rigid-body
+/// before the next simulation step. If a threshold is negative, the body never sleeps.
+///
+/// The [`Sleeping::sleeping`] flag, on the other hand, is overwritten after each simulation
+/// step with the actual state of the physics rigid-body. To change the state of a body
+/// reliably, send a [`WakeUpBody`] or [`PutBodyToSleep`] event instead of modifying this flag.
 #[derive(Copy, Clone, Debug, PartialEq, Component, Reflect)]
 #[reflect(Component, PartialEq)]
 pub struct Sleeping {
@@ -538,6 +726,8 @@ pub struct Sleeping {
     /// The angular velocity below which the body can fall asleep.
     pub angular_threshold: f32,
     /// Is this body sleeping?
+    ///
+    /// This is read back from the physics rigid-body after each simulation step.
     pub sleeping: bool,
 }
 
@@ -582,15 +772,175 @@ impl Default for Damping {
     }
 }
 
-/// If the `TimestepMode::Interpolated` mode is set and this component is present,
-/// the associated [`RigidBody`] will have its position automatically interpolated
-/// between the last two [`RigidBody`] positions set by the physics engine.
-#[derive(Copy, Clone, Debug, Default, PartialEq, Component)]
+/// How a [`RigidBody`] with a parent is affected by the motion of its ancestors.
+///
+/// Without this component, a child rigid-body uses [`PhysicsHierarchyMode::Relative`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component, PartialEq)]
+pub enum PhysicsHierarchyMode {
+    /// The rigid-body is carried along by its parent: the parent’s velocity is added to its own
+    /// during the simulation step, and its [`Velocity`] is relative to its parent.
+    ///
+    /// This is well suited for bodies moving inside a moving parent, like characters walking
+    /// inside a ship.
+    #[default]
+    Relative,
+    /// The rigid-body is simulated as if it had no parent: its [`Velocity`] is absolute, and its
+    /// `Transform` is only computed from its simulated position relative to its parent.
+    ///
+    /// This avoids any velocity coupling for simple hierarchies, like the lid of a chest.
+    Independent,
+}
+
+/// Upper bounds of the linear and angular speeds of a [`RigidBody`].
+///
+/// The velocities of the rigid-body are clamped after each simulation step, without waking it
+/// up. A bound set to `f32::INFINITY` leaves the corresponding velocity unclamped.
+#[derive(Copy, Clone, Debug, PartialEq, Component, Reflect)]
+#[reflect(Component, PartialEq)]
+pub struct MaxVelocity {
+    /// The maximum length of the linear velocity of the [`RigidBody`].
+    pub linear: f32,
+    /// The maximum length of the angular velocity of the [`RigidBody`].
+    pub angular: f32,
+}
+
+impl MaxVelocity {
+    /// Limits only the linear velocity of the [`RigidBody`].
+    pub fn linear(linear: f32) -> Self {
+        Self {
+            linear,
+            ..Default::default()
+        }
+    }
+
+    /// Limits only the angular velocity of the [`RigidBody`].
+    pub fn angular(angular: f32) -> Self {
+        Self {
+            angular,
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for MaxVelocity {
+    fn default() -> Self {
+        Self {
+            linear: f32::INFINITY,
+            angular: f32::INFINITY,
+        }
+    }
+}
+
+/// A pose a [`RigidBody::KinematicVelocityBased`] rigid-body is driven to by the physics systems.
+///
+/// Before each run of the simulation, the linear and angular velocities of the rigid-body are set
+/// so it reaches the target exactly at the end of the steps executed during this run (a single
+/// step in most [`TimestepMode`](crate::plugin::TimestepMode)s), unless limited by the maximum
+/// speeds. Once the target is reached, the velocities are zero. This is the easiest way to move
+/// platforms or doors that push the dynamic rigid-bodies they touch.
+///
+/// The target is expressed in world-space. This component is ignored by the other rigid-body
+/// types, and it overwrites the velocities set with [`Velocity`].
+#[derive(Copy, Clone, Debug, PartialEq, Component, Reflect)]
+#[reflect(Component, PartialEq)]
+pub struct KinematicTarget {
+    /// The translation to reach. If `None`, the current translation is kept.
+    pub translation: Option<Vect>,
+    /// The rotation to reach. If `None`, the current rotation is kept.
+    pub rotation: Option<Rot>,
+    /// The maximum linear speed used to reach the target.
+    pub max_linear_speed: f32,
+    /// The maximum angular speed, in radians per second, used to reach the target.
+    pub max_angular_speed: f32,
+}
+
+impl KinematicTarget {
+    /// Drives the rigid-body to the given translation, keeping its rotation.
+    pub fn translation(translation: Vect) -> Self {
+        Self {
+            translation: Some(translation),
+            ..Default::default()
+        }
+    }
+
+    /// Drives the rigid-body to the given rotation, keeping its translation.
+    pub fn rotation(rotation: Rot) -> Self {
+        Self {
+            rotation: Some(rotation),
+            ..Default::default()
+        }
+    }
+
+    /// Limits the linear and angular speeds used to reach the target.
+    pub fn with_max_speeds(mut self, linear: f32, angular: f32) -> Self {
+        self.max_linear_speed = linear;
+        self.max_angular_speed = angular;
+        self
+    }
+}
+
+impl Default for KinematicTarget {
+    fn default() -> Self {
+        Self {
+            translation: None,
+            rotation: None,
+            max_linear_speed: f32::INFINITY,
+            max_angular_speed: f32::INFINITY,
+        }
+    }
+}
+
+/// The contact forces applied to a [`RigidBody`] by the other colliders it touches.
+///
+/// When this is used as a component, it is updated after each run of the simulation with the
+/// forces of the last step. The contacts of the rigid-bodies without this component aren’t
+/// inspected. The forces are the magnitudes of the total normal impulses of each contact pair,
+/// divided by the length of the step.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Component, Reflect)]
+#[reflect(Component, PartialEq)]
+pub struct ReadContactForces {
+    /// The sum of the magnitudes of the contact forces applied by each touching collider.
+    pub total_force: f32,
+    /// The magnitude of the strongest contact force applied by a single touching collider.
+    pub max_force: f32,
+    /// The number of active contact points.
+    pub contact_count: u32,
+}
+
+/// If the `TimestepMode::Interpolated` or `TimestepMode::FixedSchedule` mode is set and this
+/// component is present, the associated [`RigidBody`] will have its position automatically
+/// interpolated between the last two [`RigidBody`] positions set by the physics engine.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Component, Reflect)]
+#[reflect(Component, Default, PartialEq)]
 pub struct TransformInterpolation {
     /// The starting point of the interpolation.
+    #[reflect(ignore)]
     pub start: Option<Isometry<f32>>,
     /// The end point of the interpolation.
+    #[reflect(ignore)]
     pub end: Option<Isometry<f32>>,
+    /// How the position of the rigid-body is estimated in-between the physics steps.
+    pub mode: InterpolationMode,
+}
+
+/// How the position of a rigid-body with a [`TransformInterpolation`] is estimated in-between
+/// the physics steps.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Default, PartialEq)]
+pub enum InterpolationMode {
+    /// Interpolate between the last two positions set by the physics engine. This lags up to
+    /// one physics step behind the simulation.
+    #[default]
+    Interpolate,
+    /// Predict the position of the rigid-body from its current position and velocity.
+    ///
+    /// Rigid-bodies with active contacts are interpolated instead, so they aren’t rendered
+    /// inside of obstacles.
+    Extrapolate,
+    /// Same as [`InterpolationMode::Extrapolate`], but never predicts more than the given
+    /// number of seconds ahead.
+    ExtrapolateClamped(f32),
 }
 
 impl TransformInterpolation {
@@ -605,6 +955,10 @@ impl TransformInterpolation {
 }
 
 /// Indicates whether or not the [`RigidBody`] is disabled explicitly by the user.
+///
+/// A disabled rigid-body isn’t simulated, and its colliders are disabled too: starting from the
+/// next simulation step, they neither interact with other colliders nor appear in the results of
+/// scene queries. Remove this component to enable the rigid-body again.
 #[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Component, Reflect)]
 #[reflect(Component, PartialEq)]
 pub struct RigidBodyDisabled;
@@ -1,5 +1,6 @@
 use crate::dynamics::{GenericJoint, GenericJointBuilder};
 use crate::math::{Real, Vect};
+use bevy::prelude::GlobalTransform;
 use rapier::dynamics::{JointAxesMask, JointAxis, JointLimits, JointMotor, MotorModel};
 
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
@@ -177,6 +178,24 @@ impl RopeJointBuilder {
         Self(RopeJoint::new(max_dist))
     }
 
+    /// Creates a new builder for rope joints attached to the given world-space anchors, given
+    /// the current transforms of the two rigid-bodies.
+    ///
+    /// The maximum length of the rope is set to the current distance between the anchors.
+    pub fn world_anchors(
+        anchor1: Vect,
+        anchor2: Vect,
+        parent_transform: &GlobalTransform,
+        child_transform: &GlobalTransform,
+    ) -> Self {
+        let mut result = Self::new(anchor1.distance(anchor2));
+        result
+            .0
+            .data
+            .set_world_anchors(anchor1, anchor2, parent_transform, child_transform);
+        result
+    }
+
     /// Sets the joint’s anchor, expressed in the local-space of the first rigid-body.
     #[must_use]
     pub fn local_anchor1(mut self, anchor1: Vect) -> Self {
@@ -267,3 +286,24 @@ impl From<RopeJointBuilder> for GenericJoint {
         joint.0.into()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rope_joint_converts_to_generic_joint() {
+        let mut joint: GenericJoint = RopeJointBuilder::new(2.0).local_anchor2(Vect::Y).into();
+
+        assert!(joint.locked_axes().is_empty());
+        assert_eq!(joint.raw.coupled_axes, JointAxesMask::LIN_AXES);
+        assert_eq!(joint.raw.limit_axes, JointAxis::X.into());
+        assert_eq!(joint.local_anchor2(), Vect::Y);
+
+        let limits = joint.limits(JointAxis::X).unwrap();
+        assert_eq!([limits.min, limits.max], [0.0, 2.0]);
+
+        joint.as_rope_mut().unwrap().set_max_distance(3.0);
+        assert_eq!(joint.as_rope().unwrap().max_distance(), 3.0);
+    }
+}
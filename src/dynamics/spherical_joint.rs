@@ -1,5 +1,6 @@
 use crate::dynamics::{GenericJoint, GenericJointBuilder};
 use crate::math::{Real, Vect};
+use bevy::prelude::{GlobalTransform, Quat};
 use rapier::dynamics::{JointAxesMask, JointAxis, JointLimits, JointMotor, MotorModel};
 
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
@@ -155,6 +156,21 @@ impl SphericalJointBuilder {
         Self(SphericalJoint::new())
     }
 
+    /// Creates a new builder for spherical joints rotating around the given world-space anchor,
+    /// given the current transforms of the two rigid-bodies.
+    pub fn world_anchor(
+        anchor: Vect,
+        parent_transform: &GlobalTransform,
+        child_transform: &GlobalTransform,
+    ) -> Self {
+        let mut result = Self::new();
+        result
+            .0
+            .data
+            .set_world_frame(anchor, Quat::IDENTITY, parent_transform, child_transform);
+        result
+    }
+
     /// Sets the joint’s anchor, expressed in the local-space of the first rigid-body.
     #[must_use]
     pub fn local_anchor1(mut self, anchor1: Vect) -> Self {
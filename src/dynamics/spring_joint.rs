@@ -1,6 +1,7 @@
 use crate::dynamics::{GenericJoint, GenericJointBuilder, JointAxesMask};
 use crate::dynamics::{JointAxis, MotorModel};
 use crate::math::{Real, Vect};
+use bevy::prelude::GlobalTransform;
 
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -78,6 +79,54 @@ impl SpringJoint {
         self.data.set_motor_model(JointAxis::X, model);
         self
     }
+
+    /// The distance between the anchors at which the spring doesn’t apply any force.
+    pub fn rest_length(&self) -> Real {
+        self.data
+            .motor(JointAxis::X)
+            .map(|m| m.target_pos)
+            .unwrap_or(0.0)
+    }
+
+    /// Sets the distance between the anchors at which the spring doesn’t apply any force.
+    pub fn set_rest_length(&mut self, rest_length: Real) -> &mut Self {
+        let (stiffness, damping) = (self.stiffness(), self.damping());
+        self.data
+            .set_motor_position(JointAxis::X, rest_length, stiffness, damping);
+        self
+    }
+
+    /// The stiffness of the spring.
+    pub fn stiffness(&self) -> Real {
+        self.data
+            .motor(JointAxis::X)
+            .map(|m| m.stiffness)
+            .unwrap_or(0.0)
+    }
+
+    /// Sets the stiffness of the spring.
+    pub fn set_stiffness(&mut self, stiffness: Real) -> &mut Self {
+        let (rest_length, damping) = (self.rest_length(), self.damping());
+        self.data
+            .set_motor_position(JointAxis::X, rest_length, stiffness, damping);
+        self
+    }
+
+    /// The damping of the spring.
+    pub fn damping(&self) -> Real {
+        self.data
+            .motor(JointAxis::X)
+            .map(|m| m.damping)
+            .unwrap_or(0.0)
+    }
+
+    /// Sets the damping of the spring.
+    pub fn set_damping(&mut self, damping: Real) -> &mut Self {
+        let (rest_length, stiffness) = (self.rest_length(), self.stiffness());
+        self.data
+            .set_motor_position(JointAxis::X, rest_length, stiffness, damping);
+        self
+    }
 }
 
 impl From<SpringJoint> for GenericJoint {
@@ -102,6 +151,26 @@ impl SpringJointBuilder {
         Self(SpringJoint::new(rest_length, stiffness, damping))
     }
 
+    /// Creates a new builder for spring joints attached to the given world-space anchors, given
+    /// the current transforms of the two rigid-bodies.
+    ///
+    /// The rest length of the spring is set to the current distance between the anchors.
+    pub fn world_anchors(
+        anchor1: Vect,
+        anchor2: Vect,
+        stiffness: Real,
+        damping: Real,
+        parent_transform: &GlobalTransform,
+        child_transform: &GlobalTransform,
+    ) -> Self {
+        let mut result = Self::new(anchor1.distance(anchor2), stiffness, damping);
+        result
+            .0
+            .data
+            .set_world_anchors(anchor1, anchor2, parent_transform, child_transform);
+        result
+    }
+
     /// Sets whether contacts between the attached rigid-bodies are enabled.
     #[must_use]
     pub fn contacts_enabled(mut self, enabled: bool) -> Self {
@@ -135,6 +204,27 @@ impl SpringJointBuilder {
         self
     }
 
+    /// Sets the distance between the anchors at which the spring doesn’t apply any force.
+    #[must_use]
+    pub fn rest_length(mut self, rest_length: Real) -> Self {
+        self.0.set_rest_length(rest_length);
+        self
+    }
+
+    /// Sets the stiffness of the spring.
+    #[must_use]
+    pub fn stiffness(mut self, stiffness: Real) -> Self {
+        self.0.set_stiffness(stiffness);
+        self
+    }
+
+    /// Sets the damping of the spring.
+    #[must_use]
+    pub fn damping(mut self, damping: Real) -> Self {
+        self.0.set_damping(damping);
+        self
+    }
+
     /// Builds the spring joint.
     #[must_use]
     pub fn build(self) -> SpringJoint {
@@ -147,3 +237,37 @@ impl From<SpringJointBuilder> for GenericJoint {
         val.0.into()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn spring_joint_converts_to_generic_joint() {
+        let joint: GenericJoint = SpringJointBuilder::new(2.0, 10.0, 0.5)
+            .local_anchor1(Vect::X)
+            .into();
+
+        assert!(joint.locked_axes().is_empty());
+        assert_eq!(joint.raw.coupled_axes, JointAxesMask::LIN_AXES);
+        assert_eq!(joint.local_anchor1(), Vect::X);
+
+        let motor = joint.motor(JointAxis::X).unwrap();
+        assert_eq!(motor.target_pos, 2.0);
+        assert_eq!(motor.stiffness, 10.0);
+        assert_eq!(motor.damping, 0.5);
+        assert!(matches!(motor.model, MotorModel::ForceBased));
+    }
+
+    #[test]
+    fn spring_joint_setters_keep_other_coefficients() {
+        let spring = SpringJointBuilder::new(2.0, 10.0, 0.5)
+            .rest_length(3.0)
+            .stiffness(20.0)
+            .build();
+
+        assert_eq!(spring.rest_length(), 3.0);
+        assert_eq!(spring.stiffness(), 20.0);
+        assert_eq!(spring.damping(), 0.5);
+    }
+}
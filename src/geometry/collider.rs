@@ -1,36 +1,115 @@
 use std::fmt;
 
 #[cfg(all(feature = "dim3", feature = "async-collider"))]
-use {crate::geometry::VHACDParameters, bevy::utils::HashMap};
+use bevy::utils::HashMap;
+#[cfg(feature = "async-collider")]
+use {
+    crate::geometry::VHACDParameters,
+    bevy::tasks::{AsyncComputeTaskPool, Task},
+};
 
 use bevy::prelude::*;
 
 use bevy::utils::HashSet;
 use rapier::geometry::Shape;
+use rapier::parry::shape::TopologyError;
 use rapier::prelude::{ColliderHandle, InteractionGroups, SharedShape};
 
 use crate::dynamics::{CoefficientCombineRule, MassProperties};
+#[cfg(feature = "async-collider")]
+use crate::geometry::TriMeshFlags;
 use crate::math::Vect;
 
 /// The Rapier handle of a collider that was inserted to the physics scene.
 #[derive(Copy, Clone, Debug, Component)]
 pub struct RapierColliderHandle(pub ColliderHandle);
 
+/// The Rapier handles of the colliders of a [`ColliderMultiple`] that were inserted to the physics
+/// scene.
+#[derive(Clone, Debug, Component)]
+pub struct RapierColliderHandles(pub Vec<ColliderHandle>);
+
 /// A component which will be replaced by the specified collider type after the referenced mesh become available.
-#[cfg(all(feature = "dim3", feature = "async-collider"))]
-#[derive(Component, Debug, Clone, Default)]
+///
+/// In 2D, the mesh vertices are projected onto the `XY` plane.
+#[cfg(feature = "async-collider")]
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component, Default)]
 pub struct AsyncCollider(pub ComputedColliderShape);
 
+/// A component holding the background task generating a [`Collider`] from a mesh.
+///
+/// It is inserted by the async collider systems, and removed once the task completes, along with
+/// the [`AsyncCollider`], if any, if a collider was generated.
+#[cfg(feature = "async-collider")]
+#[derive(Component, Debug)]
+pub struct ColliderGenerationTask {
+    pub(crate) task: Task<Result<Collider, MeshColliderError>>,
+    // The components inserted alongside the generated collider.
+    pub(crate) collision_groups: Option<CollisionGroups>,
+    pub(crate) sensor: bool,
+}
+
+#[cfg(feature = "async-collider")]
+impl ColliderGenerationTask {
+    /// Generates, on the [`AsyncComputeTaskPool`], a collider from the given mesh.
+    pub fn spawn(mesh: Mesh, shape: ComputedColliderShape) -> Self {
+        let task = AsyncComputeTaskPool::get()
+            .spawn(async move { Collider::try_from_bevy_mesh(&mesh, &shape) });
+        Self {
+            task,
+            collision_groups: None,
+            sensor: false,
+        }
+    }
+
+    /// Inserts these collision groups and, if `sensor` is `true`, a [`Sensor`] alongside the
+    /// generated collider. Nothing is inserted if no collider could be generated.
+    pub(crate) fn with_components(
+        mut self,
+        collision_groups: Option<CollisionGroups>,
+        sensor: bool,
+    ) -> Self {
+        self.collision_groups = collision_groups;
+        self.sensor = sensor;
+        self
+    }
+}
+
+/// Event emitted when no collider could be generated from the mesh of an [`AsyncCollider`] or
+/// of an `AsyncSceneCollider`.
+///
+/// The [`AsyncCollider`], if any, is left in place. The generation is only retried once its mesh
+/// handle, or the mesh asset itself, is modified, or once the `AsyncCollider` is modified.
+#[cfg(feature = "async-collider")]
+#[derive(Event, Clone, Debug)]
+pub struct AsyncColliderFailedEvent {
+    /// The entity on which the collider was supposed to be inserted.
+    pub entity: Entity,
+    /// Why the collider couldn’t be generated.
+    pub error: MeshColliderError,
+}
+
+/// Marks the entities whose [`AsyncCollider`] failed to generate a collider from their current
+/// mesh, so the generation isn’t retried every frame.
+#[cfg(feature = "async-collider")]
+#[derive(Copy, Clone, Debug, Default, Component)]
+pub(crate) struct AsyncColliderFailed;
+
 /// A component which will be replaced the specified collider types on children with meshes after the referenced scene become available.
 #[cfg(all(feature = "dim3", feature = "async-collider"))]
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Default)]
 pub struct AsyncSceneCollider {
     /// Collider type for each scene mesh not included in [`named_shapes`]. If [`None`], then all
     /// shapes will be skipped for processing except [`named_shapes`].
     pub shape: Option<ComputedColliderShape>,
     /// Shape types for meshes by name. If shape is [`None`], then it will be skipped for
     /// processing.
-    pub named_shapes: HashMap<String, Option<ComputedColliderShape>>,
+    ///
+    /// Names frequently collide between glTF nodes: use an [`AsyncSceneColliderOverride`] to
+    /// target a specific entity instead.
+    pub named_shapes: HashMap<String, Option<AsyncSceneColliderShape>>,
 }
 
 #[cfg(all(feature = "dim3", feature = "async-collider"))]
@@ -43,9 +122,85 @@ impl Default for AsyncSceneCollider {
     }
 }
 
-/// Shape type based on a Bevy mesh asset.
+/// The collider generated for a mesh of a scene with an [`AsyncSceneCollider`].
 #[cfg(all(feature = "dim3", feature = "async-collider"))]
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Reflect)]
+#[reflect(Default)]
+pub struct AsyncSceneColliderShape {
+    /// The type of shape computed from the mesh.
+    pub shape: ComputedColliderShape,
+    /// If set, the mesh is decomposed into convex parts with these parameters, regardless of
+    /// [`Self::shape`].
+    ///
+    /// This isn’t reflected, since [`VHACDParameters`] can’t be.
+    #[reflect(ignore)]
+    pub vhacd_parameters: Option<VHACDParameters>,
+    /// The collision groups inserted alongside the collider.
+    pub collision_groups: Option<CollisionGroups>,
+    /// Whether a [`Sensor`] is inserted alongside the collider.
+    pub sensor: bool,
+}
+
+#[cfg(all(feature = "dim3", feature = "async-collider"))]
+impl AsyncSceneColliderShape {
+    /// Creates the description of a collider with the given shape type.
+    pub fn new(shape: ComputedColliderShape) -> Self {
+        Self {
+            shape,
+            ..Default::default()
+        }
+    }
+
+    /// Decomposes the mesh into convex parts with the given parameters.
+    pub fn vhacd_parameters(mut self, parameters: VHACDParameters) -> Self {
+        self.vhacd_parameters = Some(parameters);
+        self
+    }
+
+    /// Sets the collision groups inserted alongside the collider.
+    pub fn collision_groups(mut self, groups: CollisionGroups) -> Self {
+        self.collision_groups = Some(groups);
+        self
+    }
+
+    /// Sets whether a [`Sensor`] is inserted alongside the collider.
+    pub fn sensor(mut self, sensor: bool) -> Self {
+        self.sensor = sensor;
+        self
+    }
+
+    /// The type of shape computed from the mesh, taking [`Self::vhacd_parameters`] into account.
+    pub fn computed_shape(&self) -> ComputedColliderShape {
+        match self.vhacd_parameters {
+            Some(parameters) => ComputedColliderShape::ConvexDecomposition(parameters),
+            None => self.shape.clone(),
+        }
+    }
+}
+
+#[cfg(all(feature = "dim3", feature = "async-collider"))]
+impl From<ComputedColliderShape> for AsyncSceneColliderShape {
+    fn from(shape: ComputedColliderShape) -> Self {
+        Self::new(shape)
+    }
+}
+
+/// Overrides, for this entity and its descendants, the colliders generated by the
+/// [`AsyncSceneCollider`] of the scene it belongs to.
+///
+/// This takes precedence over [`AsyncSceneCollider::named_shapes`]. If [`None`], this entity and
+/// its descendants will be skipped for processing.
+#[cfg(all(feature = "dim3", feature = "async-collider"))]
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct AsyncSceneColliderOverride(pub Option<AsyncSceneColliderShape>);
+
+/// Shape type based on a Bevy mesh asset.
+///
+/// This is reflected as an opaque value, since [`VHACDParameters`] and [`TriMeshFlags`] can’t be.
+#[cfg(feature = "async-collider")]
+#[derive(Debug, Clone, Default, Reflect)]
+#[reflect_value(Debug, Default)]
 pub enum ComputedColliderShape {
     /// Triangle-mesh.
     #[default]
@@ -53,9 +208,80 @@ pub enum ComputedColliderShape {
     /// Convex hull.
     ConvexHull,
     /// Convex decomposition.
+    ///
+    /// In 2D, the boundary of the mesh is decomposed.
     ConvexDecomposition(VHACDParameters),
+    /// Polyline following the boundary of the mesh.
+    #[cfg(feature = "dim2")]
+    Polyline,
+    /// Triangle-mesh with custom flags controlling its pre-processing.
+    ///
+    /// [`ComputedColliderShape::TriMesh`] uses [`TriMeshFlags::MERGE_DUPLICATE_VERTICES`].
+    TriMeshWithFlags(TriMeshFlags),
+}
+
+/// The reason why a collider couldn’t be generated from a mesh.
+#[derive(Clone, Debug)]
+pub enum MeshColliderError {
+    /// The mesh doesn’t use the `TriangleList` primitive topology.
+    UnsupportedTopology,
+    /// The mesh doesn’t have the `Mesh::ATTRIBUTE_POSITION` attribute.
+    MissingPositions,
+    /// The vertex positions of the mesh aren’t in the `Float32` or `Float32x3` format.
+    IncompatibleFormat,
+    /// The mesh doesn’t have any vertex.
+    NoVertices,
+    /// The mesh doesn’t have an index buffer.
+    MissingIndices,
+    /// The mesh doesn’t have any triangle (in 2D, any triangle with a non-zero area).
+    NoTriangles,
+    /// A triangle refers to a vertex that doesn’t exist.
+    IndexOutOfBounds {
+        /// The index of the invalid triangle.
+        triangle: usize,
+    },
+    /// The triangles don’t form a valid topology for the requested
+    /// [`TriMeshFlags`](crate::geometry::TriMeshFlags).
+    Topology(TopologyError),
+    /// The convex hull of the mesh couldn’t be computed, for example because all its vertices are
+    /// coplanar (collinear in 2D).
+    ConvexHullFailed,
+    /// The convex decomposition (VHACD) of the mesh didn’t produce any convex part.
+    ConvexDecompositionFailed,
+}
+
+impl fmt::Display for MeshColliderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedTopology => {
+                f.write_str("the mesh doesn’t use the `TriangleList` primitive topology")
+            }
+            Self::MissingPositions => f.write_str("the mesh doesn’t have vertex positions"),
+            Self::IncompatibleFormat => f.write_str(
+                "the vertex positions of the mesh aren’t in the `Float32` or `Float32x3` format",
+            ),
+            Self::NoVertices => f.write_str("the mesh doesn’t have any vertex"),
+            Self::MissingIndices => f.write_str("the mesh doesn’t have an index buffer"),
+            Self::NoTriangles => f.write_str("the mesh doesn’t have any triangle"),
+            Self::IndexOutOfBounds { triangle } => {
+                write!(
+                    f,
+                    "the triangle {triangle} refers to a vertex that doesn’t exist"
+                )
+            }
+            Self::Topology(error) => write!(f, "the mesh has an invalid topology: {error:?}"),
+            Self::ConvexHullFailed => {
+                f.write_str("the convex hull of the mesh couldn’t be computed")
+            }
+            Self::ConvexDecompositionFailed => {
+                f.write_str("the convex decomposition of the mesh is empty")
+            }
+        }
+    }
 }
 
+impl std::error::Error for MeshColliderError {}
+
 /// A geometric entity that can be attached to a [`RigidBody`] so it can be affected by contacts
 /// and intersection queries.
 ///
@@ -72,13 +298,47 @@ pub enum ComputedColliderShape {
 /// - [`CollidingEntities`]
 /// - [`ColliderScale`]
 /// - [`ColliderDisabled`]
-#[derive(Component, Clone)] // TODO: Reflect
+///
+/// This is reflected as an opaque value, which can only be serialized (in a scene for example)
+/// if the `serde-serialize` feature is enabled.
+#[derive(Component, Clone, Reflect)]
+#[reflect_value(Component, Debug)]
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-serialize", reflect_value(Serialize, Deserialize))]
 pub struct Collider {
     /// The raw shape from Rapier.
     pub raw: SharedShape,
     pub(crate) unscaled: SharedShape,
     pub(crate) scale: Vect,
+    // The number of subdivisions used to compute `raw`, if it is an approximation.
+    #[cfg_attr(feature = "serde-serialize", serde(skip))]
+    pub(crate) raw_subdivisions: u32,
+    // The last scaled shape replaced by `set_scale`, with its scale. Setting that scale again
+    // reuses this shape instead of recomputing it.
+    #[cfg_attr(feature = "serde-serialize", serde(skip))]
+    pub(crate) scaled_cache: Option<(Vect, SharedShape)>,
+}
+
+/// Multiple colliders attached to a single entity, each one positioned relative to the entity.
+///
+/// This avoids spawning one child entity per collider for simple compound shapes. The other
+/// collider-related components of the entity (like [`Friction`] or [`CollisionGroups`]) apply to
+/// all these colliders.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct ColliderMultiple(pub Vec<(Transform, Collider)>);
+
+impl ColliderMultiple {
+    /// Creates a set of colliders from their transforms relative to the entity.
+    pub fn new(colliders: Vec<(Transform, Collider)>) -> Self {
+        Self(colliders)
+    }
+
+    /// Adds a collider, positioned relative to the entity.
+    pub fn with(mut self, transform: Transform, collider: Collider) -> Self {
+        self.0.push((transform, collider));
+        self
+    }
 }
 
 impl From<SharedShape> for Collider {
@@ -87,6 +347,8 @@ impl From<SharedShape> for Collider {
             raw: shared_shape.clone(),
             unscaled: shared_shape,
             scale: Vect::ONE,
+            raw_subdivisions: 0,
+            scaled_cache: None,
         }
     }
 }
@@ -105,6 +367,7 @@ impl fmt::Debug for Collider {
 
 /// Overwrites the default application of [`GlobalTransform::scale`] to a [`Collider`]'s shapes.
 #[derive(Copy, Clone, Debug, PartialEq, Component, Reflect)]
+#[reflect(Component, PartialEq)]
 pub enum ColliderScale {
     /// This scale will be multiplied with the scale in the [`GlobalTransform`] component
     /// before being applied to the collider.
@@ -136,6 +399,34 @@ impl Default for ColliderMassProperties {
     }
 }
 
+/// The mass-properties of a [`Collider`], as computed by the physics engine.
+///
+/// When this is used as a component, this lets you read the mass properties of an individual
+/// collider, after its density and scale were applied. Modifying this component won’t affect
+/// the mass-properties of the [`Collider`] (its `ColliderMassProperties` should be modified
+/// instead).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Component, Reflect)]
+#[reflect(Component, PartialEq)]
+pub struct ReadColliderMassProperties(pub MassProperties);
+
+impl ReadColliderMassProperties {
+    /// Get the [`MassProperties`] of this [`Collider`].
+    pub fn get(&self) -> &MassProperties {
+        &self.0
+    }
+
+    pub(crate) fn set(&mut self, mass_props: MassProperties) {
+        self.0 = mass_props;
+    }
+}
+
+impl std::ops::Deref for ReadColliderMassProperties {
+    type Target = MassProperties;
+    fn deref(&self) -> &Self::Target {
+        self.get()
+    }
+}
+
 /// The friction affecting a [`Collider`].
 #[derive(Copy, Clone, Debug, PartialEq, Component, Reflect)]
 #[reflect(Component, PartialEq)]
@@ -504,15 +795,86 @@ impl Default for ContactForceEventThreshold {
 #[reflect(Component)]
 pub struct ContactSkin(pub f32);
 
+/// Turns a collider into a conveyor belt, transporting the bodies in contact with it at the
+/// given surface velocity without moving the collider itself.
+///
+/// The [`ActiveHooks::MODIFY_SOLVER_CONTACTS`] flag is automatically added to the entity’s
+/// [`ActiveHooks`], and removed along with the `ConveyorBelt` unless it was already set. The
+/// user-defined physics hooks are still called after the surface velocity was applied to the
+/// solver contacts.
+#[derive(Copy, Clone, Debug, PartialEq, Default, Component, Reflect)]
+#[reflect(Component)]
+pub struct ConveyorBelt {
+    /// The velocity of the surface of the collider, expressed in its local-space.
+    ///
+    /// Only its component tangent to the contact normal is applied.
+    pub velocity: Vect,
+}
+
+/// Marks the entities whose [`ActiveHooks::MODIFY_SOLVER_CONTACTS`] flag was added by their
+/// [`ConveyorBelt`], so the flag is removed along with the belt.
+#[derive(Copy, Clone, Debug, Default, Component)]
+pub(crate) struct ConveyorBeltActiveHooks;
+
+/// Turns a sensor collider into a volume of fluid, applying buoyancy and drag to the dynamic
+/// rigid-bodies intersecting it.
+///
+/// The buoyant force of each collider is `density * gravity * submerged_volume`, applied at the
+/// center of its submerged part, using the gravity of the fluid’s world. The submerged part of a
+/// collider is approximated by the intersection of its AABB with the AABB of the fluid, so the
+/// fluid volume is best described by a cuboid.
+///
+/// The densities are expressed in the same unit as [`ColliderMassProperties::Density`]: a body
+/// less dense than the fluid floats, and a body denser than the fluid sinks.
+///
+/// ```ignore
+/// commands.spawn((
+///     TransformBundle::from(Transform::from_xyz(0.0, -5.0, 0.0)),
+///     Collider::cuboid(20.0, 5.0, 20.0),
+///     Sensor,
+///     FluidVolume::default(),
+/// ));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct FluidVolume {
+    /// The density of the fluid.
+    pub density: f32,
+    /// The damping of the linear velocity of the bodies, relative to the flow, when they are
+    /// entirely submerged.
+    ///
+    /// Like [`Damping`](crate::dynamics::Damping), this is a rate per second, scaled by the
+    /// submerged fraction of the colliders.
+    pub linear_drag: f32,
+    /// The damping of the angular velocity of the bodies when they are entirely submerged.
+    pub angular_drag: f32,
+    /// The velocity of the fluid, in world-space.
+    pub flow_velocity: Vect,
+}
+
+impl Default for FluidVolume {
+    fn default() -> Self {
+        Self {
+            density: 1.0,
+            linear_drag: 1.0,
+            angular_drag: 1.0,
+            flow_velocity: Vect::ZERO,
+        }
+    }
+}
+
 /// Component which will be filled (if present) with a list of entities with which the current
 /// entity is currently in contact.
 ///
 /// This currently only updates when on an entity with a `Collider`, and if the
 /// [`ActiveEvents::COLLISION_EVENTS`] is set on this entity or the entity it
 /// collided with.
+///
+/// The colliding entities aren’t reflected: this component is empty when it is saved in, or
+/// spawned from, a scene, and it is filled again by the next collision events.
 #[derive(Component, Default, Reflect)]
 #[reflect(Component)]
-pub struct CollidingEntities(pub(crate) HashSet<Entity>);
+pub struct CollidingEntities(#[reflect(ignore)] pub(crate) HashSet<Entity>);
 
 impl CollidingEntities {
     /// Returns the number of colliding entities.
@@ -540,6 +902,9 @@ impl CollidingEntities {
 }
 
 /// Indicates whether or not the collider is disabled explicitly by the user.
+///
+/// A disabled collider neither interacts with other colliders nor appears in the results of
+/// scene queries. Remove this component to enable the collider again.
 #[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Component, Reflect)]
 #[reflect(Component, PartialEq)]
 pub struct ColliderDisabled;
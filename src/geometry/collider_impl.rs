@@ -1,18 +1,25 @@
 #[cfg(feature = "dim2")]
 use na::DVector;
-#[cfg(all(feature = "dim3", feature = "async-collider"))]
+#[cfg(feature = "async-collider")]
 use {
     bevy::prelude::*,
     bevy::render::mesh::{Indices, VertexAttributeValues},
 };
 
+use std::borrow::Cow;
+use std::ops::Range;
+
+use rapier::parry::shape::{HeightField, TriMesh};
 use rapier::prelude::{FeatureId, Point, Ray, SharedShape, Vector, DIM};
 
 use super::{get_snapped_scale, shape_views::*};
-#[cfg(all(feature = "dim3", feature = "async-collider"))]
+#[cfg(feature = "async-collider")]
 use crate::geometry::ComputedColliderShape;
-use crate::geometry::{Collider, PointProjection, RayIntersection, TriMeshFlags, VHACDParameters};
+use crate::geometry::{
+    Collider, MeshColliderError, PointProjection, RayIntersection, TriMeshFlags, VHACDParameters,
+};
 use crate::math::{Real, Rot, Vect};
+use crate::plugin::NonUniformScalePolicy;
 
 impl Collider {
     /// The scaling factor that was applied to this collider.
@@ -25,6 +32,7 @@ impl Collider {
     pub fn promote_scaled_shape(&mut self) {
         self.unscaled = self.raw.clone();
         self.scale = Vect::ONE;
+        self.scaled_cache = None;
     }
 
     /// Initialize a new collider with a compound shape.
@@ -167,25 +175,90 @@ impl Collider {
         SharedShape::trimesh_with_flags(vertices, indices, flags).into()
     }
 
+    /// Initializes a collider with a triangle mesh shape defined by its vertex and index buffers, and flags
+    /// controlling its pre-processing.
+    ///
+    /// Unlike [`Collider::trimesh_with_flags`], this returns an error if the indices are invalid, or if
+    /// the topology required by the flags couldn’t be computed.
+    pub fn try_trimesh_with_flags(
+        vertices: Vec<Vect>,
+        indices: Vec<[u32; 3]>,
+        flags: TriMeshFlags,
+    ) -> Result<Self, MeshColliderError> {
+        let vertices = vertices.into_iter().map(|v| v.into()).collect();
+        trimesh_shape(vertices, indices, flags).map(Into::into)
+    }
+
     /// Initializes a collider with a Bevy Mesh.
     ///
-    /// Returns `None` if the index buffer or vertex buffer of the mesh are in an incompatible format.
-    #[cfg(all(feature = "dim3", feature = "async-collider"))]
+    /// In 2D, the mesh vertices are projected onto the `XY` plane.
+    ///
+    /// Returns `None` if the collider couldn’t be generated, see [`Collider::try_from_bevy_mesh`] for
+    /// the details.
+    #[cfg(feature = "async-collider")]
     pub fn from_bevy_mesh(mesh: &Mesh, collider_shape: &ComputedColliderShape) -> Option<Self> {
+        Self::try_from_bevy_mesh(mesh, collider_shape).ok()
+    }
+
+    /// Initializes a collider with a Bevy Mesh.
+    ///
+    /// In 2D, the mesh vertices are projected onto the `XY` plane.
+    ///
+    /// Returns an error if the mesh isn’t an indexed triangle list with `Float32x3` (or `Float32`)
+    /// vertex positions, if the mesh doesn’t have any triangle (in 2D, any triangle with a non-zero
+    /// area), or if the requested shape couldn’t be computed from the mesh. See
+    /// [`MeshColliderError`] for the details.
+    #[cfg(feature = "async-collider")]
+    pub fn try_from_bevy_mesh(
+        mesh: &Mesh,
+        collider_shape: &ComputedColliderShape,
+    ) -> Result<Self, MeshColliderError> {
         let (vtx, idx) = extract_mesh_vertices_indices(mesh)?;
 
-        match collider_shape {
-            ComputedColliderShape::TriMesh => Some(
-                SharedShape::trimesh_with_flags(vtx, idx, TriMeshFlags::MERGE_DUPLICATE_VERTICES)
-                    .into(),
-            ),
+        if idx.is_empty() {
+            return Err(MeshColliderError::NoTriangles);
+        }
+
+        if let Some(triangle) = idx
+            .iter()
+            .position(|tri| tri.iter().any(|i| *i as usize >= vtx.len()))
+        {
+            return Err(MeshColliderError::IndexOutOfBounds { triangle });
+        }
+
+        #[cfg(feature = "dim2")]
+        if idx.iter().all(|[a, b, c]| {
+            let (a, b, c) = (vtx[*a as usize], vtx[*b as usize], vtx[*c as usize]);
+            (b - a).perp(&(c - a)).abs() <= Real::EPSILON
+        }) {
+            // All the triangles are degenerate.
+            return Err(MeshColliderError::NoTriangles);
+        }
+
+        let shape = match collider_shape {
+            ComputedColliderShape::TriMesh => {
+                trimesh_shape(vtx, idx, TriMeshFlags::MERGE_DUPLICATE_VERTICES)?
+            }
+            ComputedColliderShape::TriMeshWithFlags(flags) => trimesh_shape(vtx, idx, *flags)?,
             ComputedColliderShape::ConvexHull => {
-                SharedShape::convex_hull(&vtx).map(|shape| shape.into())
+                SharedShape::convex_hull(&vtx).ok_or(MeshColliderError::ConvexHullFailed)?
             }
+            #[cfg(feature = "dim2")]
             ComputedColliderShape::ConvexDecomposition(params) => {
-                Some(SharedShape::convex_decomposition_with_params(&vtx, &idx, params).into())
+                convex_decomposition_shape(&vtx, &boundary_edges(&idx), params)?
             }
-        }
+            #[cfg(feature = "dim3")]
+            ComputedColliderShape::ConvexDecomposition(params) => {
+                convex_decomposition_shape(&vtx, &idx, params)?
+            }
+            #[cfg(feature = "dim2")]
+            ComputedColliderShape::Polyline => {
+                let edges = boundary_edges(&idx);
+                SharedShape::polyline(vtx, Some(edges))
+            }
+        };
+
+        Ok(shape.into())
     }
 
     /// Initializes a collider with a compound shape obtained from the decomposition of
@@ -310,6 +383,104 @@ impl Collider {
         SharedShape::heightfield(heights, scale.into()).into()
     }
 
+    /// Replaces the heights of the given range of this heightfield.
+    ///
+    /// The heightfield is rebuilt with its new heights, which costs as much as creating it, but the
+    /// physics collider is updated without being re-created. Returns `false` if this collider
+    /// isn’t a heightfield.
+    ///
+    /// # Panics
+    /// Panics if `heights` doesn’t contain exactly one height per element of `range`, or if `range` is
+    /// out of the bounds of the heightfield.
+    #[cfg(feature = "dim2")]
+    pub fn heightfield_set_heights(&mut self, range: Range<usize>, heights: &[Real]) -> bool {
+        assert_eq!(
+            heights.len(),
+            range.len(),
+            "Invalid number of heights provided."
+        );
+        self.update_heightfield(|hf_heights| {
+            hf_heights
+                .rows_mut(range.start, range.len())
+                .copy_from_slice(heights)
+        })
+    }
+
+    /// Replaces the heights of the given block of this heightfield.
+    ///
+    /// The `heights` of the block are given in column-major format. The heightfield is rebuilt with
+    /// its new heights, which costs as much as creating it, but the physics collider is updated
+    /// without being re-created. Returns `false` if this collider isn’t a heightfield.
+    ///
+    /// # Panics
+    /// Panics if `heights` doesn’t contain exactly one height per element of the block, or if the
+    /// block is out of the bounds of the heightfield.
+    #[cfg(feature = "dim3")]
+    pub fn heightfield_set_heights(
+        &mut self,
+        rows: Range<usize>,
+        cols: Range<usize>,
+        heights: &[Real],
+    ) -> bool {
+        assert_eq!(
+            heights.len(),
+            rows.len() * cols.len(),
+            "Invalid number of heights provided."
+        );
+        self.update_heightfield(|hf_heights| {
+            hf_heights
+                .view_mut((rows.start, cols.start), (rows.len(), cols.len()))
+                .copy_from_slice(heights)
+        })
+    }
+
+    #[cfg(feature = "dim2")]
+    fn update_heightfield(&mut self, update: impl FnOnce(&mut DVector<Real>)) -> bool {
+        let Some(hf) = self.unscaled.as_heightfield() else {
+            return false;
+        };
+        let mut heights = hf.heights().clone();
+        update(&mut heights);
+
+        let mut updated = HeightField::new(heights, *hf.scale());
+        for i in 0..hf.num_cells() {
+            updated.set_segment_removed(i, hf.is_segment_removed(i));
+        }
+        self.replace_heightfield(updated);
+        true
+    }
+
+    #[cfg(feature = "dim3")]
+    fn update_heightfield(&mut self, update: impl FnOnce(&mut rapier::na::DMatrix<Real>)) -> bool {
+        let Some(hf) = self.unscaled.as_heightfield() else {
+            return false;
+        };
+        let mut heights = hf.heights().clone();
+        update(&mut heights);
+
+        let mut updated = HeightField::new(heights, *hf.scale());
+        *updated.cells_statuses_mut() = hf.cells_statuses().clone();
+        self.replace_heightfield(updated);
+        true
+    }
+
+    /// Replaces the heightfield of this collider by `updated`, keeping its scale.
+    ///
+    /// Parry can’t modify the heights of a heightfield (nor refresh its AABB) in place, so the
+    /// heightfield is rebuilt with all its heights, and shared by the scaled shape if it isn’t
+    /// scaled.
+    fn replace_heightfield(&mut self, updated: HeightField) {
+        // The cached scaled shape has the old heights.
+        self.scaled_cache = None;
+        if self.scale == Vect::ONE {
+            self.unscaled = SharedShape::new(updated);
+            self.raw = self.unscaled.clone();
+        } else {
+            self.raw = SharedShape::new(updated.clone().scaled(&self.scale.into()));
+            self.unscaled = SharedShape::new(updated);
+        }
+    }
+
     /// Takes a strongly typed reference of this collider.
     pub fn as_typed_shape(&self) -> ColliderView {
         self.raw.as_typed_shape().into()
@@ -523,27 +694,120 @@ impl Collider {
     pub fn set_scale(&mut self, scale: Vect, num_subdivisions: u32) {
         let scale = get_snapped_scale(scale);
 
-        if scale == self.scale {
+        if scale == self.scale && self.has_scale_subdivisions(num_subdivisions) {
             // Nothing to do.
             return;
         }
 
-        if scale == Vect::ONE {
-            // Trivial case.
-            self.raw = self.unscaled.clone();
-            self.scale = Vect::ONE;
-            return;
+        let same_subdivisions = self.raw_subdivisions == num_subdivisions;
+        if !same_subdivisions {
+            // The cached shape was computed with another number of subdivisions.
+            self.scaled_cache = None;
         }
 
-        if let Some(scaled) = self
+        let cached = self
+            .scaled_cache
+            .as_ref()
+            .filter(|(cached_scale, _)| *cached_scale == scale)
+            .map(|(_, shape)| shape.clone());
+
+        let scaled = if scale == Vect::ONE {
+            // Trivial case.
+            self.unscaled.clone()
+        } else if let Some(cached) = cached {
+            cached
+        } else if let Some(scaled) = self
             .as_unscaled_typed_shape()
             .raw_scale_by(scale, num_subdivisions)
         {
-            self.raw = scaled;
-            self.scale = scale;
+            scaled
         } else {
             log::error!("Failed to create the scaled convex hull geometry.");
+            return;
+        };
+
+        let previous = std::mem::replace(&mut self.raw, scaled);
+        if self.scale != Vect::ONE && same_subdivisions {
+            self.scaled_cache = Some((self.scale, previous));
+        }
+        self.scale = scale;
+        self.raw_subdivisions = num_subdivisions;
+    }
+
+    /// Set the scaling factor of this shape, following the given policy if the scaled shape can’t
+    /// be represented as the same smooth shape (a ball or a capsule).
+    ///
+    /// Returns `true` if the scale was snapped to a uniform scale.
+    pub fn set_scale_with_policy(
+        &mut self,
+        scale: Vect,
+        num_subdivisions: u32,
+        policy: NonUniformScalePolicy,
+    ) -> bool {
+        let applied_scale = self.scale_with_policy(scale, policy);
+        self.set_scale(applied_scale, num_subdivisions);
+        applied_scale != get_snapped_scale(scale)
+    }
+
+    /// The scale [`Self::set_scale_with_policy`] actually gives to this collider for the given
+    /// scale and policy.
+    ///
+    /// Balls and capsules can only keep their smooth shape under a uniform scale. Unless the
+    /// policy is [`NonUniformScalePolicy::ConvertToConvex`], a non-uniform scale is snapped to a
+    /// uniform one for them.
+    pub fn scale_with_policy(&self, scale: Vect, policy: NonUniformScalePolicy) -> Vect {
+        let scale = get_snapped_scale(scale);
+        let smooth = self.unscaled.as_ball().is_some() || self.unscaled.as_capsule().is_some();
+        if policy == NonUniformScalePolicy::ConvertToConvex
+            || !smooth
+            || scale == Vect::splat(scale.x)
+        {
+            return scale;
         }
+
+        get_snapped_scale(Vect::splat(scale.abs().max_element()))
+    }
+
+    /// This collider, rescaled with `set_scale(self.scale(), num_subdivisions)`.
+    ///
+    /// The collider is only cloned and rescaled if its shape was approximated with another number
+    /// of subdivisions.
+    pub(crate) fn rescaled(&self, num_subdivisions: u32) -> Cow<'_, Collider> {
+        if self.has_scale_subdivisions(num_subdivisions) {
+            return Cow::Borrowed(self);
+        }
+
+        let mut scaled = self.clone();
+        scaled.set_scale(self.scale, num_subdivisions);
+        Cow::Owned(scaled)
+    }
+
+    /// This collider, rescaled with
+    /// `set_scale_with_policy(self.scale(), num_subdivisions, policy)`.
+    ///
+    /// The collider is only cloned and rescaled if the policy changes its scale, or if its shape
+    /// was approximated with another number of subdivisions.
+    pub(crate) fn rescaled_with_policy(
+        &self,
+        num_subdivisions: u32,
+        policy: NonUniformScalePolicy,
+    ) -> Cow<'_, Collider> {
+        if self.scale_with_policy(self.scale, policy) == self.scale
+            && self.has_scale_subdivisions(num_subdivisions)
+        {
+            return Cow::Borrowed(self);
+        }
+
+        let mut scaled = self.clone();
+        scaled.set_scale_with_policy(self.scale, num_subdivisions, policy);
+        Cow::Owned(scaled)
+    }
+
+    /// Was the scaled shape of this collider computed with `num_subdivisions` subdivisions?
+    ///
+    /// Unscaled shapes don’t depend on the number of subdivisions.
+    fn has_scale_subdivisions(&self, num_subdivisions: u32) -> bool {
+        self.scale == Vect::ONE || self.raw_subdivisions == num_subdivisions
     }
 
     /// Projects a point on `self`, unless the projection lies further than the given max distance.
@@ -733,28 +997,65 @@ impl Default for Collider {
     }
 }
 
-#[cfg(all(feature = "dim3", feature = "async-collider"))]
+/// Builds a triangle mesh, reporting invalid indices and topology errors instead of panicking or
+/// ignoring them.
+fn trimesh_shape(
+    vertices: Vec<Point<Real>>,
+    indices: Vec<[u32; 3]>,
+    flags: TriMeshFlags,
+) -> Result<SharedShape, MeshColliderError> {
+    if indices.is_empty() {
+        return Err(MeshColliderError::NoTriangles);
+    }
+
+    if let Some(triangle) = indices
+        .iter()
+        .position(|tri| tri.iter().any(|i| *i as usize >= vertices.len()))
+    {
+        return Err(MeshColliderError::IndexOutOfBounds { triangle });
+    }
+
+    let mut trimesh = TriMesh::new(vertices, indices);
+    trimesh
+        .set_flags(flags)
+        .map_err(MeshColliderError::Topology)?;
+    Ok(SharedShape::new(trimesh))
+}
+
+#[cfg(feature = "async-collider")]
 #[allow(clippy::type_complexity)]
-fn extract_mesh_vertices_indices(mesh: &Mesh) -> Option<(Vec<na::Point3<Real>>, Vec<[u32; 3]>)> {
+fn extract_mesh_vertices_indices(
+    mesh: &Mesh,
+) -> Result<(Vec<Point<Real>>, Vec<[u32; 3]>), MeshColliderError> {
+    use bevy::render::mesh::PrimitiveTopology;
     use rapier::na::point;
 
-    let vertices = mesh.attribute(Mesh::ATTRIBUTE_POSITION)?;
-    let indices = mesh.indices()?;
+    if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
+        return Err(MeshColliderError::UnsupportedTopology);
+    }
+
+    let vertices = mesh
+        .attribute(Mesh::ATTRIBUTE_POSITION)
+        .ok_or(MeshColliderError::MissingPositions)?;
+
+    // In 2D, the vertices are projected onto the XY plane.
+    #[cfg(feature = "dim2")]
+    let to_point = |v: &[f32]| point![v[0] as Real, v[1] as Real];
+    #[cfg(feature = "dim3")]
+    let to_point = |v: &[f32]| point![v[0] as Real, v[1] as Real, v[2] as Real];
 
     let vtx: Vec<_> = match vertices {
-        VertexAttributeValues::Float32(vtx) => Some(
-            vtx.chunks(3)
-                .map(|v| point![v[0] as Real, v[1] as Real, v[2] as Real])
-                .collect(),
-        ),
-        VertexAttributeValues::Float32x3(vtx) => Some(
-            vtx.iter()
-                .map(|v| point![v[0] as Real, v[1] as Real, v[2] as Real])
-                .collect(),
-        ),
+        VertexAttributeValues::Float32(vtx) => Some(vtx.chunks(3).map(to_point).collect()),
+        VertexAttributeValues::Float32x3(vtx) => Some(vtx.iter().map(|v| to_point(v)).collect()),
         _ => None,
-    }?;
+    }
+    .ok_or(MeshColliderError::IncompatibleFormat)?;
 
+    if vtx.is_empty() {
+        return Err(MeshColliderError::NoVertices);
+    }
+
+    let indices = mesh.indices().ok_or(MeshColliderError::MissingIndices)?;
     let idx = match indices {
         Indices::U16(idx) => idx
             .chunks_exact(3)
@@ -763,5 +1064,220 @@ fn extract_mesh_vertices_indices(mesh: &Mesh) -> Option<(Vec<na::Point3<Real>>,
         Indices::U32(idx) => idx.chunks_exact(3).map(|i| [i[0], i[1], i[2]]).collect(),
     };
 
-    Some((vtx, idx))
+    Ok((vtx, idx))
+}
+
+/// Computes the convex decomposition of a mesh like `SharedShape::convex_decomposition_with_params`,
+/// but reports an empty decomposition instead of building an empty compound shape.
+#[cfg(feature = "async-collider")]
+fn convex_decomposition_shape(
+    vertices: &[Point<Real>],
+    indices: &[[u32; DIM]],
+    params: &VHACDParameters,
+) -> Result<SharedShape, MeshColliderError> {
+    use rapier::parry::transformation::vhacd::VHACD;
+    use rapier::prelude::Isometry;
+
+    let decomposition = VHACD::decompose(params, vertices, indices, true);
+    let hulls = decomposition.compute_exact_convex_hulls(vertices, indices);
+
+    #[cfg(feature = "dim2")]
+    let parts: Vec<_> = hulls
+        .into_iter()
+        .filter_map(SharedShape::convex_polyline)
+        .map(|part| (Isometry::identity(), part))
+        .collect();
+    #[cfg(feature = "dim3")]
+    let parts: Vec<_> = hulls
+        .into_iter()
+        .filter_map(|(vertices, indices)| SharedShape::convex_mesh(vertices, &indices))
+        .map(|part| (Isometry::identity(), part))
+        .collect();
+
+    if parts.is_empty() {
+        return Err(MeshColliderError::ConvexDecompositionFailed);
+    }
+
+    Ok(SharedShape::compound(parts))
+}
+
+/// The edges of the given triangles that aren’t shared with any other triangle.
+#[cfg(all(feature = "dim2", feature = "async-collider"))]
+fn boundary_edges(triangles: &[[u32; 3]]) -> Vec<[u32; 2]> {
+    let mut edge_counts = bevy::utils::HashMap::new();
+    for [a, b, c] in triangles {
+        for [i, j] in [[*a, *b], [*b, *c], [*c, *a]] {
+            *edge_counts.entry([i.min(j), i.max(j)]).or_insert(0) += 1;
+        }
+    }
+
+    // Keep the winding of the triangles, and a deterministic order.
+    triangles
+        .iter()
+        .flat_map(|[a, b, c]| [[*a, *b], [*b, *c], [*c, *a]])
+        .filter(|[i, j]| edge_counts[&[*i.min(j), *i.max(j)]] == 1)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "async-collider")]
+    fn trimesh_flags_are_applied_to_bevy_meshes() {
+        use bevy::render::render_asset::RenderAssetUsages;
+        use bevy::render::render_resource::PrimitiveTopology;
+
+        // Two triangles sharing an edge, without sharing their vertices.
+        let mesh = Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::default(),
+        )
+        .with_inserted_attribute(
+            Mesh::ATTRIBUTE_POSITION,
+            vec![
+                [0.0, 0.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [1.0, 1.0, 0.0],
+                [0.0, 1.0, 0.0],
+            ],
+        )
+        .with_inserted_indices(Indices::U32((0..6).collect()));
+
+        let num_vertices = |flags| {
+            let shape = ComputedColliderShape::TriMeshWithFlags(flags);
+            let collider = Collider::try_from_bevy_mesh(&mesh, &shape).unwrap();
+            collider.as_trimesh().unwrap().raw.vertices().len()
+        };
+
+        assert_eq!(num_vertices(TriMeshFlags::empty()), 6);
+        assert_eq!(num_vertices(TriMeshFlags::MERGE_DUPLICATE_VERTICES), 4);
+    }
+
+    #[test]
+    #[cfg(feature = "async-collider")]
+    fn invalid_bevy_meshes_are_reported() {
+        use bevy::render::render_asset::RenderAssetUsages;
+        use bevy::render::render_resource::PrimitiveTopology;
+
+        let triangle = vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]];
+        let mesh = |topology| Mesh::new(topology, RenderAssetUsages::default());
+        let error = |mesh: Mesh| {
+            Collider::try_from_bevy_mesh(&mesh, &ComputedColliderShape::TriMesh).unwrap_err()
+        };
+
+        let strip = mesh(PrimitiveTopology::TriangleStrip)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, triangle.clone())
+            .with_inserted_indices(Indices::U32(vec![0, 1, 2]));
+        assert!(matches!(
+            error(strip),
+            MeshColliderError::UnsupportedTopology
+        ));
+
+        let no_positions = mesh(PrimitiveTopology::TriangleList)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, vec![[0.0, 0.0, 1.0]; 3])
+            .with_inserted_indices(Indices::U32(vec![0, 1, 2]));
+        assert!(matches!(
+            error(no_positions),
+            MeshColliderError::MissingPositions
+        ));
+
+        let no_vertices = mesh(PrimitiveTopology::TriangleList)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, Vec::<[f32; 3]>::new())
+            .with_inserted_indices(Indices::U32(vec![]));
+        assert!(matches!(error(no_vertices), MeshColliderError::NoVertices));
+
+        let no_indices = mesh(PrimitiveTopology::TriangleList)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, triangle.clone());
+        assert!(matches!(
+            error(no_indices),
+            MeshColliderError::MissingIndices
+        ));
+
+        let no_triangles = mesh(PrimitiveTopology::TriangleList)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, triangle.clone())
+            .with_inserted_indices(Indices::U32(vec![]));
+        assert!(matches!(
+            error(no_triangles),
+            MeshColliderError::NoTriangles
+        ));
+
+        let out_of_bounds = mesh(PrimitiveTopology::TriangleList)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, triangle)
+            .with_inserted_indices(Indices::U32(vec![0, 1, 2, 0, 2, 3]));
+        assert!(matches!(
+            error(out_of_bounds),
+            MeshColliderError::IndexOutOfBounds { triangle: 1 }
+        ));
+    }
+
+    #[test]
+    fn invalid_trimesh_is_reported() {
+        let vertices = vec![Vect::ZERO; 3];
+
+        assert!(matches!(
+            Collider::try_trimesh_with_flags(vertices.clone(), vec![], TriMeshFlags::empty()),
+            Err(MeshColliderError::NoTriangles)
+        ));
+        assert!(matches!(
+            Collider::try_trimesh_with_flags(vertices, vec![[0, 1, 3]], TriMeshFlags::empty()),
+            Err(MeshColliderError::IndexOutOfBounds { triangle: 0 })
+        ));
+    }
+
+    #[test]
+    fn rescaling_reuses_the_previous_scaled_shape() {
+        use std::sync::Arc;
+
+        let mut collider = Collider::ball(1.0);
+        #[cfg(feature = "dim2")]
+        let (non_uniform, uniform) = (Vect::new(2.0, 1.0), Vect::splat(2.0));
+        #[cfg(feature = "dim3")]
+        let (non_uniform, uniform) = (Vect::new(2.0, 1.0, 1.0), Vect::splat(2.0));
+
+        collider.set_scale(non_uniform, 10);
+        let approximated = collider.raw.clone();
+        assert!(collider.raw.as_ball().is_none());
+
+        collider.set_scale(uniform, 10);
+        assert!(collider.raw.as_ball().is_some());
+
+        // Going back to the previous scale doesn’t recompute the approximation.
+        collider.set_scale(non_uniform, 10);
+        assert!(Arc::ptr_eq(&collider.raw.0, &approximated.0));
+
+        // Unless the number of subdivisions changed.
+        collider.set_scale(uniform, 20);
+        collider.set_scale(non_uniform, 20);
+        assert!(!Arc::ptr_eq(&collider.raw.0, &approximated.0));
+    }
+
+    #[test]
+    fn colliders_are_only_rescaled_for_other_subdivisions() {
+        use std::borrow::Cow;
+
+        let mut collider = Collider::ball(1.0);
+        #[cfg(feature = "dim2")]
+        let non_uniform = Vect::new(2.0, 1.0);
+        #[cfg(feature = "dim3")]
+        let non_uniform = Vect::new(2.0, 1.0, 1.0);
+        collider.set_scale(non_uniform, 10);
+
+        assert!(matches!(collider.rescaled(10), Cow::Borrowed(_)));
+        let rescaled = collider.rescaled(20);
+        assert!(matches!(rescaled, Cow::Owned(_)));
+        assert_eq!(rescaled.scale(), non_uniform);
+        assert!(!std::sync::Arc::ptr_eq(&rescaled.raw.0, &collider.raw.0));
+
+        // Only a policy snapping the scale changes this approximation.
+        assert!(matches!(
+            collider.rescaled_with_policy(10, NonUniformScalePolicy::ConvertToConvex),
+            Cow::Borrowed(_)
+        ));
+        let snapped = collider.rescaled_with_policy(10, NonUniformScalePolicy::SnapToUniform);
+        assert!(snapped.raw.as_ball().is_some());
+    }
 }
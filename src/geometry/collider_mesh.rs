@@ -0,0 +1,289 @@
+//! Conversion of colliders to Bevy meshes, for visualization purpose.
+
+use bevy::prelude::*;
+use bevy::render::mesh::{Indices, PrimitiveTopology};
+use bevy::render::render_asset::RenderAssetUsages;
+use rapier::parry::shape::{Shape, TypedShape};
+#[cfg(feature = "dim3")]
+use rapier::prelude::Vector;
+use rapier::prelude::{Isometry, Point};
+
+use crate::geometry::Collider;
+use crate::math::Real;
+
+impl Collider {
+    /// Generates a Bevy mesh matching the shape of this collider, with its scale applied.
+    ///
+    /// Curved shapes are approximated with `subdivisions` segments along their curved parts. In
+    /// 2D, the mesh is a flat polygon in the `XY` plane. Returns `None` if the shape can’t be
+    /// represented by a solid mesh (like half-spaces, segments, polylines, and round shapes).
+    pub fn as_bevy_mesh(&self, subdivisions: u32) -> Option<Mesh> {
+        let mut buffers = MeshBuffers::default();
+        buffers.append_shape(&*self.raw, &Isometry::identity(), subdivisions.max(3))?;
+
+        Some(
+            Mesh::new(
+                PrimitiveTopology::TriangleList,
+                RenderAssetUsages::default(),
+            )
+            .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, buffers.positions)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, buffers.normals)
+            .with_inserted_indices(Indices::U32(buffers.indices)),
+        )
+    }
+}
+
+#[derive(Default)]
+struct MeshBuffers {
+    positions: Vec<[f32; 3]>,
+    normals: Vec<[f32; 3]>,
+    indices: Vec<u32>,
+}
+
+impl MeshBuffers {
+    #[cfg(feature = "dim2")]
+    fn append_shape(
+        &mut self,
+        shape: &dyn Shape,
+        pos: &Isometry<Real>,
+        subdivisions: u32,
+    ) -> Option<()> {
+        match shape.as_typed_shape() {
+            TypedShape::Ball(ball) => {
+                self.push_convex_polygon(pos, &ball.to_polyline(subdivisions))
+            }
+            TypedShape::Cuboid(cuboid) => self.push_convex_polygon(pos, &cuboid.to_polyline()),
+            TypedShape::Capsule(capsule) => {
+                self.push_convex_polygon(pos, &capsule.to_polyline(subdivisions))
+            }
+            TypedShape::Triangle(triangle) => self.push_convex_polygon(pos, triangle.vertices()),
+            TypedShape::ConvexPolygon(polygon) => self.push_convex_polygon(pos, polygon.points()),
+            TypedShape::TriMesh(trimesh) => {
+                self.push_triangles(pos, trimesh.vertices(), trimesh.indices())
+            }
+            TypedShape::HeightField(heightfield) => {
+                // Fill the area between the heightfield and the bottom of its bounding box.
+                let (vertices, segments) = heightfield.to_polyline();
+                let bottom = heightfield.local_aabb().mins.y;
+
+                for [a, b] in segments {
+                    let (a, b) = (vertices[a as usize], vertices[b as usize]);
+                    let quad = [a, Point::new(a.x, bottom), Point::new(b.x, bottom), b];
+                    self.push_convex_polygon(pos, &quad);
+                }
+            }
+            TypedShape::Compound(compound) => {
+                for (shape_pos, shape) in compound.shapes() {
+                    self.append_shape(&**shape, &(pos * shape_pos), subdivisions)?;
+                }
+            }
+            _ => return None,
+        }
+
+        Some(())
+    }
+
+    #[cfg(feature = "dim3")]
+    fn append_shape(
+        &mut self,
+        shape: &dyn Shape,
+        pos: &Isometry<Real>,
+        subdivisions: u32,
+    ) -> Option<()> {
+        let half_subdivisions = (subdivisions / 2).max(2);
+
+        match shape.as_typed_shape() {
+            TypedShape::Ball(ball) => {
+                let (vertices, indices) = ball.to_trimesh(subdivisions, half_subdivisions);
+                self.push_triangles(pos, &vertices, &indices)
+            }
+            TypedShape::Cuboid(cuboid) => {
+                let (vertices, indices) = cuboid.to_trimesh();
+                self.push_triangles(pos, &vertices, &indices)
+            }
+            TypedShape::Capsule(capsule) => {
+                let (vertices, indices) = capsule.to_trimesh(subdivisions, half_subdivisions);
+                self.push_triangles(pos, &vertices, &indices)
+            }
+            TypedShape::Cylinder(cylinder) => {
+                let (vertices, indices) = cylinder.to_trimesh(subdivisions);
+                self.push_triangles(pos, &vertices, &indices)
+            }
+            TypedShape::Cone(cone) => {
+                let (vertices, indices) = cone.to_trimesh(subdivisions);
+                self.push_triangles(pos, &vertices, &indices)
+            }
+            TypedShape::ConvexPolyhedron(polyhedron) => {
+                let (vertices, indices) = polyhedron.to_trimesh();
+                self.push_triangles(pos, &vertices, &indices)
+            }
+            TypedShape::Triangle(triangle) => {
+                self.push_triangles(pos, triangle.vertices(), &[[0, 1, 2]])
+            }
+            TypedShape::TriMesh(trimesh) => {
+                self.push_triangles(pos, trimesh.vertices(), trimesh.indices())
+            }
+            TypedShape::HeightField(heightfield) => {
+                let (vertices, indices) = heightfield.to_trimesh();
+                self.push_triangles(pos, &vertices, &indices)
+            }
+            TypedShape::Compound(compound) => {
+                for (shape_pos, shape) in compound.shapes() {
+                    self.append_shape(&**shape, &(pos * shape_pos), subdivisions)?;
+                }
+            }
+            _ => return None,
+        }
+
+        Some(())
+    }
+
+    /// Appends a convex polygon, triangulated as a fan.
+    #[cfg(feature = "dim2")]
+    fn push_convex_polygon(&mut self, pos: &Isometry<Real>, vertices: &[Point<Real>]) {
+        let first = self.positions.len() as u32;
+
+        for vertex in vertices {
+            let vertex = pos * vertex;
+            self.positions.push([vertex.x, vertex.y, 0.0]);
+            self.normals.push([0.0, 0.0, 1.0]);
+        }
+
+        for i in 1..vertices.len().saturating_sub(1) as u32 {
+            self.indices.extend([first, first + i, first + i + 1]);
+        }
+    }
+
+    #[cfg(feature = "dim2")]
+    fn push_triangles(
+        &mut self,
+        pos: &Isometry<Real>,
+        vertices: &[Point<Real>],
+        indices: &[[u32; 3]],
+    ) {
+        let first = self.positions.len() as u32;
+
+        for vertex in vertices {
+            let vertex = pos * vertex;
+            self.positions.push([vertex.x, vertex.y, 0.0]);
+            self.normals.push([0.0, 0.0, 1.0]);
+        }
+
+        self.indices
+            .extend(indices.iter().flatten().map(|i| first + i));
+    }
+
+    /// Appends triangles with flat normals: the vertices are duplicated for each triangle.
+    #[cfg(feature = "dim3")]
+    fn push_triangles(
+        &mut self,
+        pos: &Isometry<Real>,
+        vertices: &[Point<Real>],
+        indices: &[[u32; 3]],
+    ) {
+        for triangle in indices {
+            let [a, b, c] = triangle.map(|i| pos * vertices[i as usize]);
+            let normal = (b - a)
+                .cross(&(c - a))
+                .try_normalize(Real::EPSILON)
+                .unwrap_or_else(Vector::zeros);
+
+            for vertex in [a, b, c] {
+                self.indices.push(self.positions.len() as u32);
+                self.positions.push([vertex.x, vertex.y, vertex.z]);
+                self.normals.push([normal.x, normal.y, normal.z]);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use bevy::render::mesh::VertexAttributeValues;
+
+    fn mesh_aabb(mesh: &Mesh) -> (Vec3, Vec3) {
+        let Some(VertexAttributeValues::Float32x3(positions)) =
+            mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+        else {
+            panic!("The mesh has no positions.");
+        };
+
+        positions.iter().fold(
+            (Vec3::splat(f32::MAX), Vec3::splat(f32::MIN)),
+            |(mins, maxs), p| (mins.min(Vec3::from(*p)), maxs.max(Vec3::from(*p))),
+        )
+    }
+
+    #[test]
+    #[cfg(feature = "dim2")]
+    fn cuboid_and_ball_meshes() {
+        let mut cuboid = Collider::cuboid(1.0, 2.0);
+        cuboid.set_scale(Vec2::new(2.0, 1.0), 10);
+        let mesh = cuboid.as_bevy_mesh(16).unwrap();
+        assert_eq!(mesh.count_vertices(), 4);
+        assert_eq!(mesh.indices().unwrap().len(), 6);
+        let (mins, maxs) = mesh_aabb(&mesh);
+        approx::assert_relative_eq!(mins, Vec3::new(-2.0, -2.0, 0.0));
+        approx::assert_relative_eq!(maxs, Vec3::new(2.0, 2.0, 0.0));
+
+        let mesh = Collider::ball(0.5).as_bevy_mesh(16).unwrap();
+        assert_eq!(mesh.count_vertices(), 16);
+        assert_eq!(mesh.indices().unwrap().len(), 14 * 3);
+        let (mins, maxs) = mesh_aabb(&mesh);
+        approx::assert_relative_eq!(maxs.x, 0.5, epsilon = 1.0e-5);
+        assert!(mins.x >= -0.5 - 1.0e-5 && maxs.y <= 0.5 + 1.0e-5);
+
+        assert!(Collider::halfspace(Vec2::Y)
+            .unwrap()
+            .as_bevy_mesh(16)
+            .is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "dim3")]
+    fn cuboid_and_ball_meshes() {
+        let mut cuboid = Collider::cuboid(1.0, 2.0, 3.0);
+        cuboid.set_scale(Vec3::new(2.0, 1.0, 1.0), 10);
+        let mesh = cuboid.as_bevy_mesh(16).unwrap();
+        // 12 triangles, with flat normals.
+        assert_eq!(mesh.count_vertices(), 36);
+        assert_eq!(mesh.indices().unwrap().len(), 36);
+        let (mins, maxs) = mesh_aabb(&mesh);
+        approx::assert_relative_eq!(mins, Vec3::new(-2.0, -2.0, -3.0));
+        approx::assert_relative_eq!(maxs, Vec3::new(2.0, 2.0, 3.0));
+
+        let mesh = Collider::ball(0.5).as_bevy_mesh(16).unwrap();
+        assert_eq!(mesh.count_vertices(), mesh.indices().unwrap().len());
+        let (mins, maxs) = mesh_aabb(&mesh);
+        approx::assert_relative_eq!(maxs.y, 0.5, epsilon = 1.0e-5);
+        approx::assert_relative_eq!(mins.y, -0.5, epsilon = 1.0e-5);
+        assert!(mins.cmpge(Vec3::splat(-0.5 - 1.0e-5)).all());
+        assert!(maxs.cmple(Vec3::splat(0.5 + 1.0e-5)).all());
+
+        assert!(Collider::halfspace(Vec3::Y)
+            .unwrap()
+            .as_bevy_mesh(16)
+            .is_none());
+    }
+
+    #[test]
+    fn compound_meshes_merge_their_parts() {
+        #[cfg(feature = "dim2")]
+        let (cuboid, offset) = (Collider::cuboid(0.5, 0.5), Vec2::X * 2.0);
+        #[cfg(feature = "dim3")]
+        let (cuboid, offset) = (Collider::cuboid(0.5, 0.5, 0.5), Vec3::X * 2.0);
+
+        let single = cuboid.as_bevy_mesh(8).unwrap();
+        let compound = Collider::compound(vec![
+            (-offset, Default::default(), cuboid.clone()),
+            (offset, Default::default(), cuboid),
+        ]);
+        let mesh = compound.as_bevy_mesh(8).unwrap();
+
+        assert_eq!(mesh.count_vertices(), single.count_vertices() * 2);
+        let (mins, maxs) = mesh_aabb(&mesh);
+        approx::assert_relative_eq!(mins.x, -2.5);
+        approx::assert_relative_eq!(maxs.x, 2.5);
+    }
+}
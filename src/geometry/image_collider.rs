@@ -0,0 +1,391 @@
+//! Generation of 2D colliders matching the silhouette of an image.
+//!
+//! The outlines of the opaque parts of the image are extracted with marching squares over its
+//! alpha channel, and simplified with the Douglas-Peucker algorithm.
+
+use std::fmt;
+
+use bevy::prelude::*;
+use bevy::render::render_resource::TextureFormat;
+use bevy::utils::HashMap;
+
+use crate::geometry::{Collider, VHACDParameters};
+use crate::math::{Real, Vect};
+
+/// Shape of the collider generated from an image.
+///
+/// This is reflected as an opaque value, since [`VHACDParameters`] can’t be.
+#[derive(Debug, Clone, Reflect)]
+#[reflect_value(Debug, Default)]
+pub enum ImageColliderShape {
+    /// A convex polygon enclosing all the opaque pixels of the image.
+    ConvexHull,
+    /// A polyline following the outlines of the opaque parts of the image.
+    Polyline,
+    /// A convex decomposition of the outlines of the opaque parts of the image.
+    ConvexDecomposition(VHACDParameters),
+}
+
+impl Default for ImageColliderShape {
+    fn default() -> Self {
+        Self::ConvexHull
+    }
+}
+
+/// Parameters controlling the generation of a collider from an image.
+#[derive(Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Default, PartialEq)]
+pub struct ImageColliderParameters {
+    /// Pixels with an alpha strictly greater than this threshold are considered opaque.
+    pub alpha_threshold: u8,
+    /// The maximum distance, in pixels, between the simplified outlines and the original ones.
+    ///
+    /// Set it to zero to disable the simplification of the outlines.
+    pub simplification_epsilon: Real,
+    /// The number of pixels per world unit.
+    pub pixels_per_unit: Real,
+}
+
+impl Default for ImageColliderParameters {
+    fn default() -> Self {
+        Self {
+            alpha_threshold: 0,
+            simplification_epsilon: 1.0,
+            pixels_per_unit: 1.0,
+        }
+    }
+}
+
+/// Error returned when a collider couldn’t be generated from an image.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ImageColliderError {
+    /// The texture format of the image isn’t supported. Only 8-bit RGBA and BGRA images are.
+    UnsupportedFormat(TextureFormat),
+    /// The image doesn’t contain any pixel with an alpha above the threshold.
+    FullyTransparent,
+    /// The convex hull of the image outlines couldn’t be computed.
+    ConvexHullFailed,
+}
+
+impl fmt::Display for ImageColliderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedFormat(format) => {
+                write!(f, "unsupported image texture format {:?}", format)
+            }
+            Self::FullyTransparent => {
+                write!(
+                    f,
+                    "the image doesn’t contain any pixel above the alpha threshold"
+                )
+            }
+            Self::ConvexHullFailed => {
+                write!(f, "the convex hull of the image couldn’t be computed")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ImageColliderError {}
+
+/// A component which will be replaced by a collider matching the silhouette of the image of
+/// the same entity (for example the image of a sprite), once this image becomes available.
+///
+/// The collider is centered on the image, like sprites with the default anchor. If no collider
+/// could be generated from the image, an error is logged and this component is removed.
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct AsyncSpriteCollider {
+    /// The shape of the generated collider.
+    pub shape: ImageColliderShape,
+    /// The parameters of the generation of the collider.
+    pub parameters: ImageColliderParameters,
+}
+
+impl Collider {
+    /// Initializes a collider matching the pixels of an image with an alpha strictly greater than
+    /// `threshold`.
+    ///
+    /// One pixel of the image maps to one world unit, and the collider is centered on the image.
+    /// See [`Collider::from_image_alpha_with_parameters`] for more control over the generation.
+    pub fn from_image_alpha(
+        image: &Image,
+        threshold: u8,
+        shape: ImageColliderShape,
+    ) -> Result<Self, ImageColliderError> {
+        let parameters = ImageColliderParameters {
+            alpha_threshold: threshold,
+            ..default()
+        };
+        Self::from_image_alpha_with_parameters(image, &shape, &parameters)
+    }
+
+    /// Initializes a collider matching the opaque pixels of an image.
+    ///
+    /// The collider is centered on the image.
+    pub fn from_image_alpha_with_parameters(
+        image: &Image,
+        shape: &ImageColliderShape,
+        parameters: &ImageColliderParameters,
+    ) -> Result<Self, ImageColliderError> {
+        let width = image.width() as usize;
+        let height = image.height() as usize;
+        let alpha = extract_alpha(image)?;
+        let opaque = |x: usize, y: usize| alpha[y * width + x] > parameters.alpha_threshold;
+
+        if !(0..height).any(|y| (0..width).any(|x| opaque(x, y))) {
+            return Err(ImageColliderError::FullyTransparent);
+        }
+
+        let half_size = Vect::new(width as Real, height as Real) / 2.0;
+        let to_world =
+            |p: Vect| Vect::new(p.x - half_size.x, half_size.y - p.y) / parameters.pixels_per_unit;
+
+        let mut vertices = vec![];
+        let mut indices = vec![];
+
+        for contour in marching_squares(width, height, opaque) {
+            let contour = simplify_closed(&contour, parameters.simplification_epsilon);
+
+            if contour.len() < 3 {
+                continue;
+            }
+
+            let first = vertices.len() as u32;
+            let len = contour.len() as u32;
+            vertices.extend(contour.into_iter().map(to_world));
+            indices.extend((0..len).map(|i| [first + i, first + (i + 1) % len]));
+        }
+
+        if vertices.is_empty() {
+            return Err(ImageColliderError::FullyTransparent);
+        }
+
+        match shape {
+            ImageColliderShape::ConvexHull => {
+                Collider::convex_hull(&vertices).ok_or(ImageColliderError::ConvexHullFailed)
+            }
+            ImageColliderShape::Polyline => Ok(Collider::polyline(vertices, Some(indices))),
+            ImageColliderShape::ConvexDecomposition(params) => Ok(
+                Collider::convex_decomposition_with_params(&vertices, &indices, params),
+            ),
+        }
+    }
+}
+
+/// Extracts the alpha channel of an image, one byte per pixel.
+fn extract_alpha(image: &Image) -> Result<Vec<u8>, ImageColliderError> {
+    let format = image.texture_descriptor.format;
+
+    match format {
+        TextureFormat::Rgba8Unorm
+        | TextureFormat::Rgba8UnormSrgb
+        | TextureFormat::Bgra8Unorm
+        | TextureFormat::Bgra8UnormSrgb => {
+            Ok(image.data.chunks_exact(4).map(|pixel| pixel[3]).collect())
+        }
+        _ => Err(ImageColliderError::UnsupportedFormat(format)),
+    }
+}
+
+/// Extracts the closed outlines of the opaque pixels, in pixel coordinates.
+///
+/// The image is sampled at the center of each pixel, and surrounded by a transparent border so
+/// that all the outlines are closed. The outline points lie halfway between the samples.
+fn marching_squares(
+    width: usize,
+    height: usize,
+    opaque: impl Fn(usize, usize) -> bool,
+) -> Vec<Vec<Vect>> {
+    // Samples are indexed with a one-sample offset, to account for the transparent border.
+    let sample =
+        |x: usize, y: usize| x > 0 && y > 0 && x <= width && y <= height && opaque(x - 1, y - 1);
+
+    // The outline points are identified by twice their sample coordinates, so they are integers.
+    // Each point starts exactly one segment, and ends exactly one other segment.
+    let mut next = HashMap::new();
+
+    for y in 0..=height {
+        for x in 0..=width {
+            let corners = [
+                sample(x, y),
+                sample(x + 1, y),
+                sample(x + 1, y + 1),
+                sample(x, y + 1),
+            ];
+            // The midpoints of the edges between consecutive corners.
+            let (x2, y2) = (x as i32 * 2, y as i32 * 2);
+            let midpoints = [
+                (x2 + 1, y2),
+                (x2 + 2, y2 + 1),
+                (x2 + 1, y2 + 2),
+                (x2, y2 + 1),
+            ];
+
+            // Going around the cell, the outline leaves the opaque region at one edge, and
+            // enters it again at the next crossed edge.
+            let crossed: Vec<_> = (0..4)
+                .filter(|&i| corners[i] != corners[(i + 1) % 4])
+                .collect();
+
+            for (k, &i) in crossed.iter().enumerate() {
+                if corners[i] {
+                    let j = crossed[(k + 1) % crossed.len()];
+                    next.insert(midpoints[i], midpoints[j]);
+                }
+            }
+        }
+    }
+
+    let mut contours = vec![];
+
+    while let Some(&start) = next.keys().next() {
+        let mut contour = vec![];
+        let mut point = start;
+
+        while let Some(following) = next.remove(&point) {
+            // Convert the point from sample coordinates to pixel coordinates.
+            contour.push(Vect::new(
+                point.0 as Real / 2.0 - 0.5,
+                point.1 as Real / 2.0 - 0.5,
+            ));
+            point = following;
+        }
+
+        contours.push(contour);
+    }
+
+    contours
+}
+
+/// Simplifies a closed polygon with the Douglas-Peucker algorithm.
+fn simplify_closed(points: &[Vect], epsilon: Real) -> Vec<Vect> {
+    if points.len() <= 3 || epsilon <= 0.0 {
+        return points.to_vec();
+    }
+
+    // Split the polygon at the point furthest from the first one, and simplify both halves.
+    let furthest = (1..points.len())
+        .max_by(|&a, &b| {
+            let da = points[a].distance_squared(points[0]);
+            let db = points[b].distance_squared(points[0]);
+            da.total_cmp(&db)
+        })
+        .unwrap_or(0);
+
+    let mut second_half = points[furthest..].to_vec();
+    second_half.push(points[0]);
+
+    let mut result = simplify_open(&points[..=furthest], epsilon);
+    result.pop();
+    result.extend(simplify_open(&second_half, epsilon));
+    result.pop();
+    result
+}
+
+/// Simplifies an open polyline with the Douglas-Peucker algorithm, keeping its endpoints.
+fn simplify_open(points: &[Vect], epsilon: Real) -> Vec<Vect> {
+    let (first, last) = (points[0], points[points.len() - 1]);
+    let furthest = (1..points.len() - 1)
+        .map(|i| (i, distance_to_segment(points[i], first, last)))
+        .max_by(|a, b| a.1.total_cmp(&b.1));
+
+    match furthest {
+        Some((i, distance)) if distance > epsilon => {
+            let mut result = simplify_open(&points[..=i], epsilon);
+            result.pop();
+            result.extend(simplify_open(&points[i..], epsilon));
+            result
+        }
+        _ => vec![first, last],
+    }
+}
+
+fn distance_to_segment(point: Vect, a: Vect, b: Vect) -> Real {
+    let ab = b - a;
+    let t = if ab.length_squared() > 0.0 {
+        ((point - a).dot(ab) / ab.length_squared()).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    point.distance(a + ab * t)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use bevy::render::render_asset::RenderAssetUsages;
+    use bevy::render::render_resource::{Extent3d, TextureDimension};
+
+    /// An image with an opaque disk of the given radius, in pixels, at its center.
+    fn disk_image(size: u32, radius: Real) -> Image {
+        let center = size as Real / 2.0;
+        let data = (0..size)
+            .flat_map(|y| (0..size).map(move |x| (x, y)))
+            .flat_map(|(x, y)| {
+                let pixel = Vect::new(x as Real + 0.5, y as Real + 0.5);
+                let alpha = if pixel.distance(Vect::splat(center)) <= radius {
+                    255
+                } else {
+                    0
+                };
+                [255, 255, 255, alpha]
+            })
+            .collect();
+
+        Image::new(
+            Extent3d {
+                width: size,
+                height: size,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            data,
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        )
+    }
+
+    #[test]
+    fn convex_hull_matches_disk_radius() {
+        let image = disk_image(32, 10.0);
+        let parameters = ImageColliderParameters {
+            alpha_threshold: 127,
+            simplification_epsilon: 0.5,
+            pixels_per_unit: 10.0,
+        };
+        let collider = Collider::from_image_alpha_with_parameters(
+            &image,
+            &ImageColliderShape::ConvexHull,
+            &parameters,
+        )
+        .unwrap();
+
+        let polygon = collider.as_convex_polygon().unwrap();
+        for point in polygon.points() {
+            // The disk has a radius of one world unit, give or take a pixel.
+            approx::assert_relative_eq!(point.length(), 1.0, epsilon = 0.15);
+        }
+    }
+
+    #[test]
+    fn polyline_outlines_are_closed() {
+        let image = disk_image(16, 5.0);
+        let collider =
+            Collider::from_image_alpha(&image, 127, ImageColliderShape::Polyline).unwrap();
+        let polyline = collider.as_polyline().unwrap();
+        let raw = polyline.raw;
+
+        assert!(raw.num_segments() >= 3);
+        assert_eq!(raw.num_segments(), raw.vertices().len());
+    }
+
+    #[test]
+    fn fully_transparent_image_is_an_error() {
+        let image = disk_image(16, 0.0);
+        assert_eq!(
+            Collider::from_image_alpha(&image, 0, ImageColliderShape::ConvexHull).unwrap_err(),
+            ImageColliderError::FullyTransparent
+        );
+    }
+}
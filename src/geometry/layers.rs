@@ -0,0 +1,260 @@
+//! Named physics layers, built on top of the [`Group`] bit masks.
+
+use bevy::prelude::*;
+
+use crate::geometry::{CollisionGroups, Group, SolverGroups};
+
+/// A physics layer, usually implemented by a user enum listing the layers of a game.
+///
+/// ```ignore
+/// #[derive(Copy, Clone)]
+/// enum Layer {
+///     Player,
+///     Enemy,
+///     Ground,
+/// }
+///
+/// impl PhysicsLayer for Layer {
+///     fn to_group(&self) -> Group {
+///         Group::from_layer_index(*self as u32)
+///     }
+/// }
+///
+/// let player = CollisionGroups::from_layers(&[Layer::Player], &[Layer::Enemy, Layer::Ground]);
+/// ```
+pub trait PhysicsLayer {
+    /// The group (usually a single bit) corresponding to this layer.
+    fn to_group(&self) -> Group;
+}
+
+impl PhysicsLayer for Group {
+    fn to_group(&self) -> Group {
+        *self
+    }
+}
+
+impl<L: PhysicsLayer> PhysicsLayer for &L {
+    fn to_group(&self) -> Group {
+        (*self).to_group()
+    }
+}
+
+impl Group {
+    /// The group with only the bit `index` set, for example to convert an enum discriminant to a
+    /// group.
+    ///
+    /// # Panics
+    /// Panics if `index` is greater than or equal to 32.
+    pub const fn from_layer_index(index: u32) -> Self {
+        assert!(index < 32, "A group only has 32 layers.");
+        Self::from_bits_retain(1 << index)
+    }
+
+    /// The union of the groups of the given layers.
+    pub fn from_layers(layers: &[impl PhysicsLayer]) -> Self {
+        layers
+            .iter()
+            .fold(Group::NONE, |group, layer| group | layer.to_group())
+    }
+}
+
+impl CollisionGroups {
+    /// Creates collision groups belonging to the `memberships` layers, and interacting with
+    /// the `filters` layers.
+    pub fn from_layers(memberships: &[impl PhysicsLayer], filters: &[impl PhysicsLayer]) -> Self {
+        Self::new(Group::from_layers(memberships), Group::from_layers(filters))
+    }
+
+    /// Can colliders with these collision groups interact with colliders with the `other`
+    /// collision groups?
+    ///
+    /// This is the test applied by Rapier to each pair of colliders.
+    pub fn interacts_with(&self, other: &CollisionGroups) -> bool {
+        self.memberships.intersects(other.filters) && other.memberships.intersects(self.filters)
+    }
+}
+
+impl SolverGroups {
+    /// Creates solver groups belonging to the `memberships` layers, and interacting with the
+    /// `filters` layers.
+    pub fn from_layers(memberships: &[impl PhysicsLayer], filters: &[impl PhysicsLayer]) -> Self {
+        Self::new(Group::from_layers(memberships), Group::from_layers(filters))
+    }
+
+    /// Can colliders with these solver groups interact with colliders with the `other`
+    /// solver groups?
+    pub fn interacts_with(&self, other: &SolverGroups) -> bool {
+        self.memberships.intersects(other.filters) && other.memberships.intersects(self.filters)
+    }
+}
+
+/// Creates [`CollisionGroups`] from the layers they belong to and the layers they interact
+/// with.
+///
+/// Each layer is any value implementing [`PhysicsLayer`], and the lists can be empty:
+/// ```ignore
+/// let player = collision_groups!(memberships: [Layer::Player], filters: [Layer::Enemy, Layer::Ground]);
+/// let ghost = collision_groups!(memberships: [Layer::Player], filters: []);
+/// ```
+#[macro_export]
+macro_rules! collision_groups {
+    (memberships: [$($membership: expr),* $(,)?], filters: [$($filter: expr),* $(,)?] $(,)?) => {
+        $crate::geometry::CollisionGroups::new(
+            $crate::geometry::Group::NONE
+                $(| $crate::geometry::PhysicsLayer::to_group(&$membership))*,
+            $crate::geometry::Group::NONE
+                $(| $crate::geometry::PhysicsLayer::to_group(&$filter))*,
+        )
+    };
+}
+
+/// A registry of physics layers identified by their name.
+///
+/// This is an alternative to implementing [`PhysicsLayer`] for layers only known at runtime,
+/// for example when they are read from a configuration file. Each registered name is given
+/// the next unused bit of the [`Group`] masks.
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+#[reflect(Resource, Default)]
+pub struct PhysicsLayers {
+    /// The names of the layers, indexed like [`Group::from_layer_index`].
+    names: Vec<String>,
+}
+
+impl PhysicsLayers {
+    /// Registers a layer with the given name, and returns its group.
+    ///
+    /// If the name is already registered, its group is returned. Returns `None` if all the 32
+    /// layers are already used.
+    pub fn register(&mut self, name: impl Into<String>) -> Option<Group> {
+        let name = name.into();
+        if let Some(group) = self.group(&name) {
+            return Some(group);
+        }
+
+        if self.names.len() >= 32 {
+            return None;
+        }
+
+        self.names.push(name);
+        Some(Group::from_layer_index(self.names.len() as u32 - 1))
+    }
+
+    /// The group of the layer with the given name, if it is registered.
+    pub fn group(&self, name: &str) -> Option<Group> {
+        self.names
+            .iter()
+            .position(|layer| layer == name)
+            .map(|index| Group::from_layer_index(index as u32))
+    }
+
+    /// The union of the groups of the layers with the given names, or `None` if one of them
+    /// isn’t registered.
+    pub fn groups(&self, names: &[&str]) -> Option<Group> {
+        names
+            .iter()
+            .try_fold(Group::NONE, |group, name| Some(group | self.group(name)?))
+    }
+
+    /// Collision groups belonging to the `memberships` layers, and interacting with the
+    /// `filters` layers, or `None` if one of the layers isn’t registered.
+    pub fn collision_groups(
+        &self,
+        memberships: &[&str],
+        filters: &[&str],
+    ) -> Option<CollisionGroups> {
+        Some(CollisionGroups::new(
+            self.groups(memberships)?,
+            self.groups(filters)?,
+        ))
+    }
+
+    /// The names of the layers of the given group, in the order they were registered.
+    pub fn names(&self, group: Group) -> impl Iterator<Item = &str> {
+        self.names
+            .iter()
+            .enumerate()
+            .filter(move |(index, _)| group.contains(Group::from_layer_index(*index as u32)))
+            .map(|(_, name)| name.as_str())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Copy, Clone)]
+    enum Layer {
+        Player,
+        Enemy,
+        Ground,
+        Projectile,
+    }
+
+    impl PhysicsLayer for Layer {
+        fn to_group(&self) -> Group {
+            Group::from_layer_index(*self as u32)
+        }
+    }
+
+    #[test]
+    fn collision_groups_from_user_layers() {
+        let player = CollisionGroups::from_layers(&[Layer::Player], &[Layer::Enemy, Layer::Ground]);
+        assert_eq!(player.memberships, Group::GROUP_1);
+        assert_eq!(player.filters, Group::GROUP_2 | Group::GROUP_3);
+
+        let enemy = crate::collision_groups!(
+            memberships: [Layer::Enemy],
+            filters: [Layer::Player, Layer::Ground, Layer::Projectile],
+        );
+        assert_eq!(enemy.memberships, Group::GROUP_2);
+        assert_eq!(
+            enemy.filters,
+            Group::GROUP_1 | Group::GROUP_3 | Group::GROUP_4
+        );
+
+        let ghost = crate::collision_groups!(memberships: [Layer::Player], filters: []);
+        assert_eq!(ghost.filters, Group::NONE);
+    }
+
+    #[test]
+    fn interaction_predicate_requires_both_directions() {
+        let player = CollisionGroups::from_layers(&[Layer::Player], &[Layer::Enemy, Layer::Ground]);
+        let enemy = CollisionGroups::from_layers(&[Layer::Enemy], &[Layer::Player]);
+        let ground = CollisionGroups::from_layers(&[Layer::Ground], &[Layer::Enemy]);
+        let projectile = CollisionGroups::from_layers(&[Layer::Projectile], &[Layer::Player]);
+
+        assert!(player.interacts_with(&enemy));
+        assert!(enemy.interacts_with(&player));
+        // The ground doesn’t interact with the player even if the player interacts with it.
+        assert!(!player.interacts_with(&ground));
+        assert!(!ground.interacts_with(&player));
+        assert!(!player.interacts_with(&projectile));
+        assert!(CollisionGroups::default().interacts_with(&CollisionGroups::default()));
+    }
+
+    #[test]
+    fn named_layers_registry() {
+        let mut layers = PhysicsLayers::default();
+        let player = layers.register("player").unwrap();
+        let enemy = layers.register("enemy").unwrap();
+        assert_eq!(player, Group::GROUP_1);
+        assert_eq!(enemy, Group::GROUP_2);
+        assert_eq!(layers.register("player"), Some(player));
+        assert_eq!(layers.group("ground"), None);
+
+        assert_eq!(
+            layers.collision_groups(&["player"], &["enemy"]),
+            Some(CollisionGroups::new(player, enemy))
+        );
+        assert_eq!(layers.collision_groups(&["player"], &["ground"]), None);
+        assert_eq!(
+            layers.names(Group::ALL).collect::<Vec<_>>(),
+            ["player", "enemy"]
+        );
+
+        for i in 2..32 {
+            assert!(layers.register(format!("layer {i}")).is_some());
+        }
+        assert_eq!(layers.register("too many"), None);
+    }
+}
@@ -1,4 +1,8 @@
 pub use self::collider::*;
+#[cfg(all(feature = "dim2", feature = "image-collider"))]
+pub use self::image_collider::*;
+pub use self::layers::{PhysicsLayer, PhysicsLayers};
+pub use self::shape_desc::ColliderShapeDesc;
 pub use self::shape_views::ColliderView;
 pub use rapier::geometry::SolverFlags;
 pub use rapier::parry::query::{ShapeCastOptions, ShapeCastStatus};
@@ -10,6 +14,13 @@ use rapier::prelude::FeatureId;
 
 mod collider;
 mod collider_impl;
+#[cfg(not(feature = "headless"))]
+mod collider_mesh;
+#[cfg(all(feature = "dim2", feature = "image-collider"))]
+mod image_collider;
+mod layers;
+mod primitives;
+mod shape_desc;
 /// Wrappers around Rapier shapes to access their properties.
 pub mod shape_views;
 
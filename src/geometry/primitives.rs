@@ -0,0 +1,234 @@
+//! Conversions from Bevy’s primitive shapes to colliders.
+//!
+//! Primitives without an analytic collider (like `Torus` or `Ellipse`) can be approximated
+//! from their mesh with [`Collider::from_primitive_mesh`].
+
+#[cfg(feature = "dim2")]
+use bevy::math::primitives::{Capsule2d, Circle, Plane2d, Rectangle, Segment2d, Triangle2d};
+#[cfg(feature = "dim3")]
+use bevy::math::primitives::{Capsule3d, Cone, Cuboid, Cylinder, Plane3d, Segment3d, Sphere};
+#[cfg(feature = "async-collider")]
+use bevy::render::mesh::{Mesh, Meshable};
+use rapier::na::Unit;
+use rapier::prelude::{SharedShape, Vector};
+
+use crate::geometry::Collider;
+#[cfg(feature = "async-collider")]
+use crate::geometry::ComputedColliderShape;
+use crate::math::Vect;
+
+impl Collider {
+    /// Initializes a collider from the mesh of a Bevy primitive shape.
+    ///
+    /// This is useful for primitives without an analytic collider, like `Torus`. Returns `None`
+    /// under the same conditions as [`Collider::from_bevy_mesh`].
+    #[cfg(feature = "async-collider")]
+    pub fn from_primitive_mesh<P: Meshable>(
+        primitive: &P,
+        shape: &ComputedColliderShape,
+    ) -> Option<Self>
+    where
+        P::Output: Into<Mesh>,
+    {
+        Self::from_bevy_mesh(&primitive.mesh().into(), shape)
+    }
+
+    fn halfspace_from_direction(normal: Vect) -> Self {
+        // The directions of Bevy’s primitives are always normalized.
+        SharedShape::halfspace(Unit::new_unchecked(Vector::from(normal))).into()
+    }
+}
+
+#[cfg(feature = "dim2")]
+impl From<Circle> for Collider {
+    fn from(circle: Circle) -> Self {
+        Collider::ball(circle.radius)
+    }
+}
+
+#[cfg(feature = "dim2")]
+impl From<Rectangle> for Collider {
+    fn from(rectangle: Rectangle) -> Self {
+        Collider::cuboid(rectangle.half_size.x, rectangle.half_size.y)
+    }
+}
+
+#[cfg(feature = "dim2")]
+impl From<Capsule2d> for Collider {
+    fn from(capsule: Capsule2d) -> Self {
+        Collider::capsule_y(capsule.half_length, capsule.radius)
+    }
+}
+
+#[cfg(feature = "dim2")]
+impl From<Segment2d> for Collider {
+    fn from(segment: Segment2d) -> Self {
+        let half = *segment.direction * segment.half_length;
+        Collider::segment(-half, half)
+    }
+}
+
+#[cfg(feature = "dim2")]
+impl From<Triangle2d> for Collider {
+    fn from(triangle: Triangle2d) -> Self {
+        let [a, b, c] = triangle.vertices;
+        Collider::triangle(a, b, c)
+    }
+}
+
+#[cfg(feature = "dim2")]
+impl From<Plane2d> for Collider {
+    fn from(plane: Plane2d) -> Self {
+        Collider::halfspace_from_direction(*plane.normal)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl From<Sphere> for Collider {
+    fn from(sphere: Sphere) -> Self {
+        Collider::ball(sphere.radius)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl From<Cuboid> for Collider {
+    fn from(cuboid: Cuboid) -> Self {
+        let half_size = cuboid.half_size;
+        Collider::cuboid(half_size.x, half_size.y, half_size.z)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl From<Capsule3d> for Collider {
+    fn from(capsule: Capsule3d) -> Self {
+        Collider::capsule_y(capsule.half_length, capsule.radius)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl From<Cylinder> for Collider {
+    fn from(cylinder: Cylinder) -> Self {
+        Collider::cylinder(cylinder.half_height, cylinder.radius)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl From<Cone> for Collider {
+    fn from(cone: Cone) -> Self {
+        Collider::cone(cone.height / 2.0, cone.radius)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl From<Segment3d> for Collider {
+    fn from(segment: Segment3d) -> Self {
+        let half = *segment.direction * segment.half_length;
+        Collider::segment(-half, half)
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl From<Plane3d> for Collider {
+    fn from(plane: Plane3d) -> Self {
+        Collider::halfspace_from_direction(*plane.normal)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    #[cfg(feature = "dim2")]
+    fn colliders_from_primitives() {
+        use bevy::math::Vec2;
+
+        let ball = Collider::from(Circle::new(0.5));
+        assert_relative_eq!(ball.as_ball().unwrap().radius(), 0.5);
+
+        let cuboid = Collider::from(Rectangle::new(2.0, 4.0));
+        assert_eq!(
+            cuboid.as_cuboid().unwrap().half_extents(),
+            Collider::cuboid(1.0, 2.0)
+                .as_cuboid()
+                .unwrap()
+                .half_extents()
+        );
+
+        let capsule = Collider::from(Capsule2d::new(0.5, 2.0));
+        let expected = Collider::capsule_y(1.0, 0.5);
+        let (capsule, expected) = (
+            capsule.as_capsule().unwrap(),
+            expected.as_capsule().unwrap(),
+        );
+        assert_relative_eq!(capsule.radius(), expected.radius());
+        assert_relative_eq!(capsule.segment().a(), expected.segment().a());
+        assert_relative_eq!(capsule.segment().b(), expected.segment().b());
+
+        let triangle = Collider::from(Triangle2d::new(Vec2::ZERO, Vec2::X, Vec2::Y));
+        let triangle = triangle.as_triangle().unwrap();
+        assert_eq!(triangle.a(), Vec2::ZERO);
+        assert_eq!(triangle.b(), Vec2::X);
+        assert_eq!(triangle.c(), Vec2::Y);
+
+        let halfspace = Collider::from(Plane2d::new(Vec2::Y));
+        assert_relative_eq!(halfspace.as_halfspace().unwrap().normal(), Vec2::Y);
+    }
+
+    #[test]
+    #[cfg(feature = "dim3")]
+    fn colliders_from_primitives() {
+        use bevy::math::Vec3;
+
+        let ball = Collider::from(Sphere::new(0.5));
+        assert_relative_eq!(ball.as_ball().unwrap().radius(), 0.5);
+
+        let cuboid = Collider::from(Cuboid::new(2.0, 4.0, 6.0));
+        assert_eq!(
+            cuboid.as_cuboid().unwrap().half_extents(),
+            Collider::cuboid(1.0, 2.0, 3.0)
+                .as_cuboid()
+                .unwrap()
+                .half_extents()
+        );
+
+        let capsule = Collider::from(Capsule3d::new(0.5, 2.0));
+        let expected = Collider::capsule_y(1.0, 0.5);
+        let (capsule, expected) = (
+            capsule.as_capsule().unwrap(),
+            expected.as_capsule().unwrap(),
+        );
+        assert_relative_eq!(capsule.radius(), expected.radius());
+        assert_relative_eq!(capsule.segment().a(), expected.segment().a());
+        assert_relative_eq!(capsule.segment().b(), expected.segment().b());
+
+        let cylinder = Collider::from(Cylinder::new(0.5, 2.0));
+        let cylinder = cylinder.as_cylinder().unwrap();
+        assert_relative_eq!(cylinder.radius(), 0.5);
+        assert_relative_eq!(cylinder.half_height(), 1.0);
+
+        let cone = Collider::from(Cone {
+            radius: 0.5,
+            height: 2.0,
+        });
+        let cone = cone.as_cone().unwrap();
+        assert_relative_eq!(cone.radius(), 0.5);
+        assert_relative_eq!(cone.half_height(), 1.0);
+
+        let halfspace = Collider::from(Plane3d::new(Vec3::Y));
+        assert_relative_eq!(halfspace.as_halfspace().unwrap().normal(), Vec3::Y);
+    }
+
+    #[test]
+    #[cfg(all(feature = "dim3", feature = "async-collider"))]
+    fn collider_from_torus_mesh() {
+        use bevy::math::primitives::Torus;
+
+        let torus = Collider::from_primitive_mesh(
+            &Torus::new(0.5, 1.0),
+            &ComputedColliderShape::ConvexHull,
+        );
+        assert!(torus.unwrap().as_convex_polyhedron().is_some());
+    }
+}
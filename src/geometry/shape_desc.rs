@@ -0,0 +1,577 @@
+use bevy::prelude::*;
+use rapier::parry::shape::TypedShape;
+
+#[cfg(feature = "async-collider")]
+use crate::geometry::ComputedColliderShape;
+use crate::geometry::{Collider, ColliderView};
+use crate::math::{Real, Rot, Vect};
+
+/// A description of the shape of a [`Collider`] that can be reflected and serialized, in a
+/// scene for example.
+///
+/// A [`Collider`] is only reflected as an opaque value, since its shape is a Rapier
+/// [`SharedShape`](rapier::geometry::SharedShape). Add this component to an entity instead (in a
+/// scene file or a prefab for example), and the plugin inserts the corresponding [`Collider`].
+/// Conversely, the plugin inserts the description of the colliders spawned without one, and keeps
+/// it up-to-date when their shape changes, so they can be saved. [`Collider::shape_desc`]
+/// describes the shape of any collider.
+///
+/// The description only covers the unscaled shape: the scale of the [`Collider`] is still
+/// computed from the [`GlobalTransform`] of its entity.
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component, PartialEq)]
+pub enum ColliderShapeDesc {
+    /// A ball.
+    Ball {
+        /// The radius of the ball.
+        radius: Real,
+    },
+    /// A cuboid.
+    Cuboid {
+        /// The half-extents of the cuboid along each axis.
+        half_extents: Vect,
+    },
+    /// A cuboid with rounded corners.
+    RoundCuboid {
+        /// The half-extents of the cuboid along each axis, without the rounded border.
+        half_extents: Vect,
+        /// The radius of the rounded border.
+        border_radius: Real,
+    },
+    /// A capsule.
+    Capsule {
+        /// The first end-point of the segment of the capsule.
+        start: Vect,
+        /// The second end-point of the segment of the capsule.
+        end: Vect,
+        /// The radius of the capsule.
+        radius: Real,
+    },
+    /// A segment.
+    Segment {
+        /// The first end-point of the segment.
+        a: Vect,
+        /// The second end-point of the segment.
+        b: Vect,
+    },
+    /// A triangle.
+    Triangle {
+        /// The first vertex of the triangle.
+        a: Vect,
+        /// The second vertex of the triangle.
+        b: Vect,
+        /// The third vertex of the triangle.
+        c: Vect,
+    },
+    /// A triangle with rounded corners.
+    RoundTriangle {
+        /// The first vertex of the triangle.
+        a: Vect,
+        /// The second vertex of the triangle.
+        b: Vect,
+        /// The third vertex of the triangle.
+        c: Vect,
+        /// The radius of the rounded border.
+        border_radius: Real,
+    },
+    /// A half-space.
+    HalfSpace {
+        /// The outward normal of the planar boundary of the half-space.
+        normal: Vect,
+    },
+    /// A cylinder, along the `Y` axis.
+    #[cfg(feature = "dim3")]
+    Cylinder {
+        /// The half-height of the cylinder.
+        half_height: Real,
+        /// The radius of the cylinder.
+        radius: Real,
+    },
+    /// A cylinder with rounded corners, along the `Y` axis.
+    #[cfg(feature = "dim3")]
+    RoundCylinder {
+        /// The half-height of the cylinder, without the rounded border.
+        half_height: Real,
+        /// The radius of the cylinder, without the rounded border.
+        radius: Real,
+        /// The radius of the rounded border.
+        border_radius: Real,
+    },
+    /// A cone, along the `Y` axis.
+    #[cfg(feature = "dim3")]
+    Cone {
+        /// The half-height of the cone.
+        half_height: Real,
+        /// The radius of the base of the cone.
+        radius: Real,
+    },
+    /// A cone with rounded corners, along the `Y` axis.
+    #[cfg(feature = "dim3")]
+    RoundCone {
+        /// The half-height of the cone, without the rounded border.
+        half_height: Real,
+        /// The radius of the base of the cone, without the rounded border.
+        radius: Real,
+        /// The radius of the rounded border.
+        border_radius: Real,
+    },
+    /// The convex hull of a set of points.
+    ConvexHull {
+        /// The points the convex hull is computed from.
+        points: Vec<Vect>,
+    },
+    /// The convex hull of a set of points, with rounded corners.
+    RoundConvexHull {
+        /// The points the convex hull is computed from.
+        points: Vec<Vect>,
+        /// The radius of the rounded border.
+        border_radius: Real,
+    },
+    /// A triangle mesh, with the default [`TriMeshFlags`](crate::geometry::TriMeshFlags).
+    TriMesh {
+        /// The vertices of the mesh.
+        vertices: Vec<Vect>,
+        /// The indices of the vertices of each triangle.
+        indices: Vec<[u32; 3]>,
+    },
+    /// A triangle mesh built from a mesh asset, with the default
+    /// [`TriMeshFlags`](crate::geometry::TriMeshFlags).
+    ///
+    /// The collider is inserted once the mesh is loaded.
+    #[cfg(feature = "async-collider")]
+    TriMeshAsset {
+        /// The handle of the mesh.
+        handle: Handle<Mesh>,
+    },
+    /// A set of segments.
+    Polyline {
+        /// The vertices of the polyline.
+        vertices: Vec<Vect>,
+        /// The indices of the vertices of each segment.
+        indices: Vec<[u32; 2]>,
+    },
+    /// A heightfield.
+    #[cfg(feature = "dim2")]
+    HeightField {
+        /// The heights of the heightfield.
+        heights: Vec<Real>,
+        /// The scale factor along each axis.
+        scale: Vect,
+    },
+    /// A heightfield.
+    #[cfg(feature = "dim3")]
+    HeightField {
+        /// The heights of the heightfield, in column-major format.
+        heights: Vec<Real>,
+        /// The number of rows of the heightfield.
+        num_rows: usize,
+        /// The number of columns of the heightfield.
+        num_cols: usize,
+        /// The scale factor along each axis.
+        scale: Vect,
+    },
+    /// A compound shape, made of several shapes positioned relative to the collider.
+    Compound {
+        /// The translation, rotation, and description of each shape. There must be at least one
+        /// shape, and none of them can be a composite shape (a triangle mesh, polyline,
+        /// heightfield, or compound shape).
+        shapes: Vec<(Vect, Rot, ColliderShapeDesc)>,
+    },
+    /// A shape that can’t be described, for example a collider built from a custom Rapier shape.
+    ///
+    /// No collider is created from this description.
+    Unserializable,
+}
+
+impl ColliderShapeDesc {
+    /// Builds the collider described by `self`.
+    ///
+    /// Returns `None` for [`ColliderShapeDesc::Unserializable`], for descriptions that don’t
+    /// define a valid shape (like the convex hull of a single point, or a triangle mesh with
+    /// out-of-bounds indices), and for descriptions referencing a mesh asset (see
+    /// [`Self::to_collider_with_meshes`]).
+    ///
+    /// Parry panics on some invalid shapes instead of reporting an error, so they are checked
+    /// here beforehand.
+    pub fn to_collider(&self) -> Option<Collider> {
+        let collider = match self {
+            Self::Ball { radius } => Collider::ball(*radius),
+            #[cfg(feature = "dim2")]
+            Self::Cuboid { half_extents } => Collider::cuboid(half_extents.x, half_extents.y),
+            #[cfg(feature = "dim3")]
+            Self::Cuboid { half_extents } => {
+                Collider::cuboid(half_extents.x, half_extents.y, half_extents.z)
+            }
+            #[cfg(feature = "dim2")]
+            Self::RoundCuboid {
+                half_extents,
+                border_radius,
+            } => Collider::round_cuboid(half_extents.x, half_extents.y, *border_radius),
+            #[cfg(feature = "dim3")]
+            Self::RoundCuboid {
+                half_extents,
+                border_radius,
+            } => Collider::round_cuboid(
+                half_extents.x,
+                half_extents.y,
+                half_extents.z,
+                *border_radius,
+            ),
+            Self::Capsule { start, end, radius } => Collider::capsule(*start, *end, *radius),
+            Self::Segment { a, b } => Collider::segment(*a, *b),
+            Self::Triangle { a, b, c } => Collider::triangle(*a, *b, *c),
+            Self::RoundTriangle {
+                a,
+                b,
+                c,
+                border_radius,
+            } => Collider::round_triangle(*a, *b, *c, *border_radius),
+            Self::HalfSpace { normal } => return Collider::halfspace(*normal),
+            #[cfg(feature = "dim3")]
+            Self::Cylinder {
+                half_height,
+                radius,
+            } => Collider::cylinder(*half_height, *radius),
+            #[cfg(feature = "dim3")]
+            Self::RoundCylinder {
+                half_height,
+                radius,
+                border_radius,
+            } => Collider::round_cylinder(*half_height, *radius, *border_radius),
+            #[cfg(feature = "dim3")]
+            Self::Cone {
+                half_height,
+                radius,
+            } => Collider::cone(*half_height, *radius),
+            #[cfg(feature = "dim3")]
+            Self::RoundCone {
+                half_height,
+                radius,
+                border_radius,
+            } => Collider::round_cone(*half_height, *radius, *border_radius),
+            Self::ConvexHull { points } => return Collider::convex_hull(points),
+            Self::RoundConvexHull {
+                points,
+                border_radius,
+            } => return Collider::round_convex_hull(points, *border_radius),
+            Self::TriMesh { vertices, indices } => {
+                if indices.is_empty() || !valid_indices(vertices, indices) {
+                    return None;
+                }
+                Collider::trimesh(vertices.clone(), indices.clone())
+            }
+            #[cfg(feature = "async-collider")]
+            Self::TriMeshAsset { .. } => return None,
+            Self::Polyline { vertices, indices } => {
+                if indices.is_empty() || !valid_indices(vertices, indices) {
+                    return None;
+                }
+                Collider::polyline(vertices.clone(), Some(indices.clone()))
+            }
+            #[cfg(feature = "dim2")]
+            Self::HeightField { heights, scale } => {
+                if heights.len() < 2 {
+                    return None;
+                }
+                Collider::heightfield(heights.clone(), *scale)
+            }
+            #[cfg(feature = "dim3")]
+            Self::HeightField {
+                heights,
+                num_rows,
+                num_cols,
+                scale,
+            } => {
+                if *num_rows < 2 || *num_cols < 2 || heights.len() != num_rows * num_cols {
+                    return None;
+                }
+                Collider::heightfield(heights.clone(), *num_rows, *num_cols, *scale)
+            }
+            Self::Compound { shapes } => {
+                if shapes.is_empty() || shapes.iter().any(|(_, _, desc)| desc.is_composite()) {
+                    return None;
+                }
+                Collider::compound(
+                    shapes
+                        .iter()
+                        .map(|(translation, rotation, desc)| {
+                            Some((*translation, *rotation, desc.to_collider()?))
+                        })
+                        .collect::<Option<_>>()?,
+                )
+            }
+            Self::Unserializable => return None,
+        };
+
+        Some(collider)
+    }
+
+    /// Builds the collider described by `self`, reading the mesh of a
+    /// [`ColliderShapeDesc::TriMeshAsset`] description from the given assets.
+    ///
+    /// Returns `None` if this mesh isn’t loaded, or in the same cases as [`Self::to_collider`].
+    #[cfg(feature = "async-collider")]
+    pub fn to_collider_with_meshes(&self, meshes: &Assets<Mesh>) -> Option<Collider> {
+        match self {
+            Self::TriMeshAsset { handle } => {
+                Collider::from_bevy_mesh(meshes.get(handle)?, &ComputedColliderShape::TriMesh)
+            }
+            _ => self.to_collider(),
+        }
+    }
+
+    /// Are all the meshes referenced by this description loaded?
+    #[cfg(feature = "async-collider")]
+    pub(crate) fn meshes_loaded(&self, meshes: &Assets<Mesh>) -> bool {
+        match self {
+            Self::TriMeshAsset { handle } => meshes.contains(handle),
+            _ => true,
+        }
+    }
+
+    /// Is this description a composite shape, which can’t be part of a compound shape?
+    fn is_composite(&self) -> bool {
+        match self {
+            Self::TriMesh { .. }
+            | Self::Polyline { .. }
+            | Self::HeightField { .. }
+            | Self::Compound { .. } => true,
+            #[cfg(feature = "async-collider")]
+            Self::TriMeshAsset { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Does this description reference a mesh asset?
+    pub(crate) fn references_meshes(&self) -> bool {
+        match self {
+            #[cfg(feature = "async-collider")]
+            Self::TriMeshAsset { .. } => true,
+            _ => false,
+        }
+    }
+}
+
+impl<'a> From<ColliderView<'a>> for ColliderShapeDesc {
+    fn from(view: ColliderView<'a>) -> Self {
+        match view {
+            ColliderView::Ball(ball) => Self::Ball {
+                radius: ball.radius(),
+            },
+            ColliderView::Cuboid(cuboid) => Self::Cuboid {
+                half_extents: cuboid.half_extents(),
+            },
+            ColliderView::RoundCuboid(round) => Self::RoundCuboid {
+                half_extents: round.inner_shape().half_extents(),
+                border_radius: round.border_radius(),
+            },
+            ColliderView::Capsule(capsule) => {
+                let segment = capsule.segment();
+                Self::Capsule {
+                    start: segment.a(),
+                    end: segment.b(),
+                    radius: capsule.radius(),
+                }
+            }
+            ColliderView::Segment(segment) => Self::Segment {
+                a: segment.a(),
+                b: segment.b(),
+            },
+            ColliderView::Triangle(triangle) => Self::Triangle {
+                a: triangle.a(),
+                b: triangle.b(),
+                c: triangle.c(),
+            },
+            ColliderView::RoundTriangle(round) => {
+                let triangle = round.inner_shape();
+                Self::RoundTriangle {
+                    a: triangle.a(),
+                    b: triangle.b(),
+                    c: triangle.c(),
+                    border_radius: round.border_radius(),
+                }
+            }
+            ColliderView::HalfSpace(halfspace) => Self::HalfSpace {
+                normal: halfspace.normal(),
+            },
+            #[cfg(feature = "dim3")]
+            ColliderView::Cylinder(cylinder) => Self::Cylinder {
+                half_height: cylinder.half_height(),
+                radius: cylinder.radius(),
+            },
+            #[cfg(feature = "dim3")]
+            ColliderView::RoundCylinder(round) => {
+                let cylinder = round.inner_shape();
+                Self::RoundCylinder {
+                    half_height: cylinder.half_height(),
+                    radius: cylinder.radius(),
+                    border_radius: round.border_radius(),
+                }
+            }
+            #[cfg(feature = "dim3")]
+            ColliderView::Cone(cone) => Self::Cone {
+                half_height: cone.half_height(),
+                radius: cone.radius(),
+            },
+            #[cfg(feature = "dim3")]
+            ColliderView::RoundCone(round) => {
+                let cone = round.inner_shape();
+                Self::RoundCone {
+                    half_height: cone.half_height(),
+                    radius: cone.radius(),
+                    border_radius: round.border_radius(),
+                }
+            }
+            #[cfg(feature = "dim2")]
+            ColliderView::ConvexPolygon(polygon) => Self::ConvexHull {
+                points: polygon.points().collect(),
+            },
+            #[cfg(feature = "dim2")]
+            ColliderView::RoundConvexPolygon(round) => Self::RoundConvexHull {
+                points: round.inner_shape().points().collect(),
+                border_radius: round.border_radius(),
+            },
+            #[cfg(feature = "dim3")]
+            ColliderView::ConvexPolyhedron(polyhedron) => Self::ConvexHull {
+                points: polyhedron.points().collect(),
+            },
+            #[cfg(feature = "dim3")]
+            ColliderView::RoundConvexPolyhedron(round) => Self::RoundConvexHull {
+                points: round.inner_shape().points().collect(),
+                border_radius: round.border_radius(),
+            },
+            ColliderView::TriMesh(trimesh) => Self::TriMesh {
+                vertices: trimesh.vertices().collect(),
+                indices: trimesh.indices().to_vec(),
+            },
+            ColliderView::Polyline(polyline) => Self::Polyline {
+                vertices: polyline.vertices().collect(),
+                indices: polyline.indices().to_vec(),
+            },
+            #[cfg(feature = "dim2")]
+            ColliderView::HeightField(heightfield) => Self::HeightField {
+                heights: heightfield.heights().to_vec(),
+                scale: heightfield.scale(),
+            },
+            #[cfg(feature = "dim3")]
+            ColliderView::HeightField(heightfield) => Self::HeightField {
+                heights: heightfield.heights().to_vec(),
+                num_rows: heightfield.nrows(),
+                num_cols: heightfield.ncols(),
+                scale: heightfield.scale(),
+            },
+            ColliderView::Compound(compound) => Self::Compound {
+                shapes: compound
+                    .raw
+                    .shapes()
+                    .iter()
+                    .map(|(position, shape)| {
+                        let (translation, rotation) = (*position).into();
+                        (translation, rotation, describe(shape.as_typed_shape()))
+                    })
+                    .collect(),
+            },
+        }
+    }
+}
+
+/// Are all the indices of these triangles or segments within the bounds of the vertices?
+fn valid_indices<const N: usize>(vertices: &[Vect], indices: &[[u32; N]]) -> bool {
+    indices
+        .iter()
+        .flatten()
+        .all(|index| (*index as usize) < vertices.len())
+}
+
+fn describe(shape: TypedShape) -> ColliderShapeDesc {
+    match shape {
+        TypedShape::Custom(_) => ColliderShapeDesc::Unserializable,
+        shape => ColliderView::from(shape).into(),
+    }
+}
+
+impl Collider {
+    /// A description of the unscaled shape of this collider, which can be reflected and
+    /// serialized.
+    ///
+    /// This is [`ColliderShapeDesc::Unserializable`] if the collider was built from a custom
+    /// Rapier shape.
+    pub fn shape_desc(&self) -> ColliderShapeDesc {
+        describe(self.unscaled.as_typed_shape())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn invalid_descs_are_not_built() {
+        let vertices = vec![Vect::ZERO, Vect::X, Vect::Y];
+        let compound_ball = (
+            Vect::ZERO,
+            Rot::default(),
+            ColliderShapeDesc::Ball { radius: 0.5 },
+        );
+
+        let invalid = [
+            ColliderShapeDesc::TriMesh {
+                vertices: vertices.clone(),
+                indices: vec![],
+            },
+            ColliderShapeDesc::TriMesh {
+                vertices: vertices.clone(),
+                indices: vec![[0, 1, 3]],
+            },
+            ColliderShapeDesc::Polyline {
+                vertices: vertices.clone(),
+                indices: vec![[0, 3]],
+            },
+            ColliderShapeDesc::Compound { shapes: vec![] },
+            ColliderShapeDesc::Compound {
+                shapes: vec![(
+                    Vect::ZERO,
+                    Rot::default(),
+                    ColliderShapeDesc::TriMesh {
+                        vertices: vertices.clone(),
+                        indices: vec![[0, 1, 2]],
+                    },
+                )],
+            },
+            ColliderShapeDesc::Compound {
+                shapes: vec![(
+                    Vect::ZERO,
+                    Rot::default(),
+                    ColliderShapeDesc::Compound {
+                        shapes: vec![compound_ball.clone()],
+                    },
+                )],
+            },
+            #[cfg(feature = "dim2")]
+            ColliderShapeDesc::HeightField {
+                heights: vec![1.0],
+                scale: Vect::ONE,
+            },
+            #[cfg(feature = "dim3")]
+            ColliderShapeDesc::HeightField {
+                heights: vec![1.0, 1.0],
+                num_rows: 1,
+                num_cols: 2,
+                scale: Vect::ONE,
+            },
+        ];
+        for desc in &invalid {
+            assert!(desc.to_collider().is_none(), "{:?} was built", desc);
+        }
+
+        let valid = ColliderShapeDesc::TriMesh {
+            vertices,
+            indices: vec![[0, 1, 2]],
+        };
+        assert!(valid.to_collider().is_some());
+        let compound = ColliderShapeDesc::Compound {
+            shapes: vec![compound_ball],
+        };
+        assert!(compound.to_collider().is_some());
+    }
+}
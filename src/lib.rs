@@ -9,6 +9,22 @@
 //!
 //! User documentation for `bevy_rapier` is on [the official Rapier site](https://rapier.rs/docs/).
 //!
+//! ## Determinism
+//!
+//! With the `enhanced-determinism` feature, running the same simulation on different platforms
+//! gives bit-exact results. The physics objects are created in the order the entities are
+//! returned by Bevy’s queries, and the worlds are always stepped in the order of their ids.
+//! The simulation can still diverge if the app itself isn’t deterministic, for example if it:
+//! - spawns physics entities in an order depending on a `HashMap` or `HashSet` iteration, or on
+//!   the order of the results of a parallel system,
+//! - feeds a variable time step to the simulation: use a fixed
+//!   [`TimestepMode`](crate::plugin::TimestepMode) instead,
+//! - computes forces or velocities with non-deterministic floating-point code (for example
+//!   with transcendental functions of the standard library, whose precision depends on the
+//!   platform),
+//! - enables the `parallel` feature (the `simd-*` features can’t be combined with
+//!   `enhanced-determinism`).
+//!
 
 #![warn(missing_docs)]
 
@@ -66,6 +82,10 @@ pub mod control;
 /// The debug-renderer.
 #[cfg(any(feature = "debug-render-3d", feature = "debug-render-2d"))]
 pub mod render;
+
+/// A picking backend based on the colliders, for `bevy_mod_picking`.
+#[cfg(feature = "picking-backend")]
+pub mod picking_backend;
 /// Miscellaneous helper functions.
 pub mod utils;
 
@@ -77,6 +97,8 @@ pub mod prelude {
     pub use crate::dynamics::*;
     pub use crate::geometry::*;
     pub use crate::math::*;
+    #[cfg(feature = "picking-backend")]
+    pub use crate::picking_backend::*;
     pub use crate::pipeline::*;
     pub use crate::plugin::*;
     #[cfg(any(feature = "debug-render-3d", feature = "debug-render-2d"))]
@@ -0,0 +1,257 @@
+//! A `bevy_mod_picking` backend, picking entities through their colliders.
+//!
+//! Add the [`RapierPickingPlugin`] alongside the picking plugins to make every collider
+//! hoverable and clickable, without requiring a mesh.
+
+use crate::geometry::CollisionGroups;
+use crate::pipeline::QueryFilter;
+use crate::plugin::{RapierContext, RapierWorld, WorldId, DEFAULT_WORLD_ID};
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy_picking_core::backend::prelude::*;
+
+/// Plugin adding a picking backend based on the colliders of a [`RapierWorld`].
+///
+/// It can be configured with the [`RapierPickingSettings`] resource.
+#[derive(Copy, Clone, Default, Debug)]
+pub struct RapierPickingPlugin;
+
+impl Plugin for RapierPickingPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<RapierPickingSettings>()
+            .register_type::<RapierPickable>()
+            .init_resource::<RapierPickingSettings>()
+            .add_systems(
+                PreUpdate,
+                update_rapier_picking_hits.in_set(PickSet::Backend),
+            );
+    }
+}
+
+/// Settings of the [`RapierPickingPlugin`].
+#[derive(Resource, Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Resource)]
+pub struct RapierPickingSettings {
+    /// The world containing the pickable colliders.
+    pub world_id: WorldId,
+    /// If `true`, only the colliders with a [`RapierPickable`] component can be picked.
+    pub require_markers: bool,
+    /// If set, only the colliders interacting with these groups can be picked.
+    pub groups: Option<CollisionGroups>,
+}
+
+impl Default for RapierPickingSettings {
+    fn default() -> Self {
+        Self {
+            world_id: DEFAULT_WORLD_ID,
+            require_markers: false,
+            groups: None,
+        }
+    }
+}
+
+/// Marker component making a collider pickable when
+/// [`RapierPickingSettings::require_markers`] is set.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component)]
+pub struct RapierPickable;
+
+/// System sending the [`PointerHits`] of the colliders under each pointer.
+pub fn update_rapier_picking_hits(
+    settings: Res<RapierPickingSettings>,
+    context: Res<RapierContext>,
+    pointers: Query<(&PointerId, &PointerLocation)>,
+    cameras: Query<(Entity, &Camera, &GlobalTransform)>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    pickables: Query<(), With<RapierPickable>>,
+    mut hits: EventWriter<PointerHits>,
+) {
+    let Some(world) = context.worlds.get(&settings.world_id) else {
+        return;
+    };
+
+    let predicate = |entity| !settings.require_markers || pickables.contains(entity);
+    let mut filter = QueryFilter::new().predicate(&predicate);
+    filter.groups = settings.groups;
+
+    for (pointer_id, pointer_location) in &pointers {
+        let Some(location) = pointer_location.location() else {
+            continue;
+        };
+
+        for (camera_entity, camera, camera_transform) in &cameras {
+            let target = camera.target.normalize(primary_window.get_single().ok());
+            if !camera.is_active
+                || target.as_ref() != Some(&location.target)
+                || !location.is_in_viewport(camera, &primary_window)
+            {
+                continue;
+            }
+
+            let viewport_position = camera
+                .logical_viewport_rect()
+                .map_or(location.position, |rect| location.position - rect.min);
+            let picks = pick_colliders(
+                world,
+                camera_entity,
+                camera,
+                camera_transform,
+                viewport_position,
+                filter,
+            );
+
+            if !picks.is_empty() {
+                hits.send(PointerHits::new(*pointer_id, picks, camera.order as f32));
+            }
+        }
+    }
+}
+
+/// The colliders of `world` under the point `viewport_position` of the viewport of `camera`.
+///
+/// In 3D, a ray is cast from the camera through this point, and the first collider hit is
+/// returned. In 2D, all the colliders containing the unprojected point are returned, with a
+/// depth of zero.
+pub fn pick_colliders(
+    world: &RapierWorld,
+    camera_entity: Entity,
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+    viewport_position: Vec2,
+    filter: QueryFilter,
+) -> Vec<(Entity, HitData)> {
+    #[cfg(feature = "dim2")]
+    {
+        let Some(point) = camera.viewport_to_world_2d(camera_transform, viewport_position) else {
+            return vec![];
+        };
+
+        let mut picks = vec![];
+        world.intersections_with_point(point, filter, |entity| {
+            let hit = HitData::new(camera_entity, 0.0, Some(point.extend(0.0)), None);
+            picks.push((entity, hit));
+            true
+        });
+        picks
+    }
+
+    #[cfg(feature = "dim3")]
+    {
+        let Some(ray) = camera.viewport_to_world(camera_transform, viewport_position) else {
+            return vec![];
+        };
+
+        world
+            .cast_ray_and_get_normal(
+                ray.origin,
+                *ray.direction,
+                crate::math::Real::MAX,
+                true,
+                filter,
+            )
+            .map(|(entity, hit)| {
+                let hit = HitData::new(
+                    camera_entity,
+                    hit.time_of_impact,
+                    Some(hit.point),
+                    Some(hit.normal),
+                );
+                (entity, hit)
+            })
+            .into_iter()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Collider;
+    use crate::math::Vect;
+    use crate::plugin::systems::tests::HeadlessRenderPlugin;
+    use crate::plugin::{NoUserData, RapierPhysicsPlugin};
+    use bevy::time::TimePlugin;
+
+    #[test]
+    fn picking_rays_go_through_the_pointer() {
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        #[cfg(feature = "dim2")]
+        let (camera, target, target_position) = (
+            app.world.spawn(Camera2dBundle::default()).id(),
+            app.world
+                .spawn((
+                    TransformBundle::from(Transform::from_xyz(100.0, 0.0, 0.0)),
+                    Collider::ball(10.0),
+                ))
+                .id(),
+            Vect::new(100.0, 0.0),
+        );
+        #[cfg(feature = "dim3")]
+        let (camera, target, target_position) = (
+            app.world
+                .spawn(Camera3dBundle {
+                    transform: Transform::from_xyz(0.0, 0.0, 10.0).looking_at(Vec3::ZERO, Vec3::Y),
+                    ..default()
+                })
+                .id(),
+            app.world
+                .spawn((TransformBundle::default(), Collider::cuboid(0.5, 0.5, 0.5)))
+                .id(),
+            Vect::new(0.0, 0.0, 0.5),
+        );
+
+        // Compute the camera matrices, and add the collider to the query pipeline.
+        app.update();
+
+        let pick = |viewport_position: Vec2| {
+            let (camera_component, camera_transform) = app
+                .world
+                .query::<(&Camera, &GlobalTransform)>()
+                .get(&app.world, camera)
+                .unwrap();
+            let context = app.world.resource::<RapierContext>();
+            pick_colliders(
+                &context.worlds[&DEFAULT_WORLD_ID],
+                camera,
+                camera_component,
+                camera_transform,
+                viewport_position,
+                QueryFilter::default(),
+            )
+        };
+
+        let window = app
+            .world
+            .query_filtered::<&Window, With<PrimaryWindow>>()
+            .single(&app.world);
+        let center = Vec2::new(window.width(), window.height()) / 2.0;
+
+        #[cfg(feature = "dim2")]
+        let (on_target, off_target) = (center + Vec2::new(100.0, 0.0), center);
+        #[cfg(feature = "dim3")]
+        let (on_target, off_target) = (center, Vec2::ZERO);
+
+        let picks = pick(on_target);
+        assert_eq!(picks.len(), 1);
+        assert_eq!(picks[0].0, target);
+        assert_eq!(picks[0].1.camera, camera);
+        let position = picks[0].1.position.unwrap();
+        #[cfg(feature = "dim2")]
+        assert!((position.truncate() - target_position).length() < 1.0e-3);
+        #[cfg(feature = "dim3")]
+        {
+            assert!((position - target_position).length() < 1.0e-3);
+            assert!((picks[0].1.depth - 9.5).abs() < 1.0e-3);
+            assert!((picks[0].1.normal.unwrap() - Vec3::Z).length() < 1.0e-3);
+        }
+
+        assert!(pick(off_target).is_empty());
+    }
+}
@@ -0,0 +1,59 @@
+use crate::geometry::Collider;
+use crate::math::{Real, Rot, Vect};
+use bevy::prelude::*;
+
+/// A ray-cast or shape-cast recorded in the [`DebugCastBuffer`].
+#[derive(Clone)]
+pub enum DebugCast {
+    /// A ray-cast performed by `RapierWorld::cast_ray_debug`.
+    Ray {
+        /// The starting point of the ray.
+        origin: Vect,
+        /// The direction of the ray.
+        dir: Vect,
+        /// The time-of-impact of the hit, or the maximum time-of-impact if nothing was hit.
+        time_of_impact: Real,
+        /// The point and normal of the hit, if any.
+        hit: Option<(Vect, Vect)>,
+    },
+    /// A shape-cast performed by `RapierWorld::cast_shape_debug`.
+    Shape {
+        /// The shape that was cast.
+        shape: Collider,
+        /// The initial translation of the shape.
+        start: Vect,
+        /// The linear velocity of the shape.
+        vel: Vect,
+        /// The time-of-impact of the hit, or the maximum time-of-impact if nothing was hit.
+        time_of_impact: Real,
+        /// The rotation of the shape.
+        rotation: Rot,
+        /// Did the shape hit a collider?
+        hit: bool,
+    },
+}
+
+/// The casts to draw during the current frame.
+///
+/// Casts are pushed by `RapierWorld::cast_ray_debug` and `RapierWorld::cast_shape_debug`, then
+/// drawn and cleared by the `RapierDebugRenderPlugin`. Nothing is recorded if the
+/// debug-render features are disabled, or if the `RapierDebugRenderPlugin` wasn’t added.
+#[derive(Resource, Default)]
+pub struct DebugCastBuffer {
+    pub(crate) recording: bool,
+    pub(crate) casts: Vec<DebugCast>,
+}
+
+impl DebugCastBuffer {
+    /// The casts recorded since they were last drawn.
+    pub fn casts(&self) -> &[DebugCast] {
+        &self.casts
+    }
+
+    #[allow(dead_code)] // Unused without the debug-render features.
+    pub(crate) fn push(&mut self, cast: impl FnOnce() -> DebugCast) {
+        if self.recording {
+            self.casts.push(cast());
+        }
+    }
+}
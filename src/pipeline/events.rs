@@ -1,25 +1,91 @@
 use crate::math::{Real, Vect};
 use crate::plugin::WorldId;
 use bevy::prelude::{Entity, Event};
+use bevy::utils::HashMap;
 use rapier::dynamics::RigidBodySet;
 use rapier::geometry::{
-    ColliderHandle, ColliderSet, CollisionEvent as RapierCollisionEvent, CollisionEventFlags,
-    ContactForceEvent as RapierContactForceEvent, ContactPair,
+    Collider, ColliderHandle, ColliderSet, CollisionEvent as RapierCollisionEvent,
+    CollisionEventFlags, ContactForceEvent as RapierContactForceEvent, ContactPair,
 };
+use rapier::math::Vector;
 use rapier::pipeline::EventHandler;
-use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::RwLock;
 
 /// Events occurring when two colliders start or stop colliding
 ///
 /// This will only get triggered if the entity has the
 /// [`ActiveEvent::COLLISION_EVENTS`] flag enabled.
+///
+/// The last field is the index of the step that emitted the event (see
+/// [`PhysicsTime::steps`](crate::plugin::PhysicsTime::steps)). Events are sent during the frame
+/// following their step, even if the simulation is paused afterward, so this can be compared to
+/// the current step of the world to detect stale events.
 #[derive(Event, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum CollisionEvent {
     /// Event occurring when two colliders start colliding
-    Started(Entity, Entity, CollisionEventFlags, WorldId),
+    Started(Entity, Entity, CollisionEventFlags, WorldId, u64),
     /// Event occurring when two colliders stop colliding
-    Stopped(Entity, Entity, CollisionEventFlags, WorldId),
+    Stopped(Entity, Entity, CollisionEventFlags, WorldId, u64),
+}
+
+impl CollisionEvent {
+    /// The world containing both colliders.
+    pub fn world_id(&self) -> WorldId {
+        match self {
+            Self::Started(.., world_id, _) | Self::Stopped(.., world_id, _) => *world_id,
+        }
+    }
+
+    /// The index of the step that emitted this event.
+    pub fn step_index(&self) -> u64 {
+        match self {
+            Self::Started(.., step_index) | Self::Stopped(.., step_index) => *step_index,
+        }
+    }
+}
+
+/// The geometry of the contact between two colliders when they started colliding.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CollisionStartedData {
+    /// The world-space point of the deepest contact, on the first collider.
+    pub point: Vect,
+    /// The world-space contact normal, pointing from the first collider toward the second one.
+    pub normal: Vect,
+    /// The velocity of the second collider’s rigid-body relative to the first one’s, at the
+    /// contact point.
+    pub relative_velocity: Vect,
+    /// The distance between the colliders at the contact point (negative if they penetrate).
+    pub dist: Real,
+}
+
+impl CollisionStartedData {
+    /// The speed at which the colliders approach each other along the contact normal.
+    ///
+    /// This is zero if they are moving apart.
+    pub fn impact_speed(&self) -> Real {
+        (-self.relative_velocity.dot(self.normal)).max(0.0)
+    }
+}
+
+/// Event sent along with the [`CollisionEvent::Started`] of two non-sensor colliders, with the
+/// geometry of their contact at impact.
+///
+/// These events are only sent if [`RapierConfiguration::detailed_collision_events`](crate::plugin::RapierConfiguration::detailed_collision_events)
+/// is enabled. As for [`CollisionEvent`], this requires the [`ActiveEvent::COLLISION_EVENTS`]
+/// flag on one of the colliders.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct DetailedCollisionEvent {
+    /// The first collider involved in the contact.
+    pub collider1: Entity,
+    /// The second collider involved in the contact.
+    pub collider2: Entity,
+    /// The world containing both colliders.
+    pub world_id: WorldId,
+    /// The index of the step that emitted this event.
+    pub step_index: u64,
+    /// The geometry of the contact when the colliders started colliding.
+    pub data: CollisionStartedData,
 }
 
 /// Event occurring when the sum of the magnitudes of the contact forces
@@ -45,6 +111,33 @@ pub struct ContactForceEvent {
     pub max_force_direction: Vect,
     /// The magnitude of the largest force at a contact point of this contact pair.
     pub max_force_magnitude: Real,
+    /// The index of the step that emitted this event.
+    pub step_index: u64,
+}
+
+/// Event occurring when a physics world dropped some simulation time, to avoid running too many
+/// steps in a single frame.
+///
+/// See the `max_steps_per_frame` field of [`TimestepMode::Interpolated`](crate::plugin::TimestepMode::Interpolated).
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct PhysicsTimeDroppedEvent {
+    /// The world that dropped some simulation time.
+    pub world_id: WorldId,
+    /// The simulation time that was dropped, in seconds.
+    pub dropped_seconds: Real,
+}
+
+/// Event sent for each step (including substeps) executed by a physics world.
+///
+/// This can be used to run gameplay logic once per physics step.
+#[derive(Event, Copy, Clone, Debug, PartialEq)]
+pub struct PhysicsStepEvent {
+    /// The world that executed the step.
+    pub world_id: WorldId,
+    /// The index of the step, starting at 0 (see [`PhysicsTime::steps`](crate::plugin::PhysicsTime::steps)).
+    pub step_index: u64,
+    /// The length of the step, in seconds.
+    pub dt: Real,
 }
 
 // TODO: it may be more efficient to use crossbeam channel.
@@ -54,30 +147,71 @@ pub struct ContactForceEvent {
 /// A set of queues collecting events emitted by the physics engine.
 pub(crate) struct EventQueue<'a> {
     pub world_id: WorldId,
+    // The index of the step being executed, set before each step.
+    pub step_index: AtomicU64,
 
     // Used to retrieve the entity of colliders that have been removed from the simulation
     // since the last physics step.
     pub deleted_colliders: &'a HashMap<ColliderHandle, Entity>,
+    // The pairs which were already given a `Stopped` event when the sensor status of one of
+    // their colliders changed, mapped to the step that would otherwise send it.
+    pub sensor_toggled_pairs: &'a HashMap<(ColliderHandle, ColliderHandle), u64>,
     pub collision_events: &'a mut RwLock<Vec<CollisionEvent>>,
     pub contact_force_events: &'a mut RwLock<Vec<ContactForceEvent>>,
+    // Only set if the detailed collision events are enabled.
+    pub detailed_collision_events: Option<&'a mut RwLock<Vec<DetailedCollisionEvent>>>,
 }
 
 impl<'a> EventQueue<'a> {
+    pub fn set_step_index(&self, step_index: u64) {
+        self.step_index.store(step_index, Ordering::Relaxed);
+    }
+
+    fn step_index(&self) -> u64 {
+        self.step_index.load(Ordering::Relaxed)
+    }
+
     fn collider2entity(&self, colliders: &ColliderSet, handle: ColliderHandle) -> Option<Entity> {
         colliders
             .get(handle)
             .map(|co| Entity::from_bits(co.user_data as u64))
             .or_else(|| self.deleted_colliders.get(&handle).copied())
     }
+
+    fn collision_started_data(
+        bodies: &RigidBodySet,
+        colliders: &ColliderSet,
+        contact_pair: &ContactPair,
+    ) -> Option<CollisionStartedData> {
+        let (manifold, contact) = contact_pair.find_deepest_contact()?;
+        let co1 = colliders.get(contact_pair.collider1)?;
+        let co2 = colliders.get(contact_pair.collider2)?;
+        let point = co1.position() * contact.local_p1;
+        let normal = co1.position() * manifold.local_n1;
+
+        let velocity_at_point = |co: &Collider| {
+            co.parent()
+                .and_then(|h| bodies.get(h))
+                .map(|rb| rb.velocity_at_point(&point))
+                .unwrap_or_else(Vector::zeros)
+        };
+
+        Some(CollisionStartedData {
+            point: point.into(),
+            normal: normal.into(),
+            relative_velocity: (velocity_at_point(co2) - velocity_at_point(co1)).into(),
+            dist: contact.dist,
+        })
+    }
 }
 
 impl<'a> EventHandler for EventQueue<'a> {
     fn handle_collision_event(
         &self,
-        _bodies: &RigidBodySet,
+        bodies: &RigidBodySet,
         colliders: &ColliderSet,
         event: RapierCollisionEvent,
-        _: Option<&ContactPair>,
+        contact_pair: Option<&ContactPair>,
     ) {
         let event = match event {
             RapierCollisionEvent::Started(h1, h2, flags) => {
@@ -88,9 +222,39 @@ impl<'a> EventHandler for EventQueue<'a> {
                     return;
                 };
 
-                CollisionEvent::Started(e1, e2, flags, self.world_id)
+                if let (Some(detailed_events), Some(contact_pair)) =
+                    (&self.detailed_collision_events, contact_pair)
+                {
+                    if let Some(data) =
+                        Self::collision_started_data(bodies, colliders, contact_pair)
+                    {
+                        let (collider1, collider2) = if contact_pair.collider1 == h1 {
+                            (e1, e2)
+                        } else {
+                            (e2, e1)
+                        };
+
+                        if let Ok(mut events) = detailed_events.write() {
+                            events.push(DetailedCollisionEvent {
+                                collider1,
+                                collider2,
+                                world_id: self.world_id,
+                                step_index: self.step_index(),
+                                data,
+                            });
+                        }
+                    }
+                }
+
+                CollisionEvent::Started(e1, e2, flags, self.world_id, self.step_index())
             }
             RapierCollisionEvent::Stopped(h1, h2, flags) => {
+                let already_sent =
+                    |pair| self.sensor_toggled_pairs.get(&pair) == Some(&self.step_index());
+                if already_sent((h1, h2)) || already_sent((h2, h1)) {
+                    return;
+                }
+
                 let Some(e1) = self.collider2entity(colliders, h1) else {
                     return;
                 };
@@ -98,7 +262,7 @@ impl<'a> EventHandler for EventQueue<'a> {
                     return;
                 };
 
-                CollisionEvent::Stopped(e1, e2, flags, self.world_id)
+                CollisionEvent::Stopped(e1, e2, flags, self.world_id, self.step_index())
             }
         };
 
@@ -132,6 +296,7 @@ impl<'a> EventHandler for EventQueue<'a> {
             total_force_magnitude: rapier_event.total_force_magnitude,
             max_force_direction: rapier_event.max_force_direction.into(),
             max_force_magnitude: rapier_event.max_force_magnitude,
+            step_index: self.step_index(),
         };
 
         if let Ok(mut events) = self.contact_force_events.write() {
@@ -139,3 +304,45 @@ impl<'a> EventHandler for EventQueue<'a> {
         }
     }
 }
+
+/// Forwards the physics events to two event handlers.
+pub(crate) struct ChainedEventHandlers<'a>(pub &'a dyn EventHandler, pub &'a dyn EventHandler);
+
+impl<'a> EventHandler for ChainedEventHandlers<'a> {
+    fn handle_collision_event(
+        &self,
+        bodies: &RigidBodySet,
+        colliders: &ColliderSet,
+        event: RapierCollisionEvent,
+        contact_pair: Option<&ContactPair>,
+    ) {
+        self.0
+            .handle_collision_event(bodies, colliders, event, contact_pair);
+        self.1
+            .handle_collision_event(bodies, colliders, event, contact_pair);
+    }
+
+    fn handle_contact_force_event(
+        &self,
+        dt: Real,
+        bodies: &RigidBodySet,
+        colliders: &ColliderSet,
+        contact_pair: &ContactPair,
+        total_force_magnitude: Real,
+    ) {
+        self.0.handle_contact_force_event(
+            dt,
+            bodies,
+            colliders,
+            contact_pair,
+            total_force_magnitude,
+        );
+        self.1.handle_contact_force_event(
+            dt,
+            bodies,
+            colliders,
+            contact_pair,
+            total_force_magnitude,
+        );
+    }
+}
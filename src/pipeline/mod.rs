@@ -1,11 +1,16 @@
 // pub(crate) use self::events::EventQueue;
-pub use self::events::{CollisionEvent, ContactForceEvent};
-pub(crate) use self::physics_hooks::BevyPhysicsHooksAdapter;
+pub use self::debug_cast::{DebugCast, DebugCastBuffer};
+pub use self::events::{
+    CollisionEvent, CollisionStartedData, ContactForceEvent, DetailedCollisionEvent,
+    PhysicsStepEvent, PhysicsTimeDroppedEvent,
+};
 pub use self::physics_hooks::{
-    BevyPhysicsHooks, ContactModificationContextView, PairFilterContextView,
+    BevyPhysicsHooks, ContactModificationContextView, PairFilterContextView, SolverContactViewMut,
 };
+pub(crate) use self::physics_hooks::{BevyPhysicsHooksAdapter, ConveyorBeltHooks};
 pub use query_filter::{QueryFilter, QueryFilterFlags};
 
+mod debug_cast;
 pub(crate) mod events;
 mod physics_hooks;
 mod query_filter;
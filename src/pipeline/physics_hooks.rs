@@ -1,16 +1,43 @@
-use bevy::{ecs::system::SystemParam, prelude::*};
+use crate::dynamics::Velocity;
+use crate::math::{Real, Vect};
+use crate::plugin::{WorldId, DEFAULT_WORLD_ID};
+use bevy::{
+    ecs::{entity::EntityHashMap, system::SystemParam},
+    prelude::*,
+};
 use rapier::{
+    geometry::{ColliderHandle, SolverContact},
+    math::Vector,
     pipeline::{ContactModificationContext, PairFilterContext},
-    prelude::{PhysicsHooks, SolverFlags},
+    prelude::{PhysicsHooks, RigidBodyHandle, SolverFlags},
 };
 
 /// Read-only access to the properties of a collision pair filter context.
 pub struct PairFilterContextView<'a> {
     /// The raw context from Rapier.
     pub raw: &'a PairFilterContext<'a>,
+    world_id: WorldId,
 }
 
 impl<'a> PairFilterContextView<'a> {
+    /// A view of the given context, for a pair of colliders of the default world.
+    pub fn new(raw: &'a PairFilterContext<'a>) -> Self {
+        Self {
+            raw,
+            world_id: DEFAULT_WORLD_ID,
+        }
+    }
+
+    pub(crate) fn with_world_id(mut self, world_id: WorldId) -> Self {
+        self.world_id = world_id;
+        self
+    }
+
+    /// The world being stepped, which contains both colliders.
+    pub fn world_id(&self) -> WorldId {
+        self.world_id
+    }
+
     /// The entity of the first collider involved in the potential collision.
     pub fn collider1(&self) -> Entity {
         let co1 = &self.raw.colliders[self.raw.collider1];
@@ -46,9 +73,28 @@ impl<'a> PairFilterContextView<'a> {
 pub struct ContactModificationContextView<'a, 'b> {
     /// The raw context from Rapier.
     pub raw: &'a mut ContactModificationContext<'b>,
+    world_id: WorldId,
 }
 
 impl<'a, 'b> ContactModificationContextView<'a, 'b> {
+    /// A view of the given context, for a pair of colliders of the default world.
+    pub fn new(raw: &'a mut ContactModificationContext<'b>) -> Self {
+        Self {
+            raw,
+            world_id: DEFAULT_WORLD_ID,
+        }
+    }
+
+    pub(crate) fn with_world_id(mut self, world_id: WorldId) -> Self {
+        self.world_id = world_id;
+        self
+    }
+
+    /// The world being stepped, which contains both colliders.
+    pub fn world_id(&self) -> WorldId {
+        self.world_id
+    }
+
     /// The entity of the first collider involved in the potential collision.
     pub fn collider1(&self) -> Entity {
         let co1 = &self.raw.colliders[self.raw.collider1];
@@ -78,6 +124,167 @@ impl<'a, 'b> ContactModificationContextView<'a, 'b> {
             Entity::from_bits(co2.user_data as u64)
         })
     }
+
+    /// The velocity of the first rigid-body, or zero if `self.collider1()` isn’t attached to a
+    /// rigid-body.
+    pub fn velocity1(&self) -> Velocity {
+        self.velocity(self.raw.rigid_body1)
+    }
+
+    /// The velocity of the second rigid-body, or zero if `self.collider2()` isn’t attached to a
+    /// rigid-body.
+    pub fn velocity2(&self) -> Velocity {
+        self.velocity(self.raw.rigid_body2)
+    }
+
+    fn velocity(&self, handle: Option<RigidBodyHandle>) -> Velocity {
+        let Some(rb) = handle.and_then(|h| self.raw.bodies.get(h)) else {
+            return Velocity::zero();
+        };
+
+        Velocity {
+            linvel: (*rb.linvel()).into(),
+            #[cfg(feature = "dim3")]
+            angvel: (*rb.angvel()).into(),
+            #[cfg(feature = "dim2")]
+            angvel: rb.angvel(),
+        }
+    }
+
+    /// The world-space contact normal seen by the constraints solver, pointing from the first
+    /// collider toward the second one.
+    pub fn normal(&self) -> Vect {
+        (*self.raw.normal).into()
+    }
+
+    /// Sets the world-space contact normal seen by the constraints solver.
+    pub fn set_normal(&mut self, normal: Vect) {
+        *self.raw.normal = normal.into();
+    }
+
+    /// The user-defined data of the contact manifold, persistent between timesteps as long as
+    /// the contact manifold exists. It is initialized to 0.
+    pub fn user_data(&self) -> u32 {
+        *self.raw.user_data
+    }
+
+    /// Sets the user-defined data of the contact manifold.
+    pub fn set_user_data(&mut self, user_data: u32) {
+        *self.raw.user_data = user_data;
+    }
+
+    /// The contact normal of the manifold, expressed in the local-space of the first collider.
+    pub fn manifold_local_n1(&self) -> Vect {
+        self.raw.manifold.local_n1.into()
+    }
+
+    /// The contact normal of the manifold, expressed in the local-space of the second collider.
+    pub fn manifold_local_n2(&self) -> Vect {
+        self.raw.manifold.local_n2.into()
+    }
+
+    /// The number of contact points of the manifold.
+    pub fn num_manifold_points(&self) -> usize {
+        self.raw.manifold.points.len()
+    }
+
+    /// The contact points of the manifold, as the world-space points on the first and second
+    /// colliders, and the distance between them (negative if they penetrate).
+    pub fn manifold_points(&self) -> impl ExactSizeIterator<Item = (Vect, Vect, Real)> + '_ {
+        let pos1 = self.raw.colliders[self.raw.collider1].position();
+        let pos2 = self.raw.colliders[self.raw.collider2].position();
+        self.raw.manifold.points.iter().map(move |pt| {
+            (
+                (pos1 * pt.local_p1).into(),
+                (pos2 * pt.local_p2).into(),
+                pt.dist,
+            )
+        })
+    }
+
+    /// The number of contacts that will be seen by the constraints solver.
+    pub fn num_solver_contacts(&self) -> usize {
+        self.raw.solver_contacts.len()
+    }
+
+    /// Read-write access to the i-th contact seen by the constraints solver.
+    pub fn solver_contact(&mut self, i: usize) -> Option<SolverContactViewMut> {
+        self.raw
+            .solver_contacts
+            .get_mut(i)
+            .map(|raw| SolverContactViewMut { raw })
+    }
+
+    /// Read-write access to the contacts seen by the constraints solver.
+    pub fn solver_contacts(&mut self) -> impl ExactSizeIterator<Item = SolverContactViewMut> {
+        self.raw
+            .solver_contacts
+            .iter_mut()
+            .map(|raw| SolverContactViewMut { raw })
+    }
+
+    /// Only keeps the solver contacts for which `f` returns `true`.
+    pub fn retain_solver_contacts(&mut self, mut f: impl FnMut(SolverContactViewMut) -> bool) {
+        self.raw
+            .solver_contacts
+            .retain_mut(|raw| f(SolverContactViewMut { raw }));
+    }
+
+    /// Removes all the solver contacts, so the constraints solver ignores this manifold.
+    pub fn clear_solver_contacts(&mut self) {
+        self.raw.solver_contacts.clear();
+    }
+}
+
+/// Read-write access to the properties of a single solver contact.
+pub struct SolverContactViewMut<'a> {
+    /// The raw solver contact from Rapier.
+    pub raw: &'a mut SolverContact,
+}
+
+impl<'a> SolverContactViewMut<'a> {
+    /// The world-space contact point.
+    pub fn point(&self) -> Vect {
+        self.raw.point.into()
+    }
+    /// Sets the world-space contact point.
+    pub fn set_point(&mut self, point: Vect) {
+        self.raw.point = point.into();
+    }
+    /// The distance between the two original contacts points along the contact normal.
+    /// If negative, this is measures the penetration depth.
+    pub fn dist(&self) -> Real {
+        self.raw.dist
+    }
+    /// Sets the distance between the two original contacts points along the contact normal.
+    pub fn set_dist(&mut self, dist: Real) {
+        self.raw.dist = dist;
+    }
+    /// The effective friction coefficient at this contact point.
+    pub fn friction(&self) -> Real {
+        self.raw.friction
+    }
+    /// Sets the effective friction coefficient at this contact point.
+    pub fn set_friction(&mut self, friction: Real) {
+        self.raw.friction = friction;
+    }
+    /// The effective restitution coefficient at this contact point.
+    pub fn restitution(&self) -> Real {
+        self.raw.restitution
+    }
+    /// Sets the effective restitution coefficient at this contact point.
+    pub fn set_restitution(&mut self, restitution: Real) {
+        self.raw.restitution = restitution;
+    }
+    /// The desired tangent relative velocity at the contact point.
+    pub fn tangent_velocity(&self) -> Vect {
+        self.raw.tangent_velocity.into()
+    }
+    /// Sets the desired tangent relative velocity at the contact point, to simulate conveyor
+    /// belts for example.
+    pub fn set_tangent_velocity(&mut self, tangent_velocity: Vect) {
+        self.raw.tangent_velocity = tangent_velocity.into();
+    }
 }
 
 /// User-defined functions called by the physics engines during one timestep in order to customize its behavior.
@@ -146,19 +353,19 @@ pub trait BevyPhysicsHooks: SystemParam + Send + Sync {
     /// can be removed and modified.
     ///
     /// Note that if all the contacts have to be ignored by the constraint solver, you may simply
-    /// do `context.solver_contacts.clear()`.
+    /// call `context.clear_solver_contacts()`.
     ///
     /// Modifying the solver contacts allow you to achieve various effects, including:
-    /// - Simulating conveyor belts by setting the `surface_velocity` of a solver contact.
+    /// - Simulating conveyor belts by setting the `tangent_velocity` of a solver contact.
     /// - Simulating shapes with multiply materials by modifying the friction and restitution
     ///   coefficient depending of the features in contacts.
     /// - Simulating one-way platforms depending on the contact normal.
     ///
     /// Each contact manifold is given a `u32` user-defined data that is persistent between
     /// timesteps (as long as the contact manifold exists). This user-defined data is initialized
-    /// as 0 and can be modified in `context.user_data`.
+    /// as 0 and can be modified with `context.set_user_data`.
     ///
-    /// The world-space contact normal can be modified in `context.normal`.
+    /// The world-space contact normal can be modified with `context.set_normal`.
     fn modify_solver_contacts(&self, _context: ContactModificationContextView) {}
 }
 
@@ -186,6 +393,7 @@ where
     Hooks: BevyPhysicsHooks,
 {
     hooks: Hooks,
+    world_id: WorldId,
 }
 
 impl<Hooks> BevyPhysicsHooksAdapter<Hooks>
@@ -193,7 +401,15 @@ where
     Hooks: BevyPhysicsHooks,
 {
     pub(crate) fn new(hooks: Hooks) -> Self {
-        Self { hooks }
+        Self {
+            hooks,
+            world_id: WorldId::default(),
+        }
+    }
+
+    /// Sets the world given to the hooks. This must be called before stepping each world.
+    pub(crate) fn set_world_id(&mut self, world_id: WorldId) {
+        self.world_id = world_id;
     }
 }
 
@@ -202,17 +418,69 @@ where
     Hooks: BevyPhysicsHooks,
 {
     fn filter_contact_pair(&self, context: &PairFilterContext) -> Option<SolverFlags> {
-        let context_view = PairFilterContextView { raw: context };
+        let context_view = PairFilterContextView::new(context).with_world_id(self.world_id);
         self.hooks.filter_contact_pair(context_view)
     }
 
     fn filter_intersection_pair(&self, context: &PairFilterContext) -> bool {
-        let context_view = PairFilterContextView { raw: context };
+        let context_view = PairFilterContextView::new(context).with_world_id(self.world_id);
         self.hooks.filter_intersection_pair(context_view)
     }
 
     fn modify_solver_contacts(&self, context: &mut ContactModificationContext) {
-        let context_view = ContactModificationContextView { raw: context };
+        let context_view =
+            ContactModificationContextView::new(context).with_world_id(self.world_id);
         self.hooks.modify_solver_contacts(context_view)
     }
 }
+
+/// Sets the tangent velocity of the contacts involving a [`ConveyorBelt`](crate::geometry::ConveyorBelt),
+/// before calling the user-defined hooks.
+pub(crate) struct ConveyorBeltHooks<'a> {
+    pub(crate) belts: &'a EntityHashMap<Vect>,
+    pub(crate) hooks: &'a dyn PhysicsHooks,
+}
+
+impl<'a> ConveyorBeltHooks<'a> {
+    /// The world-space surface velocity of the given collider, if it is a conveyor belt.
+    fn surface_velocity(
+        &self,
+        context: &ContactModificationContext,
+        handle: ColliderHandle,
+    ) -> Option<Vect> {
+        let co = &context.colliders[handle];
+        let local_velocity = self.belts.get(&Entity::from_bits(co.user_data as u64))?;
+        Some((co.position().rotation * Vector::from(*local_velocity)).into())
+    }
+}
+
+impl<'a> PhysicsHooks for ConveyorBeltHooks<'a> {
+    fn filter_contact_pair(&self, context: &PairFilterContext) -> Option<SolverFlags> {
+        self.hooks.filter_contact_pair(context)
+    }
+
+    fn filter_intersection_pair(&self, context: &PairFilterContext) -> bool {
+        self.hooks.filter_intersection_pair(context)
+    }
+
+    fn modify_solver_contacts(&self, context: &mut ContactModificationContext) {
+        if !self.belts.is_empty() {
+            let velocity1 = self.surface_velocity(context, context.collider1);
+            let velocity2 = self.surface_velocity(context, context.collider2);
+
+            if velocity1.is_some() || velocity2.is_some() {
+                // The tangent velocity is the desired velocity of the second collider relative
+                // to the first one.
+                let velocity = velocity1.unwrap_or_default() - velocity2.unwrap_or_default();
+                let normal: Vect = (*context.normal).into();
+                let tangent_velocity = velocity - normal * normal.dot(velocity);
+
+                for contact in context.solver_contacts.iter_mut() {
+                    contact.tangent_velocity = tangent_velocity.into();
+                }
+            }
+        }
+
+        self.hooks.modify_solver_contacts(context)
+    }
+}
@@ -19,6 +19,9 @@ pub struct QueryFilter<'a> {
     /// If set, any collider attached to the rigid-body attached to that entity
     /// will be excluded from the query.
     pub exclude_rigid_body: Option<Entity>,
+    /// The entities to exclude from the query: the colliders attached to these entities, and
+    /// any collider attached to the rigid-bodies attached to these entities.
+    pub exclude_entities: &'a [Entity],
     /// If set, any collider for which this closure returns false.
     pub predicate: Option<&'a dyn Fn(Entity) -> bool>,
 }
@@ -62,7 +65,11 @@ impl<'a> QueryFilter<'a> {
         QueryFilterFlags::EXCLUDE_DYNAMIC.into()
     }
 
-    /// Excludes all colliders not attached to a dynamic rigid-body.
+    /// Excludes all colliders not attached to a dynamic rigid-body, including the colliders not
+    /// attached to any rigid-body.
+    ///
+    /// This can be combined with the other builder methods, for example
+    /// `QueryFilter::only_dynamic().exclude_sensors()`.
     pub fn only_dynamic() -> Self {
         QueryFilterFlags::ONLY_DYNAMIC.into()
     }
@@ -72,6 +79,12 @@ impl<'a> QueryFilter<'a> {
         QueryFilterFlags::ONLY_KINEMATIC.into()
     }
 
+    /// Only colliders with collision groups interacting with the given groups will be included
+    /// in the scene query, as if the query was a collider with these groups.
+    pub fn groups_interacting_with(groups: CollisionGroups) -> Self {
+        groups.into()
+    }
+
     /// Exclude all colliders attached to a non-fixed rigid-body
     /// (this will not exclude colliders not attached to any rigid-body).
     pub fn only_fixed() -> Self {
@@ -109,6 +122,16 @@ impl<'a> QueryFilter<'a> {
         self
     }
 
+    /// Set the entities that will be excluded from the scene query.
+    ///
+    /// The colliders attached to these entities are excluded, as well as any collider attached
+    /// to the rigid-bodies attached to these entities. This composes with
+    /// [`Self::exclude_collider`], [`Self::exclude_rigid_body`] and [`Self::predicate`].
+    pub fn exclude_entities(mut self, entities: &'a [Entity]) -> Self {
+        self.exclude_entities = entities;
+        self
+    }
+
     /// Set the predicate to apply a custom collider filtering during the scene query.
     pub fn predicate(mut self, predicate: &'a impl Fn(Entity) -> bool) -> Self {
         self.predicate = Some(predicate);
@@ -1,15 +1,29 @@
-use bevy::prelude::Resource;
-
+use bevy::prelude::{Reflect, ReflectDefault, ReflectResource, Resource};
 
 /// Difference between simulation and rendering time
-#[derive(Resource, Default)]
+#[derive(Resource, Default, Reflect)]
+#[reflect(Resource, Default)]
 pub struct SimulationToRenderTime {
     /// Difference between simulation and rendering time
     pub diff: f32,
 }
 
+/// The substep being executed while the [`PhysicsSubstepSchedule`](crate::plugin::PhysicsSubstepSchedule)
+/// runs.
+#[derive(Resource, Copy, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Resource, Default, PartialEq)]
+pub struct SubstepTime {
+    /// The length of the substep, in seconds.
+    pub dt: f32,
+    /// The index of the substep within its step, from 0 to `substeps - 1`.
+    pub index: u32,
+    /// The number of substeps of each step.
+    pub substeps: u32,
+}
+
 /// The different ways of adjusting the timestep length.
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(PartialEq)]
 pub enum TimestepMode {
     /// Use a fixed timestep: the physics simulation will be advanced by the fixed value
     /// `dt` seconds at each Bevy tick by performing `substeps` of length `dt / substeps`.
@@ -45,16 +59,110 @@ pub enum TimestepMode {
         time_scale: f32,
         /// The number of substeps that will be performed whenever the physics simulation is advanced.
         substeps: usize,
+        /// The maximum number of times the physics simulation may be advanced at each Bevy tick.
+        ///
+        /// If the physics simulation is behind the real time by more than
+        /// `max_steps_per_frame * dt` (for example after a long frame), the remaining time is
+        /// dropped so the simulation doesn’t slow down the next frames even more. Use `u32::MAX`
+        /// to never drop any time.
+        max_steps_per_frame: u32,
+    },
+    /// Advance the physics simulation by `Time<Fixed>::timestep()` seconds at each run of the
+    /// fixed schedule, performing `substeps` of equal length.
+    ///
+    /// This is meant to be used when the physics systems run in `FixedUpdate` (see
+    /// [`RapierPhysicsPlugin::in_fixed_schedule`](crate::plugin::RapierPhysicsPlugin::in_fixed_schedule)).
+    /// Rigid-bodies with a `TransformInterpolation` component are interpolated between the last
+    /// two fixed steps, using `Time<Fixed>::overstep_fraction()`, before being rendered.
+    FixedSchedule {
+        /// The number of substeps that will be performed at each run of the fixed schedule.
+        substeps: usize,
     },
+    /// Only advance the physics simulation when requested with [`RapierContext::request_step`](crate::plugin::RapierContext::request_step),
+    /// by the fixed value `dt` seconds per requested step, performing `substeps` of length `dt / substeps`.
+    ///
+    /// This is useful to debug the simulation one step at a time. Rigid-bodies aren’t
+    /// interpolated in this mode.
+    Manual {
+        /// The physics simulation will be advanced by this total amount at each requested step.
+        dt: f32,
+        /// This number of substeps of length `dt / substeps` will be performed at each requested step.
+        substeps: usize,
+    },
+}
+
+impl TimestepMode {
+    /// A [`TimestepMode::Interpolated`] that never drops any time, like before the
+    /// `max_steps_per_frame` field was added.
+    pub fn interpolated(dt: f32, time_scale: f32, substeps: usize) -> Self {
+        Self::Interpolated {
+            dt,
+            time_scale,
+            substeps,
+            max_steps_per_frame: u32::MAX,
+        }
+    }
+}
+
+/// How colliders with a smooth shape (balls and capsules) react to a scale that can’t be represented
+/// exactly by the same shape type, like a ball with a non-uniform scale.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Default, PartialEq)]
+pub enum NonUniformScalePolicy {
+    /// Replace the scale by a uniform scale equal to its largest component, keeping the smooth
+    /// shape.
+    SnapToUniform,
+    /// Approximate the scaled shape by a convex polygon (in 2D) or convex polyhedron (in 3D), using
+    /// [`RapierConfiguration::scaled_shape_subdivision`] subdivisions.
+    #[default]
+    ConvertToConvex,
+    /// Same as [`NonUniformScalePolicy::SnapToUniform`], but also logs a warning the first time
+    /// the scale of an entity’s collider is snapped.
+    Warn,
+}
+
+/// When the query pipeline, used by the scene queries (ray-casts, shape-casts, etc.), is updated.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Default, PartialEq)]
+pub enum QueryPipelineUpdateMode {
+    /// Update the query pipeline right after the simulation is stepped, during
+    /// [`PhysicsSet::StepSimulation`](crate::plugin::PhysicsSet::StepSimulation).
+    ///
+    /// The scene queries see the colliders as they were at the end of the step: the changes made
+    /// to the physics worlds after the step (for example by the systems of
+    /// [`PhysicsSet::Writeback`](crate::plugin::PhysicsSet::Writeback), or by systems modifying
+    /// the [`RapierContext`](crate::plugin::RapierContext) directly) are only visible after the
+    /// next step.
+    #[default]
+    AfterStep,
+    /// Update the query pipeline at the end of
+    /// [`PhysicsSet::Writeback`](crate::plugin::PhysicsSet::Writeback), after propagating the
+    /// latest positions of the rigid-bodies to their colliders.
+    ///
+    /// The scene queries of the following systems, including the ones of the next frame’s
+    /// `Update`, see the latest poses of the colliders, including the changes made after the
+    /// step. This costs an additional propagation of the rigid-body positions every frame.
+    AfterWriteback,
+    /// Never update the query pipeline automatically.
+    ///
+    /// Call [`RapierContext::update_query_pipeline`](crate::plugin::RapierContext::update_query_pipeline)
+    /// (or the method of a single [`RapierWorld`](crate::plugin::RapierWorld)) when the scene
+    /// queries need to see the latest changes. This avoids the cost of the update when there are
+    /// no scene queries, or when they can be batched, but the scene queries see stale colliders
+    /// until then.
+    Manual,
 }
 
-#[derive(Resource, Copy, Clone, Debug)]
+#[derive(Resource, Copy, Clone, Debug, Reflect)]
+#[reflect(Resource, Default)]
 /// A resource for specifying configuration information for the physics simulation
 pub struct RapierConfiguration {
     /// Specifies if the physics simulation is active and update the physics world.
     pub physics_pipeline_active: bool,
     /// Specifies if the query pipeline is active and update the query pipeline.
     pub query_pipeline_active: bool,
+    /// Specifies when the query pipeline is updated, if it is active.
+    pub query_pipeline_update_mode: QueryPipelineUpdateMode,
     /// Specifies the way the timestep length should be adjusted at each frame.
     pub timestep_mode: TimestepMode,
     /// Specifies the number of subdivisions along each axes a shape should be subdivided
@@ -65,8 +173,25 @@ pub struct RapierConfiguration {
     /// discretized into a convex polyhedron, using `scaled_shape_subdivision` as the number of subdivisions
     /// along each spherical coordinates angle.
     pub scaled_shape_subdivision: u32,
+    /// Specifies how balls and capsules react to a scale they can’t represent exactly.
+    pub nonuniform_scale_policy: NonUniformScalePolicy,
     /// Specifies if backend sync should always accept transform changes, which may be from the writeback stage.
     pub force_update_from_transform_changes: bool,
+    /// Specifies if a [`TransformInterpolation`](crate::dynamics::TransformInterpolation) should
+    /// automatically be inserted on every rigid-body with a `Transform`, and removed along with
+    /// the rigid-body.
+    pub auto_insert_interpolation: bool,
+    /// Specifies if Rapier should time each phase of the simulation steps.
+    ///
+    /// The results can be read with [`RapierWorld::step_counters`](crate::plugin::RapierWorld::step_counters),
+    /// and are recorded on the `rapier_step` tracing spans. This adds a small overhead to
+    /// every step.
+    pub enable_profiling: bool,
+    /// Specifies if a [`DetailedCollisionEvent`](crate::pipeline::DetailedCollisionEvent), with the
+    /// contact point, normal, and relative velocity at impact, should be sent along with each
+    /// [`CollisionEvent::Started`](crate::pipeline::CollisionEvent::Started) of two non-sensor
+    /// colliders.
+    pub detailed_collision_events: bool,
 }
 
 impl Default for RapierConfiguration {
@@ -86,13 +211,24 @@ impl RapierConfiguration {
         Self {
             physics_pipeline_active: true,
             query_pipeline_active: true,
+            query_pipeline_update_mode: QueryPipelineUpdateMode::AfterStep,
             timestep_mode: TimestepMode::Variable {
                 max_dt: 1.0 / 60.0,
                 time_scale: 1.0,
                 substeps: 1,
             },
             scaled_shape_subdivision: 10,
+            nonuniform_scale_policy: NonUniformScalePolicy::ConvertToConvex,
             force_update_from_transform_changes: false,
+            auto_insert_interpolation: false,
+            enable_profiling: false,
+            detailed_collision_events: false,
         }
     }
+
+    /// Whether the query pipeline must be updated right after the simulation is stepped.
+    pub(crate) fn update_query_pipeline_after_step(&self) -> bool {
+        self.query_pipeline_active
+            && self.query_pipeline_update_mode == QueryPipelineUpdateMode::AfterStep
+    }
 }
@@ -1,28 +1,49 @@
+use bevy::ecs::entity::EntityHashMap;
 use bevy::prelude::*;
 use core::fmt;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::AtomicU64;
 use std::sync::RwLock;
 
+use bevy::utils::tracing::field::Empty;
+
 use crate::geometry::{Collider, PointProjection, RayIntersection};
 use crate::math::{Rot, Vect};
-use crate::pipeline::{CollisionEvent, ContactForceEvent, QueryFilter};
-use crate::prelude::events::EventQueue;
+#[cfg(any(feature = "debug-render-3d", feature = "debug-render-2d"))]
+use crate::pipeline::DebugCast;
+use crate::pipeline::{
+    CollisionEvent, ContactForceEvent, ConveyorBeltHooks, DebugCastBuffer, DetailedCollisionEvent,
+    QueryFilter,
+};
+use crate::prelude::events::{ChainedEventHandlers, EventQueue};
 use rapier::control::CharacterAutostep;
+use rapier::counters::Counters;
+use rapier::math::{DIM, SPATIAL_DIM};
 use rapier::prelude::{
-    CCDSolver, ColliderHandle, ColliderSet, EventHandler, FeatureId, ImpulseJointHandle,
-    ImpulseJointSet, IntegrationParameters, IslandManager, MultibodyJointHandle, MultibodyJointSet,
-    NarrowPhase, PhysicsHooks, PhysicsPipeline, QueryFilter as RapierQueryFilter, QueryPipeline,
-    Ray, Real, RigidBodyHandle, RigidBodySet,
+    Ball, CCDSolver, ColliderHandle, ColliderSet, CollisionEventFlags, EventHandler, FeatureId,
+    ImpulseJointHandle, ImpulseJointSet, IntegrationParameters, IslandManager,
+    MultibodyJointHandle, MultibodyJointSet, NarrowPhase, PhysicsHooks, PhysicsPipeline, Point,
+    QueryFilter as RapierQueryFilter, QueryPipeline, Ray, Real, RigidBodyHandle, RigidBodySet,
 };
 
 use crate::geometry::ShapeCastHit;
 use bevy::prelude::{Entity, EventWriter, GlobalTransform, Query};
 
-use crate::control::{CharacterCollision, MoveShapeOptions, MoveShapeOutput};
-use crate::dynamics::TransformInterpolation;
+use crate::control::{
+    step_and_snap_heights, CharacterCollision, MoveShapeOptions, MoveShapeOutput,
+};
+use crate::dynamics::{
+    Falloff, FixedJointBuilder, ImpulseJoint, MassChangeCause, MassModifiedEvent, MassProperties,
+    PhysicsWorld, RadialImpulseFlags, TransformInterpolation,
+};
+use crate::parry::bounding_volume::Aabb;
+use crate::parry::math::SIMD_WIDTH;
+use crate::parry::partitioning::QbvhNode;
 use crate::parry::query::details::ShapeCastOptions;
+use crate::parry::query::PointQuery;
 use crate::plugin::configuration::{SimulationToRenderTime, TimestepMode};
 use crate::prelude::{CollisionGroups, RapierRigidBodyHandle};
+use crate::utils;
 use rapier::geometry::DefaultBroadPhase;
 
 /// Points to the [`RapierWorld`] within the [`RapierContext`].
@@ -51,6 +72,114 @@ impl WorldId {
 /// so it may not always be valid.
 pub const DEFAULT_WORLD_ID: WorldId = WorldId(0);
 
+/// The rigid-bodies that were active before the last two simulation steps.
+///
+/// Along with the currently active rigid-bodies, these are the only bodies that may have been
+/// moved by the simulation since the last writeback, or that need their interpolation updated.
+/// Keeping the bodies of the previous step lets the writeback see the final position of the
+/// bodies that just fell asleep.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct RecentlyActiveBodies {
+    before_last_step: Vec<RigidBodyHandle>,
+    before_previous_step: Vec<RigidBodyHandle>,
+}
+
+impl RecentlyActiveBodies {
+    /// Records the currently active rigid-bodies, right before a simulation step.
+    fn record(&mut self, islands: &IslandManager) {
+        let mut active = std::mem::take(&mut self.before_previous_step);
+        active.clear();
+        active.extend(islands.active_dynamic_bodies());
+        active.extend(islands.active_kinematic_bodies());
+        self.before_previous_step = std::mem::replace(&mut self.before_last_step, active);
+    }
+
+    /// The rigid-bodies active before one of the last two steps. Can contain duplicates, and
+    /// handles of removed rigid-bodies.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = RigidBodyHandle> + '_ {
+        self.before_last_step
+            .iter()
+            .chain(&self.before_previous_step)
+            .copied()
+    }
+}
+
+/// The simulated time of a [`RapierWorld`], and the number of steps it executed.
+///
+/// Each substep counts as a step.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+pub struct PhysicsTime {
+    /// The total simulated time, in seconds.
+    pub elapsed: f64,
+    /// The total number of steps executed.
+    pub steps: u64,
+    /// The number of steps executed during the last frame.
+    pub last_frame_steps: u32,
+    /// The length of the last step, in seconds.
+    pub last_dt: Real,
+}
+
+impl PhysicsTime {
+    fn record_step(&mut self, dt: Real) {
+        self.elapsed += dt as f64;
+        self.steps += 1;
+        self.last_frame_steps += 1;
+        self.last_dt = dt;
+    }
+}
+
+/// The time spent in each phase of the last step of a [`RapierWorld`], copied from the
+/// counters of its [`PhysicsPipeline`].
+///
+/// These are only measured if `RapierConfiguration::enable_profiling` is set, and are all zero
+/// otherwise. The times are in milliseconds, summed over all the substeps of the last step (and
+/// over all the steps of the last frame with [`TimestepMode::Interpolated`]). The
+/// collision-detection time includes the broad-phase and narrow-phase times, and `step_time`
+/// includes everything else, so the phases don’t add up to more than `step_time`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct StepCounters {
+    /// The total time spent in the physics pipeline.
+    pub step_time: f64,
+    /// The time spent detecting collisions (broad-phase and narrow-phase).
+    pub collision_detection_time: f64,
+    /// The time spent in the broad-phase, finding the pairs of colliders with intersecting AABBs.
+    pub broad_phase_time: f64,
+    /// The time spent in the narrow-phase, computing the contacts of these pairs.
+    pub narrow_phase_time: f64,
+    /// The time spent grouping the rigid-bodies into islands, and putting them to sleep.
+    pub island_construction_time: f64,
+    /// The time spent in the constraints solver.
+    pub solver_time: f64,
+    /// The time spent in continuous collision detection.
+    pub ccd_time: f64,
+    /// The number of contact pairs found by the narrow-phase during the last substep.
+    pub num_contact_pairs: usize,
+    /// The number of constraints solved during the last substep.
+    pub num_constraints: usize,
+    /// The number of CCD substeps.
+    pub num_ccd_substeps: usize,
+}
+
+impl StepCounters {
+    fn accumulate(&mut self, counters: &Counters) {
+        if !counters.enabled() {
+            return;
+        }
+
+        self.step_time += counters.step_time();
+        self.collision_detection_time += counters.collision_detection_time();
+        self.broad_phase_time += counters.broad_phase_time();
+        self.narrow_phase_time += counters.narrow_phase_time();
+        self.island_construction_time += counters.island_construction_time();
+        self.solver_time += counters.solver_time();
+        self.ccd_time += counters.ccd_time();
+        self.num_contact_pairs = counters.cd.ncontact_pairs;
+        self.num_constraints = counters.solver.nconstraints;
+        self.num_ccd_substeps += counters.ccd.num_substeps;
+    }
+}
+
 /// The Rapier context, containing all the state of the physics engine.
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 pub struct RapierWorld {
@@ -81,30 +210,70 @@ pub struct RapierWorld {
     pub query_pipeline: QueryPipeline,
     /// The integration parameters, controlling various low-level coefficient of the simulation.
     pub integration_parameters: IntegrationParameters,
+    /// The number of subdivisions used when approximating non-uniformly scaled shapes
+    /// passed to scene queries (shape-casts, intersection tests, character controller).
+    ///
+    /// This is kept in sync with `RapierConfiguration::scaled_shape_subdivision` by the plugin
+    /// so that queries see the same approximation as the colliders of the simulation.
+    pub scaled_shape_subdivision: u32,
+    /// Should a [`DetailedCollisionEvent`] be sent along with each [`CollisionEvent::Started`]
+    /// of two non-sensor colliders?
+    ///
+    /// This value is kept in sync with `RapierConfiguration::detailed_collision_events` by the
+    /// plugin. The events are sent by [`Self::send_detailed_collision_events`].
+    pub detailed_collision_events: bool,
+    /// Should the Bevy events still be sent when a custom event handler was installed with
+    /// [`Self::set_event_handler`]?
+    ///
+    /// If `false` (the default), the custom event handler replaces the Bevy events.
+    pub chain_bevy_events: bool,
+    /// The simulated time of this world, updated at each step.
+    pub physics_time: PhysicsTime,
+    #[cfg_attr(feature = "serde-serialize", serde(skip))]
+    step_counters: StepCounters,
     #[cfg_attr(feature = "serde-serialize", serde(skip))]
     pub(crate) event_handler: Option<Box<dyn EventHandler>>,
     // For transform change detection.
     #[cfg_attr(feature = "serde-serialize", serde(skip))]
-    pub(crate) last_body_transform_set: HashMap<RigidBodyHandle, GlobalTransform>,
+    pub(crate) last_body_transform_set: bevy::utils::HashMap<RigidBodyHandle, GlobalTransform>,
+    // For skipping the bodies that weren’t moved by the simulation during the writeback.
+    #[cfg_attr(feature = "serde-serialize", serde(skip))]
+    pub(crate) recently_active_bodies: RecentlyActiveBodies,
     // NOTE: these maps are needed to handle despawning.
     #[cfg_attr(feature = "serde-serialize", serde(skip))]
-    pub(crate) entity2body: HashMap<Entity, RigidBodyHandle>,
+    pub(crate) entity2body: EntityHashMap<RigidBodyHandle>,
+    #[cfg_attr(feature = "serde-serialize", serde(skip))]
+    pub(crate) entity2collider: EntityHashMap<ColliderHandle>,
     #[cfg_attr(feature = "serde-serialize", serde(skip))]
-    pub(crate) entity2collider: HashMap<Entity, ColliderHandle>,
+    pub(crate) entity2colliders: EntityHashMap<Vec<ColliderHandle>>,
     #[cfg_attr(feature = "serde-serialize", serde(skip))]
-    pub(crate) entity2impulse_joint: HashMap<Entity, ImpulseJointHandle>,
+    pub(crate) entity2impulse_joint: EntityHashMap<ImpulseJointHandle>,
     #[cfg_attr(feature = "serde-serialize", serde(skip))]
-    pub(crate) entity2multibody_joint: HashMap<Entity, MultibodyJointHandle>,
+    pub(crate) entity2multibody_joint: EntityHashMap<MultibodyJointHandle>,
     // This maps the handles of colliders that have been deleted since the last
     // physics update, to the entity they was attached to.
     #[cfg_attr(feature = "serde-serialize", serde(skip))]
-    pub(crate) deleted_colliders: HashMap<ColliderHandle, Entity>,
+    pub(crate) deleted_colliders: bevy::utils::HashMap<ColliderHandle, Entity>,
+    // The pairs of colliders which were given a `CollisionEvent::Stopped` when the sensor
+    // status of one of their colliders changed since the last physics update, mapped to the
+    // index of the step that would otherwise send the event.
+    #[cfg_attr(feature = "serde-serialize", serde(skip))]
+    pub(crate) sensor_toggled_pairs: bevy::utils::HashMap<(ColliderHandle, ColliderHandle), u64>,
     #[cfg_attr(feature = "serde-serialize", serde(skip))]
     pub(crate) collision_events_to_send: RwLock<Vec<CollisionEvent>>,
     #[cfg_attr(feature = "serde-serialize", serde(skip))]
     pub(crate) contact_force_events_to_send: RwLock<Vec<ContactForceEvent>>,
     #[cfg_attr(feature = "serde-serialize", serde(skip))]
+    pub(crate) detailed_collision_events_to_send: RwLock<Vec<DetailedCollisionEvent>>,
+    #[cfg_attr(feature = "serde-serialize", serde(skip))]
     pub(crate) character_collisions_collector: Vec<rapier::control::CharacterCollision>,
+    // The local-space surface velocity of the colliders with a `ConveyorBelt`.
+    #[cfg_attr(feature = "serde-serialize", serde(skip))]
+    pub(crate) conveyor_belts: EntityHashMap<Vect>,
+    // The time spent stepping this world during the last frame, only measured if
+    // `RapierContext::measure_step_time` is set.
+    #[cfg_attr(feature = "serde-serialize", serde(skip))]
+    pub(crate) last_step_duration: std::time::Duration,
 }
 
 impl Default for RapierWorld {
@@ -121,22 +290,61 @@ impl Default for RapierWorld {
             pipeline: PhysicsPipeline::new(),
             query_pipeline: QueryPipeline::new(),
             integration_parameters: IntegrationParameters::default(),
+            scaled_shape_subdivision: 10,
+            detailed_collision_events: false,
+            chain_bevy_events: false,
+            physics_time: PhysicsTime::default(),
+            step_counters: StepCounters::default(),
             event_handler: None,
-            last_body_transform_set: HashMap::new(),
-            entity2body: HashMap::new(),
-            entity2collider: HashMap::new(),
-            entity2impulse_joint: HashMap::new(),
-            entity2multibody_joint: HashMap::new(),
-            deleted_colliders: HashMap::new(),
+            last_body_transform_set: Default::default(),
+            recently_active_bodies: RecentlyActiveBodies::default(),
+            entity2body: Default::default(),
+            entity2collider: Default::default(),
+            entity2colliders: Default::default(),
+            entity2impulse_joint: Default::default(),
+            entity2multibody_joint: Default::default(),
+            deleted_colliders: Default::default(),
+            sensor_toggled_pairs: Default::default(),
             character_collisions_collector: vec![],
+            conveyor_belts: Default::default(),
+            last_step_duration: Default::default(),
             collision_events_to_send: RwLock::new(Vec::new()),
             contact_force_events_to_send: RwLock::new(Vec::new()),
+            detailed_collision_events_to_send: RwLock::new(Vec::new()),
             gravity: Vect::Y * -9.81,
         }
     }
 }
 
 impl RapierWorld {
+    /// Creates a world with enough capacity for `bodies` rigid-bodies and `colliders` colliders
+    /// without reallocating.
+    pub fn with_capacity(bodies: usize, colliders: usize) -> Self {
+        let mut world = Self {
+            bodies: RigidBodySet::with_capacity(bodies),
+            colliders: ColliderSet::with_capacity(colliders),
+            ..Default::default()
+        };
+        world.reserve(bodies, colliders);
+        world
+    }
+
+    /// Reserves capacity for at least `additional_bodies` more rigid-bodies and
+    /// `additional_colliders` more colliders in the maps between entities and Rapier handles.
+    ///
+    /// This avoids rehashing these maps when spawning many physics entities at once. The maps of
+    /// the joints, which are attached to rigid-bodies, are reserved for `additional_bodies`
+    /// joints of each kind, and the map of the [`ColliderMultiple`](crate::geometry::ColliderMultiple)
+    /// entities for `additional_colliders` entities.
+    pub fn reserve(&mut self, additional_bodies: usize, additional_colliders: usize) {
+        self.entity2body.reserve(additional_bodies);
+        self.last_body_transform_set.reserve(additional_bodies);
+        self.entity2impulse_joint.reserve(additional_bodies);
+        self.entity2multibody_joint.reserve(additional_bodies);
+        self.entity2collider.reserve(additional_colliders);
+        self.entity2colliders.reserve(additional_colliders);
+    }
+
     /// Generates bevy events for any physics interactions that have happened
     /// that are stored in the events list
     pub fn send_bevy_events(
@@ -161,6 +369,17 @@ impl RapierWorld {
         }
     }
 
+    /// Generates the bevy [`DetailedCollisionEvent`]s stored since the last call, if
+    /// [`Self::detailed_collision_events`] is enabled.
+    pub fn send_detailed_collision_events(
+        &mut self,
+        detailed_collision_event_writer: &mut EventWriter<DetailedCollisionEvent>,
+    ) {
+        if let Ok(mut events_to_send) = self.detailed_collision_events_to_send.write() {
+            detailed_collision_event_writer.send_batch(events_to_send.drain(..));
+        }
+    }
+
     /// Sets the gravity of this world with respect to its integration parameters.
     ///
     /// Prefer using this over setting gravity manually
@@ -177,6 +396,59 @@ impl RapierWorld {
         self
     }
 
+    /// The distance, in world units, at which contacts are generated before colliders actually
+    /// touch.
+    pub fn prediction_distance(&self) -> Real {
+        self.integration_parameters.prediction_distance()
+    }
+
+    /// Sets the distance, in world units, at which contacts are generated before colliders
+    /// actually touch.
+    ///
+    /// Prefer using this over setting `normalized_prediction_distance` manually, since it takes
+    /// the integration parameters’ `length_unit` into account. Increasing it can stabilize stacks
+    /// of thin objects, at the cost of more contacts to process.
+    pub fn set_prediction_distance(&mut self, distance: Real) {
+        self.integration_parameters.normalized_prediction_distance =
+            distance / self.integration_parameters.length_unit;
+    }
+
+    /// Sets the distance, in world units, at which contacts are generated before colliders
+    /// actually touch.
+    ///
+    /// See [`Self::set_prediction_distance`].
+    pub fn with_prediction_distance(mut self, distance: Real) -> Self {
+        self.set_prediction_distance(distance);
+
+        self
+    }
+
+    /// Sets how stiffly the joints of this world correct their positional errors.
+    ///
+    /// The `natural_frequency` (in Hz) is the frequency at which a joint oscillates while
+    /// correcting its error, and `damping_ratio` how quickly these oscillations fade out. They
+    /// apply to all the impulse and multibody joints of the world, since Rapier doesn’t support
+    /// per-joint stiffness.
+    ///
+    /// Rapier defaults to a natural frequency of `1.0e6` and a damping ratio of `1.0`, the
+    /// stiffest joints it can simulate. A natural frequency between `1.0` and `100.0` makes the
+    /// joints visibly springy, and a damping ratio below `1.0` makes them oscillate before
+    /// settling. If long chains of joints still stretch under load with the default values,
+    /// increase `IntegrationParameters::num_solver_iterations` instead.
+    pub fn set_joint_stiffness(&mut self, natural_frequency: Real, damping_ratio: Real) {
+        self.integration_parameters.joint_natural_frequency = natural_frequency;
+        self.integration_parameters.joint_damping_ratio = damping_ratio;
+    }
+
+    /// Sets how stiffly the joints of this world correct their positional errors.
+    ///
+    /// See [`Self::set_joint_stiffness`].
+    pub fn with_joint_stiffness(mut self, natural_frequency: Real, damping_ratio: Real) -> Self {
+        self.set_joint_stiffness(natural_frequency, damping_ratio);
+
+        self
+    }
+
     /// If the collider attached to `entity` is attached to a rigid-body, this
     /// returns the `Entity` containing that rigid-body.
     pub fn collider_parent(&self, entity: Entity) -> Option<Entity> {
@@ -187,6 +459,149 @@ impl RapierWorld {
             .and_then(|h| self.rigid_body_entity(h))
     }
 
+    /// Recomputes the mass properties of the rigid-body attached to `body`, and returns the
+    /// [`MassModifiedEvent`] describing them.
+    pub(crate) fn mass_modified_event(
+        &mut self,
+        world_id: WorldId,
+        body: Entity,
+        cause: MassChangeCause,
+    ) -> Option<MassModifiedEvent> {
+        let handle = self.entity2body.get(&body)?;
+        let rb = self.bodies.get_mut(*handle)?;
+        rb.recompute_mass_properties_from_colliders(&self.colliders);
+
+        Some(MassModifiedEvent {
+            entity: body,
+            world_id,
+            new_mass_properties: MassProperties::from_rapier(rb.mass_properties().local_mprops),
+            cause,
+        })
+    }
+
+    /// Wakes up the rigid-body attached to the collider `handle`, as well as the rigid-bodies
+    /// attached to the colliders it has a contact or intersection pair with.
+    ///
+    /// Pairs involving only sleeping or fixed rigid-bodies aren’t updated by the narrow-phase,
+    /// so this is needed for a change of the interaction groups or sensor status of the
+    /// collider to take effect on its existing pairs.
+    pub(crate) fn wake_up_collider_pairs(&mut self, handle: ColliderHandle) {
+        let contacts = self
+            .narrow_phase
+            .contact_pairs_with(handle)
+            .map(|pair| [pair.collider1, pair.collider2]);
+        let intersections = self
+            .narrow_phase
+            .intersection_pairs_with(handle)
+            .map(|(collider1, collider2, _)| [collider1, collider2]);
+        let bodies: Vec<_> = contacts
+            .chain(intersections)
+            .flatten()
+            .chain([handle])
+            .filter_map(|collider| self.colliders.get(collider)?.parent())
+            .collect();
+
+        for body in bodies {
+            if let Some(rb) = self.bodies.get_mut(body) {
+                rb.wake_up(true);
+            }
+        }
+    }
+
+    /// Sets whether the collider `handle` is a sensor.
+    ///
+    /// If the collider has touching pairs, they change from contacts to intersections (or
+    /// conversely) during the next step: a [`CollisionEvent::Stopped`] is sent for each of them
+    /// right away, and the next step sends the [`CollisionEvent::Started`] events of the new
+    /// pairs. The rigid-bodies involved are woken up.
+    pub(crate) fn set_collider_sensor(
+        &mut self,
+        world_id: WorldId,
+        handle: ColliderHandle,
+        is_sensor: bool,
+    ) {
+        if self
+            .colliders
+            .get(handle)
+            .map_or(true, |co| co.is_sensor() == is_sensor)
+        {
+            return;
+        }
+
+        let contacts = self
+            .narrow_phase
+            .contact_pairs_with(handle)
+            .filter(|pair| pair.has_any_active_contact)
+            .map(|pair| (pair.collider1, pair.collider2, CollisionEventFlags::empty()));
+        let intersections = self
+            .narrow_phase
+            .intersection_pairs_with(handle)
+            .filter(|(_, _, intersecting)| *intersecting)
+            .map(|(collider1, collider2, _)| (collider1, collider2, CollisionEventFlags::SENSOR));
+        let touching: Vec<_> = contacts.chain(intersections).collect();
+
+        for (collider1, collider2, flags) in touching {
+            let (Some(co1), Some(co2)) =
+                (self.colliders.get(collider1), self.colliders.get(collider2))
+            else {
+                continue;
+            };
+
+            let events = co1.active_events() | co2.active_events();
+            if !events.contains(rapier::pipeline::ActiveEvents::COLLISION_EVENTS) {
+                continue;
+            }
+
+            let (Some(entity1), Some(entity2)) = (
+                self.collider_entity(collider1),
+                self.collider_entity(collider2),
+            ) else {
+                continue;
+            };
+
+            // The event is sent as if it was emitted by the next step, which would otherwise
+            // send it.
+            if let Ok(mut events) = self.collision_events_to_send.write() {
+                events.push(CollisionEvent::Stopped(
+                    entity1,
+                    entity2,
+                    flags,
+                    world_id,
+                    self.physics_time.steps,
+                ));
+            }
+            self.sensor_toggled_pairs
+                .insert((collider1, collider2), self.physics_time.steps);
+        }
+
+        if let Some(co) = self.colliders.get_mut(handle) {
+            co.set_sensor(is_sensor);
+        }
+        self.wake_up_collider_pairs(handle);
+    }
+
+    /// If the colliders of the `ColliderMultiple` attached to `entity` are attached to a
+    /// rigid-body, this returns the `Entity` containing that rigid-body.
+    pub fn collider_multiple_parent(&self, entity: Entity) -> Option<Entity> {
+        self.entity2colliders
+            .get(&entity)
+            .and_then(|handles| handles.first())
+            .and_then(|h| self.colliders.get(*h))
+            .and_then(|co| co.parent())
+            .and_then(|h| self.rigid_body_entity(h))
+    }
+
+    /// The linear velocity of a world-space point of the rigid-body attached to `entity`, taking
+    /// its angular velocity and current center-of-mass into account.
+    ///
+    /// Returns `None` if `entity` isn’t a rigid-body of this world.
+    pub fn velocity_at_point(&self, entity: Entity, point: Vect) -> Option<Vect> {
+        self.entity2body
+            .get(&entity)
+            .and_then(|handle| self.bodies.get(*handle))
+            .map(|body| body.velocity_at_point(&point.into()).into())
+    }
+
     /// If entity is a rigid-body, this returns the collider `Entity`s attached
     /// to that rigid-body.
     pub fn rigid_body_colliders(&self, entity: Entity) -> impl Iterator<Item = Entity> + '_ {
@@ -224,6 +639,260 @@ impl RapierWorld {
             .map(|c| Entity::from_bits(c.user_data as u64))
     }
 
+    /// Maps `entity` to the rigid-body `handle`, removing the rigid-body previously mapped to
+    /// `entity`, if any.
+    pub(crate) fn insert_rigid_body_entity(&mut self, entity: Entity, handle: RigidBodyHandle) {
+        self.remove_rigid_body_entity(entity);
+        self.entity2body.insert(entity, handle);
+    }
+
+    /// Removes the rigid-body attached to `entity` from this world, along with all the data
+    /// associated to its handle.
+    ///
+    /// This is the only place where entries of `entity2body` should be removed, so that no
+    /// stale handle is left behind.
+    pub(crate) fn remove_rigid_body_entity(&mut self, entity: Entity) -> Option<RigidBodyHandle> {
+        let handle = self.entity2body.remove(&entity)?;
+        self.last_body_transform_set.remove(&handle);
+        self.bodies.remove(
+            handle,
+            &mut self.islands,
+            &mut self.colliders,
+            &mut self.impulse_joints,
+            &mut self.multibody_joints,
+            false,
+        );
+        Some(handle)
+    }
+
+    /// Checks that all the handles of `last_body_transform_set` are handles of live rigid-bodies.
+    pub(crate) fn last_body_transforms_are_live(&self) -> bool {
+        self.last_body_transform_set
+            .keys()
+            .all(|handle| self.bodies.contains(*handle))
+    }
+
+    /// Checks that the maps between Bevy entities and Rapier handles of this world are
+    /// consistent with the Rapier sets, and returns every inconsistency found.
+    ///
+    /// An empty result means that every mapped handle exists, that every rigid-body and collider
+    /// points back to the entity it is mapped to, and that no handle is shared by several entities.
+    pub fn validate(&self) -> Vec<ConsistencyError> {
+        let mut errors = vec![];
+
+        let mut body_owners = HashMap::new();
+        for (&entity, &handle) in &self.entity2body {
+            if let Some(other) = body_owners.insert(handle, entity) {
+                errors.push(ConsistencyError::SharedRigidBodyHandle {
+                    entities: [other, entity],
+                    handle,
+                });
+            }
+
+            match self.bodies.get(handle) {
+                Some(rb) => {
+                    let owner = Entity::try_from_bits(rb.user_data as u64).ok();
+                    if owner != Some(entity) {
+                        errors.push(ConsistencyError::RigidBodyEntityMismatch {
+                            entity,
+                            handle,
+                            owner,
+                        });
+                    }
+                }
+                None => errors.push(ConsistencyError::MissingRigidBody { entity, handle }),
+            }
+        }
+
+        let mut collider_owners = HashMap::new();
+        let mapped_colliders = self
+            .entity2collider
+            .iter()
+            .map(|(entity, handle)| (*entity, *handle))
+            .chain(self.entity2colliders.iter().flat_map(|(entity, handles)| {
+                handles.iter().map(move |handle| (*entity, *handle))
+            }));
+        for (entity, handle) in mapped_colliders {
+            if let Some(other) = collider_owners.insert(handle, entity) {
+                errors.push(ConsistencyError::SharedColliderHandle {
+                    entities: [other, entity],
+                    handle,
+                });
+            }
+
+            match self.colliders.get(handle) {
+                Some(co) => {
+                    let owner = Entity::try_from_bits(co.user_data as u64).ok();
+                    if owner != Some(entity) {
+                        errors.push(ConsistencyError::ColliderEntityMismatch {
+                            entity,
+                            handle,
+                            owner,
+                        });
+                    }
+                }
+                None => errors.push(ConsistencyError::MissingCollider { entity, handle }),
+            }
+        }
+
+        let mut impulse_joint_owners = HashMap::new();
+        for (&entity, &handle) in &self.entity2impulse_joint {
+            if let Some(other) = impulse_joint_owners.insert(handle, entity) {
+                errors.push(ConsistencyError::SharedImpulseJointHandle {
+                    entities: [other, entity],
+                    handle,
+                });
+            }
+
+            if !self.impulse_joints.contains(handle) {
+                errors.push(ConsistencyError::MissingImpulseJoint { entity, handle });
+            }
+        }
+
+        let mut multibody_joint_owners = HashMap::new();
+        for (&entity, &handle) in &self.entity2multibody_joint {
+            if let Some(other) = multibody_joint_owners.insert(handle, entity) {
+                errors.push(ConsistencyError::SharedMultibodyJointHandle {
+                    entities: [other, entity],
+                    handle,
+                });
+            }
+
+            if self.multibody_joints.get(handle).is_none() {
+                errors.push(ConsistencyError::MissingMultibodyJoint { entity, handle });
+            }
+        }
+
+        for &handle in self.last_body_transform_set.keys() {
+            if !self.bodies.contains(handle) {
+                errors.push(ConsistencyError::StaleLastBodyTransform { handle });
+            }
+        }
+
+        errors
+    }
+
+    /// The current position of the multibody joint attached to `entity` along each of its free axes.
+    ///
+    /// Positions along linear axes are translations, and positions along angular axes are angles.
+    /// The free linear axes come first, followed by the free angular axes. For joints with more
+    /// than one free angular axis, angular positions are the components of the joint’s rotation
+    /// vector, expressed in its first frame, rather than independent angles around each axis.
+    ///
+    /// Returns `None` if `entity` doesn’t have a multibody joint. This doesn’t apply to impulse
+    /// joints.
+    pub fn multibody_joint_positions(&self, entity: Entity) -> Option<Vec<Real>> {
+        let handle = self.entity2multibody_joint.get(&entity)?;
+        let (mb, link_id) = self.multibody_joints.get(*handle)?;
+        let link = mb.link(link_id)?;
+        let data = &link.joint.data;
+
+        // The joint’s position, relative to its first frame.
+        let joint_pos = data
+            .local_frame1
+            .inv_mul(&(link.local_to_parent() * data.local_frame2));
+        #[cfg(feature = "dim2")]
+        let angles = [joint_pos.rotation.angle()];
+        #[cfg(feature = "dim3")]
+        let angles = joint_pos.rotation.scaled_axis();
+
+        let positions = (0..SPATIAL_DIM)
+            .filter(|i| data.locked_axes.bits() & (1 << i) == 0)
+            .map(|i| {
+                if i < DIM {
+                    joint_pos.translation.vector[i]
+                } else {
+                    angles[i - DIM]
+                }
+            })
+            .collect();
+        Some(positions)
+    }
+
+    /// Teleports the multibody joint attached to `entity` to the given position along each of
+    /// its free axes.
+    ///
+    /// See [`Self::multibody_joint_positions`] for the layout of `positions`. The positions of the
+    /// rigid-bodies and colliders of the multibody are updated right away.
+    ///
+    /// Returns `false`, leaving the joint untouched, if `entity` doesn’t have a multibody joint,
+    /// or if the length of `positions` doesn’t match the number of free axes of the joint. This
+    /// doesn’t apply to impulse joints.
+    pub fn set_multibody_joint_positions(&mut self, entity: Entity, positions: &[Real]) -> bool {
+        let Some(current) = self.multibody_joint_positions(entity) else {
+            return false;
+        };
+        if current.len() != positions.len() {
+            return false;
+        }
+
+        let Some((mb, link_id)) = self
+            .entity2multibody_joint
+            .get(&entity)
+            .and_then(|handle| self.multibody_joints.get_mut(*handle))
+        else {
+            return false;
+        };
+
+        // The degrees of freedom of the links are stored contiguously, in link order.
+        let first_dof: usize = mb
+            .links()
+            .take(link_id)
+            .map(|link| link.joint.ndofs())
+            .sum();
+        let Some(locked_axes) = mb.link(link_id).map(|link| link.joint.data.locked_axes) else {
+            return false;
+        };
+        let num_linear = (0..DIM)
+            .filter(|i| locked_axes.bits() & (1 << i) == 0)
+            .count();
+        let mut displacements = vec![0.0; mb.ndofs()];
+        let (linear, angular) = positions.split_at(num_linear);
+        for (i, (target, current)) in linear.iter().zip(&current).enumerate() {
+            displacements[first_dof + i] = target - current;
+        }
+
+        let angular_dofs = &mut displacements[first_dof + num_linear..first_dof + current.len()];
+        match angular.len() {
+            #[cfg(feature = "dim3")]
+            3 => {
+                // The joint applies a multi-axis angular displacement as a rotation on top of its
+                // current rotation, so the displacement is the rotation from the current
+                // orientation to the target one, expressed in the joint frame.
+                let current_rot = rapier::math::Rotation::from_scaled_axis(
+                    rapier::math::Vector::from_row_slice(&current[num_linear..]),
+                );
+                let target_rot = rapier::math::Rotation::from_scaled_axis(
+                    rapier::math::Vector::from_row_slice(angular),
+                );
+                let displacement = (target_rot * current_rot.inverse()).scaled_axis();
+                angular_dofs.copy_from_slice(displacement.as_slice());
+            }
+            _ => {
+                for ((dof, target), current) in angular_dofs
+                    .iter_mut()
+                    .zip(angular)
+                    .zip(&current[num_linear..])
+                {
+                    *dof = target - current;
+                }
+            }
+        }
+
+        mb.apply_displacements(&displacements);
+        mb.forward_kinematics(&self.bodies, false);
+        mb.update_rigid_bodies(&mut self.bodies, true);
+
+        for link in mb.links() {
+            if let Some(rb) = self.bodies.get_mut(link.rigid_body_handle()) {
+                rb.wake_up(true);
+            }
+        }
+
+        self.propagate_modified_body_positions_to_colliders();
+        true
+    }
+
     /// Calls the closure `f` once after converting the given [`QueryFilter`] into a raw `rapier::QueryFilter`.
     pub fn with_query_filter<T>(
         &self,
@@ -242,8 +911,8 @@ impl RapierWorld {
     /// Without borrowing the [`RapierContext`], calls the closure `f` once
     /// after converting the given [`QueryFilter`] into a raw `rapier::QueryFilter`.
     pub fn with_query_filter_elts<T>(
-        entity2collider: &HashMap<Entity, ColliderHandle>,
-        entity2body: &HashMap<Entity, RigidBodyHandle>,
+        entity2collider: &EntityHashMap<ColliderHandle>,
+        entity2body: &EntityHashMap<RigidBodyHandle>,
         colliders: &ColliderSet,
         filter: QueryFilter,
         f: impl FnOnce(RapierQueryFilter) -> T,
@@ -260,20 +929,42 @@ impl RapierWorld {
             predicate: None,
         };
 
-        if let Some(predicate) = filter.predicate {
-            let wrapped_predicate = |h: ColliderHandle, _: &rapier::geometry::Collider| {
-                Self::collider_entity_with_set(colliders, h)
-                    .map(predicate)
-                    .unwrap_or(false)
-            };
-            rapier_filter.predicate = Some(&wrapped_predicate);
-            f(rapier_filter)
-        } else {
-            f(rapier_filter)
-        }
+        let excluded_colliders: Vec<ColliderHandle> = filter
+            .exclude_entities
+            .iter()
+            .filter_map(|e| entity2collider.get(e).copied())
+            .collect();
+        let excluded_bodies: Vec<RigidBodyHandle> = filter
+            .exclude_entities
+            .iter()
+            .filter_map(|e| entity2body.get(e).copied())
+            .collect();
+
+        // Disabled colliders, including the colliders of disabled rigid-bodies, are never part
+        // of the query results.
+        let wrapped_predicate = |h: ColliderHandle, co: &rapier::geometry::Collider| {
+            co.is_enabled()
+                && !excluded_colliders.contains(&h)
+                && co
+                    .parent()
+                    .map_or(true, |parent| !excluded_bodies.contains(&parent))
+                && filter.predicate.map_or(true, |predicate| {
+                    Self::collider_entity_with_set(colliders, h)
+                        .map(predicate)
+                        .unwrap_or(false)
+                })
+        };
+        rapier_filter.predicate = Some(&wrapped_predicate);
+        f(rapier_filter)
     }
 
     /// Advance the simulation, based on the given timestep mode.
+    ///
+    /// With [`TimestepMode::Manual`], a single step is executed regardless of the requested
+    /// steps.
+    ///
+    /// Returns the simulation time, in seconds, that was dropped because of the
+    /// `max_steps_per_frame` limit of [`TimestepMode::Interpolated`].
     #[allow(clippy::too_many_arguments)]
     pub fn step_simulation(
         &mut self,
@@ -286,46 +977,104 @@ impl RapierWorld {
         interpolation_query: &mut Option<
             &mut Query<(&RapierRigidBodyHandle, &mut TransformInterpolation)>,
         >,
-    ) {
+    ) -> Real {
         let gravity = self.gravity;
+        let mut dropped_time = 0.0;
+        let first_step = self.physics_time.steps;
+
+        // The phases of a step run inside Rapier, so they can’t have their own spans. Their
+        // durations are recorded on the step span instead, when profiling is enabled.
+        let span = info_span!(
+            "rapier_step",
+            world = ?world_id,
+            step_ms = Empty,
+            collision_detection_ms = Empty,
+            island_construction_ms = Empty,
+            solver_ms = Empty,
+            ccd_ms = Empty,
+        );
+        let _guard = span.enter();
+        self.step_counters = StepCounters::default();
+
+        let hooks = &ConveyorBeltHooks {
+            belts: &self.conveyor_belts,
+            hooks,
+        };
 
         let event_queue = if create_bevy_events {
             Some(EventQueue {
                 world_id,
+                step_index: AtomicU64::new(self.physics_time.steps),
                 deleted_colliders: &self.deleted_colliders,
+                sensor_toggled_pairs: &self.sensor_toggled_pairs,
                 collision_events: &mut self.collision_events_to_send,
                 contact_force_events: &mut self.contact_force_events_to_send,
+                detailed_collision_events: self
+                    .detailed_collision_events
+                    .then_some(&mut self.detailed_collision_events_to_send),
             })
         } else {
             None
         };
 
-        let events = self
-            .event_handler
-            .as_deref()
-            .or_else(|| event_queue.as_ref().map(|q| q as &dyn EventHandler))
-            .unwrap_or(&() as &dyn EventHandler);
+        let chained_events;
+        let events: &dyn EventHandler = match (self.event_handler.as_deref(), &event_queue) {
+            (Some(handler), Some(event_queue)) if self.chain_bevy_events => {
+                chained_events = ChainedEventHandlers(handler, event_queue);
+                &chained_events
+            }
+            (Some(handler), _) => handler,
+            (None, Some(event_queue)) => event_queue,
+            (None, None) => &(),
+        };
+        let start_step = |step_index| {
+            if let Some(event_queue) = &event_queue {
+                event_queue.set_step_index(step_index);
+            }
+        };
 
         match timestep_mode {
             TimestepMode::Interpolated {
                 dt,
                 time_scale,
                 substeps,
+                max_steps_per_frame,
             } => {
                 self.integration_parameters.dt = dt;
 
                 sim_to_render_time.diff += time.delta_seconds();
 
-                while sim_to_render_time.diff > 0.0 {
+                // Don’t try to catch up with more time than the allowed number of steps can
+                // simulate, otherwise the next frames would only get longer.
+                let max_diff = max_steps_per_frame as Real * dt;
+                if sim_to_render_time.diff > max_diff {
+                    dropped_time = sim_to_render_time.diff - max_diff;
+                    sim_to_render_time.diff = max_diff;
+                }
+
+                let mut num_steps = 0;
+
+                while sim_to_render_time.diff > 0.0 && num_steps < max_steps_per_frame {
+                    num_steps += 1;
+                    self.recently_active_bodies.record(&self.islands);
+
                     // NOTE: in this comparison we do the same computations we
                     // will do for the next `while` iteration test, to make sure we
                     // don't get bit by potential float inaccuracy.
-                    if sim_to_render_time.diff - dt <= 0.0 {
+                    if sim_to_render_time.diff - dt <= 0.0 || num_steps == max_steps_per_frame {
                         if let Some(interpolation_query) = interpolation_query.as_mut() {
                             // This is the last simulation step to be executed in the loop
-                            // Update the previous state transforms
-                            for (handle, mut interpolation) in interpolation_query.iter_mut() {
-                                if let Some(body) = self.bodies.get(handle.0) {
+                            // Update the previous state transforms. Only the bodies that
+                            // were recently active can have moved since the last update.
+                            for handle in self.recently_active_bodies.iter() {
+                                let Some(body) = self.bodies.get(handle) else {
+                                    continue;
+                                };
+                                let entity = Entity::from_bits(body.user_data as u64);
+
+                                if let Ok((_, mut interpolation)) =
+                                    interpolation_query.get_mut(entity)
+                                {
                                     interpolation.start = Some(*body.position());
                                     interpolation.end = None;
                                 }
@@ -337,6 +1086,8 @@ impl RapierWorld {
                     substep_integration_parameters.dt = dt / (substeps as Real) * time_scale;
 
                     for _ in 0..substeps {
+                        let _span = info_span!("rapier_substep").entered();
+                        start_step(self.physics_time.steps);
                         self.pipeline.step(
                             &gravity.into(),
                             &substep_integration_parameters,
@@ -352,6 +1103,9 @@ impl RapierWorld {
                             hooks,
                             events,
                         );
+                        self.physics_time
+                            .record_step(substep_integration_parameters.dt);
+                        self.step_counters.accumulate(&self.pipeline.counters);
                     }
 
                     sim_to_render_time.diff -= dt;
@@ -363,11 +1117,14 @@ impl RapierWorld {
                 substeps,
             } => {
                 self.integration_parameters.dt = (time.delta_seconds() * time_scale).min(max_dt);
+                self.recently_active_bodies.record(&self.islands);
 
                 let mut substep_integration_parameters = self.integration_parameters;
                 substep_integration_parameters.dt /= substeps as Real;
 
                 for _ in 0..substeps {
+                    let _span = info_span!("rapier_substep").entered();
+                    start_step(self.physics_time.steps);
                     self.pipeline.step(
                         &gravity.into(),
                         &substep_integration_parameters,
@@ -383,15 +1140,68 @@ impl RapierWorld {
                         hooks,
                         events,
                     );
+                    self.physics_time
+                        .record_step(substep_integration_parameters.dt);
+                    self.step_counters.accumulate(&self.pipeline.counters);
                 }
             }
-            TimestepMode::Fixed { dt, substeps } => {
+            TimestepMode::Fixed { dt, substeps } | TimestepMode::Manual { dt, substeps } => {
                 self.integration_parameters.dt = dt;
+                self.recently_active_bodies.record(&self.islands);
 
                 let mut substep_integration_parameters = self.integration_parameters;
                 substep_integration_parameters.dt = dt / (substeps as Real);
 
                 for _ in 0..substeps {
+                    let _span = info_span!("rapier_substep").entered();
+                    start_step(self.physics_time.steps);
+                    self.pipeline.step(
+                        &gravity.into(),
+                        &substep_integration_parameters,
+                        &mut self.islands,
+                        &mut self.broad_phase,
+                        &mut self.narrow_phase,
+                        &mut self.bodies,
+                        &mut self.colliders,
+                        &mut self.impulse_joints,
+                        &mut self.multibody_joints,
+                        &mut self.ccd_solver,
+                        None,
+                        hooks,
+                        events,
+                    );
+                    self.physics_time
+                        .record_step(substep_integration_parameters.dt);
+                    self.step_counters.accumulate(&self.pipeline.counters);
+                }
+            }
+            TimestepMode::FixedSchedule { substeps } => {
+                // Within the fixed schedule, `time` is the `Time<Fixed>` clock.
+                self.integration_parameters.dt = time.delta_seconds();
+                self.recently_active_bodies.record(&self.islands);
+
+                if let Some(interpolation_query) = interpolation_query.as_mut() {
+                    // The previous state is needed to interpolate between this step and the
+                    // next one.
+                    for handle in self.recently_active_bodies.iter() {
+                        let Some(body) = self.bodies.get(handle) else {
+                            continue;
+                        };
+                        let entity = Entity::from_bits(body.user_data as u64);
+
+                        if let Ok((_, mut interpolation)) = interpolation_query.get_mut(entity) {
+                            interpolation.start = Some(*body.position());
+                            interpolation.end = None;
+                        }
+                    }
+                }
+
+                let mut substep_integration_parameters = self.integration_parameters;
+                substep_integration_parameters.dt /= substeps as Real;
+
+                for _ in 0..substeps {
+                    let _span = info_span!("rapier_substep").entered();
+                    start_step(self.physics_time.steps);
                     self.pipeline.step(
                         &gravity.into(),
                         &substep_integration_parameters,
@@ -407,9 +1217,144 @@ impl RapierWorld {
                         hooks,
                         events,
                     );
+                    self.physics_time
+                        .record_step(substep_integration_parameters.dt);
+                    self.step_counters.accumulate(&self.pipeline.counters);
                 }
             }
         }
+
+        if self.physics_time.steps > first_step {
+            // The pairs changed type during the first step.
+            self.sensor_toggled_pairs.clear();
+        }
+
+        if self.pipeline.counters.enabled() {
+            let counters = &self.step_counters;
+            span.record("step_ms", counters.step_time);
+            span.record("collision_detection_ms", counters.collision_detection_time);
+            span.record("island_construction_ms", counters.island_construction_time);
+            span.record("solver_ms", counters.solver_time);
+            span.record("ccd_ms", counters.ccd_time);
+        }
+
+        dropped_time
+    }
+
+    /// Runs `steps` steps of length `dt`, calling `per_step` with the world and the index of the
+    /// step (starting at 0) before each of them.
+    ///
+    /// This replays the steps of a rolled-back world for client-side prediction: `per_step` can
+    /// re-apply the inputs recorded for each step (forces, impulses, kinematic targets, etc.).
+    /// The events generated by these steps are discarded, even with a custom event handler, and
+    /// the [`SimulationToRenderTime`] isn’t affected. The Bevy components are updated by the
+    /// next writeback.
+    pub fn resimulate(
+        &mut self,
+        steps: u32,
+        dt: Real,
+        hooks: &dyn PhysicsHooks,
+        mut per_step: impl FnMut(&mut RapierWorld, u32),
+    ) {
+        let _span = info_span!("rapier_resimulate", steps).entered();
+
+        for step in 0..steps {
+            per_step(self, step);
+
+            self.integration_parameters.dt = dt;
+            self.recently_active_bodies.record(&self.islands);
+            let hooks = &ConveyorBeltHooks {
+                belts: &self.conveyor_belts,
+                hooks,
+            };
+            self.pipeline.step(
+                &self.gravity.into(),
+                &self.integration_parameters,
+                &mut self.islands,
+                &mut self.broad_phase,
+                &mut self.narrow_phase,
+                &mut self.bodies,
+                &mut self.colliders,
+                &mut self.impulse_joints,
+                &mut self.multibody_joints,
+                &mut self.ccd_solver,
+                None,
+                hooks,
+                &(),
+            );
+            self.physics_time.record_step(dt);
+        }
+    }
+
+    /// The time spent in each phase of the last step of this world.
+    ///
+    /// These are only measured if `RapierConfiguration::enable_profiling` is set.
+    pub fn step_counters(&self) -> StepCounters {
+        self.step_counters
+    }
+
+    /// A hash of the state of this world, to detect desynchronizations between the peers of a
+    /// lockstep networked game.
+    ///
+    /// The position, rotation, velocities, and sleep state of each rigid-body are hashed
+    /// bit-exactly, in the order of their handles, along with the number of colliders and joints.
+    /// Identical simulations are only guaranteed to reach bit-exact states on different
+    /// platforms with the `enhanced-determinism` feature, so the hash is only meaningful with it.
+    pub fn state_hash(&self) -> u64 {
+        let mut hasher = StateHasher::default();
+        self.hash_state(&mut hasher);
+        hasher.0
+    }
+
+    fn hash_state(&self, hasher: &mut StateHasher) {
+        hasher.write_u64(self.bodies.len() as u64);
+        // The rigid-body set iterates in the order of the handle indices.
+        for (handle, body) in self.bodies.iter() {
+            let (index, generation) = handle.into_raw_parts();
+            hasher.write_u64(index as u64);
+            hasher.write_u64(generation as u64);
+            hasher.write_reals(body.translation().iter().copied());
+            #[cfg(feature = "dim2")]
+            hasher.write_reals([body.rotation().re, body.rotation().im]);
+            #[cfg(feature = "dim3")]
+            hasher.write_reals(body.rotation().coords.iter().copied());
+            hasher.write_reals(body.linvel().iter().copied());
+            #[cfg(feature = "dim2")]
+            hasher.write_reals([body.angvel()]);
+            #[cfg(feature = "dim3")]
+            hasher.write_reals(body.angvel().iter().copied());
+            hasher.write_u64(body.is_sleeping() as u64);
+        }
+
+        hasher.write_u64(self.colliders.len() as u64);
+        hasher.write_u64(self.impulse_joints.len() as u64);
+        hasher.write_u64(self.multibody_joints.iter().count() as u64);
+    }
+
+    /// Installs a custom handler receiving the raw Rapier events emitted by this world.
+    ///
+    /// The custom handler replaces the Bevy events ([`CollisionEvent`], [`ContactForceEvent`],
+    /// and [`DetailedCollisionEvent`]) of this world, unless [`Self::chain_bevy_events`] is
+    /// enabled. Any handler installed previously is replaced.
+    pub fn set_event_handler(&mut self, handler: Box<dyn EventHandler>) {
+        self.event_handler = Some(handler);
+    }
+
+    /// Removes the custom event handler installed with [`Self::set_event_handler`], returning it.
+    pub fn remove_event_handler(&mut self) -> Option<Box<dyn EventHandler>> {
+        self.event_handler.take()
+    }
+
+    /// Enables or disables the internal counters of the physics pipeline, read by
+    /// [`Self::step_counters`].
+    pub(crate) fn set_profiling_enabled(&mut self, enabled: bool) {
+        if enabled != self.pipeline.counters.enabled() {
+            if enabled {
+                self.pipeline.counters.enable();
+            } else {
+                self.pipeline.counters.disable();
+            }
+        }
     }
 
     /// This method makes sure that the rigid-body positions have been propagated to
@@ -422,9 +1367,41 @@ impl RapierWorld {
     /// Updates the state of the query pipeline, based on the collider positions known
     /// from the last timestep or the last call to `self.propagate_modified_body_positions_to_colliders()`.
     pub fn update_query_pipeline(&mut self) {
+        let _span = info_span!("rapier_query_pipeline").entered();
         self.query_pipeline.update(&self.bodies, &self.colliders);
     }
 
+    /// Calls `f` with the depth and the bounding box of the nodes of the query pipeline’s
+    /// bounding volume hierarchy, down to `max_depth`.
+    ///
+    /// The children of the root node are at depth 0. The leaves are the bounding boxes of the
+    /// colliders, as seen by the last update of the query pipeline.
+    pub fn visit_query_pipeline_bvh(&self, max_depth: usize, mut f: impl FnMut(usize, &Aabb)) {
+        let nodes = self.query_pipeline.qbvh().raw_nodes();
+        if nodes.is_empty() {
+            return;
+        }
+
+        let mut stack = vec![(0, 0)];
+        while let Some((node_id, depth)) = stack.pop() {
+            let node: &QbvhNode = &nodes[node_id as usize];
+
+            for i in 0..SIMD_WIDTH {
+                let aabb = node.simd_aabb.extract(i);
+                // Unused lanes have an invalid bounding box.
+                if node.children[i] == u32::MAX || aabb.mins.x > aabb.maxs.x {
+                    continue;
+                }
+
+                f(depth, &aabb);
+
+                if !node.is_leaf() && depth < max_depth {
+                    stack.push((node.children[i], depth + 1));
+                }
+            }
+        }
+    }
+
     /// Attempts to move shape, optionally sliding or climbing obstacles.
     ///
     /// # Parameters
@@ -449,11 +1426,7 @@ impl RapierWorld {
         filter: QueryFilter,
         events: &mut impl FnMut(CharacterCollision),
     ) -> MoveShapeOutput {
-        let mut scaled_shape = shape.clone();
-        // TODO: how to set a good number of subdivisions, we don’t have access to the
-        //       RapierConfiguration::scaled_shape_subdivision here.
-        scaled_shape.set_scale(shape.scale, 20);
-
+        let scaled_shape = shape.rescaled(self.scaled_shape_subdivision);
         let up = options
             .up
             .try_into()
@@ -479,13 +1452,14 @@ impl RapierWorld {
         // TODO: having to grab all the references to avoid having self in
         //       the closure is ugly.
         let dt = self.integration_parameters.dt;
+        let epsilon = self.integration_parameters.length_unit * 1.0e-4;
         let colliders = &self.colliders;
         let bodies = &mut self.bodies;
         let query_pipeline = &self.query_pipeline;
         let collisions = &mut self.character_collisions_collector;
         collisions.clear();
 
-        let result = Self::with_query_filter_elts(
+        let (result, (stepped_up, snapped_to_ground)) = Self::with_query_filter_elts(
             &self.entity2collider,
             &self.entity2body,
             &self.colliders,
@@ -496,7 +1470,7 @@ impl RapierWorld {
                     bodies,
                     colliders,
                     query_pipeline,
-                    (&scaled_shape).into(),
+                    (&*scaled_shape).into(),
                     &(shape_translation, shape_rotation).into(),
                     movement.into(),
                     filter,
@@ -517,7 +1491,7 @@ impl RapierWorld {
                             bodies,
                             colliders,
                             query_pipeline,
-                            (&scaled_shape).into(),
+                            (&*scaled_shape).into(),
                             shape_mass,
                             collision,
                             filter,
@@ -525,7 +1499,15 @@ impl RapierWorld {
                     }
                 }
 
-                result
+                let step_and_snap = step_and_snap_heights(
+                    &controller,
+                    &movement.into(),
+                    &result,
+                    collisions,
+                    epsilon,
+                );
+
+                (result, step_and_snap)
             },
         );
 
@@ -533,6 +1515,8 @@ impl RapierWorld {
             effective_translation: result.translation.into(),
             grounded: result.grounded,
             is_sliding_down_slope: result.is_sliding_down_slope,
+            stepped_up,
+            snapped_to_ground,
         }
     }
 
@@ -607,6 +1591,86 @@ impl RapierWorld {
             .map(|e| (e, RayIntersection::from_rapier(result, ray_origin, ray_dir)))
     }
 
+    /// Same as [`Self::cast_ray_and_get_normal`], but also records the ray, the hit point, and
+    /// the hit normal into `buffer`, to be drawn for one frame by the debug-renderer.
+    ///
+    /// Nothing is recorded if the debug-render features are disabled.
+    pub fn cast_ray_debug(
+        &self,
+        buffer: &mut DebugCastBuffer,
+        ray_origin: Vect,
+        ray_dir: Vect,
+        max_toi: Real,
+        solid: bool,
+        filter: QueryFilter,
+    ) -> Option<(Entity, RayIntersection)> {
+        let result = self.cast_ray_and_get_normal(ray_origin, ray_dir, max_toi, solid, filter);
+
+        #[cfg(any(feature = "debug-render-3d", feature = "debug-render-2d"))]
+        buffer.push(|| DebugCast::Ray {
+            origin: ray_origin,
+            dir: ray_dir,
+            time_of_impact: result
+                .as_ref()
+                .map_or(max_toi, |(_, hit)| hit.time_of_impact),
+            hit: result.as_ref().map(|(_, hit)| (hit.point, hit.normal)),
+        });
+        #[cfg(not(any(feature = "debug-render-3d", feature = "debug-render-2d")))]
+        let _ = buffer;
+
+        result
+    }
+
+    /// Casts a ray from `camera` through the point `screen_pos` of its viewport, and returns the
+    /// first collider hit.
+    ///
+    /// `screen_pos` is in logical pixels, relative to the top-left corner of the viewport: this
+    /// is the `Window::cursor_position` for a camera rendering to the whole window. Both
+    /// perspective and orthographic projections are supported.
+    ///
+    /// In 2D, the ray would be orthogonal to the simulation plane. Instead, this returns a
+    /// collider containing the unprojected point, with a time-of-impact and a normal of zero;
+    /// `max_toi` and `solid` are ignored.
+    ///
+    /// Returns `None` if nothing was hit, or if `screen_pos` can’t be unprojected by `camera`.
+    #[cfg(not(feature = "headless"))]
+    pub fn cast_ray_from_screen(
+        &self,
+        camera: &Camera,
+        camera_transform: &GlobalTransform,
+        screen_pos: Vec2,
+        max_toi: Real,
+        solid: bool,
+        filter: QueryFilter,
+    ) -> Option<(Entity, RayIntersection)> {
+        #[cfg(feature = "dim2")]
+        {
+            let _ = (max_toi, solid);
+            let point = camera.viewport_to_world_2d(camera_transform, screen_pos)?;
+            let mut hit = None;
+            self.intersections_with_point(point, filter, |entity| {
+                hit = Some(entity);
+                false
+            });
+
+            hit.map(|entity| {
+                let intersection = RayIntersection {
+                    time_of_impact: 0.0,
+                    point,
+                    normal: Vect::ZERO,
+                    feature: FeatureId::Unknown,
+                };
+                (entity, intersection)
+            })
+        }
+
+        #[cfg(feature = "dim3")]
+        {
+            let ray = camera.viewport_to_world(camera_transform, screen_pos)?;
+            self.cast_ray_and_get_normal(ray.origin, *ray.direction, max_toi, solid, filter)
+        }
+    }
+
     /// Find the all intersections between a ray and a set of collider and passes them to a callback.
     ///
     /// # Parameters
@@ -665,10 +1729,7 @@ impl RapierWorld {
         filter: QueryFilter,
     ) -> Option<Entity> {
         let scaled_transform = (shape_pos, shape_rot).into();
-        let mut scaled_shape = shape.clone();
-        // TODO: how to set a good number of subdivisions, we don’t have access to the
-        //       RapierConfiguration::scaled_shape_subdivision here.
-        scaled_shape.set_scale(shape.scale, 20);
+        let scaled_shape = shape.rescaled(self.scaled_shape_subdivision);
 
         let h = self.with_query_filter(filter, move |filter| {
             self.query_pipeline.intersection_with_shape(
@@ -832,10 +1893,7 @@ impl RapierWorld {
         filter: QueryFilter,
     ) -> Option<(Entity, ShapeCastHit)> {
         let scaled_transform = (shape_pos, shape_rot).into();
-        let mut scaled_shape = shape.clone();
-        // TODO: how to set a good number of subdivisions, we don’t have access to the
-        //       RapierConfiguration::scaled_shape_subdivision here.
-        scaled_shape.set_scale(shape.scale, 20);
+        let scaled_shape = shape.rescaled(self.scaled_shape_subdivision);
 
         let (h, result) = self.with_query_filter(filter, move |filter| {
             self.query_pipeline.cast_shape(
@@ -857,6 +1915,40 @@ impl RapierWorld {
         })
     }
 
+    /// Same as [`Self::cast_shape`], but also records the shape at its initial position and at
+    /// its time of impact into `buffer`, to be drawn for one frame by the debug-renderer.
+    ///
+    /// Nothing is recorded if the debug-render features are disabled.
+    #[allow(clippy::too_many_arguments)]
+    pub fn cast_shape_debug(
+        &self,
+        buffer: &mut DebugCastBuffer,
+        shape_pos: Vect,
+        shape_rot: Rot,
+        shape_vel: Vect,
+        shape: &Collider,
+        options: ShapeCastOptions,
+        filter: QueryFilter,
+    ) -> Option<(Entity, ShapeCastHit)> {
+        let result = self.cast_shape(shape_pos, shape_rot, shape_vel, shape, options, filter);
+
+        #[cfg(any(feature = "debug-render-3d", feature = "debug-render-2d"))]
+        buffer.push(|| DebugCast::Shape {
+            shape: shape.clone(),
+            start: shape_pos,
+            vel: shape_vel,
+            time_of_impact: result
+                .as_ref()
+                .map_or(options.max_time_of_impact, |(_, hit)| hit.time_of_impact),
+            rotation: shape_rot,
+            hit: result.is_some(),
+        });
+        #[cfg(not(any(feature = "debug-render-3d", feature = "debug-render-2d")))]
+        let _ = buffer;
+
+        result
+    }
+
     /* TODO: we need to wrap the NonlinearRigidMotion somehow.
      *
     /// Casts a shape with an arbitrary continuous motion and retrieve the first collider it hits.
@@ -887,10 +1979,7 @@ impl RapierWorld {
         filter: QueryFilter,
     ) -> Option<(Entity, Toi)> {
         let scaled_transform = (shape_pos, shape_rot).into();
-        let mut scaled_shape = shape.clone();
-        // TODO: how to set a good number of subdivisions, we don’t have access to the
-        //       RapierConfiguration::scaled_shape_subdivision here.
-        scaled_shape.set_scale(shape.scale, 20);
+        let scaled_shape = shape.rescaled(self.scaled_shape_subdivision);
 
         let (h, result) = self.with_query_filter(filter, move |filter| {
             self.query_pipeline.nonlinear_cast_shape(
@@ -926,10 +2015,7 @@ impl RapierWorld {
         mut callback: impl FnMut(Entity) -> bool,
     ) {
         let scaled_transform = (shape_pos, shape_rot).into();
-        let mut scaled_shape = shape.clone();
-        // TODO: how to set a good number of subdivisions, we don’t have access to the
-        //       RapierConfiguration::scaled_shape_subdivision here.
-        scaled_shape.set_scale(shape.scale, 20);
+        let scaled_shape = shape.rescaled(self.scaled_shape_subdivision);
 
         #[allow(clippy::redundant_closure)]
         // False-positive, we can't move callback, closure becomes `FnOnce`
@@ -946,31 +2032,350 @@ impl RapierWorld {
             )
         });
     }
+
+    /// Applies an impulse pushing the dynamic rigid-bodies around `center` away from it, like an
+    /// explosion, and wakes them up.
+    ///
+    /// The rigid-bodies with a collider within `radius` of `center` are affected. The impulse
+    /// applied to each of them is `strength` scaled by the `falloff`, depending on the distance
+    /// between `center` and the closest point of their colliders. It is applied at the
+    /// center-of-mass of the rigid-bodies, unless [`RadialImpulseFlags::AT_CLOSEST_POINT`] is set.
+    ///
+    /// Returns the entities of the rigid-bodies the impulse was applied to.
+    ///
+    /// # Parameters
+    /// * `center` - The center of the impulse.
+    /// * `radius` - The distance beyond which the rigid-bodies aren’t affected.
+    /// * `strength` - The magnitude of the impulse at the center.
+    /// * `falloff` - How the magnitude decreases with the distance to the center.
+    /// * `flags` - Whether the impulse is applied at the closest points and is occluded by the
+    ///   colliders between the center and the rigid-bodies.
+    /// * `filter`: set of rules used to determine which collider is taken into account by this
+    ///   scene query, including the occlusion tests.
+    pub fn apply_radial_impulse(
+        &mut self,
+        center: Vect,
+        radius: Real,
+        strength: Real,
+        falloff: Falloff,
+        flags: RadialImpulseFlags,
+        filter: QueryFilter,
+    ) -> Vec<Entity> {
+        let center_point: Point<Real> = center.into();
+        let sphere = Ball::new(radius);
+        let shape_pos = (center, Rot::default()).into();
+
+        // The closest collider of each rigid-body, with its distance to the center.
+        let mut closest: HashMap<RigidBodyHandle, (Real, Point<Real>)> = HashMap::new();
+        self.with_query_filter(filter, |filter| {
+            let mut hits = vec![];
+            self.query_pipeline.intersections_with_shape(
+                &self.bodies,
+                &self.colliders,
+                &shape_pos,
+                &sphere,
+                filter,
+                |handle| {
+                    hits.push(handle);
+                    true
+                },
+            );
+
+            for handle in hits {
+                let co = &self.colliders[handle];
+                let Some(body) = co
+                    .parent()
+                    .filter(|body| self.bodies.get(*body).is_some_and(|rb| rb.is_dynamic()))
+                else {
+                    continue;
+                };
+
+                let point = co
+                    .shape()
+                    .project_point(co.position(), &center_point, true)
+                    .point;
+                let distance = (point - center_point).norm();
+                if closest.get(&body).is_some_and(|(d, _)| *d <= distance) {
+                    continue;
+                }
+
+                if flags.contains(RadialImpulseFlags::OCCLUSION) && distance > 0.0 {
+                    let ray = Ray::new(center_point, (point - center_point) / distance);
+                    let occluder = self.query_pipeline.cast_ray(
+                        &self.bodies,
+                        &self.colliders,
+                        &ray,
+                        distance,
+                        true,
+                        filter,
+                    );
+                    // The ray may hit another collider of the same rigid-body first.
+                    if occluder.is_some_and(|(hit, _)| self.colliders[hit].parent() != Some(body)) {
+                        continue;
+                    }
+                }
+
+                closest.insert(body, (distance, point));
+            }
+        });
+
+        let mut pushed = vec![];
+        for (body, (distance, point)) in closest {
+            let Some(rb) = self.bodies.get_mut(body) else {
+                continue;
+            };
+
+            let magnitude = strength * falloff.factor(distance, radius);
+            let com = *rb.center_of_mass();
+            let at_closest_point = flags.contains(RadialImpulseFlags::AT_CLOSEST_POINT);
+            let application_point = if at_closest_point { point } else { com };
+
+            // Push along the direction from the center to the rigid-body, falling back to the
+            // direction of its center-of-mass if the center is inside the collider.
+            let Some(direction) = (application_point - center_point)
+                .try_normalize(Real::EPSILON)
+                .or_else(|| (com - center_point).try_normalize(Real::EPSILON))
+            else {
+                continue;
+            };
+
+            let impulse = direction * magnitude;
+            if at_closest_point {
+                rb.apply_impulse_at_point(impulse, application_point, true);
+            } else {
+                rb.apply_impulse(impulse, true);
+            }
+
+            pushed.extend(self.rigid_body_entity(body));
+        }
+
+        pushed
+    }
 }
 
 #[derive(Debug)]
 pub enum WorldError {
-    WorldNotFound { world_id: WorldId },
+    WorldNotFound {
+        world_id: WorldId,
+    },
+    /// The entity isn’t a rigid-body of the world.
+    RigidBodyNotFound {
+        /// The entity without a rigid-body.
+        entity: Entity,
+        /// The world that was searched.
+        world_id: WorldId,
+    },
 }
 
 impl fmt::Display for WorldError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             Self::WorldNotFound { world_id } => write!(f, "World with id {world_id} not found."),
+            Self::RigidBodyNotFound { entity, world_id } => write!(
+                f,
+                "Entity {entity:?} has no rigid-body in the world with id {world_id}."
+            ),
         }
     }
 }
 
 impl std::error::Error for WorldError {}
 
+/// An inconsistency between the entity-to-handle maps of a [`RapierWorld`] and its Rapier sets.
+///
+/// See [`RapierWorld::validate`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ConsistencyError {
+    /// The rigid-body mapped to `entity` doesn’t exist.
+    MissingRigidBody {
+        /// The entity mapped to the missing rigid-body.
+        entity: Entity,
+        /// The handle of the missing rigid-body.
+        handle: RigidBodyHandle,
+    },
+    /// The rigid-body mapped to `entity` is attached to another entity.
+    RigidBodyEntityMismatch {
+        /// The entity mapped to the rigid-body.
+        entity: Entity,
+        /// The handle of the rigid-body.
+        handle: RigidBodyHandle,
+        /// The entity stored in the user-data of the rigid-body, if it is a valid entity.
+        owner: Option<Entity>,
+    },
+    /// Several entities are mapped to the same rigid-body.
+    SharedRigidBodyHandle {
+        /// Two of the entities mapped to the rigid-body.
+        entities: [Entity; 2],
+        /// The handle of the shared rigid-body.
+        handle: RigidBodyHandle,
+    },
+    /// The collider mapped to `entity` doesn’t exist.
+    MissingCollider {
+        /// The entity mapped to the missing collider.
+        entity: Entity,
+        /// The handle of the missing collider.
+        handle: ColliderHandle,
+    },
+    /// The collider mapped to `entity` is attached to another entity.
+    ColliderEntityMismatch {
+        /// The entity mapped to the collider.
+        entity: Entity,
+        /// The handle of the collider.
+        handle: ColliderHandle,
+        /// The entity stored in the user-data of the collider, if it is a valid entity.
+        owner: Option<Entity>,
+    },
+    /// Several entities are mapped to the same collider.
+    SharedColliderHandle {
+        /// Two of the entities mapped to the collider.
+        entities: [Entity; 2],
+        /// The handle of the shared collider.
+        handle: ColliderHandle,
+    },
+    /// The impulse joint mapped to `entity` doesn’t exist.
+    MissingImpulseJoint {
+        /// The entity mapped to the missing joint.
+        entity: Entity,
+        /// The handle of the missing joint.
+        handle: ImpulseJointHandle,
+    },
+    /// Several entities are mapped to the same impulse joint.
+    SharedImpulseJointHandle {
+        /// Two of the entities mapped to the joint.
+        entities: [Entity; 2],
+        /// The handle of the shared joint.
+        handle: ImpulseJointHandle,
+    },
+    /// The multibody joint mapped to `entity` doesn’t exist.
+    MissingMultibodyJoint {
+        /// The entity mapped to the missing joint.
+        entity: Entity,
+        /// The handle of the missing joint.
+        handle: MultibodyJointHandle,
+    },
+    /// Several entities are mapped to the same multibody joint.
+    SharedMultibodyJointHandle {
+        /// Two of the entities mapped to the joint.
+        entities: [Entity; 2],
+        /// The handle of the shared joint.
+        handle: MultibodyJointHandle,
+    },
+    /// A transform is still stored for a rigid-body that no longer exists.
+    StaleLastBodyTransform {
+        /// The handle of the removed rigid-body.
+        handle: RigidBodyHandle,
+    },
+}
+
+impl fmt::Display for ConsistencyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::MissingRigidBody { entity, handle } => {
+                write!(
+                    f,
+                    "Entity {entity:?} is mapped to the missing rigid-body {handle:?}."
+                )
+            }
+            Self::RigidBodyEntityMismatch {
+                entity,
+                handle,
+                owner,
+            } => write!(
+                f,
+                "Entity {entity:?} is mapped to the rigid-body {handle:?} of entity {owner:?}."
+            ),
+            Self::SharedRigidBodyHandle { entities, handle } => write!(
+                f,
+                "Entities {:?} and {:?} are both mapped to the rigid-body {handle:?}.",
+                entities[0], entities[1]
+            ),
+            Self::MissingCollider { entity, handle } => {
+                write!(
+                    f,
+                    "Entity {entity:?} is mapped to the missing collider {handle:?}."
+                )
+            }
+            Self::ColliderEntityMismatch {
+                entity,
+                handle,
+                owner,
+            } => write!(
+                f,
+                "Entity {entity:?} is mapped to the collider {handle:?} of entity {owner:?}."
+            ),
+            Self::SharedColliderHandle { entities, handle } => write!(
+                f,
+                "Entities {:?} and {:?} are both mapped to the collider {handle:?}.",
+                entities[0], entities[1]
+            ),
+            Self::MissingImpulseJoint { entity, handle } => write!(
+                f,
+                "Entity {entity:?} is mapped to the missing impulse joint {handle:?}."
+            ),
+            Self::SharedImpulseJointHandle { entities, handle } => write!(
+                f,
+                "Entities {:?} and {:?} are both mapped to the impulse joint {handle:?}.",
+                entities[0], entities[1]
+            ),
+            Self::MissingMultibodyJoint { entity, handle } => write!(
+                f,
+                "Entity {entity:?} is mapped to the missing multibody joint {handle:?}."
+            ),
+            Self::SharedMultibodyJointHandle { entities, handle } => write!(
+                f,
+                "Entities {:?} and {:?} are both mapped to the multibody joint {handle:?}.",
+                entities[0], entities[1]
+            ),
+            Self::StaleLastBodyTransform { handle } => write!(
+                f,
+                "A transform is stored for the removed rigid-body {handle:?}."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConsistencyError {}
+
+/// A FNV-1a hasher. Unlike the hashers of the standard library, its results are the same on every
+/// platform and Rust version.
+struct StateHasher(u64);
+
+impl Default for StateHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl StateHasher {
+    fn write_u64(&mut self, value: u64) {
+        for byte in value.to_le_bytes() {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_reals(&mut self, values: impl IntoIterator<Item = Real>) {
+        for value in values {
+            self.write_u64(value.to_bits() as u64);
+        }
+    }
+}
+
 /// The Rapier context, containing all the state of the physics engine.
 #[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 #[derive(Resource)]
 pub struct RapierContext {
     /// Stores all the worlds in the simulation.
-    pub worlds: HashMap<WorldId, RapierWorld>,
+    ///
+    /// The worlds are ordered by id, so they are always stepped in the same order.
+    pub worlds: BTreeMap<WorldId, RapierWorld>,
 
     next_world_id: WorldId,
+    #[cfg_attr(feature = "serde-serialize", serde(skip))]
+    requested_steps: u32,
+    // Set by the `RapierDiagnosticsPlugin`.
+    #[cfg_attr(feature = "serde-serialize", serde(skip))]
+    pub(crate) measure_step_time: bool,
 }
 
 impl RapierContext {}
@@ -984,15 +2389,34 @@ impl Default for RapierContext {
 impl RapierContext {
     /// Creates a new RapierContext with a custom starting world
     pub fn new(world: RapierWorld) -> Self {
-        let mut worlds = HashMap::new();
+        let mut worlds = BTreeMap::new();
         worlds.insert(DEFAULT_WORLD_ID, world);
 
         Self {
             worlds,
             next_world_id: WorldId::new(1),
+            requested_steps: 0,
+            measure_step_time: false,
         }
     }
 
+    /// Requests `num_steps` more steps of the simulation, with [`TimestepMode::Manual`].
+    ///
+    /// The requested steps are all executed the next time the simulation is stepped.
+    pub fn request_step(&mut self, num_steps: u32) {
+        self.requested_steps = self.requested_steps.saturating_add(num_steps);
+    }
+
+    /// The number of steps requested with [`Self::request_step`] that were not executed yet.
+    pub fn requested_steps(&self) -> u32 {
+        self.requested_steps
+    }
+
+    /// Resets the number of requested steps, returning its previous value.
+    pub(crate) fn take_requested_steps(&mut self) -> u32 {
+        std::mem::take(&mut self.requested_steps)
+    }
+
     /// Adds a world to the simulation
     ///
     /// Returns that world's id
@@ -1016,6 +2440,34 @@ impl RapierContext {
             .ok_or(WorldError::WorldNotFound { world_id })
     }
 
+    /// Checks the consistency of every world, see [`RapierWorld::validate`].
+    ///
+    /// Returns every inconsistency found, along with the id of the world it was found in.
+    pub fn validate_all(&self) -> Vec<(WorldId, ConsistencyError)> {
+        self.worlds
+            .iter()
+            .flat_map(|(world_id, world)| {
+                world
+                    .validate()
+                    .into_iter()
+                    .map(move |error| (*world_id, error))
+            })
+            .collect()
+    }
+
+    /// A hash of the state of every world, combined in the order of their ids.
+    ///
+    /// See [`RapierWorld::state_hash`]: the hash is only meaningful with the
+    /// `enhanced-determinism` feature.
+    pub fn state_hash(&self) -> u64 {
+        let mut hasher = StateHasher::default();
+        for (world_id, world) in self.worlds.iter() {
+            hasher.write_u64(world_id.0 as u64);
+            world.hash_state(&mut hasher);
+        }
+        hasher.0
+    }
+
     /// Gets the world at the given id. If the world does not exist, an Err result will be returned
     pub fn get_world(&self, world_id: WorldId) -> Result<&RapierWorld, WorldError> {
         self.worlds
@@ -1067,6 +2519,71 @@ impl RapierContext {
         }
     }
 
+    /// Welds the rigid-bodies of `entity1` and `entity2` together, in their current relative pose.
+    ///
+    /// This spawns a child of `entity2` with an [`ImpulseJoint`] attaching it to `entity1` with
+    /// a fixed joint, and returns it. The joint is created by the physics systems, like any other
+    /// [`ImpulseJoint`], and the contacts between the two rigid-bodies are disabled. Despawn the
+    /// returned entity to break the weld.
+    ///
+    /// Returns an error if either entity isn’t a rigid-body of the world.
+    pub fn weld_entities(
+        &self,
+        world_id: WorldId,
+        entity1: Entity,
+        entity2: Entity,
+        commands: &mut Commands,
+    ) -> Result<Entity, WorldError> {
+        let world = self.get_world(world_id)?;
+        let body_position = |entity| {
+            world
+                .entity2body
+                .get(&entity)
+                .and_then(|handle| world.bodies.get(*handle))
+                .map(|rb| *rb.position())
+                .ok_or(WorldError::RigidBodyNotFound { entity, world_id })
+        };
+
+        let pos1 = body_position(entity1)?;
+        let pos2 = body_position(entity2)?;
+        // The pose of the second rigid-body, relative to the first one.
+        let frame1 = utils::iso_to_transform(&pos1.inv_mul(&pos2));
+        #[cfg(feature = "dim2")]
+        let (anchor, basis) = (
+            frame1.translation.truncate(),
+            frame1.rotation.to_scaled_axis().z,
+        );
+        #[cfg(feature = "dim3")]
+        let (anchor, basis) = (frame1.translation, frame1.rotation);
+
+        let mut joint = FixedJointBuilder::new()
+            .local_anchor1(anchor)
+            .local_basis1(basis)
+            .build();
+        joint.set_contacts_enabled(false);
+
+        let mut joint_entity = commands.spawn((
+            TransformBundle::default(),
+            ImpulseJoint::new(entity1, joint),
+        ));
+        if world_id != DEFAULT_WORLD_ID {
+            joint_entity.insert(PhysicsWorld { world_id });
+        }
+        joint_entity.set_parent(entity2);
+
+        Ok(joint_entity.id())
+    }
+
+    /// The linear velocity of a world-space point of the rigid-body attached to `entity`, taking
+    /// its angular velocity and current center-of-mass into account.
+    ///
+    /// Returns `None` if `entity` isn’t a rigid-body of any world.
+    pub fn velocity_at_point(&self, entity: Entity, point: Vect) -> Option<Vect> {
+        self.worlds
+            .values()
+            .find_map(|world| world.velocity_at_point(entity, point))
+    }
+
     /// Retrieve the Bevy entity the given Rapier collider (identified by its handle) is attached.
     pub fn collider_entity(&self, handle: ColliderHandle) -> Option<Entity> {
         for (_, world) in self.worlds.iter() {
@@ -1091,6 +2608,50 @@ impl RapierContext {
         None
     }
 
+    /// The current position of the multibody joint attached to `entity` along each of its free axes.
+    ///
+    /// See [`RapierWorld::multibody_joint_positions`] for details.
+    pub fn multibody_joint_positions(
+        &self,
+        world_id: WorldId,
+        entity: Entity,
+    ) -> Result<Option<Vec<Real>>, WorldError> {
+        self.worlds
+            .get(&world_id)
+            .map_or(Err(WorldError::WorldNotFound { world_id }), |world| {
+                Ok(world.multibody_joint_positions(entity))
+            })
+    }
+
+    /// Teleports the multibody joint attached to `entity` to the given position along each of
+    /// its free axes.
+    ///
+    /// See [`RapierWorld::set_multibody_joint_positions`] for details.
+    pub fn set_multibody_joint_positions(
+        &mut self,
+        world_id: WorldId,
+        entity: Entity,
+        positions: &[Real],
+    ) -> Result<bool, WorldError> {
+        self.worlds
+            .get_mut(&world_id)
+            .map_or(Err(WorldError::WorldNotFound { world_id }), |world| {
+                Ok(world.set_multibody_joint_positions(entity, positions))
+            })
+    }
+
+    /// Installs a custom handler receiving the raw Rapier events emitted by the given world.
+    ///
+    /// See [`RapierWorld::set_event_handler`].
+    pub fn set_event_handler(
+        &mut self,
+        world_id: WorldId,
+        handler: Box<dyn EventHandler>,
+    ) -> Result<(), WorldError> {
+        self.get_world_mut(world_id)?.set_event_handler(handler);
+        Ok(())
+    }
+
     /// Retrieve the Bevy entity the given Rapier rigid-body (identified by its handle) is attached.
     ///
     /// Returns None if this world does not exist
@@ -1175,7 +2736,7 @@ impl RapierContext {
     pub fn entity2body(
         &self,
         world_id: WorldId,
-    ) -> Result<&HashMap<Entity, RigidBodyHandle>, WorldError> {
+    ) -> Result<&EntityHashMap<RigidBodyHandle>, WorldError> {
         self.worlds
             .get(&world_id)
             .map_or(Err(WorldError::WorldNotFound { world_id }), |x| {
@@ -1187,7 +2748,7 @@ impl RapierContext {
     pub fn entity2collider(
         &self,
         world_id: WorldId,
-    ) -> Result<&HashMap<Entity, ColliderHandle>, WorldError> {
+    ) -> Result<&EntityHashMap<ColliderHandle>, WorldError> {
         self.worlds
             .get(&world_id)
             .map_or(Err(WorldError::WorldNotFound { world_id }), |x| {
@@ -1199,7 +2760,7 @@ impl RapierContext {
     pub fn entity2impulse_joint(
         &self,
         world_id: WorldId,
-    ) -> Result<&HashMap<Entity, ImpulseJointHandle>, WorldError> {
+    ) -> Result<&EntityHashMap<ImpulseJointHandle>, WorldError> {
         self.worlds
             .get(&world_id)
             .map_or(Err(WorldError::WorldNotFound { world_id }), |x| {
@@ -1211,7 +2772,7 @@ impl RapierContext {
     pub fn entity2multibody_joint(
         &self,
         world_id: WorldId,
-    ) -> Result<&HashMap<Entity, MultibodyJointHandle>, WorldError> {
+    ) -> Result<&EntityHashMap<MultibodyJointHandle>, WorldError> {
         self.worlds
             .get(&world_id)
             .map_or(Err(WorldError::WorldNotFound { world_id }), |x| {
@@ -1317,6 +2878,58 @@ impl RapierContext {
             })
     }
 
+    /// Same as [`Self::cast_ray_and_get_normal`], but also records the ray, the hit point, and
+    /// the hit normal into `buffer`, to be drawn for one frame by the debug-renderer.
+    ///
+    /// See [`RapierWorld::cast_ray_debug`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn cast_ray_debug(
+        &self,
+        world_id: WorldId,
+        buffer: &mut DebugCastBuffer,
+        ray_origin: Vect,
+        ray_dir: Vect,
+        max_toi: Real,
+        solid: bool,
+        filter: QueryFilter,
+    ) -> Result<Option<(Entity, RayIntersection)>, WorldError> {
+        self.worlds
+            .get(&world_id)
+            .map_or(Err(WorldError::WorldNotFound { world_id }), |world| {
+                Ok(world.cast_ray_debug(buffer, ray_origin, ray_dir, max_toi, solid, filter))
+            })
+    }
+
+    /// Casts a ray from `camera` through the point `screen_pos` of its viewport, and returns the
+    /// first collider hit.
+    ///
+    /// See [`RapierWorld::cast_ray_from_screen`].
+    #[cfg(not(feature = "headless"))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn cast_ray_from_screen(
+        &self,
+        world_id: WorldId,
+        camera: &Camera,
+        camera_transform: &GlobalTransform,
+        screen_pos: Vec2,
+        max_toi: Real,
+        solid: bool,
+        filter: QueryFilter,
+    ) -> Result<Option<(Entity, RayIntersection)>, WorldError> {
+        self.worlds
+            .get(&world_id)
+            .map_or(Err(WorldError::WorldNotFound { world_id }), |world| {
+                Ok(world.cast_ray_from_screen(
+                    camera,
+                    camera_transform,
+                    screen_pos,
+                    max_toi,
+                    solid,
+                    filter,
+                ))
+            })
+    }
+
     /// Find the all intersections between a ray and a set of collider and passes them to a callback.
     ///
     /// # Parameters
@@ -1490,6 +3103,31 @@ impl RapierContext {
             })
     }
 
+    /// Same as [`Self::cast_shape`], but also records the shape at its initial position and at
+    /// its time of impact into `buffer`, to be drawn for one frame by the debug-renderer.
+    ///
+    /// See [`RapierWorld::cast_shape_debug`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn cast_shape_debug(
+        &self,
+        world_id: WorldId,
+        buffer: &mut DebugCastBuffer,
+        shape_pos: Vect,
+        shape_rot: Rot,
+        shape_vel: Vect,
+        shape: &Collider,
+        options: ShapeCastOptions,
+        filter: QueryFilter,
+    ) -> Result<Option<(Entity, ShapeCastHit)>, WorldError> {
+        self.worlds
+            .get(&world_id)
+            .map_or(Err(WorldError::WorldNotFound { world_id }), |world| {
+                Ok(world.cast_shape_debug(
+                    buffer, shape_pos, shape_rot, shape_vel, shape, options, filter,
+                ))
+            })
+    }
+
     /* TODO: we need to wrap the NonlinearRigidMotion somehow.
      *
     /// Casts a shape with an arbitrary continuous motion and retrieve the first collider it hits.
@@ -1552,4 +3190,24 @@ impl RapierContext {
                 Ok(())
             })
     }
+
+    /// Applies an impulse pushing the dynamic rigid-bodies around `center` away from it, in the
+    /// given world.
+    ///
+    /// See [`RapierWorld::apply_radial_impulse`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn apply_radial_impulse(
+        &mut self,
+        world_id: WorldId,
+        center: Vect,
+        radius: Real,
+        strength: Real,
+        falloff: Falloff,
+        flags: RadialImpulseFlags,
+        filter: QueryFilter,
+    ) -> Result<Vec<Entity>, WorldError> {
+        self.get_world_mut(world_id).map(|world| {
+            world.apply_radial_impulse(center, radius, strength, falloff, flags, filter)
+        })
+    }
 }
@@ -0,0 +1,194 @@
+use crate::plugin::{PhysicsSet, RapierContext, RapierWorld, WorldId};
+use bevy::diagnostic::{
+    Diagnostic, DiagnosticId, DiagnosticMeasurement, DiagnosticsStore, DEFAULT_MAX_HISTORY_LENGTH,
+};
+use bevy::prelude::*;
+use bevy::utils::{HashMap, Instant};
+use rapier::dynamics::RigidBodyHandle;
+
+/// A plugin recording the state of the physics worlds into Bevy’s [`DiagnosticsStore`], so
+/// they can be displayed by the `LogDiagnosticsPlugin` for example.
+///
+/// Each [`RapierDiagnostic`] is recorded for each world (with a name like
+/// `rapier/world_0/step_time_ms`), and summed over all the worlds (with a name like
+/// `rapier/all/step_time_ms`). Their ids are given by [`RapierDiagnostic::id`].
+#[derive(Default)]
+pub struct RapierDiagnosticsPlugin;
+
+impl Plugin for RapierDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DiagnosticsStore>().add_systems(
+            PostUpdate,
+            record_diagnostics.after(PhysicsSet::StepSimulation),
+        );
+    }
+
+    fn finish(&self, app: &mut App) {
+        // Only measure the duration of the steps if someone is interested.
+        app.world.resource_mut::<RapierContext>().measure_step_time = true;
+    }
+}
+
+/// The values recorded by the [`RapierDiagnosticsPlugin`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RapierDiagnostic {
+    /// The number of rigid-bodies.
+    Bodies,
+    /// The number of rigid-bodies that aren’t sleeping.
+    ActiveBodies,
+    /// The number of colliders.
+    Colliders,
+    /// The number of impulse and multibody joints.
+    Joints,
+    /// The number of contact pairs with at least one active contact.
+    ContactPairs,
+    /// The number of islands of rigid-bodies that aren’t sleeping.
+    Islands,
+    /// The time spent stepping the simulation during the last frame, in milliseconds.
+    StepTimeMs,
+}
+
+impl RapierDiagnostic {
+    /// All the values recorded by the [`RapierDiagnosticsPlugin`].
+    pub const ALL: [Self; 7] = [
+        Self::Bodies,
+        Self::ActiveBodies,
+        Self::Colliders,
+        Self::Joints,
+        Self::ContactPairs,
+        Self::Islands,
+        Self::StepTimeMs,
+    ];
+
+    /// The last segment of the name of this diagnostic.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Bodies => "bodies",
+            Self::ActiveBodies => "active_bodies",
+            Self::Colliders => "colliders",
+            Self::Joints => "joints",
+            Self::ContactPairs => "contact_pairs",
+            Self::Islands => "islands",
+            Self::StepTimeMs => "step_time_ms",
+        }
+    }
+
+    /// The id of this diagnostic for the given world, or for all the worlds if `world_id` is
+    /// `None`.
+    pub fn id(self, world_id: Option<WorldId>) -> DiagnosticId {
+        const BASE: u128 = 0x6ef9_2d5c_4a41_4b8e_9f3c_0000_0000_0000;
+        // Keep 0 for the sum over all the worlds.
+        let world = world_id.map_or(0, |id| id.0 as u128 + 1);
+        DiagnosticId::from_u128(BASE | ((world & 0xffff_ffff) << 8) | self as u128)
+    }
+
+    fn full_name(self, world_id: Option<WorldId>) -> String {
+        match world_id {
+            Some(world_id) => format!("rapier/world_{world_id}/{}", self.name()),
+            None => format!("rapier/all/{}", self.name()),
+        }
+    }
+
+    fn measure(self, world: &RapierWorld) -> f64 {
+        match self {
+            Self::Bodies => world.bodies.len() as f64,
+            Self::ActiveBodies => {
+                (world.islands.active_dynamic_bodies().len()
+                    + world.islands.active_kinematic_bodies().len()) as f64
+            }
+            Self::Colliders => world.colliders.len() as f64,
+            Self::Joints => {
+                (world.impulse_joints.len() + world.multibody_joints.iter().count()) as f64
+            }
+            Self::ContactPairs => world
+                .narrow_phase
+                .contact_pairs()
+                .filter(|pair| pair.has_any_active_contact)
+                .count() as f64,
+            Self::Islands => num_active_islands(world) as f64,
+            Self::StepTimeMs => world.last_step_duration.as_secs_f64() * 1000.0,
+        }
+    }
+}
+
+/// The number of groups of active dynamic rigid-bodies connected by contacts, impulse joints,
+/// or multibody joints.
+fn num_active_islands(world: &RapierWorld) -> usize {
+    fn find(parents: &mut [usize], mut i: usize) -> usize {
+        while parents[i] != i {
+            parents[i] = parents[parents[i]];
+            i = parents[i];
+        }
+        i
+    }
+
+    let active = world.islands.active_dynamic_bodies();
+    let indices: HashMap<RigidBodyHandle, usize> =
+        active.iter().enumerate().map(|(i, h)| (*h, i)).collect();
+    let mut parents: Vec<usize> = (0..active.len()).collect();
+
+    let mut union = |body1: Option<RigidBodyHandle>, body2: Option<RigidBodyHandle>| {
+        let i1 = body1.and_then(|h| indices.get(&h));
+        let i2 = body2.and_then(|h| indices.get(&h));
+        if let (Some(i1), Some(i2)) = (i1, i2) {
+            let (root1, root2) = (find(&mut parents, *i1), find(&mut parents, *i2));
+            parents[root1] = root2;
+        }
+    };
+
+    for pair in world.narrow_phase.contact_pairs() {
+        if pair.has_any_active_contact {
+            let body = |handle| world.colliders.get(handle).and_then(|co| co.parent());
+            union(body(pair.collider1), body(pair.collider2));
+        }
+    }
+
+    for (_, joint) in world.impulse_joints.iter() {
+        union(Some(joint.body1), Some(joint.body2));
+    }
+
+    for (_, _, multibody, link) in world.multibody_joints.iter() {
+        let parent = link
+            .parent_id()
+            .and_then(|id| multibody.link(id))
+            .map(|parent| parent.rigid_body_handle());
+        union(parent, Some(link.rigid_body_handle()));
+    }
+
+    (0..active.len())
+        .filter(|i| find(&mut parents, *i) == *i)
+        .count()
+}
+
+/// System recording the [`RapierDiagnostic`]s of every world.
+pub fn record_diagnostics(context: Res<RapierContext>, mut store: ResMut<DiagnosticsStore>) {
+    let time = Instant::now();
+    let mut totals = [0.0; RapierDiagnostic::ALL.len()];
+
+    let mut record = |diagnostic: RapierDiagnostic, world_id: Option<WorldId>, value: f64| {
+        let id = diagnostic.id(world_id);
+        if store.get(id).is_none() {
+            store.add(Diagnostic::new(
+                id,
+                diagnostic.full_name(world_id),
+                DEFAULT_MAX_HISTORY_LENGTH,
+            ));
+        }
+
+        if let Some(diagnostic) = store.get_mut(id) {
+            diagnostic.add_measurement(DiagnosticMeasurement { time, value });
+        }
+    };
+
+    for (world_id, world) in context.worlds.iter() {
+        for (diagnostic, total) in RapierDiagnostic::ALL.into_iter().zip(totals.iter_mut()) {
+            let value = diagnostic.measure(world);
+            *total += value;
+            record(diagnostic, Some(*world_id), value);
+        }
+    }
+
+    for (diagnostic, total) in RapierDiagnostic::ALL.into_iter().zip(totals) {
+        record(diagnostic, None, total);
+    }
+}
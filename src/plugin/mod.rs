@@ -1,10 +1,14 @@
 use crate::prelude::PhysicsWorld;
 
-pub use self::configuration::{RapierConfiguration, SimulationToRenderTime, TimestepMode};
-pub use self::context::RapierContext;
+pub use self::configuration::{
+    NonUniformScalePolicy, QueryPipelineUpdateMode, RapierConfiguration, SimulationToRenderTime,
+    SubstepTime, TimestepMode,
+};
+pub use self::context::{ConsistencyError, PhysicsTime, RapierContext, StepCounters};
+pub use self::diagnostics::{RapierDiagnostic, RapierDiagnosticsPlugin};
 pub use self::plugin::{
-    NoUserData, PhysicsSet, RapierPhysicsPlugin, RapierTransformPropagateSet, RapierWorld, WorldId,
-    DEFAULT_WORLD_ID,
+    NoUserData, PhysicsSet, PhysicsSubstepSchedule, RapierPhysicsPlugin,
+    RapierTransformPropagateSet, RapierWorld, WorldId, DEFAULT_WORLD_ID,
 };
 
 #[allow(clippy::type_complexity)]
@@ -13,6 +17,7 @@ pub mod systems;
 
 mod configuration;
 pub(crate) mod context;
+mod diagnostics;
 mod narrow_phase;
 #[allow(clippy::module_inception)]
 pub(crate) mod plugin;
@@ -32,9 +37,16 @@ fn find_item_and_world<T>(
     context: &mut RapierContext,
     item_finder: impl Fn(&mut RapierWorld) -> Option<T>,
 ) -> Option<(&mut RapierWorld, T)> {
-    for (_, world) in context.worlds.iter_mut() {
+    find_item_and_world_id(context, item_finder).map(|(_, world, item)| (world, item))
+}
+
+fn find_item_and_world_id<T>(
+    context: &mut RapierContext,
+    item_finder: impl Fn(&mut RapierWorld) -> Option<T>,
+) -> Option<(WorldId, &mut RapierWorld, T)> {
+    for (world_id, world) in context.worlds.iter_mut() {
         if let Some(handle) = item_finder(world) {
-            return Some((world, handle));
+            return Some((*world_id, world, handle));
         }
     }
 
@@ -9,6 +9,7 @@ use bevy::{
 };
 use bevy::{prelude::*, transform::TransformSystem};
 use rapier::dynamics::IntegrationParameters;
+use std::any::TypeId;
 use std::marker::PhantomData;
 
 pub use super::context::RapierWorld;
@@ -26,6 +27,9 @@ pub struct RapierPhysicsPlugin<PhysicsHooks = ()> {
     schedule: Interned<dyn ScheduleLabel>,
     length_unit: f32,
     default_system_setup: bool,
+    async_step: bool,
+    writeback: bool,
+    async_colliders: bool,
     _phantom: PhantomData<PhysicsHooks>,
 }
 
@@ -54,6 +58,53 @@ where
         self
     }
 
+    /// Specifies whether the simulation should be stepped on the [`AsyncComputeTaskPool`](bevy::tasks::AsyncComputeTaskPool)
+    /// while the current frame is being rendered.
+    ///
+    /// The step starts in the [`Last`] schedule and its results are retrieved by
+    /// [`systems::wait_for_physics`] at the beginning of the next frame, so the simulation
+    /// results (including the collision and contact force events) are delivered with one frame
+    /// of latency. While the step is running, the worlds are moved out of the [`RapierContext`].
+    ///
+    /// With [`TimestepMode::Interpolated`], the interpolation start is recorded before the
+    /// first step of a frame rather than before its last one, which is only different if
+    /// several steps are executed within a single frame.
+    ///
+    /// This is only supported when the physics systems run in `PostUpdate`, without custom
+    /// physics hooks (which can’t be accessed from the background task). Otherwise, the
+    /// simulation is stepped synchronously.
+    ///
+    /// The default value is `false`.
+    pub fn with_async_step(mut self, async_step: bool) -> Self {
+        self.async_step = async_step;
+        self
+    }
+
+    /// Don’t write the positions of the rigid-bodies back into their `Transform`, for a headless
+    /// server that doesn’t render them for example.
+    ///
+    /// The other components ([`Velocity`], [`ReadMassProperties`], etc.) are still written back,
+    /// and the positions can be read from the [`RapierContext`]. Since the plugin doesn’t modify
+    /// the transforms anymore, every change to the `GlobalTransform` of a rigid-body is a user
+    /// change: unless a [`RapierConfiguration`] was inserted beforehand,
+    /// [`RapierConfiguration::force_update_from_transform_changes`] is enabled so that moving a
+    /// rigid-body back to a previous position isn’t ignored.
+    pub fn without_writeback(mut self) -> Self {
+        self.writeback = false;
+        self
+    }
+
+    /// Don’t add the systems creating the colliders of `AsyncCollider`, `AsyncSceneCollider`,
+    /// and `AsyncSpriteCollider` components, for an app that doesn’t load meshes or images for
+    /// example.
+    ///
+    /// The debug-render systems are only added by the `RapierDebugRenderPlugin`, so they can be
+    /// skipped by not adding this plugin.
+    pub fn without_async_colliders(mut self) -> Self {
+        self.async_colliders = false;
+        self
+    }
+
     /// Specifies how many pixels on the 2D canvas equal one meter on the physics world.
     ///
     /// This conversion unit assumes that the 2D camera uses an unscaled projection.
@@ -67,6 +118,10 @@ where
     }
 
     /// Adds the physics systems to the `FixedUpdate` schedule rather than `PostUpdate`.
+    ///
+    /// Unless a [`RapierConfiguration`] was inserted beforehand, the timestep mode is set to
+    /// [`TimestepMode::FixedSchedule`], so the simulation is advanced by `Time<Fixed>::timestep()`
+    /// at each fixed step.
     pub fn in_fixed_schedule(self) -> Self {
         self.in_schedule(FixedUpdate)
     }
@@ -80,9 +135,59 @@ where
     /// Provided for use when staging systems outside of this plugin using
     /// [`with_system_setup(false)`](Self::with_system_setup).
     /// See [`PhysicsSet`] for a description of these systems.
+    ///
+    /// This includes every system: use [`Self::configured_systems`] to leave out the systems
+    /// disabled with [`Self::without_writeback`] or [`Self::without_async_colliders`].
     pub fn get_systems(set: PhysicsSet) -> SystemConfigs {
+        Self::default().configured_systems(set)
+    }
+
+    /// The systems of the given set, without the systems disabled on this plugin.
+    ///
+    /// See [`Self::get_systems`].
+    pub fn configured_systems(&self, set: PhysicsSet) -> SystemConfigs {
+        let async_colliders = (
+            #[cfg(all(feature = "dim3", feature = "async-collider"))]
+            systems::init_async_scene_colliders,
+            #[cfg(feature = "async-collider")]
+            (
+                // Poll first, so that the tasks spawned this frame are polled on the next one.
+                systems::poll_collider_generation_tasks,
+                systems::init_async_colliders,
+            )
+                .chain(),
+            #[cfg(all(feature = "dim2", feature = "image-collider"))]
+            systems::init_async_sprite_colliders,
+            (
+                systems::init_colliders_from_shape_descs,
+                systems::sync_collider_shape_descs,
+            )
+                .chain(),
+        );
+        let init_colliders_from_assets = if self.async_colliders {
+            async_colliders.chain()
+        } else {
+            (
+                systems::init_colliders_from_shape_descs,
+                systems::sync_collider_shape_descs,
+            )
+                .chain()
+        };
+        let writeback_rigid_bodies = if self.writeback {
+            (
+                systems::update_colliding_entities,
+                systems::writeback_rigid_bodies,
+            )
+                .chain()
+        } else {
+            systems::update_colliding_entities.into_configs()
+        };
+
         match set {
             PhysicsSet::SyncBackend => (
+                // Make sure scene queries (including the character controller) see the
+                // up-to-date configuration.
+                systems::sync_scaled_shape_subdivision,
                 // Run the character controller before the manual transform propagation.
                 systems::update_character_controls,
                 // Run Bevy transform propagation additionally to sync [`GlobalTransform`]
@@ -92,36 +197,74 @@ where
                 )
                     .chain()
                     .in_set(RapierTransformPropagateSet),
-                #[cfg(all(feature = "dim3", feature = "async-collider"))]
-                systems::init_async_scene_colliders,
-                #[cfg(all(feature = "dim3", feature = "async-collider"))]
-                systems::init_async_colliders,
+                init_colliders_from_assets,
+                systems::apply_conveyor_belts,
                 systems::init_rigid_bodies,
-                systems::init_colliders,
+                (systems::init_colliders, systems::init_collider_multiples).chain(),
                 systems::init_joints,
                 // Run this here so the following systems do not have a 1 frame delay.
                 apply_deferred,
                 systems::apply_scale,
-                systems::apply_collider_user_changes,
-                systems::apply_rigid_body_user_changes,
+                systems::insert_transform_interpolations,
+                (
+                    systems::apply_collider_user_changes,
+                    systems::apply_collider_multiple_user_changes,
+                    systems::apply_collider_parent_changes,
+                    systems::sync_disabled_colliders,
+                )
+                    .chain(),
+                (
+                    systems::apply_rigid_body_user_changes,
+                    systems::sync_disabled_rigid_bodies,
+                    systems::apply_sleep_events,
+                    systems::apply_gravity_override_mass_changes,
+                )
+                    .chain(),
                 systems::apply_joint_user_changes,
+                systems::apply_joint_motors,
                 systems::apply_initial_rigid_body_impulses,
                 systems::sync_vel,
+                systems::apply_kinematic_targets,
+                #[cfg(feature = "dim3")]
+                systems::update_vehicles,
             )
                 .chain()
                 .into_configs(),
             PhysicsSet::StepSimulation => (
                 event_update_system::<CollisionEvent>,
                 event_update_system::<ContactForceEvent>,
-                systems::step_simulation::<PhysicsHooks>,
+                event_update_system::<DetailedCollisionEvent>,
+                event_update_system::<PhysicsTimeDroppedEvent>,
+                event_update_system::<PhysicsStepEvent>,
+                (
+                    systems::step_simulation::<PhysicsHooks>
+                        .run_if(not(systems::has_substep_schedule)),
+                    systems::step_simulation_in_substeps::<PhysicsHooks>
+                        .run_if(systems::has_substep_schedule),
+                )
+                    .chain(),
+                systems::apply_fluid_volumes,
+                systems::clamp_velocities,
             )
                 .chain()
                 .into_configs(),
             PhysicsSet::Writeback => (
-                systems::update_colliding_entities,
-                systems::writeback_rigid_bodies,
+                systems::reset_one_frame_forces,
+                writeback_rigid_bodies,
                 systems::writeback_mass_properties,
+                systems::writeback_collider_mass_properties,
+                systems::writeback_contact_forces,
                 event_update_system::<MassModifiedEvent>,
+                event_update_system::<WakeUpBody>,
+                event_update_system::<PutBodyToSleep>,
+                event_update_system::<JointBrokenEvent>,
+                event_update_system::<JointInitFailedEvent>,
+                #[cfg(feature = "async-collider")]
+                event_update_system::<AsyncColliderFailedEvent>,
+                systems::writeback_impulse_joint_forces,
+                systems::break_impulse_joints,
+                systems::weld_on_contact,
+                systems::update_query_pipelines,
             )
                 .chain()
                 .into_configs(),
@@ -135,12 +278,35 @@ where
 #[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
 pub struct RapierTransformPropagateSet;
 
+/// A schedule run by the plugin after each substep of the simulation.
+///
+/// [`PhysicsSet::StepSimulation`] executes all the substeps of a step at once, so the systems
+/// of the other schedules only see the state of the simulation once per step. If systems are
+/// added to this schedule, the simulation is instead stepped one substep at a time by
+/// [`systems::step_simulation_in_substeps`], and this schedule runs after each substep (and after
+/// every world executed it), with the [`SubstepTime`] resource describing the substep. This lets
+/// controllers act at the rate of the substeps.
+///
+/// The systems of this schedule should read and modify the simulation through the
+/// [`RapierContext`], for example by setting the forces of the Rapier rigid-bodies: the
+/// components of the plugin are only synchronized with the simulation before and after the
+/// whole step. Commands issued by these systems are applied at the end of the schedule, but
+/// the entities and components they create are only added to the simulation by the next
+/// [`PhysicsSet::SyncBackend`].
+///
+/// This schedule isn’t run with [`RapierPhysicsPlugin::with_async_step`].
+#[derive(ScheduleLabel, Debug, Hash, PartialEq, Eq, Clone)]
+pub struct PhysicsSubstepSchedule;
+
 impl<PhysicsHooksSystemParam> Default for RapierPhysicsPlugin<PhysicsHooksSystemParam> {
     fn default() -> Self {
         Self {
             schedule: PostUpdate.intern(),
             length_unit: 1.0,
             default_system_setup: true,
+            async_step: false,
+            writeback: true,
+            async_colliders: true,
             _phantom: PhantomData,
         }
     }
@@ -178,25 +344,78 @@ where
             .register_type::<MassProperties>()
             .register_type::<LockedAxes>()
             .register_type::<ExternalForce>()
+            .register_type::<ForceMode>()
             .register_type::<ExternalImpulse>()
+            .register_type::<ExternalForceAtPoint>()
+            .register_type::<ExternalImpulseAtPoint>()
             .register_type::<Sleeping>()
             .register_type::<Damping>()
             .register_type::<Dominance>()
             .register_type::<Ccd>()
             .register_type::<SoftCcd>()
             .register_type::<GravityScale>()
+            .register_type::<GravityOverride>()
+            .register_type::<MaxVelocity>()
+            .register_type::<KinematicTarget>()
+            .register_type::<ReadContactForces>()
+            .register_type::<PhysicsHierarchyMode>()
             .register_type::<CollidingEntities>()
             .register_type::<Sensor>()
             .register_type::<Friction>()
             .register_type::<Restitution>()
             .register_type::<CollisionGroups>()
+            .register_type::<PhysicsLayers>()
             .register_type::<SolverGroups>()
             .register_type::<ContactForceEventThreshold>()
             .register_type::<Group>()
             .register_type::<PhysicsWorld>()
-            .register_type::<ContactSkin>();
+            .register_type::<ContactSkin>()
+            .register_type::<ConveyorBelt>()
+            .register_type::<FluidVolume>()
+            .register_type::<ReadColliderMassProperties>()
+            .register_type::<ImpulseJointBreakForce>()
+            .register_type::<JointDisabled>()
+            .register_type::<WeldOnContact>()
+            .register_type::<ReadMassProperties>()
+            .register_type::<TransformInterpolation>()
+            .register_type::<RigidBodyDisabled>()
+            .register_type::<AdditionalSolverIterations>()
+            .register_type::<ImpulseJoint>()
+            .register_type::<MultibodyJoint>()
+            .register_type::<ReadImpulseJointForces>()
+            .register_type::<JointMotor>()
+            .register_type::<Collider>()
+            .register_type::<ColliderMultiple>()
+            .register_type::<ColliderShapeDesc>()
+            .register_type::<ColliderScale>()
+            .register_type::<ColliderMassProperties>()
+            .register_type::<ColliderDisabled>()
+            .register_type::<ActiveCollisionTypes>()
+            .register_type::<ActiveHooks>()
+            .register_type::<ActiveEvents>()
+            .register_type::<KinematicCharacterController>()
+            .register_type::<KinematicCharacterControllerOutput>()
+            .register_type::<CharacterControllerFilter>()
+            .register_type::<RapierConfiguration>()
+            .register_type::<QueryPipelineUpdateMode>()
+            .register_type::<SimulationToRenderTime>()
+            .register_type::<SubstepTime>();
+
+        #[cfg(feature = "async-collider")]
+        app.register_type::<AsyncCollider>()
+            .insert_resource(Events::<AsyncColliderFailedEvent>::default());
+        #[cfg(feature = "dim3")]
+        app.register_type::<RaycastVehicle>()
+            .register_type::<VehicleWheel>()
+            .register_type::<RaycastVehicleOutput>();
+        #[cfg(all(feature = "dim3", feature = "async-collider"))]
+        app.register_type::<AsyncSceneCollider>()
+            .register_type::<AsyncSceneColliderOverride>();
+        #[cfg(all(feature = "dim2", feature = "image-collider"))]
+        app.register_type::<AsyncSpriteCollider>();
 
         app.insert_resource(SimulationToRenderTime::default())
+            .insert_resource(SubstepTime::default())
             .insert_resource(RapierContext::new(RapierWorld {
                 integration_parameters: IntegrationParameters {
                     length_unit: self.length_unit,
@@ -206,28 +425,80 @@ where
             }))
             .insert_resource(Events::<CollisionEvent>::default())
             .insert_resource(Events::<ContactForceEvent>::default())
-            .insert_resource(Events::<MassModifiedEvent>::default());
+            .insert_resource(Events::<DetailedCollisionEvent>::default())
+            .insert_resource(Events::<PhysicsTimeDroppedEvent>::default())
+            .insert_resource(Events::<PhysicsStepEvent>::default())
+            .insert_resource(Events::<MassModifiedEvent>::default())
+            .insert_resource(Events::<WakeUpBody>::default())
+            .insert_resource(Events::<PutBodyToSleep>::default())
+            .insert_resource(Events::<JointBrokenEvent>::default())
+            .insert_resource(Events::<JointInitFailedEvent>::default());
 
         // Insert all of our required resources. Don’t overwrite
         // the `RapierConfiguration` if it already exists.
         //
         // NOTE: be sure to call this after the `.insert_resource(RapierContext)` so we can
         //       access the length_unit when initializing the RapierConfiguration.
+        let fixed_schedule = self.schedule.as_dyn_eq().dyn_eq(FixedUpdate.as_dyn_eq());
+        let user_config = app.world.contains_resource::<RapierConfiguration>();
         app.init_resource::<RapierConfiguration>();
+        if fixed_schedule && !user_config {
+            app.world
+                .resource_mut::<RapierConfiguration>()
+                .timestep_mode = TimestepMode::FixedSchedule { substeps: 1 };
+        }
+        if !self.writeback && !user_config {
+            app.world
+                .resource_mut::<RapierConfiguration>()
+                .force_update_from_transform_changes = true;
+        }
+        app.init_resource::<CharacterControllerPredicates>()
+            .init_resource::<DebugCastBuffer>()
+            .init_resource::<PhysicsLayers>();
+
+        let async_step = self.async_step
+            && {
+                let supported = self.schedule.as_dyn_eq().dyn_eq(PostUpdate.as_dyn_eq())
+                    && TypeId::of::<PhysicsHooks>() == TypeId::of::<()>();
+                if !supported {
+                    warn!("The asynchronous physics step is only supported in `PostUpdate` and without physics hooks, the simulation will be stepped synchronously.");
+                }
+                supported
+            };
 
         // Add each set as necessary
         if self.default_system_setup {
-            app.configure_sets(
-                self.schedule,
-                (
-                    PhysicsSet::SyncBackend,
-                    PhysicsSet::StepSimulation,
-                    PhysicsSet::Writeback,
-                )
-                    .chain()
-                    .before(TransformSystem::TransformPropagate)
-                    .after(systems::sync_removals),
-            );
+            if async_step {
+                // The results of the step started during the previous frame must be written
+                // back before the user changes are applied.
+                app.configure_sets(
+                    self.schedule,
+                    (
+                        PhysicsSet::Writeback,
+                        PhysicsSet::SyncBackend,
+                        PhysicsSet::StepSimulation,
+                    )
+                        .chain()
+                        .before(TransformSystem::TransformPropagate)
+                        .after(systems::sync_removals),
+                );
+
+                app.init_resource::<systems::AsyncPhysicsStep>()
+                    .add_systems(First, systems::wait_for_physics)
+                    .add_systems(Last, systems::start_async_physics_step);
+            } else {
+                app.configure_sets(
+                    self.schedule,
+                    (
+                        PhysicsSet::SyncBackend,
+                        PhysicsSet::StepSimulation,
+                        PhysicsSet::Writeback,
+                    )
+                        .chain()
+                        .before(TransformSystem::TransformPropagate)
+                        .after(systems::sync_removals),
+                );
+            }
 
             // These *must* be in the main schedule currently so that they do not miss events.
             app.add_systems(
@@ -246,20 +517,38 @@ where
             app.add_systems(
                 self.schedule,
                 (
-                    Self::get_systems(PhysicsSet::SyncBackend).in_set(PhysicsSet::SyncBackend),
-                    Self::get_systems(PhysicsSet::StepSimulation)
+                    self.configured_systems(PhysicsSet::SyncBackend)
+                        .in_set(PhysicsSet::SyncBackend),
+                    self.configured_systems(PhysicsSet::StepSimulation)
                         .in_set(PhysicsSet::StepSimulation),
-                    Self::get_systems(PhysicsSet::Writeback).in_set(PhysicsSet::Writeback),
+                    self.configured_systems(PhysicsSet::Writeback)
+                        .in_set(PhysicsSet::Writeback),
                 ),
             );
 
+            #[cfg(feature = "debug-validate")]
+            app.add_systems(
+                self.schedule,
+                systems::log_consistency_errors.after(PhysicsSet::Writeback),
+            );
+
+            // Interpolate the rigid-bodies between the fixed steps before rendering.
+            if self.writeback {
+                app.add_systems(
+                    PostUpdate,
+                    systems::interpolate_fixed_schedule_transforms
+                        .after(PhysicsSet::Writeback)
+                        .before(TransformSystem::TransformPropagate),
+                );
+            }
+
             // Warn user if the timestep mode isn't in Fixed
-            if self.schedule.as_dyn_eq().dyn_eq(FixedUpdate.as_dyn_eq()) {
+            if fixed_schedule {
                 let config = app.world.resource::<RapierConfiguration>();
                 match config.timestep_mode {
-                    TimestepMode::Fixed { .. } => {}
+                    TimestepMode::Fixed { .. } | TimestepMode::FixedSchedule { .. } => {}
                     mode => {
-                        warn!("TimestepMode is set to `{:?}`, it is recommended to use `TimestepMode::Fixed` if you have the physics in `FixedUpdate`", mode);
+                        warn!("TimestepMode is set to `{:?}`, it is recommended to use `TimestepMode::FixedSchedule` if you have the physics in `FixedUpdate`", mode);
                     }
                 }
             }
@@ -0,0 +1,173 @@
+//! Systems responsible for stepping the simulation on a background task.
+
+use crate::dynamics::TransformInterpolation;
+use crate::pipeline::{
+    CollisionEvent, ContactForceEvent, DetailedCollisionEvent, PhysicsStepEvent,
+    PhysicsTimeDroppedEvent,
+};
+use crate::plugin::configuration::{SimulationToRenderTime, TimestepMode};
+use crate::plugin::context::{RapierWorld, WorldId};
+use crate::plugin::{RapierConfiguration, RapierContext};
+use bevy::prelude::*;
+use bevy::tasks::{block_on, AsyncComputeTaskPool, Task};
+use bevy::utils::Instant;
+use std::collections::BTreeMap;
+
+type AsyncStepOutput = (
+    BTreeMap<WorldId, RapierWorld>,
+    SimulationToRenderTime,
+    Vec<PhysicsTimeDroppedEvent>,
+    Vec<PhysicsStepEvent>,
+);
+
+/// The physics step currently running on the [`AsyncComputeTaskPool`].
+///
+/// This resource only exists if the plugin was configured with
+/// [`RapierPhysicsPlugin::with_async_step`](crate::plugin::RapierPhysicsPlugin::with_async_step).
+/// While a step is running, the worlds are moved out of the [`RapierContext`].
+#[derive(Resource, Default)]
+pub struct AsyncPhysicsStep {
+    task: Option<Task<AsyncStepOutput>>,
+}
+
+impl AsyncPhysicsStep {
+    /// Is a physics step currently running in the background?
+    pub fn is_running(&self) -> bool {
+        self.task.is_some()
+    }
+}
+
+/// System responsible for starting the physics step on the [`AsyncComputeTaskPool`].
+///
+/// It runs in the [`Last`] schedule so the step overlaps with rendering. The results are
+/// retrieved by [`wait_for_physics`].
+pub fn start_async_physics_step(
+    mut context: ResMut<RapierContext>,
+    config: Res<RapierConfiguration>,
+    time: Res<Time>,
+    sim_to_render_time: Res<SimulationToRenderTime>,
+    mut async_step: ResMut<AsyncPhysicsStep>,
+    mut interpolation_query: Query<&mut TransformInterpolation>,
+) {
+    if !config.physics_pipeline_active || async_step.is_running() {
+        return;
+    }
+
+    if let TimestepMode::Interpolated { .. } = config.timestep_mode {
+        if sim_to_render_time.diff + time.delta_seconds() > 0.0 {
+            // The bodies can’t be accessed once the step started, so the previous state is
+            // recorded before the first step of the frame instead of the last one.
+            for world in context.worlds.values() {
+                let active = world.islands.active_dynamic_bodies().iter().copied();
+                for handle in active.chain(world.recently_active_bodies.iter()) {
+                    let Some(body) = world.bodies.get(handle) else {
+                        continue;
+                    };
+
+                    if let Ok(mut interpolation) =
+                        interpolation_query.get_mut(Entity::from_bits(body.user_data as u64))
+                    {
+                        interpolation.start = Some(*body.position());
+                        interpolation.end = None;
+                    }
+                }
+            }
+        }
+    }
+
+    let num_steps = super::num_steps_to_run(&mut context, &config);
+    let measure_step_time = context.measure_step_time;
+    let mut worlds = std::mem::take(&mut context.worlds);
+    let timestep_mode = config.timestep_mode;
+    let update_query_pipeline = config.update_query_pipeline_after_step();
+    let enable_profiling = config.enable_profiling;
+    let detailed_collision_events = config.detailed_collision_events;
+    let time = time.clone();
+    let mut sim_to_render_time = SimulationToRenderTime {
+        diff: sim_to_render_time.diff,
+    };
+
+    async_step.task = Some(AsyncComputeTaskPool::get().spawn(async move {
+        let mut time_dropped_events = vec![];
+        let mut step_events = vec![];
+
+        for (world_id, world) in worlds.iter_mut() {
+            world.physics_time.last_frame_steps = 0;
+            world.set_profiling_enabled(enable_profiling);
+            world.detailed_collision_events = detailed_collision_events;
+            let start_time = measure_step_time.then(Instant::now);
+
+            for _ in 0..num_steps {
+                let first_step = world.physics_time.steps;
+                let dropped_seconds = world.step_simulation(
+                    *world_id,
+                    timestep_mode,
+                    true,
+                    &(),
+                    &time,
+                    &mut sim_to_render_time,
+                    &mut None,
+                );
+
+                if dropped_seconds > 0.0 {
+                    time_dropped_events.push(PhysicsTimeDroppedEvent {
+                        world_id: *world_id,
+                        dropped_seconds,
+                    });
+                }
+
+                step_events.extend(super::step_events(*world_id, world, first_step));
+            }
+
+            if num_steps == 0 {
+                world.propagate_modified_body_positions_to_colliders();
+            }
+
+            if let Some(start_time) = start_time {
+                world.last_step_duration = start_time.elapsed();
+            }
+
+            if update_query_pipeline {
+                world.update_query_pipeline();
+            }
+        }
+
+        (worlds, sim_to_render_time, time_dropped_events, step_events)
+    }));
+}
+
+/// System responsible for waiting for the physics step started by
+/// [`start_async_physics_step`], and for sending its events.
+///
+/// This runs at the beginning of the [`First`] schedule. It can be added to other schedules
+/// if the simulation results are needed earlier.
+pub fn wait_for_physics(
+    mut context: ResMut<RapierContext>,
+    async_step: Option<ResMut<AsyncPhysicsStep>>,
+    mut sim_to_render_time: ResMut<SimulationToRenderTime>,
+    mut collision_event_writer: EventWriter<CollisionEvent>,
+    mut contact_force_event_writer: EventWriter<ContactForceEvent>,
+    mut detailed_collision_event_writer: EventWriter<DetailedCollisionEvent>,
+    mut time_dropped_event_writer: EventWriter<PhysicsTimeDroppedEvent>,
+    mut step_event_writer: EventWriter<PhysicsStepEvent>,
+) {
+    let Some(task) = async_step.and_then(|mut async_step| async_step.task.take()) else {
+        return;
+    };
+
+    let (worlds, diff, time_dropped_events, step_events) = block_on(task);
+    *sim_to_render_time = diff;
+    time_dropped_event_writer.send_batch(time_dropped_events);
+    step_event_writer.send_batch(step_events);
+
+    for (world_id, mut world) in worlds {
+        world.send_bevy_events(&mut collision_event_writer, &mut contact_force_event_writer);
+        world.send_detailed_collision_events(&mut detailed_collision_event_writer);
+
+        if world.physics_time.last_frame_steps > 0 {
+            world.deleted_colliders.clear();
+        }
+
+        context.worlds.insert(world_id, world);
+    }
+}
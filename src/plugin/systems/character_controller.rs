@@ -1,17 +1,27 @@
-use crate::control::CharacterCollision;
-use crate::dynamics::RapierRigidBodyHandle;
+use crate::control::{
+    step_and_snap_heights, CharacterApplyMode, CharacterCollision, CharacterControllerFilter,
+    CharacterControllerPredicates,
+};
+use crate::dynamics::{RapierRigidBodyHandle, Velocity};
 use crate::geometry::RapierColliderHandle;
+use crate::math::Vect;
 use crate::plugin::get_world;
 use crate::plugin::RapierConfiguration;
 use crate::plugin::RapierContext;
+use crate::plugin::RapierWorld;
 use crate::prelude::KinematicCharacterController;
 use crate::prelude::KinematicCharacterControllerOutput;
 use crate::prelude::PhysicsWorld;
 use crate::utils;
 use bevy::prelude::*;
-use rapier::math::Isometry;
-use rapier::math::Real;
+use rapier::control::CharacterLength;
+use rapier::geometry::ColliderHandle;
+use rapier::math::{Isometry, Point, Real};
+use rapier::na::Unit;
+use rapier::parry::query::ShapeCastOptions;
+use rapier::parry::shape::Shape;
 use rapier::pipeline::QueryFilter;
+use std::collections::HashSet;
 
 /// System responsible for applying the character controller translation to the underlying
 /// collider.
@@ -19,6 +29,7 @@ pub fn update_character_controls(
     mut commands: Commands,
     config: Res<RapierConfiguration>,
     mut context: ResMut<RapierContext>,
+    predicates: Res<CharacterControllerPredicates>,
     mut character_controllers: Query<(
         Entity,
         &mut KinematicCharacterController,
@@ -27,8 +38,11 @@ pub fn update_character_controls(
         Option<&RapierRigidBodyHandle>,
         Option<&GlobalTransform>,
         Option<&PhysicsWorld>,
+        Option<&CharacterControllerFilter>,
     )>,
     mut transforms: Query<&mut Transform>,
+    mut velocities: Query<&mut Velocity>,
+    mut excluded_colliders: Local<HashSet<ColliderHandle>>,
 ) {
     for (
         entity,
@@ -38,37 +52,59 @@ pub fn update_character_controls(
         body_handle,
         glob_transform,
         world_within,
+        custom_filter,
     ) in character_controllers.iter_mut()
     {
         let world = get_world(world_within, &mut context);
 
-        if let (Some(raw_controller), Some(translation)) =
-            (controller.to_raw(), controller.translation)
-        {
-            let scaled_custom_shape =
-                controller
-                    .custom_shape
-                    .as_ref()
-                    .map(|(custom_shape, tra, rot)| {
-                        // TODO: avoid the systematic scale somehow?
-                        let mut scaled_shape = custom_shape.clone();
-                        scaled_shape.set_scale(custom_shape.scale, config.scaled_shape_subdivision);
-
-                        (scaled_shape, *tra, *rot)
-                    });
-
-            let parent_rigid_body = body_handle.map(|h| h.0).or_else(|| {
-                collider_handle
-                    .and_then(|h| world.colliders.get(h.0))
-                    .and_then(|c| c.parent())
-            });
-            let entity_to_move = parent_rigid_body
-                .and_then(|rb| world.rigid_body_entity(rb))
-                .unwrap_or(entity);
-
-            let (character_shape, character_pos) = if let Some((scaled_shape, tra, rot)) =
-                &scaled_custom_shape
-            {
+        // A character following the ground must be updated even if it isn’t moving by itself.
+        let translation = controller
+            .translation
+            .or_else(|| controller.follow_ground.then_some(Vect::ZERO));
+
+        let (Some(mut raw_controller), Some(desired)) = (controller.to_raw(), translation) else {
+            // The character didn’t move this frame, so it didn’t hit, climb, or snap to anything either.
+            if let Some(mut output) = output {
+                if !output.collisions.is_empty()
+                    || output.stepped_up.is_some()
+                    || output.snapped_to_ground.is_some()
+                {
+                    output.collisions.clear();
+                    output.stepped_up = None;
+                    output.snapped_to_ground = None;
+                }
+            }
+            continue;
+        };
+
+        if controller.up_from_gravity {
+            if let Some(up) = Unit::try_new((-world.gravity).into(), 1.0e-6) {
+                raw_controller.up = up;
+            }
+        }
+
+        let scaled_custom_shape =
+            controller
+                .custom_shape
+                .as_ref()
+                .map(|(custom_shape, tra, rot)| {
+                    // Only cloned and rescaled if it used other subdivisions.
+                    let scaled_shape = custom_shape.rescaled(config.scaled_shape_subdivision);
+
+                    (scaled_shape, *tra, *rot)
+                });
+
+        let parent_rigid_body = body_handle.map(|h| h.0).or_else(|| {
+            collider_handle
+                .and_then(|h| world.colliders.get(h.0))
+                .and_then(|c| c.parent())
+        });
+        let entity_to_move = parent_rigid_body
+            .and_then(|rb| world.rigid_body_entity(rb))
+            .unwrap_or(entity);
+
+        let (character_shape, character_pos) =
+            if let Some((scaled_shape, tra, rot)) = &scaled_custom_shape {
                 let mut shape_pos: Isometry<Real> = (*tra, *rot).into();
 
                 if let Some(body) = body_handle.and_then(|h| world.bodies.get(h.0)) {
@@ -84,96 +120,638 @@ pub fn update_character_controls(
                 continue;
             };
 
-            let exclude_collider = collider_handle.map(|h| h.0);
-
-            let character_mass = controller
-                .custom_mass
-                .or_else(|| {
-                    parent_rigid_body
-                        .and_then(|h| world.bodies.get(h))
-                        .map(|rb| rb.mass())
-                })
-                .unwrap_or(0.0);
-
-            let mut filter = QueryFilter {
-                flags: controller.filter_flags,
-                groups: controller.filter_groups.map(|g| g.into()),
-                exclude_collider: None,
-                exclude_rigid_body: None,
-                predicate: None,
-            };
+        let exclude_collider = collider_handle.map(|h| h.0);
 
-            if let Some(parent) = parent_rigid_body {
-                filter = filter.exclude_rigid_body(parent);
-            } else if let Some(excl_co) = exclude_collider {
-                filter = filter.exclude_collider(excl_co)
-            };
+        let character_mass = controller
+            .custom_mass
+            .or_else(|| {
+                parent_rigid_body
+                    .and_then(|h| world.bodies.get(h))
+                    .map(|rb| rb.mass())
+            })
+            .unwrap_or(0.0);
+
+        let (flags, groups) = match custom_filter {
+            Some(custom_filter) => (custom_filter.flags, custom_filter.groups),
+            None => (controller.filter_flags, controller.filter_groups),
+        };
+
+        // Reused across controllers and frames to avoid allocating a new set each time.
+        excluded_colliders.clear();
+        if let Some(custom_filter) = custom_filter {
+            for excluded in &custom_filter.exclude {
+                if let Some(handle) = world.entity2collider.get(excluded) {
+                    excluded_colliders.insert(*handle);
+                }
+                if let Some(body) = world
+                    .entity2body
+                    .get(excluded)
+                    .and_then(|h| world.bodies.get(*h))
+                {
+                    excluded_colliders.extend(body.colliders().iter().copied());
+                }
+            }
+        }
 
-            let collisions = &mut world.character_collisions_collector;
-            collisions.clear();
+        let user_predicate = custom_filter
+            .and_then(|f| f.predicate)
+            .and_then(|id| predicates.get(id));
+        let predicate = |handle: ColliderHandle, co: &rapier::geometry::Collider| {
+            !excluded_colliders.contains(&handle)
+                && user_predicate.map_or(true, |p| p(Entity::from_bits(co.user_data as u64)))
+        };
 
-            let movement = raw_controller.move_shape(
-                world.integration_parameters.dt,
-                &world.bodies,
-                &world.colliders,
-                &world.query_pipeline,
+        let mut filter = QueryFilter {
+            flags,
+            groups: groups.map(|g| g.into()),
+            exclude_collider: None,
+            exclude_rigid_body: None,
+            exclude_entities: &[],
+            predicate: custom_filter.map(|_| &predicate as _),
+        };
+
+        if let Some(parent) = parent_rigid_body {
+            filter = filter.exclude_rigid_body(parent);
+        } else if let Some(excl_co) = exclude_collider {
+            filter = filter.exclude_collider(excl_co)
+        };
+
+        let mut translation = desired;
+        if controller.follow_ground {
+            if let Some(ground_velocity) = ground_velocity(
+                world,
+                &raw_controller,
                 character_shape,
                 &character_pos,
-                translation.into(),
                 filter,
-                |c| collisions.push(c),
-            );
-
-            if controller.apply_impulse_to_dynamic_bodies {
-                for collision in collisions.iter() {
-                    raw_controller.solve_character_collision_impulses(
-                        world.integration_parameters.dt,
-                        &mut world.bodies,
-                        &world.colliders,
-                        &world.query_pipeline,
-                        character_shape,
-                        character_mass,
-                        collision,
-                        filter,
-                    )
-                }
+            ) {
+                translation += ground_velocity * world.integration_parameters.dt;
             }
+        }
 
-            if let Ok(mut transform) = transforms.get_mut(entity_to_move) {
-                // TODO: take the parent’s GlobalTransform rotation into account?
-                transform.translation.x += movement.translation.x;
-                transform.translation.y += movement.translation.y;
-                #[cfg(feature = "dim3")]
-                {
-                    transform.translation.z += movement.translation.z;
+        let collisions = &mut world.character_collisions_collector;
+        collisions.clear();
+
+        let movement = raw_controller.move_shape(
+            world.integration_parameters.dt,
+            &world.bodies,
+            &world.colliders,
+            &world.query_pipeline,
+            character_shape,
+            &character_pos,
+            translation.into(),
+            filter,
+            |c| collisions.push(c),
+        );
+
+        if controller.apply_impulse_to_dynamic_bodies {
+            for collision in collisions.iter() {
+                raw_controller.solve_character_collision_impulses(
+                    world.integration_parameters.dt,
+                    &mut world.bodies,
+                    &world.colliders,
+                    &world.query_pipeline,
+                    character_shape,
+                    character_mass,
+                    collision,
+                    filter,
+                )
+            }
+        }
+
+        let (stepped_up, snapped_to_ground) = step_and_snap_heights(
+            &raw_controller,
+            &translation.into(),
+            &movement,
+            &world.character_collisions_collector,
+            world.integration_parameters.length_unit * 1.0e-4,
+        );
+
+        match controller.apply_mode {
+            CharacterApplyMode::Translation => {
+                if let Ok(mut transform) = transforms.get_mut(entity_to_move) {
+                    // TODO: take the parent’s GlobalTransform rotation into account?
+                    transform.translation.x += movement.translation.x;
+                    transform.translation.y += movement.translation.y;
+                    #[cfg(feature = "dim3")]
+                    {
+                        transform.translation.z += movement.translation.z;
+                    }
                 }
             }
+            CharacterApplyMode::Velocity => {
+                if let Ok(mut velocity) = velocities.get_mut(entity_to_move) {
+                    let up: Vect = (*raw_controller.up).into();
+                    let mut linvel =
+                        Vect::from(movement.translation) / world.integration_parameters.dt;
 
-            let converted_collisions = world
-                .character_collisions_collector
-                .iter()
-                .filter_map(|c| CharacterCollision::from_raw(world, c));
+                    if !movement.grounded {
+                        // Let the body keep falling (or jumping) under the effect of gravity: its
+                        // velocity along `up` replaces the one of the movement, instead of being
+                        // added to it.
+                        linvel += up * (velocity.linvel.dot(up) - linvel.dot(up));
+                    }
 
-            if let Some(mut output) = output {
-                output.desired_translation = controller.translation.unwrap();
-                output.effective_translation = movement.translation.into();
-                output.grounded = movement.grounded;
-                output.collisions.clear();
-                output.collisions.extend(converted_collisions);
-                output.is_sliding_down_slope = movement.is_sliding_down_slope;
-            } else {
-                commands
-                    .entity(entity)
-                    .insert(KinematicCharacterControllerOutput {
-                        desired_translation: controller.translation.unwrap(),
-                        effective_translation: movement.translation.into(),
-                        grounded: movement.grounded,
-                        collisions: converted_collisions.collect(),
-                        is_sliding_down_slope: movement.is_sliding_down_slope,
-                    });
+                    // NOTE: we write the new value only if there was an
+                    //       actual change, in order to not trigger bevy’s
+                    //       change tracking when the values didn’t change.
+                    if velocity.linvel != linvel {
+                        velocity.linvel = linvel;
+                    }
+                }
             }
+        }
 
-            controller.translation = None;
+        let converted_collisions = world
+            .character_collisions_collector
+            .iter()
+            .filter_map(|c| CharacterCollision::from_raw(world, c));
+
+        if let Some(mut output) = output {
+            output.desired_translation = desired;
+            output.effective_translation = movement.translation.into();
+            output.grounded = movement.grounded;
+            output.collisions.clear();
+            output.collisions.extend(converted_collisions);
+            output.is_sliding_down_slope = movement.is_sliding_down_slope;
+            output.stepped_up = stepped_up;
+            output.snapped_to_ground = snapped_to_ground;
+        } else {
+            commands
+                .entity(entity)
+                .insert(KinematicCharacterControllerOutput {
+                    desired_translation: desired,
+                    effective_translation: movement.translation.into(),
+                    grounded: movement.grounded,
+                    collisions: converted_collisions.collect(),
+                    is_sliding_down_slope: movement.is_sliding_down_slope,
+                    stepped_up,
+                    snapped_to_ground,
+                });
+        }
+
+        controller.translation = None;
+    }
+}
+
+/// The velocity, at the character’s position, of the rigid-body the character is standing on.
+fn ground_velocity(
+    world: &RapierWorld,
+    controller: &rapier::control::KinematicCharacterController,
+    character_shape: &dyn Shape,
+    character_pos: &Isometry<Real>,
+    filter: QueryFilter,
+) -> Option<Vect> {
+    let offset = match controller.offset {
+        CharacterLength::Absolute(offset) => offset,
+        CharacterLength::Relative(offset) => {
+            offset
+                * character_shape
+                    .compute_local_aabb()
+                    .extents()
+                    .dot(&controller.up.abs())
         }
+    };
+
+    // Probe a bit further than the offset the controller keeps between the character and
+    // the ground.
+    let (handle, hit) = world.query_pipeline.cast_shape(
+        &world.bodies,
+        &world.colliders,
+        character_pos,
+        &-*controller.up,
+        character_shape,
+        ShapeCastOptions::with_max_time_of_impact(offset * 2.0),
+        filter,
+    )?;
+
+    if hit.normal1.dot(&controller.up) < controller.max_slope_climb_angle.cos() {
+        // This is a wall or a steep slope, not something we can stand on.
+        return None;
+    }
+
+    let body = world
+        .colliders
+        .get(handle)?
+        .parent()
+        .and_then(|h| world.bodies.get(h))?;
+    let velocity = body.velocity_at_point(&Point::from(character_pos.translation.vector));
+    Some(velocity.into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::math::Vect;
+    use crate::plugin::systems::tests::HeadlessRenderPlugin;
+    use crate::plugin::{NoUserData, RapierPhysicsPlugin};
+    use crate::prelude::{Collider, RigidBody};
+    use bevy::time::TimePlugin;
+
+    #[test]
+    fn character_controller_reports_collisions() {
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        #[cfg(feature = "dim2")]
+        let wall_shape = Collider::cuboid(0.5, 5.0);
+        #[cfg(feature = "dim3")]
+        let wall_shape = Collider::cuboid(0.5, 5.0, 5.0);
+        let wall = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(2.0, 0.0, 0.0)),
+                RigidBody::Fixed,
+                wall_shape,
+            ))
+            .id();
+        let character = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                Collider::ball(0.5),
+                KinematicCharacterController::default(),
+            ))
+            .id();
+
+        // Let the colliders and the query pipeline initialize.
+        app.update();
+
+        app.world
+            .get_mut::<KinematicCharacterController>(character)
+            .unwrap()
+            .translation = Some(Vect::X * 2.0);
+        app.update();
+
+        let output = app
+            .world
+            .get::<KinematicCharacterControllerOutput>(character)
+            .expect("The controller output should have been inserted.");
+        assert!(
+            output.effective_translation.x < 1.0,
+            "The wall should have stopped the character"
+        );
+        assert!(
+            output.collisions.iter().any(|c| c.entity == wall),
+            "The wall should be listed in the controller collisions"
+        );
+
+        // No movement requested: the collisions of the previous move must not linger.
+        app.update();
+
+        let output = app
+            .world
+            .get::<KinematicCharacterControllerOutput>(character)
+            .unwrap();
+        assert!(output.collisions.is_empty());
+    }
+
+    #[test]
+    fn character_controller_ignores_excluded_entities() {
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        #[cfg(feature = "dim2")]
+        let (floor_shape, obstacle_shape) =
+            (Collider::cuboid(10.0, 0.5), Collider::cuboid(0.5, 5.0));
+        #[cfg(feature = "dim3")]
+        let (floor_shape, obstacle_shape) = (
+            Collider::cuboid(10.0, 0.5, 10.0),
+            Collider::cuboid(0.5, 5.0, 5.0),
+        );
+        app.world.spawn((
+            TransformBundle::from(Transform::from_xyz(0.0, -1.0, 0.0)),
+            RigidBody::Fixed,
+            floor_shape,
+        ));
+        let obstacle = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(2.0, 0.0, 0.0)),
+                RigidBody::Fixed,
+                obstacle_shape,
+            ))
+            .id();
+        let character = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(0.0, 0.1, 0.0)),
+                Collider::ball(0.5),
+                KinematicCharacterController::default(),
+                CharacterControllerFilter {
+                    exclude: vec![obstacle],
+                    ..Default::default()
+                },
+            ))
+            .id();
+
+        // Let the colliders and the query pipeline initialize.
+        app.update();
+
+        let desired = Vect::X * 3.0 - Vect::Y * 0.5;
+        app.world
+            .get_mut::<KinematicCharacterController>(character)
+            .unwrap()
+            .translation = Some(desired);
+        app.update();
+
+        let output = app
+            .world
+            .get::<KinematicCharacterControllerOutput>(character)
+            .unwrap();
+        approx::assert_relative_eq!(output.effective_translation.x, desired.x, epsilon = 1.0e-2);
+        assert!(output.grounded, "The floor should still stop the character");
+        assert!(output.collisions.iter().all(|c| c.entity != obstacle));
+    }
+
+    #[test]
+    fn character_controller_uses_its_own_world() {
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        // An upside-down world.
+        let world_id = app
+            .world
+            .resource_mut::<RapierContext>()
+            .add_world(RapierWorld::default().with_gravity(Vect::Y * 9.81));
+        let physics_world = PhysicsWorld { world_id };
+
+        #[cfg(feature = "dim2")]
+        let (ceiling_shape, wall_shape) = (Collider::cuboid(10.0, 0.5), Collider::cuboid(0.5, 5.0));
+        #[cfg(feature = "dim3")]
+        let (ceiling_shape, wall_shape) = (
+            Collider::cuboid(10.0, 0.5, 10.0),
+            Collider::cuboid(0.5, 5.0, 5.0),
+        );
+        // This ceiling is the floor of the character, but only exists in its world.
+        let ceiling = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(0.0, 1.0, 0.0)),
+                RigidBody::Fixed,
+                ceiling_shape,
+                physics_world,
+            ))
+            .id();
+        // This wall lives in the default world and must not block the character.
+        let wall = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(2.0, 0.0, 0.0)),
+                RigidBody::Fixed,
+                wall_shape,
+            ))
+            .id();
+        let character = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(0.0, -0.1, 0.0)),
+                Collider::ball(0.5),
+                KinematicCharacterController {
+                    up_from_gravity: true,
+                    ..Default::default()
+                },
+                physics_world,
+            ))
+            .id();
+
+        // Let the colliders and the query pipeline initialize.
+        app.update();
+
+        let desired = Vect::X * 3.0 + Vect::Y * 0.5;
+        app.world
+            .get_mut::<KinematicCharacterController>(character)
+            .unwrap()
+            .translation = Some(desired);
+        app.update();
+
+        let output = app
+            .world
+            .get::<KinematicCharacterControllerOutput>(character)
+            .unwrap();
+        approx::assert_relative_eq!(output.effective_translation.x, desired.x, epsilon = 1.0e-2);
+        assert!(
+            output.grounded,
+            "The ceiling should act as the floor of the character"
+        );
+        assert!(output.collisions.iter().all(|c| c.entity != wall));
+        assert!(output.collisions.iter().any(|c| c.entity == ceiling));
+    }
+
+    #[test]
+    fn character_controller_reports_autostep() {
+        use crate::control::{CharacterAutostep, CharacterLength};
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        #[cfg(feature = "dim2")]
+        let (floor_shape, step_shape) = (Collider::cuboid(10.0, 0.5), Collider::cuboid(2.0, 0.1));
+        #[cfg(feature = "dim3")]
+        let (floor_shape, step_shape) = (
+            Collider::cuboid(10.0, 0.5, 10.0),
+            Collider::cuboid(2.0, 0.1, 2.0),
+        );
+        app.world.spawn((
+            TransformBundle::from(Transform::from_xyz(0.0, -0.5, 0.0)),
+            RigidBody::Fixed,
+            floor_shape,
+        ));
+        // A single stair, 0.2 high, starting at x = 1.
+        app.world.spawn((
+            TransformBundle::from(Transform::from_xyz(3.0, 0.1, 0.0)),
+            RigidBody::Fixed,
+            step_shape,
+        ));
+        let character = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(0.0, 0.26, 0.0)),
+                Collider::ball(0.25),
+                KinematicCharacterController {
+                    autostep: Some(CharacterAutostep {
+                        max_height: CharacterLength::Absolute(0.3),
+                        min_width: CharacterLength::Absolute(0.1),
+                        include_dynamic_bodies: true,
+                    }),
+                    ..Default::default()
+                },
+            ))
+            .id();
+
+        // Let the colliders and the query pipeline initialize.
+        app.update();
+
+        // Walk on the flat floor first.
+        app.world
+            .get_mut::<KinematicCharacterController>(character)
+            .unwrap()
+            .translation = Some(Vect::X * 0.2 - Vect::Y * 0.01);
+        app.update();
+
+        let output = app
+            .world
+            .get::<KinematicCharacterControllerOutput>(character)
+            .unwrap();
+        assert!(output.grounded);
+        assert_eq!(output.stepped_up, None);
+
+        // Then walk into the stair.
+        app.world
+            .get_mut::<KinematicCharacterController>(character)
+            .unwrap()
+            .translation = Some(Vect::X * 0.8 - Vect::Y * 0.01);
+        app.update();
+
+        let output = app
+            .world
+            .get::<KinematicCharacterControllerOutput>(character)
+            .unwrap();
+        let stepped_up = output
+            .stepped_up
+            .expect("The character should have climbed the stair.");
+        approx::assert_relative_eq!(stepped_up, 0.2, epsilon = 0.05);
+    }
+
+    #[test]
+    fn airborne_velocity_mode_keeps_the_vertical_velocity() {
+        use crate::control::CharacterApplyMode;
+        use crate::dynamics::{GravityScale, Velocity};
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        let falling = Vect::Y * -2.0;
+        let character = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(0.0, 10.0, 0.0)),
+                RigidBody::Dynamic,
+                GravityScale(0.0),
+                Velocity::linear(falling),
+                Collider::ball(0.5),
+                KinematicCharacterController {
+                    apply_mode: CharacterApplyMode::Velocity,
+                    ..Default::default()
+                },
+            ))
+            .id();
+
+        // Let the colliders and the query pipeline initialize.
+        app.update();
+
+        // A movement with a downward component, like one including gravity.
+        for _ in 0..3 {
+            app.world
+                .get_mut::<KinematicCharacterController>(character)
+                .unwrap()
+                .translation = Some(Vect::X * 0.1 - Vect::Y * 0.1);
+            app.update();
+
+            let output = app
+                .world
+                .get::<KinematicCharacterControllerOutput>(character)
+                .unwrap();
+            assert!(!output.grounded);
+
+            // The vertical velocity of the body isn’t increased by the movement.
+            let velocity = app.world.get::<Velocity>(character).unwrap();
+            approx::assert_relative_eq!(velocity.linvel.y, falling.y, epsilon = 1.0e-3);
+            assert!(velocity.linvel.x > 0.0);
+        }
+    }
+
+    #[test]
+    fn walking_into_a_wall_is_not_a_step() {
+        use crate::control::{CharacterAutostep, CharacterLength};
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        #[cfg(feature = "dim2")]
+        let (floor_shape, wall_shape) = (Collider::cuboid(10.0, 0.5), Collider::cuboid(0.5, 2.0));
+        #[cfg(feature = "dim3")]
+        let (floor_shape, wall_shape) = (
+            Collider::cuboid(10.0, 0.5, 10.0),
+            Collider::cuboid(0.5, 2.0, 10.0),
+        );
+        app.world.spawn((
+            TransformBundle::from(Transform::from_xyz(0.0, -0.5, 0.0)),
+            RigidBody::Fixed,
+            floor_shape,
+        ));
+        // A wall much higher than the autostep height, starting at x = 1.
+        app.world.spawn((
+            TransformBundle::from(Transform::from_xyz(1.5, 2.0, 0.0)),
+            RigidBody::Fixed,
+            wall_shape,
+        ));
+        let character = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(0.0, 0.26, 0.0)),
+                Collider::ball(0.25),
+                KinematicCharacterController {
+                    autostep: Some(CharacterAutostep {
+                        max_height: CharacterLength::Absolute(0.3),
+                        min_width: CharacterLength::Absolute(0.1),
+                        include_dynamic_bodies: true,
+                    }),
+                    ..Default::default()
+                },
+            ))
+            .id();
+
+        // Let the colliders and the query pipeline initialize.
+        app.update();
+
+        // Walk into the wall, with gravity pulling the character down against the floor.
+        for _ in 0..10 {
+            app.world
+                .get_mut::<KinematicCharacterController>(character)
+                .unwrap()
+                .translation = Some(Vect::X * 0.3 - Vect::Y * 0.1);
+            app.update();
+
+            let output = app
+                .world
+                .get::<KinematicCharacterControllerOutput>(character)
+                .unwrap();
+            assert!(output.grounded);
+            assert_eq!(output.stepped_up, None);
+        }
+
+        let transform = app.world.get::<Transform>(character).unwrap();
+        assert!(transform.translation.x < 1.0);
     }
 }
@@ -1,20 +1,37 @@
+use crate::dynamics::MassChangeCause;
 use crate::dynamics::ReadMassProperties;
-use crate::geometry::Collider;
-use crate::plugin::{get_world, RapierConfiguration, RapierContext, RapierWorld};
+use crate::geometry::ConveyorBeltActiveHooks;
+use crate::geometry::{Collider, ColliderShapeDesc};
+use crate::math::Vect;
+use crate::plugin::{
+    get_world, NonUniformScalePolicy, RapierConfiguration, RapierContext, RapierWorld,
+    DEFAULT_WORLD_ID,
+};
+#[cfg(all(feature = "dim2", feature = "image-collider"))]
+use crate::prelude::AsyncSpriteCollider;
 use crate::prelude::{
     ActiveCollisionTypes, ActiveEvents, ActiveHooks, ColliderDisabled, ColliderMassProperties,
-    ColliderScale, CollidingEntities, CollisionEvent, CollisionGroups, ContactForceEventThreshold,
-    ContactSkin, Friction, MassModifiedEvent, MassProperties, PhysicsWorld, RapierColliderHandle,
-    RapierRigidBodyHandle, Restitution, Sensor, SolverGroups,
+    ColliderMultiple, ColliderScale, CollidingEntities, CollisionEvent, CollisionGroups,
+    ContactForceEventThreshold, ContactSkin, ConveyorBelt, Friction, MassModifiedEvent,
+    PhysicsWorld, RapierColliderHandle, RapierColliderHandles, RapierRigidBodyHandle, Restitution,
+    Sensor, SolverGroups,
 };
 use crate::utils;
 use bevy::prelude::*;
+use bevy::utils::HashSet;
 use rapier::dynamics::RigidBodyHandle;
-use rapier::geometry::ColliderBuilder;
+use rapier::geometry::{ColliderBuilder, ColliderHandle, SharedShape};
+#[cfg(feature = "async-collider")]
+use {
+    crate::geometry::AsyncColliderFailed,
+    crate::prelude::{AsyncCollider, AsyncColliderFailedEvent, ColliderGenerationTask},
+    bevy::tasks::{block_on, futures_lite::future},
+};
 #[cfg(all(feature = "dim3", feature = "async-collider"))]
 use {
-    crate::prelude::{AsyncCollider, AsyncSceneCollider},
+    crate::prelude::{AsyncSceneCollider, AsyncSceneColliderOverride},
     bevy::scene::SceneInstance,
+    bevy::utils::HashMap,
 };
 
 #[cfg(feature = "dim2")]
@@ -38,124 +55,243 @@ pub type ColliderComponents<'a> = (
     Option<&'a ColliderDisabled>,
 );
 
+/// The components configuring the colliders of an entity, apart from their shape.
+pub type ColliderSettings<'a> = (
+    Option<&'a Sensor>,
+    Option<&'a ColliderMassProperties>,
+    Option<&'a ActiveEvents>,
+    Option<&'a ActiveHooks>,
+    Option<&'a ActiveCollisionTypes>,
+    Option<&'a Friction>,
+    Option<&'a Restitution>,
+    Option<&'a ContactSkin>,
+    Option<&'a CollisionGroups>,
+    Option<&'a SolverGroups>,
+    Option<&'a ContactForceEventThreshold>,
+    Option<&'a ColliderDisabled>,
+);
+
 /// System responsible for applying [`GlobalTransform::scale`] and/or [`ColliderScale`] to
-/// colliders.
+/// colliders, including the colliders of a [`ColliderMultiple`].
+///
+/// Removing the [`ColliderScale`] resets the collider to the scale of its [`GlobalTransform`].
+#[allow(clippy::type_complexity)]
 pub fn apply_scale(
     config: Res<RapierConfiguration>,
-    mut changed_collider_scales: Query<
-        (&mut Collider, &GlobalTransform, Option<&ColliderScale>),
-        Or<(
-            Changed<Collider>,
-            Changed<GlobalTransform>,
-            Changed<ColliderScale>,
-        )>,
-    >,
+    mut warned: Local<HashSet<Entity>>,
+    mut removed_collider_scales: RemovedComponents<ColliderScale>,
+    mut collider_scales: ParamSet<(
+        Query<
+            (
+                Entity,
+                &mut Collider,
+                &GlobalTransform,
+                Option<&ColliderScale>,
+            ),
+            Or<(
+                Changed<Collider>,
+                Changed<GlobalTransform>,
+                Changed<ColliderScale>,
+            )>,
+        >,
+        Query<(Entity, &mut Collider, &GlobalTransform), Without<ColliderScale>>,
+    )>,
+    mut collider_multiple_scales: ParamSet<(
+        Query<
+            (
+                &mut ColliderMultiple,
+                &GlobalTransform,
+                Option<&ColliderScale>,
+            ),
+            Or<(
+                Changed<ColliderMultiple>,
+                Changed<GlobalTransform>,
+                Changed<ColliderScale>,
+            )>,
+        >,
+        Query<(&mut ColliderMultiple, &GlobalTransform), Without<ColliderScale>>,
+    )>,
 ) {
-    for (mut shape, transform, custom_scale) in changed_collider_scales.iter_mut() {
-        #[cfg(feature = "dim2")]
-        let effective_scale = match custom_scale {
-            Some(ColliderScale::Absolute(scale)) => *scale,
-            Some(ColliderScale::Relative(scale)) => {
-                *scale * transform.compute_transform().scale.xy()
-            }
-            None => transform.compute_transform().scale.xy(),
-        };
-        #[cfg(feature = "dim3")]
-        let effective_scale = match custom_scale {
-            Some(ColliderScale::Absolute(scale)) => *scale,
-            Some(ColliderScale::Relative(scale)) => *scale * transform.compute_transform().scale,
-            None => transform.compute_transform().scale,
-        };
+    for (entity, mut shape, transform, custom_scale) in collider_scales.p0().iter_mut() {
+        let effective_scale = effective_collider_scale(transform, custom_scale);
+        update_collider_scale(entity, &mut shape, effective_scale, &config, &mut warned);
+    }
+
+    for (mut multiple, transform, custom_scale) in collider_multiple_scales.p0().iter_mut() {
+        let effective_scale = effective_collider_scale(transform, custom_scale);
+        update_collider_multiple_scale(&mut multiple, effective_scale, &config);
+    }
+
+    let removed: Vec<Entity> = removed_collider_scales.read().collect();
 
-        if shape.scale != crate::geometry::get_snapped_scale(effective_scale) {
-            shape.set_scale(effective_scale, config.scaled_shape_subdivision);
+    let mut without_custom_scale = collider_scales.p1();
+    for entity in &removed {
+        if let Ok((entity, mut shape, transform)) = without_custom_scale.get_mut(*entity) {
+            let effective_scale = effective_collider_scale(transform, None);
+            update_collider_scale(entity, &mut shape, effective_scale, &config, &mut warned);
         }
     }
+
+    let mut multiples_without_custom_scale = collider_multiple_scales.p1();
+    for entity in &removed {
+        if let Ok((mut multiple, transform)) = multiples_without_custom_scale.get_mut(*entity) {
+            let effective_scale = effective_collider_scale(transform, None);
+            update_collider_multiple_scale(&mut multiple, effective_scale, &config);
+        }
+    }
+}
+
+fn effective_collider_scale(
+    transform: &GlobalTransform,
+    custom_scale: Option<&ColliderScale>,
+) -> Vect {
+    #[cfg(feature = "dim2")]
+    let effective_scale = match custom_scale {
+        Some(ColliderScale::Absolute(scale)) => *scale,
+        Some(ColliderScale::Relative(scale)) => *scale * transform.compute_transform().scale.xy(),
+        None => transform.compute_transform().scale.xy(),
+    };
+    #[cfg(feature = "dim3")]
+    let effective_scale = match custom_scale {
+        Some(ColliderScale::Absolute(scale)) => *scale,
+        Some(ColliderScale::Relative(scale)) => *scale * transform.compute_transform().scale,
+        None => transform.compute_transform().scale,
+    };
+    effective_scale
+}
+
+fn update_collider_scale(
+    entity: Entity,
+    shape: &mut Mut<Collider>,
+    effective_scale: Vect,
+    config: &RapierConfiguration,
+    warned: &mut HashSet<Entity>,
+) {
+    // Compare against the scale the policy actually applies: a snapped scale never matches the
+    // non-uniform effective scale.
+    if shape.scale == shape.scale_with_policy(effective_scale, config.nonuniform_scale_policy) {
+        return;
+    }
+
+    let snapped = shape.set_scale_with_policy(
+        effective_scale,
+        config.scaled_shape_subdivision,
+        config.nonuniform_scale_policy,
+    );
+
+    if snapped
+        && config.nonuniform_scale_policy == NonUniformScalePolicy::Warn
+        && warned.insert(entity)
+    {
+        warn!(
+            "The collider of entity {:?} can’t represent the non-uniform scale {}, it was snapped to {}.",
+            entity, effective_scale, shape.scale
+        );
+    }
+}
+
+/// The scale of a collider of a [`ColliderMultiple`]: the effective scale of the entity combined
+/// with the scale of the collider’s own transform.
+fn collider_multiple_part_scale(effective_scale: Vect, transform: &Transform) -> Vect {
+    #[cfg(feature = "dim2")]
+    return effective_scale * transform.scale.xy();
+    #[cfg(feature = "dim3")]
+    return effective_scale * transform.scale;
+}
+
+/// The position of a collider of a [`ColliderMultiple`] relative to the given transform of the
+/// entity. Its offset is scaled along with its shape.
+fn collider_multiple_part_position(
+    entity_transform: &Transform,
+    effective_scale: Vect,
+    transform: &Transform,
+) -> Transform {
+    #[cfg(feature = "dim2")]
+    let effective_scale = effective_scale.extend(1.0);
+    let part = Transform {
+        translation: effective_scale * transform.translation,
+        rotation: transform.rotation,
+        scale: Vec3::ONE,
+    };
+    entity_transform.with_scale(Vec3::ONE) * part
+}
+
+fn update_collider_multiple_scale(
+    multiple: &mut Mut<ColliderMultiple>,
+    effective_scale: Vect,
+    config: &RapierConfiguration,
+) {
+    let needs_update = multiple.0.iter().any(|(transform, collider)| {
+        let scale = collider_multiple_part_scale(effective_scale, transform);
+        collider.scale != collider.scale_with_policy(scale, config.nonuniform_scale_policy)
+    });
+    if !needs_update {
+        return;
+    }
+
+    // Changing the colliders re-creates them with their new scale.
+    for (transform, collider) in multiple.0.iter_mut() {
+        collider.set_scale_with_policy(
+            collider_multiple_part_scale(effective_scale, transform),
+            config.scaled_shape_subdivision,
+            config.nonuniform_scale_policy,
+        );
+    }
 }
 
+/// The collider components synchronized by [`apply_collider_user_changes`], along with their
+/// change ticks so that only the components that actually changed are written.
+pub type ColliderChangesComponents<'a> = (
+    &'a RapierColliderHandle,
+    Option<&'a PhysicsWorld>,
+    Option<Ref<'a, Collider>>,
+    Option<Ref<'a, ActiveEvents>>,
+    Option<Ref<'a, ActiveHooks>>,
+    Option<Ref<'a, ActiveCollisionTypes>>,
+    Option<Ref<'a, Friction>>,
+    Option<Ref<'a, Restitution>>,
+    Option<Ref<'a, ContactSkin>>,
+    Option<Ref<'a, CollisionGroups>>,
+    Option<Ref<'a, SolverGroups>>,
+    Option<Ref<'a, Sensor>>,
+    Option<Ref<'a, ContactForceEventThreshold>>,
+    Option<Ref<'a, ColliderMassProperties>>,
+);
+
+/// Filters the colliders with at least one of the [`ColliderChangesComponents`] changed.
+pub type ColliderChangesFilter = Or<(
+    Changed<Collider>,
+    Changed<ActiveEvents>,
+    Changed<ActiveHooks>,
+    Changed<ActiveCollisionTypes>,
+    Changed<Friction>,
+    Changed<Restitution>,
+    Changed<ContactSkin>,
+    Changed<CollisionGroups>,
+    Changed<SolverGroups>,
+    Changed<Sensor>,
+    Changed<ContactForceEventThreshold>,
+    Changed<ColliderMassProperties>,
+)>;
+
 /// System responsible for applying changes the user made to a collider-related component.
+///
+/// Each changed collider is fetched once, and all its changed components are applied together.
 pub fn apply_collider_user_changes(
     mut context: ResMut<RapierContext>,
     config: Res<RapierConfiguration>,
-    (changed_collider_transforms, parent_query, transform_query): (
-        Query<
-            (
-                Entity,
-                &RapierColliderHandle,
-                &GlobalTransform,
-                Option<&PhysicsWorld>,
-            ),
-            (Without<RapierRigidBodyHandle>, Changed<GlobalTransform>),
-        >,
-        Query<&Parent>,
-        Query<&Transform>,
-    ),
-    changed_shapes: Query<
-        (&RapierColliderHandle, &Collider, Option<&PhysicsWorld>),
-        Changed<Collider>,
-    >,
-    changed_active_events: Query<
-        (&RapierColliderHandle, &ActiveEvents, Option<&PhysicsWorld>),
-        Changed<ActiveEvents>,
-    >,
-    changed_active_hooks: Query<
-        (&RapierColliderHandle, &ActiveHooks, Option<&PhysicsWorld>),
-        Changed<ActiveHooks>,
-    >,
-    changed_active_collision_types: Query<
-        (
-            &RapierColliderHandle,
-            &ActiveCollisionTypes,
-            Option<&PhysicsWorld>,
-        ),
-        Changed<ActiveCollisionTypes>,
-    >,
-    (changed_friction, changed_restitution, changed_contact_skin): (
-        Query<(&RapierColliderHandle, &Friction, Option<&PhysicsWorld>), Changed<Friction>>,
-        Query<(&RapierColliderHandle, &Restitution, Option<&PhysicsWorld>), Changed<Restitution>>,
-        Query<(&RapierColliderHandle, &ContactSkin, Option<&PhysicsWorld>), Changed<ContactSkin>>,
-    ),
-    changed_collision_groups: Query<
-        (
-            &RapierColliderHandle,
-            &CollisionGroups,
-            Option<&PhysicsWorld>,
-        ),
-        Changed<CollisionGroups>,
-    >,
-    changed_solver_groups: Query<
-        (&RapierColliderHandle, &SolverGroups, Option<&PhysicsWorld>),
-        Changed<SolverGroups>,
-    >,
-    changed_sensors: Query<
-        (&RapierColliderHandle, &Sensor, Option<&PhysicsWorld>),
-        Changed<Sensor>,
-    >,
-    changed_disabled: Query<
-        (
-            &RapierColliderHandle,
-            &ColliderDisabled,
-            Option<&PhysicsWorld>,
-        ),
-        Changed<ColliderDisabled>,
-    >,
-    changed_contact_force_threshold: Query<
-        (
-            &RapierColliderHandle,
-            &ContactForceEventThreshold,
-            Option<&PhysicsWorld>,
-        ),
-        Changed<ContactForceEventThreshold>,
-    >,
-    changed_collider_mass_props: Query<
+    changed_collider_transforms: Query<
         (
+            Entity,
             &RapierColliderHandle,
-            &ColliderMassProperties,
+            &GlobalTransform,
             Option<&PhysicsWorld>,
         ),
-        Changed<ColliderMassProperties>,
+        (Without<RapierRigidBodyHandle>, Changed<GlobalTransform>),
     >,
-
+    parent_query: Query<&Parent>,
+    transform_query: Query<&Transform>,
+    changed_colliders: Query<ColliderChangesComponents, ColliderChangesFilter>,
     mut mass_modified: EventWriter<MassModifiedEvent>,
 ) {
     for (entity, handle, transform, world_within) in changed_collider_transforms.iter() {
@@ -173,128 +309,274 @@ pub fn apply_collider_user_changes(
         }
     }
 
-    for (handle, shape, world_within) in changed_shapes.iter() {
+    for (
+        handle,
+        world_within,
+        shape,
+        active_events,
+        active_hooks,
+        active_collision_types,
+        friction,
+        restitution,
+        contact_skin,
+        collision_groups,
+        solver_groups,
+        sensor,
+        contact_force_threshold,
+        mprops,
+    ) in changed_colliders.iter()
+    {
+        let world_id = world_within.map_or(DEFAULT_WORLD_ID, |w| w.world_id);
         let world = get_world(world_within, &mut context);
+        let Some(co) = world.colliders.get_mut(handle.0) else {
+            continue;
+        };
+        let mut mass_change_cause = None;
+        let mut pairs_changed = false;
 
-        if let Some(co) = world.colliders.get_mut(handle.0) {
-            let mut scaled_shape = shape.clone();
-            scaled_shape.set_scale(shape.scale, config.scaled_shape_subdivision);
+        if let Some(shape) = shape.filter(|shape| shape.is_changed()) {
+            let old_mprops = co.mass_properties();
+
+            // Only cloned and rescaled if `apply_scale` didn’t already scale it this way.
+            let scaled_shape = shape.rescaled_with_policy(
+                config.scaled_shape_subdivision,
+                config.nonuniform_scale_policy,
+            );
+
+            // The Rapier collider shares the shape of the `Collider`, so large shapes (like a
+            // heightfield whose heights were modified) aren’t copied.
             co.set_shape(scaled_shape.raw.clone());
 
-            if let Some(body) = co.parent() {
-                if let Some(body_entity) = world.rigid_body_entity(body) {
-                    mass_modified.send(body_entity.into());
-                }
+            // The shape of a collider without mass (like a sensor with a zero density) can
+            // change every frame without affecting the mass of its rigid-body.
+            if co.mass_properties() != old_mprops {
+                mass_change_cause = Some(MassChangeCause::ColliderShapeChanged);
             }
         }
-    }
 
-    for (handle, active_events, world_within) in changed_active_events.iter() {
-        let world = get_world(world_within, &mut context);
-
-        if let Some(co) = world.colliders.get_mut(handle.0) {
+        if let Some(active_events) = active_events.filter(|c| c.is_changed()) {
             co.set_active_events((*active_events).into())
         }
-    }
 
-    for (handle, active_hooks, world_within) in changed_active_hooks.iter() {
-        let world = get_world(world_within, &mut context);
-
-        if let Some(co) = world.colliders.get_mut(handle.0) {
+        if let Some(active_hooks) = active_hooks.filter(|c| c.is_changed()) {
             co.set_active_hooks((*active_hooks).into())
         }
-    }
 
-    for (handle, active_collision_types, world_within) in changed_active_collision_types.iter() {
-        let world = get_world(world_within, &mut context);
-
-        if let Some(co) = world.colliders.get_mut(handle.0) {
+        if let Some(active_collision_types) = active_collision_types.filter(|c| c.is_changed()) {
             co.set_active_collision_types((*active_collision_types).into())
         }
-    }
 
-    for (handle, friction, world_within) in changed_friction.iter() {
-        let world = get_world(world_within, &mut context);
-
-        if let Some(co) = world.colliders.get_mut(handle.0) {
+        if let Some(friction) = friction.filter(|c| c.is_changed()) {
             co.set_friction(friction.coefficient);
             co.set_friction_combine_rule(friction.combine_rule.into());
         }
-    }
 
-    for (handle, restitution, world_within) in changed_restitution.iter() {
-        let world = get_world(world_within, &mut context);
-
-        if let Some(co) = world.colliders.get_mut(handle.0) {
+        if let Some(restitution) = restitution.filter(|c| c.is_changed()) {
             co.set_restitution(restitution.coefficient);
             co.set_restitution_combine_rule(restitution.combine_rule.into());
         }
-    }
 
-    for (handle, contact_skin, world_within) in changed_contact_skin.iter() {
-        let world = get_world(world_within, &mut context);
-
-        if let Some(co) = world.colliders.get_mut(handle.0) {
+        if let Some(contact_skin) = contact_skin.filter(|c| c.is_changed()) {
             co.set_contact_skin(contact_skin.0);
         }
-    }
 
-    for (handle, collision_groups, world_within) in changed_collision_groups.iter() {
-        let world = get_world(world_within, &mut context);
-
-        if let Some(co) = world.colliders.get_mut(handle.0) {
+        if let Some(collision_groups) = collision_groups.filter(|c| c.is_changed()) {
             co.set_collision_groups((*collision_groups).into());
+            pairs_changed = true;
         }
-    }
 
-    for (handle, solver_groups, world_within) in changed_solver_groups.iter() {
-        let world = get_world(world_within, &mut context);
-
-        if let Some(co) = world.colliders.get_mut(handle.0) {
+        if let Some(solver_groups) = solver_groups.filter(|c| c.is_changed()) {
             co.set_solver_groups((*solver_groups).into());
+            pairs_changed = true;
         }
-    }
 
-    for (handle, _, world_within) in changed_sensors.iter() {
-        let world = get_world(world_within, &mut context);
+        let sensor_inserted = sensor.is_some_and(|c| c.is_changed());
 
-        if let Some(co) = world.colliders.get_mut(handle.0) {
-            co.set_sensor(true);
+        if let Some(threshold) = contact_force_threshold.filter(|c| c.is_changed()) {
+            co.set_contact_force_event_threshold(threshold.0);
+        }
+
+        if let Some(mprops) = mprops.filter(|c| c.is_changed()) {
+            match *mprops {
+                ColliderMassProperties::Density(density) => co.set_density(density),
+                ColliderMassProperties::Mass(mass) => co.set_mass(mass),
+                ColliderMassProperties::MassProperties(mprops) => {
+                    co.set_mass_properties(mprops.into_rapier())
+                }
+            }
+
+            mass_change_cause = Some(MassChangeCause::ColliderMassPropertiesChanged);
+        }
+
+        // Send a single event even if both the shape and the mass-properties changed.
+        if let Some(cause) = mass_change_cause {
+            if let Some(event) = co
+                .parent()
+                .and_then(|body| world.rigid_body_entity(body))
+                .and_then(|body_entity| world.mass_modified_event(world_id, body_entity, cause))
+            {
+                mass_modified.send(event);
+            }
+        }
+
+        if sensor_inserted {
+            world.set_collider_sensor(world_id, handle.0, true);
+        }
+
+        // Make sure the existing pairs of the collider are re-evaluated during the next step.
+        if pairs_changed {
+            world.wake_up_collider_pairs(handle.0);
         }
     }
+}
 
-    for (handle, _, world_within) in changed_disabled.iter() {
-        let world = get_world(world_within, &mut context);
+/// System responsible for registering the [`ConveyorBelt`]s into the world of their collider,
+/// and for enabling the [`ActiveHooks::MODIFY_SOLVER_CONTACTS`] flag they rely on.
+pub fn apply_conveyor_belts(
+    mut commands: Commands,
+    mut context: ResMut<RapierContext>,
+    mut changed_belts: Query<
+        (
+            Entity,
+            &ConveyorBelt,
+            Option<&mut ActiveHooks>,
+            Option<&PhysicsWorld>,
+        ),
+        Or<(Changed<ConveyorBelt>, Changed<PhysicsWorld>)>,
+    >,
+) {
+    for (entity, belt, active_hooks, world_within) in changed_belts.iter_mut() {
+        // Remember that the flag was added by the belt, so it can be removed along with it.
+        match active_hooks {
+            Some(mut active_hooks) => {
+                if !active_hooks.contains(ActiveHooks::MODIFY_SOLVER_CONTACTS) {
+                    active_hooks.insert(ActiveHooks::MODIFY_SOLVER_CONTACTS);
+                    commands.entity(entity).insert(ConveyorBeltActiveHooks);
+                }
+            }
+            None => {
+                commands
+                    .entity(entity)
+                    .insert((ActiveHooks::MODIFY_SOLVER_CONTACTS, ConveyorBeltActiveHooks));
+            }
+        }
 
-        if let Some(co) = world.colliders.get_mut(handle.0) {
-            co.set_enabled(false);
+        // The belt may have moved to another world.
+        for world in context.worlds.values_mut() {
+            world.conveyor_belts.remove(&entity);
         }
+
+        get_world(world_within, &mut context)
+            .conveyor_belts
+            .insert(entity, belt.velocity);
     }
+}
+
+/// System responsible for enabling or disabling the Rapier colliders depending on the presence
+/// of the [`ColliderDisabled`] component.
+///
+/// Like [`sync_disabled_rigid_bodies`](super::sync_disabled_rigid_bodies), the state is derived
+/// from the components whenever the marker is inserted or removed, or the collider is
+/// (re)created. This applies to both the [`Collider`] and the [`ColliderMultiple`] of the entity.
+pub fn sync_disabled_colliders(
+    mut context: ResMut<RapierContext>,
+    mut removed_disabled: RemovedComponents<ColliderDisabled>,
+    changed: Query<
+        Entity,
+        Or<(
+            Changed<RapierColliderHandle>,
+            Changed<RapierColliderHandles>,
+            Changed<ColliderDisabled>,
+        )>,
+    >,
+    colliders: Query<(
+        Option<&RapierColliderHandle>,
+        Option<&RapierColliderHandles>,
+        Has<ColliderDisabled>,
+        Option<&PhysicsWorld>,
+    )>,
+) {
+    for entity in changed.iter().chain(removed_disabled.read()) {
+        let Ok((handle, handles, disabled, world_within)) = colliders.get(entity) else {
+            continue;
+        };
 
-    for (handle, threshold, world_within) in changed_contact_force_threshold.iter() {
         let world = get_world(world_within, &mut context);
+        let handles = handle.map(|handle| handle.0).into_iter().chain(
+            handles
+                .into_iter()
+                .flat_map(|handles| handles.0.iter().copied()),
+        );
 
-        if let Some(co) = world.colliders.get_mut(handle.0) {
-            co.set_contact_force_event_threshold(threshold.0);
+        for handle in handles {
+            if let Some(co) = world.colliders.get_mut(handle) {
+                if co.is_enabled() == disabled {
+                    co.set_enabled(!disabled);
+                }
+            }
         }
     }
+}
+
+/// System responsible for attaching colliders to their new rigid-body when their `Parent`
+/// changed or was removed.
+pub fn apply_collider_parent_changes(
+    mut context: ResMut<RapierContext>,
+    changed_parents: Query<Entity, (With<RapierColliderHandle>, Changed<Parent>)>,
+    mut removed_parents: RemovedComponents<Parent>,
+    colliders: Query<
+        (
+            &RapierColliderHandle,
+            &GlobalTransform,
+            Option<&PhysicsWorld>,
+        ),
+        Without<RapierRigidBodyHandle>,
+    >,
+    (parent_query, transform_query): (Query<&Parent>, Query<&Transform>),
+    mut mass_modified: EventWriter<MassModifiedEvent>,
+) {
+    for entity in changed_parents.iter().chain(removed_parents.read()) {
+        let Ok((handle, transform, world_within)) = colliders.get(entity) else {
+            continue;
+        };
 
-    for (handle, mprops, world_within) in changed_collider_mass_props.iter() {
         let world = get_world(world_within, &mut context);
+        let Some(old_body) = world.colliders.get(handle.0).map(|co| co.parent()) else {
+            continue;
+        };
+        let (new_body, collider_position) =
+            collider_offset(entity, world, &parent_query, &transform_query);
+
+        if new_body != old_body {
+            world
+                .colliders
+                .set_parent(handle.0, new_body, &mut world.bodies);
+        }
 
         if let Some(co) = world.colliders.get_mut(handle.0) {
-            match mprops {
-                ColliderMassProperties::Density(density) => co.set_density(*density),
-                ColliderMassProperties::Mass(mass) => co.set_mass(*mass),
-                ColliderMassProperties::MassProperties(mprops) => {
-                    co.set_mass_properties(mprops.into_rapier())
-                }
+            if new_body.is_some() {
+                co.set_position_wrt_parent(utils::transform_to_iso(&collider_position));
+            } else {
+                co.set_position(utils::transform_to_iso(&transform.compute_transform()));
             }
+        }
 
-            if let Some(body) = co.parent() {
-                if let Some(body_entity) = world.rigid_body_entity(body) {
-                    mass_modified.send(body_entity.into());
-                }
+        if new_body == old_body {
+            continue;
+        }
+
+        // Refresh the mass-properties of both the old and new rigid-bodies.
+        let world_id = world_within.map_or(DEFAULT_WORLD_ID, |w| w.world_id);
+        let bodies = [
+            (old_body, MassChangeCause::ColliderRemoved),
+            (new_body, MassChangeCause::ColliderAdded),
+        ];
+        for (body, cause) in bodies {
+            if let Some(event) = body
+                .and_then(|body| world.rigid_body_entity(body))
+                .and_then(|body_entity| world.mass_modified_event(world_id, body_entity, cause))
+            {
+                mass_modified.send(event);
             }
         }
     }
@@ -352,6 +634,7 @@ pub fn init_colliders(
     mut rigid_body_mprops: Query<&mut ReadMassProperties>,
     parent_query: Query<&Parent>,
     transform_query: Query<&Transform>,
+    mut mass_modified: EventWriter<MassModifiedEvent>,
 ) {
     for (
         (
@@ -374,64 +657,31 @@ pub fn init_colliders(
         world_within,
     ) in colliders.iter()
     {
+        let world_id = world_within.map_or(DEFAULT_WORLD_ID, |w| w.world_id);
         let world = get_world(world_within, &mut context);
 
-        let mut scaled_shape = shape.clone();
-        scaled_shape.set_scale(shape.scale, config.scaled_shape_subdivision);
-        let mut builder = ColliderBuilder::new(scaled_shape.raw.clone());
-
-        builder = builder.sensor(sensor.is_some());
-        builder = builder.enabled(disabled.is_none());
-
-        if let Some(mprops) = mprops {
-            builder = match mprops {
-                ColliderMassProperties::Density(density) => builder.density(*density),
-                ColliderMassProperties::Mass(mass) => builder.mass(*mass),
-                ColliderMassProperties::MassProperties(mprops) => {
-                    builder.mass_properties(mprops.into_rapier())
-                }
-            };
-        }
-
-        if let Some(active_events) = active_events {
-            builder = builder.active_events((*active_events).into());
-        }
-
-        if let Some(active_hooks) = active_hooks {
-            builder = builder.active_hooks((*active_hooks).into());
-        }
-
-        if let Some(active_collision_types) = active_collision_types {
-            builder = builder.active_collision_types((*active_collision_types).into());
-        }
-
-        if let Some(friction) = friction {
-            builder = builder
-                .friction(friction.coefficient)
-                .friction_combine_rule(friction.combine_rule.into());
-        }
-
-        if let Some(restitution) = restitution {
-            builder = builder
-                .restitution(restitution.coefficient)
-                .restitution_combine_rule(restitution.combine_rule.into());
-        }
-
-        if let Some(contact_skin) = contact_skin {
-            builder = builder.contact_skin(contact_skin.0);
-        }
-
-        if let Some(collision_groups) = collision_groups {
-            builder = builder.collision_groups((*collision_groups).into());
-        }
-
-        if let Some(solver_groups) = solver_groups {
-            builder = builder.solver_groups((*solver_groups).into());
-        }
-
-        if let Some(threshold) = contact_force_event_threshold {
-            builder = builder.contact_force_event_threshold(threshold.0);
-        }
+        // Only cloned and rescaled if `apply_scale` didn’t already scale it this way.
+        let scaled_shape = shape.rescaled_with_policy(
+            config.scaled_shape_subdivision,
+            config.nonuniform_scale_policy,
+        );
+        let mut builder = collider_builder(
+            scaled_shape.raw.clone(),
+            (
+                sensor,
+                mprops,
+                active_events,
+                active_hooks,
+                active_collision_types,
+                friction,
+                restitution,
+                contact_skin,
+                collision_groups,
+                solver_groups,
+                contact_force_event_threshold,
+                disabled,
+            ),
+        );
 
         let body_entity = entity;
         let (body_handle, child_transform) =
@@ -449,11 +699,18 @@ pub fn init_colliders(
                 // Inserting the collider changed the rigid-body’s mass properties.
                 // Read them back from the engine.
                 if let Some(parent_body) = world.bodies.get(body_handle) {
-                    mprops.set(MassProperties::from_rapier(
-                        parent_body.mass_properties().local_mprops,
-                    ));
+                    *mprops = ReadMassProperties::from_rapier(parent_body.mass_properties());
                 }
             }
+
+            if let Some(event) = world
+                .rigid_body_entity(body_handle)
+                .and_then(|body_entity| {
+                    world.mass_modified_event(world_id, body_entity, MassChangeCause::ColliderAdded)
+                })
+            {
+                mass_modified.send(event);
+            }
             handle
         } else {
             let global_transform = global_transform.cloned().unwrap_or_default();
@@ -468,64 +725,697 @@ pub fn init_colliders(
         world.entity2collider.insert(entity, handle);
     }
 }
-/// System responsible for creating `Collider` components from `AsyncCollider` components if the
-/// corresponding mesh has become available.
-#[cfg(all(feature = "dim3", feature = "async-collider"))]
-pub fn init_async_colliders(
-    mut commands: Commands,
-    meshes: Res<Assets<Mesh>>,
-    async_colliders: Query<(Entity, &Handle<Mesh>, &AsyncCollider)>,
-) {
-    for (entity, mesh_handle, async_collider) in async_colliders.iter() {
-        if let Some(mesh) = meshes.get(mesh_handle) {
-            match Collider::from_bevy_mesh(mesh, &async_collider.0) {
-                Some(collider) => {
-                    commands
-                        .entity(entity)
-                        .insert(collider)
-                        .remove::<AsyncCollider>();
-                }
-                None => error!("Unable to generate collider from mesh {:?}", mesh),
+/// Creates the builder of a collider with the given shape, configured by the given components.
+fn collider_builder(shape: SharedShape, settings: ColliderSettings) -> ColliderBuilder {
+    let (
+        sensor,
+        mprops,
+        active_events,
+        active_hooks,
+        active_collision_types,
+        friction,
+        restitution,
+        contact_skin,
+        collision_groups,
+        solver_groups,
+        contact_force_event_threshold,
+        disabled,
+    ) = settings;
+    let mut builder = ColliderBuilder::new(shape);
+
+    builder = builder.sensor(sensor.is_some());
+    builder = builder.enabled(disabled.is_none());
+
+    if let Some(mprops) = mprops {
+        builder = match mprops {
+            ColliderMassProperties::Density(density) => builder.density(*density),
+            ColliderMassProperties::Mass(mass) => builder.mass(*mass),
+            ColliderMassProperties::MassProperties(mprops) => {
+                builder.mass_properties(mprops.into_rapier())
             }
-        }
+        };
     }
-}
 
-/// System responsible for creating `Collider` components from `AsyncSceneCollider` components if the
-/// corresponding scene has become available.
+    if let Some(active_events) = active_events {
+        builder = builder.active_events((*active_events).into());
+    }
+
+    if let Some(active_hooks) = active_hooks {
+        builder = builder.active_hooks((*active_hooks).into());
+    }
+
+    if let Some(active_collision_types) = active_collision_types {
+        builder = builder.active_collision_types((*active_collision_types).into());
+    }
+
+    if let Some(friction) = friction {
+        builder = builder
+            .friction(friction.coefficient)
+            .friction_combine_rule(friction.combine_rule.into());
+    }
+
+    if let Some(restitution) = restitution {
+        builder = builder
+            .restitution(restitution.coefficient)
+            .restitution_combine_rule(restitution.combine_rule.into());
+    }
+
+    if let Some(contact_skin) = contact_skin {
+        builder = builder.contact_skin(contact_skin.0);
+    }
+
+    if let Some(collision_groups) = collision_groups {
+        builder = builder.collision_groups((*collision_groups).into());
+    }
+
+    if let Some(solver_groups) = solver_groups {
+        builder = builder.solver_groups((*solver_groups).into());
+    }
+
+    if let Some(threshold) = contact_force_event_threshold {
+        builder = builder.contact_force_event_threshold(threshold.0);
+    }
+
+    builder
+}
+
+/// System responsible for creating the Rapier colliders of new `ColliderMultiple` components.
+///
+/// The colliders are attached to the same rigid-body a `Collider` on this entity would be
+/// attached to.
+pub fn init_collider_multiples(
+    mut commands: Commands,
+    config: Res<RapierConfiguration>,
+    mut context: ResMut<RapierContext>,
+    colliders: Query<
+        (
+            Entity,
+            &ColliderMultiple,
+            ColliderSettings,
+            Option<&GlobalTransform>,
+            Option<&ColliderScale>,
+            Option<&PhysicsWorld>,
+        ),
+        Without<RapierColliderHandles>,
+    >,
+    mut rigid_body_mprops: Query<&mut ReadMassProperties>,
+    parent_query: Query<&Parent>,
+    transform_query: Query<&Transform>,
+    mut mass_modified: EventWriter<MassModifiedEvent>,
+) {
+    for (entity, multiple, settings, global_transform, custom_scale, world_within) in
+        colliders.iter()
+    {
+        let world_id = world_within.map_or(DEFAULT_WORLD_ID, |w| w.world_id);
+        let world = get_world(world_within, &mut context);
+        let handles = insert_collider_multiple(
+            world,
+            entity,
+            multiple,
+            settings,
+            global_transform,
+            custom_scale,
+            &config,
+            &parent_query,
+            &transform_query,
+        );
+        commands
+            .entity(entity)
+            .insert(RapierColliderHandles(handles.clone()));
+        world.entity2colliders.insert(entity, handles);
+
+        if let Some(body_entity) = world.collider_multiple_parent(entity) {
+            if let Ok(mut mprops) = rigid_body_mprops.get_mut(body_entity) {
+                // Inserting the colliders changed the rigid-body’s mass properties.
+                // Read them back from the engine.
+                if let Some(parent_body) = world
+                    .entity2body
+                    .get(&body_entity)
+                    .and_then(|h| world.bodies.get(*h))
+                {
+                    *mprops = ReadMassProperties::from_rapier(parent_body.mass_properties());
+                }
+            }
+
+            if let Some(event) =
+                world.mass_modified_event(world_id, body_entity, MassChangeCause::ColliderAdded)
+            {
+                mass_modified.send(event);
+            }
+        }
+    }
+}
+
+/// Inserts the colliders of a `ColliderMultiple` to the given world.
+#[allow(clippy::too_many_arguments)]
+fn insert_collider_multiple(
+    world: &mut RapierWorld,
+    entity: Entity,
+    multiple: &ColliderMultiple,
+    settings: ColliderSettings,
+    global_transform: Option<&GlobalTransform>,
+    custom_scale: Option<&ColliderScale>,
+    config: &RapierConfiguration,
+    parent_query: &Query<&Parent>,
+    transform_query: &Query<&Transform>,
+) -> Vec<ColliderHandle> {
+    let (body_handle, child_transform) =
+        collider_offset(entity, world, parent_query, transform_query);
+    let global_transform = global_transform.cloned().unwrap_or_default();
+    let entity_transform = match body_handle {
+        Some(_) => child_transform,
+        None => global_transform.compute_transform(),
+    };
+    // The same scale `apply_scale` gives to the colliders, so they are created with their final
+    // shape.
+    let effective_scale = effective_collider_scale(&global_transform, custom_scale);
+
+    multiple
+        .0
+        .iter()
+        .map(|(transform, collider)| {
+            let mut scaled_shape = collider.clone();
+            scaled_shape.set_scale_with_policy(
+                collider_multiple_part_scale(effective_scale, transform),
+                config.scaled_shape_subdivision,
+                config.nonuniform_scale_policy,
+            );
+
+            let builder = collider_builder(scaled_shape.raw.clone(), settings)
+                .user_data(entity.to_bits() as u128)
+                .position(utils::transform_to_iso(&collider_multiple_part_position(
+                    &entity_transform,
+                    effective_scale,
+                    transform,
+                )));
+
+            match body_handle {
+                Some(body_handle) => {
+                    world
+                        .colliders
+                        .insert_with_parent(builder, body_handle, &mut world.bodies)
+                }
+                None => world.colliders.insert(builder),
+            }
+        })
+        .collect()
+}
+
+/// System responsible for applying changes the user made to a `ColliderMultiple`, or to the
+/// components configuring its colliders.
+///
+/// Changing the `ColliderMultiple` itself re-creates all its colliders.
+#[allow(clippy::type_complexity)]
+pub fn apply_collider_multiple_user_changes(
+    mut commands: Commands,
+    mut context: ResMut<RapierContext>,
+    config: Res<RapierConfiguration>,
+    changed_multiples: Query<
+        (
+            Entity,
+            &ColliderMultiple,
+            Ref<RapierColliderHandles>,
+            ColliderSettings,
+            Option<&GlobalTransform>,
+            Option<&ColliderScale>,
+            Option<&PhysicsWorld>,
+        ),
+        Changed<ColliderMultiple>,
+    >,
+    changed_settings: Query<
+        (
+            &RapierColliderHandles,
+            ColliderSettings,
+            Option<&PhysicsWorld>,
+        ),
+        Or<(
+            Changed<Sensor>,
+            Changed<ColliderMassProperties>,
+            Changed<ActiveEvents>,
+            Changed<ActiveHooks>,
+            Changed<ActiveCollisionTypes>,
+            Changed<Friction>,
+            Changed<Restitution>,
+            Changed<ContactSkin>,
+            Changed<CollisionGroups>,
+            Changed<SolverGroups>,
+            Changed<ContactForceEventThreshold>,
+        )>,
+    >,
+    changed_transforms: Query<
+        (
+            Entity,
+            &ColliderMultiple,
+            &RapierColliderHandles,
+            &GlobalTransform,
+            Option<&ColliderScale>,
+            Option<&PhysicsWorld>,
+        ),
+        (Without<RapierRigidBodyHandle>, Changed<GlobalTransform>),
+    >,
+    (parent_query, transform_query): (Query<&Parent>, Query<&Transform>),
+    mut mass_modified: EventWriter<MassModifiedEvent>,
+) {
+    for (entity, multiple, handles, settings, global_transform, custom_scale, world_within) in
+        changed_multiples.iter()
+    {
+        if handles.is_added() {
+            // The colliders were just created by `init_collider_multiples`.
+            continue;
+        }
+
+        let world_id = world_within.map_or(DEFAULT_WORLD_ID, |w| w.world_id);
+        let world = get_world(world_within, &mut context);
+
+        for handle in world.entity2colliders.remove(&entity).unwrap_or_default() {
+            world
+                .colliders
+                .remove(handle, &mut world.islands, &mut world.bodies, true);
+            world.deleted_colliders.insert(handle, entity);
+        }
+
+        let handles = insert_collider_multiple(
+            world,
+            entity,
+            multiple,
+            settings,
+            global_transform,
+            custom_scale,
+            &config,
+            &parent_query,
+            &transform_query,
+        );
+        commands
+            .entity(entity)
+            .insert(RapierColliderHandles(handles.clone()));
+        world.entity2colliders.insert(entity, handles);
+
+        if let Some(event) = world
+            .collider_multiple_parent(entity)
+            .and_then(|body_entity| {
+                world.mass_modified_event(
+                    world_id,
+                    body_entity,
+                    MassChangeCause::ColliderShapeChanged,
+                )
+            })
+        {
+            mass_modified.send(event);
+        }
+    }
+
+    for (handles, settings, world_within) in changed_settings.iter() {
+        let world_id = world_within.map_or(DEFAULT_WORLD_ID, |w| w.world_id);
+        let world = get_world(world_within, &mut context);
+        let (
+            sensor,
+            mprops,
+            active_events,
+            active_hooks,
+            active_collision_types,
+            friction,
+            restitution,
+            contact_skin,
+            collision_groups,
+            solver_groups,
+            contact_force_event_threshold,
+            _disabled,
+        ) = settings;
+
+        for handle in &handles.0 {
+            world.set_collider_sensor(world_id, *handle, sensor.is_some());
+            let Some(co) = world.colliders.get_mut(*handle) else {
+                continue;
+            };
+
+            match mprops {
+                Some(ColliderMassProperties::Density(density)) => co.set_density(*density),
+                Some(ColliderMassProperties::Mass(mass)) => co.set_mass(*mass),
+                Some(ColliderMassProperties::MassProperties(mprops)) => {
+                    co.set_mass_properties(mprops.into_rapier())
+                }
+                None => {}
+            }
+            if let Some(active_events) = active_events {
+                co.set_active_events((*active_events).into());
+            }
+            if let Some(active_hooks) = active_hooks {
+                co.set_active_hooks((*active_hooks).into());
+            }
+            if let Some(active_collision_types) = active_collision_types {
+                co.set_active_collision_types((*active_collision_types).into());
+            }
+            if let Some(friction) = friction {
+                co.set_friction(friction.coefficient);
+                co.set_friction_combine_rule(friction.combine_rule.into());
+            }
+            if let Some(restitution) = restitution {
+                co.set_restitution(restitution.coefficient);
+                co.set_restitution_combine_rule(restitution.combine_rule.into());
+            }
+            if let Some(contact_skin) = contact_skin {
+                co.set_contact_skin(contact_skin.0);
+            }
+            if let Some(collision_groups) = collision_groups {
+                co.set_collision_groups((*collision_groups).into());
+            }
+            if let Some(solver_groups) = solver_groups {
+                co.set_solver_groups((*solver_groups).into());
+            }
+            if let Some(threshold) = contact_force_event_threshold {
+                co.set_contact_force_event_threshold(threshold.0);
+            }
+
+            // The changed settings may be the interaction groups, so make sure the existing pairs
+            // of the collider are re-evaluated during the next step.
+            world.wake_up_collider_pairs(*handle);
+        }
+    }
+
+    for (entity, multiple, handles, global_transform, custom_scale, world_within) in
+        changed_transforms.iter()
+    {
+        let world = get_world(world_within, &mut context);
+        let attached = world.collider_multiple_parent(entity).is_some();
+        let (_, child_transform) = collider_offset(entity, world, &parent_query, &transform_query);
+        let effective_scale = effective_collider_scale(global_transform, custom_scale);
+
+        for ((transform, _), handle) in multiple.0.iter().zip(handles.0.iter()) {
+            if let Some(co) = world.colliders.get_mut(*handle) {
+                if attached {
+                    let position = collider_multiple_part_position(
+                        &child_transform,
+                        effective_scale,
+                        transform,
+                    );
+                    co.set_position_wrt_parent(utils::transform_to_iso(&position));
+                } else {
+                    let position = collider_multiple_part_position(
+                        &global_transform.compute_transform(),
+                        effective_scale,
+                        transform,
+                    );
+                    co.set_position(utils::transform_to_iso(&position));
+                }
+            }
+        }
+    }
+}
+
+/// System responsible for generating, in the background, `Collider` components from
+/// `AsyncCollider` components if the corresponding mesh has become available.
+///
+/// The generated collider is inserted by [`poll_collider_generation_tasks`]. If the generation
+/// failed, it is only retried once the mesh handle, the mesh asset, or the `AsyncCollider` is
+/// modified.
+#[cfg(feature = "async-collider")]
+pub fn init_async_colliders(
+    mut commands: Commands,
+    meshes: Res<Assets<Mesh>>,
+    mut mesh_events: EventReader<AssetEvent<Mesh>>,
+    async_colliders: Query<
+        (
+            Entity,
+            Ref<Handle<Mesh>>,
+            Ref<AsyncCollider>,
+            Has<AsyncColliderFailed>,
+        ),
+        Without<ColliderGenerationTask>,
+    >,
+) {
+    let modified_meshes: HashSet<AssetId<Mesh>> = mesh_events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::Modified { id } => Some(*id),
+            _ => None,
+        })
+        .collect();
+
+    for (entity, mesh_handle, async_collider, failed) in async_colliders.iter() {
+        if failed {
+            if !mesh_handle.is_changed()
+                && !async_collider.is_changed()
+                && !modified_meshes.contains(&mesh_handle.id())
+            {
+                continue;
+            }
+
+            commands.entity(entity).remove::<AsyncColliderFailed>();
+        }
+
+        if let Some(mesh) = meshes.get(&*mesh_handle) {
+            commands
+                .entity(entity)
+                .insert(ColliderGenerationTask::spawn(
+                    mesh.clone(),
+                    async_collider.0.clone(),
+                ));
+        }
+    }
+}
+
+/// System responsible for inserting the `Collider` components generated by the
+/// `ColliderGenerationTask` components, and removing the corresponding `AsyncCollider`.
+///
+/// If no collider could be generated from the mesh, an error is logged, an
+/// [`AsyncColliderFailedEvent`] is emitted, and only the `ColliderGenerationTask` is removed,
+/// leaving the `AsyncCollider` (if any) in place. It is then marked as failed, so the generation
+/// isn’t retried every frame.
+#[cfg(feature = "async-collider")]
+pub fn poll_collider_generation_tasks(
+    mut commands: Commands,
+    mut tasks: Query<(Entity, &mut ColliderGenerationTask, Has<AsyncCollider>)>,
+    mut failures: EventWriter<AsyncColliderFailedEvent>,
+) {
+    for (entity, mut task, has_async_collider) in tasks.iter_mut() {
+        let Some(result) = block_on(future::poll_once(&mut task.task)) else {
+            continue;
+        };
+
+        let mut entity_commands = commands.entity(entity);
+        entity_commands.remove::<ColliderGenerationTask>();
+
+        match result {
+            Ok(collider) => {
+                entity_commands.insert(collider).remove::<AsyncCollider>();
+                if let Some(groups) = task.collision_groups {
+                    entity_commands.insert(groups);
+                }
+                if task.sensor {
+                    entity_commands.insert(Sensor);
+                }
+            }
+            Err(error) => {
+                error!(
+                    "Unable to generate collider from the mesh of entity {:?}: {}",
+                    entity, error
+                );
+                if has_async_collider {
+                    entity_commands.insert(AsyncColliderFailed);
+                }
+                failures.send(AsyncColliderFailedEvent { entity, error });
+            }
+        }
+    }
+}
+
+/// System responsible for creating `Collider` components from `AsyncSpriteCollider` components if the
+/// corresponding image has become available.
+#[cfg(all(feature = "dim2", feature = "image-collider"))]
+pub fn init_async_sprite_colliders(
+    mut commands: Commands,
+    images: Res<Assets<Image>>,
+    async_colliders: Query<(Entity, &Handle<Image>, &AsyncSpriteCollider)>,
+) {
+    for (entity, image_handle, async_collider) in async_colliders.iter() {
+        let Some(image) = images.get(image_handle) else {
+            continue;
+        };
+
+        let mut entity_commands = commands.entity(entity);
+        entity_commands.remove::<AsyncSpriteCollider>();
+
+        match Collider::from_image_alpha_with_parameters(
+            image,
+            &async_collider.shape,
+            &async_collider.parameters,
+        ) {
+            Ok(collider) => {
+                entity_commands.insert(collider);
+            }
+            Err(error) => error!(
+                "Unable to generate collider from the image of entity {:?}: {}",
+                entity, error
+            ),
+        }
+    }
+}
+
+/// System responsible for inserting the `Collider` described by new or modified
+/// `ColliderShapeDesc` components.
+///
+/// The collider isn’t replaced if it already has the described shape, so saving the description
+/// of a collider doesn’t recreate it. Descriptions referencing a mesh that isn’t loaded yet are
+/// retried on the next frames.
+pub fn init_colliders_from_shape_descs(
+    mut commands: Commands,
+    #[cfg(feature = "async-collider")] meshes: Option<Res<Assets<Mesh>>>,
+    changed_descs: Query<Entity, Changed<ColliderShapeDesc>>,
+    descs: Query<(&ColliderShapeDesc, Option<&Collider>)>,
+    mut pending: Local<HashSet<Entity>>,
+) {
+    pending.extend(changed_descs.iter());
+
+    pending.retain(|entity| {
+        let Ok((desc, collider)) = descs.get(*entity) else {
+            return false;
+        };
+        if collider.is_some_and(|collider| collider.shape_desc() == *desc) {
+            return false;
+        }
+
+        #[cfg(feature = "async-collider")]
+        let new_collider = match meshes.as_deref() {
+            Some(meshes) if !desc.meshes_loaded(meshes) => return true,
+            Some(meshes) => desc.to_collider_with_meshes(meshes),
+            None => desc.to_collider(),
+        };
+        #[cfg(not(feature = "async-collider"))]
+        let new_collider = desc.to_collider();
+
+        match new_collider {
+            Some(collider) => {
+                commands.entity(*entity).insert(collider);
+            }
+            None => warn!(
+                "Unable to create a collider from the shape description of entity {:?}.",
+                entity
+            ),
+        }
+        false
+    });
+}
+
+/// System responsible for inserting or updating the `ColliderShapeDesc` of new or modified
+/// colliders, so they can be saved in a scene.
+///
+/// Descriptions referencing mesh assets are kept as-is, since the collider built from the mesh
+/// can’t be described by its handle.
+pub fn sync_collider_shape_descs(
+    mut commands: Commands,
+    mut colliders: Query<(Entity, &Collider, Option<&mut ColliderShapeDesc>), Changed<Collider>>,
+) {
+    for (entity, collider, desc) in colliders.iter_mut() {
+        match desc {
+            Some(desc) if desc.references_meshes() => {}
+            Some(mut desc) => {
+                desc.set_if_neq(collider.shape_desc());
+            }
+            None => {
+                commands.entity(entity).insert(collider.shape_desc());
+            }
+        }
+    }
+}
+
+/// System responsible for creating `Collider` components from `AsyncSceneCollider` components if the
+/// corresponding scene has become available.
+///
+/// An `AsyncSceneColliderOverride` on a scene entity takes precedence over the shapes matched by
+/// name for this entity and its descendants.
+///
+/// Scene entities whose mesh isn’t loaded are retried on the next frames: the `AsyncSceneCollider`
+/// is only removed once every scene entity received its collider or was skipped.
 #[cfg(all(feature = "dim3", feature = "async-collider"))]
+#[allow(clippy::too_many_arguments)]
 pub fn init_async_scene_colliders(
     mut commands: Commands,
     meshes: Res<Assets<Mesh>>,
     scene_spawner: Res<SceneSpawner>,
     async_colliders: Query<(Entity, &SceneInstance, &AsyncSceneCollider)>,
     children: Query<&Children>,
-    mesh_handles: Query<(&Name, &Handle<Mesh>)>,
+    mesh_handles: Query<(Option<&Name>, &Handle<Mesh>)>,
+    overrides: Query<&AsyncSceneColliderOverride>,
+    mut completed_entities: Local<HashMap<Entity, HashSet<Entity>>>,
+    mut warned_entities: Local<HashSet<Entity>>,
 ) {
+    // Forget about the scenes and scene entities that were removed in the meantime.
+    completed_entities.retain(|scene_entity, _| async_colliders.contains(*scene_entity));
+    warned_entities.retain(|entity| mesh_handles.contains(*entity));
+
     for (scene_entity, scene_instance, async_collider) in async_colliders.iter() {
         if scene_spawner.instance_is_ready(**scene_instance) {
-            for child_entity in children.iter_descendants(scene_entity) {
-                if let Ok((name, handle)) = mesh_handles.get(child_entity) {
-                    let shape = async_collider
-                        .named_shapes
-                        .get(name.as_str())
-                        .unwrap_or(&async_collider.shape);
-                    if let Some(shape) = shape {
-                        let mesh = meshes.get(handle).unwrap(); // NOTE: Mesh is already loaded
-                        match Collider::from_bevy_mesh(mesh, shape) {
-                            Some(collider) => {
-                                commands.entity(child_entity).insert(collider);
-                            }
-                            None => error!(
-                                "Unable to generate collider from mesh {:?} with name {}",
-                                mesh, name
-                            ),
-                        }
-                    }
+            let completed = completed_entities.entry(scene_entity).or_default();
+            let mut all_completed = true;
+
+            // The entities to process, along with the override inherited from their ancestors.
+            let mut to_visit: Vec<_> = children
+                .get(scene_entity)
+                .map(|c| c.iter().map(|e| (*e, None)).collect())
+                .unwrap_or_default();
+
+            while let Some((child_entity, inherited_override)) = to_visit.pop() {
+                let shape_override = overrides.get(child_entity).ok().or(inherited_override);
+
+                if let Some(AsyncSceneColliderOverride(None)) = shape_override {
+                    // Skip this entity and its descendants.
+                    continue;
+                }
+
+                if let Ok(grandchildren) = children.get(child_entity) {
+                    to_visit.extend(grandchildren.iter().map(|e| (*e, shape_override)));
+                }
+
+                if completed.contains(&child_entity) {
+                    continue;
                 }
+
+                let Ok((name, handle)) = mesh_handles.get(child_entity) else {
+                    continue;
+                };
+
+                let shape = match shape_override {
+                    Some(AsyncSceneColliderOverride(shape)) => shape.clone(),
+                    None => name
+                        .and_then(|name| async_collider.named_shapes.get(name.as_str()).cloned())
+                        .unwrap_or_else(|| async_collider.shape.clone().map(Into::into)),
+                };
+
+                let Some(shape) = shape else {
+                    completed.insert(child_entity);
+                    continue;
+                };
+
+                let Some(mesh) = meshes.get(handle) else {
+                    // The mesh may have been unloaded or be reloading, retry on the next frame.
+                    // Only warn once per entity, rather than on every retry.
+                    if warned_entities.insert(child_entity) {
+                        warn!(
+                            "The mesh {:?} of the scene entity {:?} isn’t loaded.",
+                            handle.path(),
+                            child_entity
+                        );
+                    }
+                    all_completed = false;
+                    continue;
+                };
+
+                // Even if no collider can be generated, retrying wouldn’t help.
+                completed.insert(child_entity);
+                warned_entities.remove(&child_entity);
+
+                // The collider, along with its other components, is inserted by
+                // `poll_collider_generation_tasks`.
+                commands.entity(child_entity).insert(
+                    ColliderGenerationTask::spawn(mesh.clone(), shape.computed_shape())
+                        .with_components(shape.collision_groups, shape.sensor),
+                );
             }
 
-            commands.entity(scene_entity).remove::<AsyncSceneCollider>();
+            if all_completed {
+                commands.entity(scene_entity).remove::<AsyncSceneCollider>();
+                completed_entities.remove(&scene_entity);
+            }
         }
     }
 }
@@ -538,7 +1428,7 @@ pub fn update_colliding_entities(
 ) {
     for event in collision_events.read() {
         match event.to_owned() {
-            CollisionEvent::Started(entity1, entity2, _, _) => {
+            CollisionEvent::Started(entity1, entity2, ..) => {
                 if let Ok(mut entities) = colliding_entities.get_mut(entity1) {
                     entities.0.insert(entity2);
                 }
@@ -546,7 +1436,7 @@ pub fn update_colliding_entities(
                     entities.0.insert(entity1);
                 }
             }
-            CollisionEvent::Stopped(entity1, entity2, _, _) => {
+            CollisionEvent::Stopped(entity1, entity2, ..) => {
                 if let Ok(mut entities) = colliding_entities.get_mut(entity1) {
                     entities.0.remove(&entity2);
                 }
@@ -560,23 +1450,52 @@ pub fn update_colliding_entities(
 
 #[cfg(test)]
 pub mod test {
+    #[cfg(feature = "async-collider")]
+    use crate::plugin::systems::tests::async_collider_test_app;
+
+    /// Updates the app until all the collider generation tasks completed.
+    #[cfg(feature = "async-collider")]
+    fn finish_collider_generation_tasks(app: &mut bevy::app::App) {
+        use super::*;
+
+        for _ in 0..1000 {
+            app.update();
+
+            let mut tasks = app.world.query::<&ColliderGenerationTask>();
+            if tasks.iter(&app.world).next().is_none() {
+                return;
+            }
+
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+
+        panic!("The collider generation tasks didn’t complete.");
+    }
+
     #[test]
-    #[cfg(all(feature = "dim3", feature = "async-collider"))]
+    #[cfg(feature = "async-collider")]
     fn async_collider_initializes() {
         use super::*;
-        use crate::plugin::systems::tests::HeadlessRenderPlugin;
 
-        let mut app = App::new();
-        app.add_plugins(HeadlessRenderPlugin)
-            .add_systems(Update, init_async_colliders);
+        let mut app = async_collider_test_app();
 
         let mut meshes = app.world.resource_mut::<Assets<Mesh>>();
+        #[cfg(feature = "dim2")]
+        let cube = meshes.add(Rectangle::default());
+        #[cfg(feature = "dim3")]
         let cube = meshes.add(Cuboid::default());
 
         let entity = app.world.spawn((cube, AsyncCollider::default())).id();
 
         app.update();
 
+        // The collider is generated in the background, and inserted on a later frame.
+        let entity_ref = app.world.entity(entity);
+        assert!(entity_ref.get::<Collider>().is_none());
+        assert!(entity_ref.get::<ColliderGenerationTask>().is_some());
+
+        finish_collider_generation_tasks(&mut app);
+
         let entity = app.world.entity(entity);
         assert!(
             entity.get::<Collider>().is_some(),
@@ -588,15 +1507,145 @@ pub mod test {
         );
     }
 
+    #[test]
+    #[cfg(all(feature = "dim2", feature = "async-collider"))]
+    fn async_collider_initializes_with_all_shapes() {
+        use super::*;
+        use crate::geometry::ComputedColliderShape;
+
+        let mut app = async_collider_test_app();
+
+        let mut meshes = app.world.resource_mut::<Assets<Mesh>>();
+        let circle = meshes.add(Circle::default());
+
+        let shapes = [
+            ComputedColliderShape::TriMesh,
+            ComputedColliderShape::ConvexHull,
+            ComputedColliderShape::ConvexDecomposition(Default::default()),
+            ComputedColliderShape::Polyline,
+        ];
+        let entities: Vec<_> = shapes
+            .into_iter()
+            .map(|shape| app.world.spawn((circle.clone(), AsyncCollider(shape))).id())
+            .collect();
+
+        finish_collider_generation_tasks(&mut app);
+
+        for entity in entities {
+            let entity = app.world.entity(entity);
+            assert!(entity.get::<Collider>().is_some());
+            assert!(entity.get::<AsyncCollider>().is_none());
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "dim2", feature = "async-collider"))]
+    fn async_collider_with_degenerate_mesh_is_kept() {
+        use super::*;
+        use crate::geometry::MeshColliderError;
+        use bevy::render::mesh::{Indices, PrimitiveTopology};
+        use bevy::render::render_asset::RenderAssetUsages;
+
+        let mut app = async_collider_test_app();
+
+        // All the vertices lie on the same line.
+        let collinear = Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::default(),
+        )
+        .with_inserted_attribute(
+            Mesh::ATTRIBUTE_POSITION,
+            vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [2.0, 0.0, 0.0]],
+        )
+        .with_inserted_indices(Indices::U32(vec![0, 1, 2]));
+        let empty = Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::default(),
+        )
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, Vec::<[f32; 3]>::new())
+        .with_inserted_indices(Indices::U32(vec![]));
+
+        let mut meshes = app.world.resource_mut::<Assets<Mesh>>();
+        let handles = [meshes.add(collinear), meshes.add(empty)];
+        let entities: Vec<_> = handles
+            .iter()
+            .map(|mesh| {
+                app.world
+                    .spawn((mesh.clone(), AsyncCollider::default()))
+                    .id()
+            })
+            .collect();
+
+        let events = app.world.resource::<Events<AsyncColliderFailedEvent>>();
+        let mut reader = events.get_reader();
+        finish_collider_generation_tasks(&mut app);
+
+        let events = app.world.resource::<Events<AsyncColliderFailedEvent>>();
+        let mut failures: Vec<_> = reader
+            .read(events)
+            .map(|event| (event.entity, event.error.clone()))
+            .collect();
+        failures.sort_by_key(|(entity, _)| *entity);
+        assert!(matches!(
+            failures[..],
+            [
+                (_, MeshColliderError::NoTriangles),
+                (_, MeshColliderError::NoVertices)
+            ]
+        ));
+        assert_eq!(
+            failures
+                .iter()
+                .map(|(entity, _)| *entity)
+                .collect::<Vec<_>>(),
+            entities
+        );
+
+        // The generation isn’t retried on the next frames.
+        app.update();
+        app.update();
+        let events = app.world.resource::<Events<AsyncColliderFailedEvent>>();
+        assert_eq!(reader.read(events).count(), 0);
+
+        for entity in &entities {
+            let entity = app.world.entity(*entity);
+            assert!(entity.get::<Collider>().is_none());
+            assert!(entity.get::<ColliderGenerationTask>().is_none());
+            assert!(
+                entity.get::<AsyncCollider>().is_some(),
+                "AsyncCollider component should be kept if no collider can be generated"
+            );
+        }
+
+        // The generation is retried once the mesh is fixed.
+        app.world
+            .resource_mut::<Assets<Mesh>>()
+            .get_mut(&handles[0])
+            .unwrap()
+            .insert_attribute(
+                Mesh::ATTRIBUTE_POSITION,
+                vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+            );
+        app.update();
+        finish_collider_generation_tasks(&mut app);
+
+        let fixed = app.world.entity(entities[0]);
+        assert!(fixed.get::<Collider>().is_some());
+        assert!(fixed.get::<AsyncCollider>().is_none());
+        let empty = app.world.entity(entities[1]);
+        assert!(empty.get::<Collider>().is_none());
+        assert!(empty.get::<AsyncCollider>().is_some());
+        let events = app.world.resource::<Events<AsyncColliderFailedEvent>>();
+        assert_eq!(reader.read(events).count(), 0);
+    }
+
     #[test]
     #[cfg(all(feature = "dim3", feature = "async-collider"))]
     fn async_scene_collider_initializes() {
         use super::*;
-        use crate::plugin::systems::tests::HeadlessRenderPlugin;
 
-        let mut app = App::new();
-        app.add_plugins(HeadlessRenderPlugin)
-            .add_systems(PostUpdate, init_async_scene_colliders);
+        let mut app = async_collider_test_app();
+        app.add_systems(PostUpdate, init_async_scene_colliders);
 
         let mut meshes = app.world.resource_mut::<Assets<Mesh>>();
         let cube_handle = meshes.add(Cuboid::default());
@@ -621,7 +1670,7 @@ pub mod test {
             .push_children(&[cube, capsule])
             .id();
 
-        app.update();
+        finish_collider_generation_tasks(&mut app);
 
         assert!(
             app.world.entity(cube).get::<Collider>().is_some(),
@@ -636,4 +1685,1034 @@ pub mod test {
             "AsyncSceneCollider component should be removed after Collider components creation"
         );
     }
+
+    #[test]
+    #[cfg(all(feature = "dim3", feature = "async-collider"))]
+    fn async_scene_collider_components_are_only_inserted_with_the_collider() {
+        use super::*;
+        use crate::geometry::{AsyncSceneColliderShape, ComputedColliderShape, Group};
+        use bevy::render::mesh::{Indices, PrimitiveTopology};
+        use bevy::render::render_asset::RenderAssetUsages;
+
+        let mut app = async_collider_test_app();
+        app.add_systems(PostUpdate, init_async_scene_colliders);
+
+        let empty = Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::default(),
+        )
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, Vec::<[f32; 3]>::new())
+        .with_inserted_indices(Indices::U32(vec![]));
+        let mut meshes = app.world.resource_mut::<Assets<Mesh>>();
+        let empty_handle = meshes.add(empty);
+        let cube_handle = meshes.add(Cuboid::default());
+        let empty = app.world.spawn((Name::new("Empty"), empty_handle)).id();
+        let cube = app.world.spawn((Name::new("Cube"), cube_handle)).id();
+
+        let mut scenes = app.world.resource_mut::<Assets<Scene>>();
+        let scene = scenes.add(Scene::new(World::new()));
+
+        let sensor_shape = AsyncSceneColliderShape::from(ComputedColliderShape::TriMesh)
+            .collision_groups(CollisionGroups::new(Group::GROUP_2, Group::GROUP_3))
+            .sensor(true);
+        let mut named_shapes = bevy::utils::HashMap::new();
+        named_shapes.insert("Empty".to_string(), Some(sensor_shape.clone()));
+        named_shapes.insert("Cube".to_string(), Some(sensor_shape));
+        app.world
+            .spawn((
+                scene,
+                AsyncSceneCollider {
+                    named_shapes,
+                    ..Default::default()
+                },
+            ))
+            .push_children(&[empty, cube]);
+
+        finish_collider_generation_tasks(&mut app);
+
+        let cube = app.world.entity(cube);
+        assert!(cube.get::<Collider>().is_some());
+        assert!(cube.get::<CollisionGroups>().is_some());
+        assert!(cube.get::<Sensor>().is_some());
+
+        let empty = app.world.entity(empty);
+        assert!(empty.get::<Collider>().is_none());
+        assert!(empty.get::<CollisionGroups>().is_none());
+        assert!(empty.get::<Sensor>().is_none());
+    }
+
+    #[test]
+    #[cfg(all(feature = "dim3", feature = "async-collider"))]
+    fn async_scene_collider_overrides() {
+        use super::*;
+        use crate::geometry::{AsyncSceneColliderShape, ComputedColliderShape, VHACDParameters};
+        use rapier::geometry::ShapeType;
+
+        let mut app = async_collider_test_app();
+        app.add_systems(PostUpdate, init_async_scene_colliders);
+
+        let mut meshes = app.world.resource_mut::<Assets<Mesh>>();
+        let torus_handle = meshes.add(Torus::default());
+        let cube_handle = meshes.add(Cuboid::default());
+
+        // Both meshes have the same name, so only the overrides can tell them apart.
+        let decomposed = app
+            .world
+            .spawn((
+                Name::new("Mesh"),
+                torus_handle,
+                AsyncSceneColliderOverride(Some(
+                    AsyncSceneColliderShape::new(ComputedColliderShape::TriMesh)
+                        .vhacd_parameters(VHACDParameters::default())
+                        .sensor(true),
+                )),
+            ))
+            .id();
+        let skipped = app.world.spawn((Name::new("Mesh"), cube_handle)).id();
+        let skipped_parent = app
+            .world
+            .spawn(AsyncSceneColliderOverride(None))
+            .push_children(&[skipped])
+            .id();
+
+        let mut scenes = app.world.resource_mut::<Assets<Scene>>();
+        let scene = scenes.add(Scene::new(World::new()));
+
+        let mut named_shapes = bevy::utils::HashMap::new();
+        named_shapes.insert(
+            "Mesh".to_string(),
+            Some(ComputedColliderShape::ConvexHull.into()),
+        );
+        let parent = app
+            .world
+            .spawn((
+                scene,
+                AsyncSceneCollider {
+                    named_shapes,
+                    ..Default::default()
+                },
+            ))
+            .push_children(&[decomposed, skipped_parent])
+            .id();
+
+        finish_collider_generation_tasks(&mut app);
+
+        let decomposed = app.world.entity(decomposed);
+        let collider = decomposed
+            .get::<Collider>()
+            .expect("Collider component should be added for the overridden mesh");
+        assert_eq!(collider.raw.shape_type(), ShapeType::Compound);
+        assert!(decomposed.get::<Sensor>().is_some());
+        assert!(
+            app.world.entity(skipped).get::<Collider>().is_none(),
+            "Collider component shouldn't be added for the skipped subtree"
+        );
+        assert!(app
+            .world
+            .entity(parent)
+            .get::<AsyncSceneCollider>()
+            .is_none());
+    }
+
+    #[test]
+    #[cfg(all(feature = "dim3", feature = "async-collider"))]
+    fn async_scene_collider_waits_for_missing_mesh() {
+        use super::*;
+
+        let mut app = async_collider_test_app();
+        app.add_systems(PostUpdate, init_async_scene_colliders);
+
+        let mut meshes = app.world.resource_mut::<Assets<Mesh>>();
+        let cube_handle = meshes.add(Cuboid::default());
+        let missing_handle = meshes.add(Cuboid::default());
+        // Simulate a mesh evicted after the scene was spawned.
+        let missing_mesh = meshes.remove(&missing_handle).unwrap();
+
+        let cube = app.world.spawn((Name::new("Cube"), cube_handle)).id();
+        let missing = app
+            .world
+            .spawn((Name::new("Missing"), missing_handle.clone()))
+            .id();
+
+        let mut scenes = app.world.resource_mut::<Assets<Scene>>();
+        let scene = scenes.add(Scene::new(World::new()));
+        let parent = app
+            .world
+            .spawn((scene, AsyncSceneCollider::default()))
+            .push_children(&[cube, missing])
+            .id();
+
+        finish_collider_generation_tasks(&mut app);
+
+        assert!(app.world.entity(cube).get::<Collider>().is_some());
+        assert!(app.world.entity(missing).get::<Collider>().is_none());
+        assert!(
+            app.world
+                .entity(parent)
+                .get::<AsyncSceneCollider>()
+                .is_some(),
+            "AsyncSceneCollider component should be kept until every mesh is loaded"
+        );
+
+        app.world
+            .resource_mut::<Assets<Mesh>>()
+            .insert(&missing_handle, missing_mesh);
+        finish_collider_generation_tasks(&mut app);
+
+        assert!(app.world.entity(missing).get::<Collider>().is_some());
+        assert!(app
+            .world
+            .entity(parent)
+            .get::<AsyncSceneCollider>()
+            .is_none());
+    }
+
+    #[test]
+    fn heightfield_heights_are_updated_in_place() {
+        use super::*;
+        use crate::dynamics::RigidBody;
+        use crate::math::{Real, Vect};
+        use crate::plugin::systems::tests::HeadlessRenderPlugin;
+        use crate::plugin::{NoUserData, RapierPhysicsPlugin, TimestepMode, DEFAULT_WORLD_ID};
+        use crate::prelude::QueryFilter;
+        use std::sync::Arc;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .timestep_mode = TimestepMode::Fixed {
+            dt: 1.0 / 60.0,
+            substeps: 1,
+        };
+
+        // A flat heightfield, with one unit between heights, from -5 to 5.
+        #[cfg(feature = "dim2")]
+        let collider = Collider::heightfield(vec![0.0; 11], Vect::new(10.0, 1.0));
+        #[cfg(feature = "dim3")]
+        let collider = Collider::heightfield(vec![0.0; 121], 11, 11, Vect::new(10.0, 1.0, 10.0));
+        #[cfg(feature = "dim2")]
+        let above_modified_height = Vect::new(3.0, 10.0);
+        #[cfg(feature = "dim3")]
+        let above_modified_height = Vect::new(3.0, 10.0, 3.0);
+        let entity = app.world.spawn((TransformBundle::default(), collider)).id();
+        // A ball resting on the heightfield, far from the modified heights.
+        let ball = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(-3.0, 0.5, -3.0)),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                ActiveEvents::COLLISION_EVENTS,
+            ))
+            .id();
+
+        let cast_down = |app: &App| {
+            app.world
+                .resource::<RapierContext>()
+                .cast_ray(
+                    DEFAULT_WORLD_ID,
+                    above_modified_height,
+                    -Vect::Y,
+                    Real::MAX,
+                    true,
+                    QueryFilter::default(),
+                )
+                .unwrap()
+                .map(|(_, toi)| toi)
+        };
+        let touching = |app: &App| {
+            app.world
+                .resource::<RapierContext>()
+                .get_world(DEFAULT_WORLD_ID)
+                .unwrap()
+                .contact_pair(entity, ball)
+                .is_some_and(|pair| pair.has_any_active_contacts())
+        };
+
+        for _ in 0..30 {
+            app.update();
+        }
+        let handle = app.world.get::<RapierColliderHandle>(entity).unwrap().0;
+        approx::assert_relative_eq!(cast_down(&app).unwrap(), 10.0);
+        assert!(touching(&app));
+        app.world.resource_mut::<Events<CollisionEvent>>().clear();
+        app.world
+            .resource_mut::<Events<MassModifiedEvent>>()
+            .clear();
+
+        let mut collider = app.world.get_mut::<Collider>(entity).unwrap();
+        #[cfg(feature = "dim2")]
+        assert!(collider.heightfield_set_heights(8..9, &[2.0]));
+        #[cfg(feature = "dim3")]
+        assert!(collider.heightfield_set_heights(8..9, 8..9, &[2.0]));
+
+        app.update();
+        app.update();
+        approx::assert_relative_eq!(cast_down(&app).unwrap(), 8.0);
+
+        // The Rapier collider wasn’t re-created, and shares the updated heightfield instead of
+        // copying it.
+        assert_eq!(
+            app.world.get::<RapierColliderHandle>(entity).unwrap().0,
+            handle
+        );
+        let context = app.world.resource::<RapierContext>();
+        let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+        let raw = &app.world.get::<Collider>(entity).unwrap().raw;
+        assert!(Arc::ptr_eq(
+            &world.colliders[handle].shared_shape().0,
+            &raw.0
+        ));
+
+        // The contact with the ball was kept, and no mass was recomputed.
+        assert!(touching(&app));
+        assert!(app.world.resource::<Events<CollisionEvent>>().is_empty());
+        assert!(app.world.resource::<Events<MassModifiedEvent>>().is_empty());
+    }
+
+    #[test]
+    fn reparented_collider_moves_its_mass() {
+        use super::*;
+        use crate::dynamics::{GravityScale, RigidBody};
+        use crate::plugin::systems::tests::HeadlessRenderPlugin;
+        use crate::plugin::{NoUserData, RapierPhysicsPlugin, DEFAULT_WORLD_ID};
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        let mut spawn_body = |x: f32| {
+            app.world
+                .spawn((
+                    TransformBundle::from(Transform::from_xyz(x, 0.0, 0.0)),
+                    RigidBody::Dynamic,
+                    GravityScale(0.0),
+                    Collider::ball(0.5),
+                    ReadMassProperties::default(),
+                ))
+                .id()
+        };
+        let body_a = spawn_body(-5.0);
+        let body_b = spawn_body(5.0);
+        let collider = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(0.0, 1.0, 0.0)),
+                Collider::ball(1.0),
+            ))
+            .set_parent(body_a)
+            .id();
+
+        app.update();
+        app.update();
+
+        let mass = |app: &App, entity| app.world.get::<ReadMassProperties>(entity).unwrap().mass;
+        let small_mass = mass(&app, body_b);
+        let large_mass = mass(&app, body_a) - small_mass;
+        assert!(large_mass > small_mass);
+
+        app.world.entity_mut(collider).set_parent(body_b);
+        app.update();
+        app.update();
+
+        approx::assert_relative_eq!(mass(&app, body_a), small_mass, epsilon = 1.0e-4);
+        approx::assert_relative_eq!(
+            mass(&app, body_b),
+            small_mass + large_mass,
+            epsilon = 1.0e-4
+        );
+
+        let context = app.world.resource::<RapierContext>();
+        let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+        assert_eq!(world.collider_parent(collider), Some(body_b));
+        let co = &world.colliders[world.entity2collider[&collider]];
+        approx::assert_relative_eq!(co.translation().x, 5.0, epsilon = 1.0e-4);
+        approx::assert_relative_eq!(co.translation().y, 1.0, epsilon = 1.0e-4);
+
+        // Detaching the collider leaves it where it is, in world-space.
+        app.world.entity_mut(collider).remove_parent();
+        app.update();
+        app.update();
+
+        approx::assert_relative_eq!(mass(&app, body_b), small_mass, epsilon = 1.0e-4);
+        let context = app.world.resource::<RapierContext>();
+        let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+        assert_eq!(world.collider_parent(collider), None);
+    }
+
+    #[test]
+    fn simultaneous_collider_changes_are_applied_together() {
+        use super::*;
+        use crate::dynamics::RigidBody;
+        use crate::geometry::Group;
+        use crate::plugin::systems::tests::HeadlessRenderPlugin;
+        use crate::plugin::{NoUserData, RapierPhysicsPlugin, DEFAULT_WORLD_ID};
+        use rapier::geometry::InteractionGroups;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+            ))
+            .id();
+        app.update();
+        app.update();
+
+        let groups = CollisionGroups::new(Group::GROUP_2, Group::GROUP_3);
+        app.world.entity_mut(body).insert((
+            Friction::new(0.1),
+            Restitution::new(0.9),
+            groups,
+            Collider::ball(1.0),
+            ColliderMassProperties::Density(2.0),
+        ));
+        app.update();
+
+        let context = app.world.resource::<RapierContext>();
+        let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+        let co = &world.colliders[world.entity2collider[&body]];
+        assert_eq!(co.friction(), 0.1);
+        assert_eq!(co.restitution(), 0.9);
+        assert_eq!(co.collision_groups(), InteractionGroups::from(groups));
+        assert_eq!(co.shape().as_ball().unwrap().radius, 1.0);
+        assert_eq!(co.density(), 2.0);
+
+        // Both the shape and the mass-properties changed, but a single event is sent.
+        let events = app.world.resource::<Events<MassModifiedEvent>>();
+        let mut reader = events.get_reader();
+        let modified = reader
+            .read(events)
+            .filter(|event| event.entity == body)
+            .count();
+        assert_eq!(modified, 1);
+    }
+
+    #[test]
+    fn moving_a_snapped_collider_does_not_change_its_shape() {
+        use super::*;
+        use crate::dynamics::{GravityScale, RigidBody};
+        use crate::plugin::systems::tests::HeadlessRenderPlugin;
+        use crate::plugin::{NoUserData, RapierPhysicsPlugin};
+
+        #[derive(Resource, Default)]
+        struct ColliderChanges(usize);
+
+        fn count_collider_changes(
+            changed: Query<(), Changed<Collider>>,
+            mut changes: ResMut<ColliderChanges>,
+        ) {
+            changes.0 += changed.iter().count();
+        }
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ))
+        .init_resource::<ColliderChanges>()
+        .add_systems(Last, count_collider_changes);
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .nonuniform_scale_policy = NonUniformScalePolicy::SnapToUniform;
+
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_scale(Vec3::new(2.0, 1.0, 1.0))),
+                RigidBody::Dynamic,
+                GravityScale(0.0),
+                Collider::ball(0.5),
+            ))
+            .id();
+        app.update();
+        app.update();
+        app.world.resource_mut::<ColliderChanges>().0 = 0;
+
+        for i in 0..5 {
+            app.world.get_mut::<Transform>(body).unwrap().translation.x = i as f32;
+            app.update();
+
+            let events = app.world.resource::<Events<MassModifiedEvent>>();
+            let mut reader = events.get_reader();
+            let modified = reader
+                .read(events)
+                .filter(|event| event.entity == body)
+                .count();
+            assert_eq!(modified, 0);
+        }
+
+        // The snapped scale was applied once, the collider isn’t modified when it moves.
+        assert_eq!(app.world.resource::<ColliderChanges>().0, 0);
+        let collider = app.world.get::<Collider>(body).unwrap();
+        assert!(collider.raw.as_ball().is_some());
+        assert_eq!(collider.scale().max_element(), 2.0);
+    }
+
+    #[test]
+    fn moving_a_scaled_trimesh_reuses_its_shape() {
+        use super::*;
+        use crate::plugin::systems::tests::HeadlessRenderPlugin;
+        use crate::plugin::{NoUserData, RapierPhysicsPlugin};
+        use std::sync::Arc;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        let entity = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_scale(Vec3::new(2.0, 1.0, 3.0))),
+                Collider::trimesh(vec![Vect::ZERO, Vect::X, Vect::Y], vec![[0, 1, 2]]),
+            ))
+            .id();
+        app.update();
+        app.update();
+
+        let shape_of = |app: &App| {
+            let handle = app.world.get::<RapierColliderHandle>(entity).unwrap().0;
+            let context = app.world.resource::<RapierContext>();
+            let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+            let raw = app.world.get::<Collider>(entity).unwrap().raw.clone();
+            (raw, world.colliders[handle].shared_shape().clone())
+        };
+        let (raw, uploaded) = shape_of(&app);
+        assert!(Arc::ptr_eq(&raw.0, &uploaded.0));
+
+        for i in 0..5 {
+            app.world
+                .get_mut::<Transform>(entity)
+                .unwrap()
+                .translation
+                .x = i as f32;
+            app.update();
+
+            // Neither the collider nor its Rapier shape were cloned or rescaled.
+            let (new_raw, new_uploaded) = shape_of(&app);
+            assert!(Arc::ptr_eq(&new_raw.0, &raw.0));
+            assert!(Arc::ptr_eq(&new_uploaded.0, &raw.0));
+        }
+    }
+
+    #[test]
+    fn collider_multiple_mass_is_the_sum_of_its_parts() {
+        use super::*;
+        use crate::dynamics::RigidBody;
+        use crate::math::Vect;
+        use crate::plugin::systems::tests::HeadlessRenderPlugin;
+        use crate::plugin::{NoUserData, RapierPhysicsPlugin, DEFAULT_WORLD_ID};
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        #[cfg(feature = "dim2")]
+        let cuboid = Collider::cuboid(0.5, 0.5);
+        #[cfg(feature = "dim3")]
+        let cuboid = Collider::cuboid(0.5, 0.5, 0.5);
+        let colliders = ColliderMultiple::default()
+            .with(Transform::from_translation(-Vect::X), cuboid.clone())
+            .with(Transform::from_translation(Vect::X * 2.0), cuboid);
+
+        let entity = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                ReadMassProperties::default(),
+                colliders,
+            ))
+            .id();
+
+        app.update();
+        app.update();
+
+        assert_eq!(
+            app.world
+                .get::<RapierColliderHandles>(entity)
+                .unwrap()
+                .0
+                .len(),
+            2
+        );
+
+        let mass_props = app.world.get::<ReadMassProperties>(entity).unwrap().get();
+        approx::assert_relative_eq!(mass_props.mass, 2.0, epsilon = 1.0e-5);
+        approx::assert_relative_eq!(
+            mass_props.local_center_of_mass,
+            Vect::X * 0.5,
+            epsilon = 1.0e-5
+        );
+
+        app.world.entity_mut(entity).remove::<ColliderMultiple>();
+        app.update();
+
+        let context = app.world.resource::<RapierContext>();
+        let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+        assert!(world.colliders.is_empty());
+        assert!(world.entity2colliders.is_empty());
+        assert!(app.world.get::<RapierColliderHandles>(entity).is_none());
+    }
+
+    #[test]
+    fn collider_multiple_follows_the_entity_scale() {
+        use super::*;
+        use crate::math::Vect;
+        use crate::plugin::systems::tests::HeadlessRenderPlugin;
+        use crate::plugin::{NoUserData, RapierPhysicsPlugin, DEFAULT_WORLD_ID};
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        #[cfg(feature = "dim2")]
+        let cuboid = Collider::cuboid(0.5, 0.5);
+        #[cfg(feature = "dim3")]
+        let cuboid = Collider::cuboid(0.5, 0.5, 0.5);
+        let entity = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_scale(Vec3::splat(2.0))),
+                ColliderMultiple::default()
+                    .with(Transform::from_translation(Vec3::X), cuboid.clone())
+                    .with(
+                        Transform::from_translation(-Vec3::X).with_scale(Vec3::splat(0.5)),
+                        cuboid,
+                    ),
+            ))
+            .id();
+
+        let aabbs = |app: &App| -> Vec<(Vect, Vect)> {
+            let context = app.world.resource::<RapierContext>();
+            let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+            app.world
+                .get::<RapierColliderHandles>(entity)
+                .unwrap()
+                .0
+                .iter()
+                .map(|handle| {
+                    let aabb = world.colliders[*handle].compute_aabb();
+                    (aabb.center().into(), aabb.half_extents().into())
+                })
+                .collect()
+        };
+
+        app.update();
+        let expected = [
+            (Vect::X * 2.0, Vect::ONE),
+            (-Vect::X * 2.0, Vect::splat(0.5)),
+        ];
+        for ((center, half_extents), (expected_center, expected_half_extents)) in
+            aabbs(&app).into_iter().zip(expected)
+        {
+            assert!(center.abs_diff_eq(expected_center, 1.0e-5));
+            assert!(half_extents.abs_diff_eq(expected_half_extents, 1.0e-5));
+        }
+
+        app.world
+            .entity_mut(entity)
+            .insert(ColliderScale::Relative(Vect::splat(1.5)));
+        app.update();
+        app.update();
+        let expected = [
+            (Vect::X * 3.0, Vect::splat(1.5)),
+            (-Vect::X * 3.0, Vect::splat(0.75)),
+        ];
+        for ((center, half_extents), (expected_center, expected_half_extents)) in
+            aabbs(&app).into_iter().zip(expected)
+        {
+            assert!(center.abs_diff_eq(expected_center, 1.0e-5), "{center}");
+            assert!(
+                half_extents.abs_diff_eq(expected_half_extents, 1.0e-5),
+                "{half_extents}"
+            );
+        }
+    }
+
+    #[test]
+    fn contact_skin_stabilizes_stacks() {
+        use super::*;
+        use crate::dynamics::RigidBody;
+        use crate::math::{Real, Vect};
+        use crate::plugin::systems::tests::HeadlessRenderPlugin;
+        use crate::plugin::{NoUserData, RapierPhysicsPlugin, TimestepMode};
+
+        const HALF_HEIGHT: Real = 0.05;
+
+        /// Simulates a stack of thin boxes, and returns how much the top box moved vertically
+        /// during the last frames.
+        fn top_box_jitter(skin: Real) -> Real {
+            let mut app = App::new();
+            app.add_plugins((
+                HeadlessRenderPlugin,
+                TransformPlugin,
+                TimePlugin,
+                RapierPhysicsPlugin::<NoUserData>::default(),
+            ));
+            app.world
+                .resource_mut::<RapierConfiguration>()
+                .timestep_mode = TimestepMode::Fixed {
+                dt: 1.0 / 60.0,
+                substeps: 1,
+            };
+
+            #[cfg(feature = "dim2")]
+            let ground = Collider::cuboid(10.0, 0.5);
+            #[cfg(feature = "dim3")]
+            let ground = Collider::cuboid(10.0, 0.5, 10.0);
+            app.world.spawn((
+                TransformBundle::from(Transform::from_xyz(0.0, -0.5, 0.0)),
+                ground,
+                ContactSkin(skin),
+            ));
+
+            let mut top = None;
+            for i in 0..20 {
+                #[cfg(feature = "dim2")]
+                let collider = Collider::cuboid(1.0, HALF_HEIGHT);
+                #[cfg(feature = "dim3")]
+                let collider = Collider::cuboid(1.0, HALF_HEIGHT, 1.0);
+                let y = HALF_HEIGHT + i as Real * (HALF_HEIGHT + skin) * 2.0;
+                top = Some(
+                    app.world
+                        .spawn((
+                            TransformBundle::from(Transform::from_xyz(0.0, y, 0.0)),
+                            RigidBody::Dynamic,
+                            collider,
+                            ContactSkin(skin),
+                        ))
+                        .id(),
+                );
+            }
+            let top = top.unwrap();
+
+            for _ in 0..200 {
+                app.update();
+            }
+
+            let (mut min_y, mut max_y) = (Real::MAX, Real::MIN);
+            for _ in 0..60 {
+                app.update();
+                let y = app.world.get::<Transform>(top).unwrap().translation.y;
+                min_y = min_y.min(y);
+                max_y = max_y.max(y);
+            }
+
+            // The stack must not have collapsed.
+            let expected_y = HALF_HEIGHT + 19.0 * (HALF_HEIGHT + skin) * 2.0;
+            assert!((max_y - expected_y).abs() < 0.1);
+
+            max_y - min_y
+        }
+
+        let jitter_without_skin = top_box_jitter(0.0);
+        let jitter_with_skin = top_box_jitter(0.01);
+        assert!(jitter_with_skin < 1.0e-3);
+        assert!(jitter_with_skin <= jitter_without_skin);
+    }
+
+    #[test]
+    fn prediction_distance_is_scaled_by_length_unit() {
+        use crate::plugin::RapierWorld;
+
+        let mut world = RapierWorld::default();
+        world.integration_parameters.length_unit = 10.0;
+        world.set_prediction_distance(0.5);
+        approx::assert_relative_eq!(world.prediction_distance(), 0.5);
+        approx::assert_relative_eq!(
+            world.integration_parameters.normalized_prediction_distance,
+            0.05
+        );
+    }
+
+    #[test]
+    fn nonuniform_scale_policies() {
+        use super::*;
+        use crate::math::Vect;
+        use crate::plugin::systems::tests::HeadlessRenderPlugin;
+        use crate::plugin::{NoUserData, RapierPhysicsPlugin, DEFAULT_WORLD_ID};
+
+        fn scaled_ball_aabb_extents(policy: NonUniformScalePolicy) -> Vect {
+            let mut app = App::new();
+            app.add_plugins((
+                HeadlessRenderPlugin,
+                TransformPlugin,
+                TimePlugin,
+                RapierPhysicsPlugin::<NoUserData>::default(),
+            ));
+            app.world
+                .resource_mut::<RapierConfiguration>()
+                .nonuniform_scale_policy = policy;
+
+            let entity = app
+                .world
+                .spawn((
+                    TransformBundle::from(Transform::from_scale(Vec3::new(2.0, 1.0, 1.0))),
+                    Collider::ball(0.5),
+                ))
+                .id();
+
+            app.update();
+            app.update();
+
+            let context = app.world.resource::<RapierContext>();
+            let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+            let handle = app.world.get::<RapierColliderHandle>(entity).unwrap().0;
+            let aabb = world.colliders[handle].compute_aabb();
+            aabb.extents().into()
+        }
+
+        // The ball is elongated along the `x` axis.
+        let extents = scaled_ball_aabb_extents(NonUniformScalePolicy::ConvertToConvex);
+        approx::assert_relative_eq!(extents.x, 2.0, epsilon = 1.0e-3);
+        approx::assert_relative_eq!(extents.y, 1.0, epsilon = 1.0e-3);
+
+        // The ball stays a ball, with the largest scale component.
+        for policy in [
+            NonUniformScalePolicy::SnapToUniform,
+            NonUniformScalePolicy::Warn,
+        ] {
+            let extents = scaled_ball_aabb_extents(policy);
+            approx::assert_relative_eq!(extents.x, 2.0, epsilon = 1.0e-3);
+            approx::assert_relative_eq!(extents.y, 2.0, epsilon = 1.0e-3);
+        }
+    }
+
+    #[test]
+    fn collider_scale_removal_resets_to_transform_scale() {
+        use super::*;
+        use crate::plugin::systems::tests::HeadlessRenderPlugin;
+
+        let mut app = App::new();
+        app.add_plugins((HeadlessRenderPlugin, TransformPlugin))
+            .init_resource::<RapierConfiguration>()
+            .add_systems(Update, apply_scale);
+
+        #[cfg(feature = "dim2")]
+        let collider = Collider::cuboid(0.5, 0.5);
+        #[cfg(feature = "dim3")]
+        let collider = Collider::cuboid(0.5, 0.5, 0.5);
+
+        let entity = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_scale(Vec3::splat(3.0))),
+                collider,
+                ColliderScale::Relative(Vect::splat(2.0)),
+            ))
+            .id();
+
+        let half_extents = |app: &App| {
+            let collider = app.world.get::<Collider>(entity).unwrap();
+            Vect::from(collider.raw.as_cuboid().unwrap().half_extents)
+        };
+
+        // The `GlobalTransform` is propagated at the end of the first update.
+        app.update();
+        app.update();
+        approx::assert_relative_eq!(half_extents(&app), Vect::splat(3.0));
+
+        // Switching from a relative to an absolute scale in place.
+        *app.world.get_mut::<ColliderScale>(entity).unwrap() =
+            ColliderScale::Absolute(Vect::splat(2.0));
+        app.update();
+        approx::assert_relative_eq!(half_extents(&app), Vect::splat(1.0));
+
+        app.world.entity_mut(entity).remove::<ColliderScale>();
+        app.update();
+        approx::assert_relative_eq!(half_extents(&app), Vect::splat(1.5));
+    }
+
+    #[test]
+    fn mass_modified_events_carry_the_cause_and_new_mass() {
+        use super::*;
+        use crate::dynamics::{AdditionalMassProperties, RigidBody};
+        use crate::math::Real;
+        use crate::plugin::systems::tests::HeadlessRenderPlugin;
+        use crate::plugin::{NoUserData, RapierPhysicsPlugin, DEFAULT_WORLD_ID};
+        use bevy::ecs::event::ManualEventReader;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        #[cfg(feature = "dim2")]
+        let ball_mass =
+            |radius: Real, density: Real| std::f32::consts::PI * radius.powi(2) * density;
+        #[cfg(feature = "dim3")]
+        let ball_mass = |radius: Real, density: Real| {
+            4.0 / 3.0 * std::f32::consts::PI * radius.powi(3) * density
+        };
+
+        let mut reader = ManualEventReader::<MassModifiedEvent>::default();
+        let mut update = |app: &mut App| {
+            app.update();
+            let events = app.world.resource::<Events<MassModifiedEvent>>();
+            let events: Vec<_> = reader.read(events).copied().collect();
+            assert_eq!(events.len(), 1, "{events:?}");
+            assert_eq!(events[0].world_id, DEFAULT_WORLD_ID);
+            assert_eq!(
+                app.world
+                    .get::<ReadMassProperties>(events[0].entity)
+                    .map(|m| m.mass),
+                Some(events[0].new_mass_properties.mass)
+            );
+            events[0]
+        };
+        let check = |event: MassModifiedEvent, cause: MassChangeCause, mass: Real| {
+            assert_eq!(event.cause, cause);
+            approx::assert_relative_eq!(event.new_mass_properties.mass, mass, epsilon = 1.0e-4);
+        };
+
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+            ))
+            .id();
+        let event = update(&mut app);
+        assert_eq!(event.entity, body);
+        check(event, MassChangeCause::ColliderAdded, ball_mass(0.5, 1.0));
+
+        app.world
+            .entity_mut(body)
+            .insert(ColliderMassProperties::Density(2.0));
+        check(
+            update(&mut app),
+            MassChangeCause::ColliderMassPropertiesChanged,
+            ball_mass(0.5, 2.0),
+        );
+
+        app.world.entity_mut(body).insert(Collider::ball(1.0));
+        check(
+            update(&mut app),
+            MassChangeCause::ColliderShapeChanged,
+            ball_mass(1.0, 2.0),
+        );
+
+        app.world
+            .entity_mut(body)
+            .insert(AdditionalMassProperties::Mass(1.0));
+        check(
+            update(&mut app),
+            MassChangeCause::AdditionalMassPropertiesChanged,
+            ball_mass(1.0, 2.0) + 1.0,
+        );
+
+        let child = app
+            .world
+            .spawn((TransformBundle::default(), Collider::ball(0.5)))
+            .set_parent(body)
+            .id();
+        check(
+            update(&mut app),
+            MassChangeCause::ColliderAdded,
+            ball_mass(1.0, 2.0) + ball_mass(0.5, 1.0) + 1.0,
+        );
+
+        app.world.entity_mut(child).despawn_recursive();
+        check(
+            update(&mut app),
+            MassChangeCause::ColliderRemoved,
+            ball_mass(1.0, 2.0) + 1.0,
+        );
+    }
+
+    #[test]
+    fn massless_shape_changes_dont_modify_the_mass() {
+        use super::*;
+        use crate::dynamics::RigidBody;
+        use crate::geometry::Sensor;
+        use crate::plugin::systems::tests::HeadlessRenderPlugin;
+        use crate::plugin::{NoUserData, RapierPhysicsPlugin};
+        use bevy::ecs::event::ManualEventReader;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+            ))
+            .id();
+        let sensor = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                Collider::ball(1.0),
+                Sensor,
+                ColliderMassProperties::Density(0.0),
+            ))
+            .set_parent(body)
+            .id();
+        app.update();
+
+        let mut reader = ManualEventReader::<MassModifiedEvent>::default();
+        reader.clear(app.world.resource::<Events<MassModifiedEvent>>());
+        let mut num_events = |app: &mut App| {
+            app.update();
+            reader
+                .read(app.world.resource::<Events<MassModifiedEvent>>())
+                .count()
+        };
+
+        // A pulsing trigger volume.
+        for i in 0..5 {
+            app.world
+                .entity_mut(sensor)
+                .insert(Collider::ball(1.0 + i as f32 * 0.1));
+            assert_eq!(num_events(&mut app), 0);
+        }
+
+        app.world.entity_mut(body).insert(Collider::ball(1.0));
+        assert_eq!(num_events(&mut app), 1);
+    }
 }
@@ -0,0 +1,196 @@
+//! The buoyancy and drag applied by the [`FluidVolume`]s.
+
+use crate::geometry::{FluidVolume, RapierColliderHandle};
+use crate::math::Real;
+use crate::plugin::{get_world, RapierConfiguration, RapierContext, RapierWorld};
+use crate::prelude::PhysicsWorld;
+use bevy::prelude::*;
+use rapier::geometry::ColliderHandle;
+use rapier::prelude::Vector;
+
+/// System responsible for applying the buoyancy and drag of the [`FluidVolume`]s to the dynamic
+/// rigid-bodies intersecting them.
+///
+/// It runs after the simulation steps of the frame, and applies to each rigid-body the impulse
+/// the fluid would have applied to it during these steps. The submerged part of a collider is
+/// approximated by the intersection of its AABB with the AABB of the fluid.
+pub fn apply_fluid_volumes(
+    mut context: ResMut<RapierContext>,
+    config: Res<RapierConfiguration>,
+    fluids: Query<(&RapierColliderHandle, &FluidVolume, Option<&PhysicsWorld>)>,
+) {
+    if !config.physics_pipeline_active {
+        return;
+    }
+
+    for (handle, fluid, world_within) in fluids.iter() {
+        let world = get_world(world_within, &mut context);
+        let physics_time = world.physics_time;
+        let dt = physics_time.last_dt * physics_time.last_frame_steps as Real;
+
+        if dt > 0.0 {
+            apply_fluid_volume(world, handle.0, fluid, dt);
+        }
+    }
+}
+
+fn apply_fluid_volume(
+    world: &mut RapierWorld,
+    handle: ColliderHandle,
+    fluid: &FluidVolume,
+    dt: Real,
+) {
+    let Some(fluid_aabb) = world.colliders.get(handle).map(|co| co.compute_aabb()) else {
+        return;
+    };
+
+    let gravity: Vector<Real> = world.gravity.into();
+    let flow_velocity: Vector<Real> = fluid.flow_velocity.into();
+    let length_unit = world.integration_parameters.length_unit;
+
+    let submerged: Vec<_> = world
+        .narrow_phase
+        .intersection_pairs_with(handle)
+        .filter(|(_, _, intersecting)| *intersecting)
+        .map(|(collider1, collider2, _)| {
+            if collider1 == handle {
+                collider2
+            } else {
+                collider1
+            }
+        })
+        .collect();
+
+    for other in submerged {
+        let Some(co) = world.colliders.get(other) else {
+            continue;
+        };
+        let Some(body) = co.parent() else {
+            continue;
+        };
+        if co.is_sensor() {
+            continue;
+        }
+
+        let aabb = co.compute_aabb();
+        let Some(submerged_aabb) = aabb.intersection(&fluid_aabb) else {
+            continue;
+        };
+        if aabb.volume() <= 0.0 {
+            continue;
+        }
+
+        // The mass of the shape with a unit density is its volume (its area in 2D).
+        let fraction = (submerged_aabb.volume() / aabb.volume()).min(1.0);
+        let submerged_volume = co.shape().mass_properties(1.0).mass() * fraction;
+        let centroid = submerged_aabb.center();
+
+        let Some(rb) = world.bodies.get_mut(body) else {
+            continue;
+        };
+        if !rb.is_dynamic() {
+            continue;
+        }
+
+        let buoyancy = -gravity * fluid.density * submerged_volume;
+
+        if rb.is_sleeping() {
+            // Don’t wake up a body floating at rest.
+            let net_force = buoyancy + gravity * rb.mass();
+            let velocity_change =
+                net_force.norm() * rb.mass_properties().local_mprops.inv_mass * dt;
+            if velocity_change < rb.activation().normalized_linear_threshold * length_unit {
+                continue;
+            }
+        }
+
+        // The drag is a damping of the velocity relative to the flow, proportional to the
+        // submerged fraction, and can’t reverse the velocity.
+        let linear_damping = (fluid.linear_drag * fraction * dt).min(1.0);
+        let angular_damping = (fluid.angular_drag * fraction * dt).min(1.0);
+        let relative_velocity = rb.velocity_at_point(&centroid) - flow_velocity;
+        let drag = -relative_velocity * rb.mass() * linear_damping;
+
+        rb.apply_impulse_at_point(buoyancy * dt + drag, centroid, true);
+        #[cfg(feature = "dim2")]
+        let angvel = rb.angvel();
+        #[cfg(feature = "dim3")]
+        let angvel = *rb.angvel();
+        rb.set_angvel(angvel * (1.0 - angular_damping), true);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::dynamics::RigidBody;
+    use crate::geometry::{Collider, ColliderMassProperties, Sensor};
+    use crate::plugin::systems::tests::HeadlessRenderPlugin;
+    use crate::plugin::{NoUserData, RapierPhysicsPlugin, TimestepMode};
+    use bevy::time::TimePlugin;
+
+    #[test]
+    fn light_bodies_float_and_dense_bodies_sink() {
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .timestep_mode = TimestepMode::Fixed {
+            dt: 1.0 / 60.0,
+            substeps: 1,
+        };
+
+        // The surface of the water is at `y = 0`.
+        #[cfg(feature = "dim2")]
+        let (water, cube) = (Collider::cuboid(20.0, 10.0), Collider::cuboid(0.5, 0.5));
+        #[cfg(feature = "dim3")]
+        let (water, cube) = (
+            Collider::cuboid(20.0, 10.0, 20.0),
+            Collider::cuboid(0.5, 0.5, 0.5),
+        );
+        app.world.spawn((
+            TransformBundle::from(Transform::from_xyz(0.0, -10.0, 0.0)),
+            water,
+            Sensor,
+            FluidVolume {
+                linear_drag: 2.0,
+                ..Default::default()
+            },
+        ));
+
+        let mut spawn_box = |x: f32, density: f32| {
+            app.world
+                .spawn((
+                    TransformBundle::from(Transform::from_xyz(x, -3.0, 0.0)),
+                    RigidBody::Dynamic,
+                    cube.clone(),
+                    ColliderMassProperties::Density(density),
+                ))
+                .id()
+        };
+        let light = spawn_box(-2.0, 0.5);
+        let dense = spawn_box(2.0, 3.0);
+
+        for _ in 0..300 {
+            app.update();
+        }
+
+        let height = |entity| app.world.get::<Transform>(entity).unwrap().translation.y;
+        // The light box floats half submerged.
+        assert!(
+            (height(light) - 0.0).abs() < 0.5,
+            "the light box didn’t float: {}",
+            height(light)
+        );
+        assert!(
+            height(dense) < -5.0,
+            "the dense box didn’t sink: {}",
+            height(dense)
+        );
+    }
+}
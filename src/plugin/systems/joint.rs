@@ -1,27 +1,67 @@
 use crate::dynamics::ImpulseJoint;
+use crate::dynamics::ImpulseJointBreakForce;
+use crate::dynamics::JointBrokenEvent;
+use crate::dynamics::JointDisabled;
 use crate::dynamics::MultibodyJoint;
 use crate::dynamics::RapierImpulseJointHandle;
 use crate::dynamics::RapierMultibodyJointHandle;
+use crate::dynamics::WeldOnContact;
+use crate::dynamics::{
+    JointAxis, JointInitFailedEvent, JointInitFailure, JointMotor, JointMotorState,
+    ReadImpulseJointForces,
+};
+use crate::math::{Real, Vect};
+use crate::pipeline::CollisionEvent;
 use crate::plugin::get_world;
+use crate::plugin::RapierConfiguration;
 use crate::plugin::RapierContext;
+use crate::plugin::RapierWorld;
+use crate::plugin::TimestepMode;
+use crate::plugin::DEFAULT_WORLD_ID;
 use crate::prelude::PhysicsWorld;
 use bevy::prelude::*;
+use bevy::utils::{HashMap, HashSet};
+use rapier::dynamics::{ImpulseJointHandle, MultibodyJointHandle};
+use rapier::geometry::CollisionEventFlags;
+use rapier::math::{Point, DIM};
+
+/// The number of frames a joint can fail to be created before a [`JointInitFailedEvent`] is emitted.
+const JOINT_INIT_ATTEMPTS_BEFORE_FAILURE: usize = 10;
 
 /// System responsible for creating new Rapier joints from the related `bevy_rapier` components.
+///
+/// Joints whose rigid-bodies aren’t registered yet are retried every frame. If a joint still
+/// can’t be created after a few frames, a warning is logged and a [`JointInitFailedEvent`]
+/// is emitted.
 pub fn init_joints(
     mut commands: Commands,
     mut context: ResMut<RapierContext>,
     impulse_joints: Query<
-        (Entity, &ImpulseJoint, Option<&PhysicsWorld>),
+        (
+            Entity,
+            &ImpulseJoint,
+            Option<&JointDisabled>,
+            Option<&PhysicsWorld>,
+        ),
         Without<RapierImpulseJointHandle>,
     >,
     multibody_joints: Query<
-        (Entity, &MultibodyJoint, Option<&PhysicsWorld>),
+        (
+            Entity,
+            &MultibodyJoint,
+            Option<&JointDisabled>,
+            Option<&PhysicsWorld>,
+        ),
         Without<RapierMultibodyJointHandle>,
     >,
     parent_query: Query<&Parent>,
+    mut failed_attempts: Local<HashMap<Entity, usize>>,
+    mut init_failures: EventWriter<JointInitFailedEvent>,
 ) {
-    for (entity, joint, world_within) in impulse_joints.iter() {
+    failed_attempts
+        .retain(|entity, _| impulse_joints.contains(*entity) || multibody_joints.contains(*entity));
+
+    for (entity, joint, disabled, world_within) in impulse_joints.iter() {
         let world = get_world(world_within, &mut context);
 
         let mut target = None;
@@ -36,77 +76,1305 @@ pub fn init_joints(
         }
 
         if let (Some(target), Some(source)) = (target, world.entity2body.get(&joint.parent)) {
-            let handle =
-                world
-                    .impulse_joints
-                    .insert(*source, target, joint.data.into_rapier(), true);
+            let mut data = joint.data.into_rapier();
+            data.set_enabled(disabled.is_none());
+            let handle = world.impulse_joints.insert(*source, target, data, true);
             commands
                 .entity(entity)
                 .insert(RapierImpulseJointHandle(handle));
             world.entity2impulse_joint.insert(entity, handle);
+            failed_attempts.remove(&entity);
+        } else if register_failed_attempt(&mut failed_attempts, entity) {
+            let child_bodies: Vec<_> = std::iter::once(entity)
+                .chain(parent_query.iter_ancestors(entity))
+                .collect();
+            let reason = joint_init_failure(&context, world_within, joint.parent, &child_bodies);
+            warn!("Failed to create the impulse joint of entity {entity:?}: {reason}.");
+            init_failures.send(JointInitFailedEvent { entity, reason });
         }
     }
 
-    for (entity, joint, world_within) in multibody_joints.iter() {
+    for (entity, joint, disabled, world_within) in multibody_joints.iter() {
         let world = get_world(world_within, &mut context);
 
         let target = world.entity2body.get(&entity);
 
         if let (Some(target), Some(source)) = (target, world.entity2body.get(&joint.parent)) {
-            if let Some(handle) =
-                world
-                    .multibody_joints
-                    .insert(*source, *target, joint.data.into_rapier(), true)
-            {
+            let mut data = joint.data.into_rapier();
+            data.set_enabled(disabled.is_none());
+            if let Some(handle) = world.multibody_joints.insert(*source, *target, data, true) {
                 commands
                     .entity(entity)
                     .insert(RapierMultibodyJointHandle(handle));
                 world.entity2multibody_joint.insert(entity, handle);
+                failed_attempts.remove(&entity);
             } else {
-                error!("Failed to create multibody joint: loop detected.")
+                error!("Failed to create multibody joint: loop detected.");
+
+                if register_failed_attempt(&mut failed_attempts, entity) {
+                    init_failures.send(JointInitFailedEvent {
+                        entity,
+                        reason: JointInitFailure::MultibodyLoop,
+                    });
+                }
             }
+        } else if register_failed_attempt(&mut failed_attempts, entity) {
+            let reason = joint_init_failure(&context, world_within, joint.parent, &[entity]);
+            warn!("Failed to create the multibody joint of entity {entity:?}: {reason}.");
+            init_failures.send(JointInitFailedEvent { entity, reason });
         }
     }
 }
 
+/// Counts a failed attempt at creating the joint of `entity`.
+///
+/// Returns `true` if the failure should be reported.
+fn register_failed_attempt(failed_attempts: &mut HashMap<Entity, usize>, entity: Entity) -> bool {
+    let attempts = failed_attempts.entry(entity).or_default();
+    *attempts += 1;
+    *attempts == JOINT_INIT_ATTEMPTS_BEFORE_FAILURE
+}
+
+/// Finds out why a joint between `parent` and the first registered entity of `child_bodies`
+/// couldn’t be created.
+fn joint_init_failure(
+    context: &RapierContext,
+    world_within: Option<&PhysicsWorld>,
+    parent: Entity,
+    child_bodies: &[Entity],
+) -> JointInitFailure {
+    let joint_world = world_within.map_or(DEFAULT_WORLD_ID, |w| w.world_id);
+    let world_of = |entity: &Entity| {
+        context
+            .worlds
+            .iter()
+            .find(|(_, world)| world.entity2body.contains_key(entity))
+            .map(|(world_id, _)| *world_id)
+    };
+
+    match (world_of(&parent), child_bodies.iter().find_map(world_of)) {
+        (Some(parent_world), Some(child_world)) if parent_world != child_world => {
+            JointInitFailure::DifferentWorlds {
+                parent_world,
+                child_world,
+            }
+        }
+        (Some(parent_world), _) if parent_world == joint_world => {
+            JointInitFailure::MissingChildBody
+        }
+        (_, Some(child_world)) if child_world == joint_world => JointInitFailure::MissingParentBody,
+        _ => JointInitFailure::MissingBodies,
+    }
+}
+
 /// System responsible for applying changes the user made to a joint component.
 pub fn apply_joint_user_changes(
+    mut commands: Commands,
     mut context: ResMut<RapierContext>,
     changed_impulse_joints: Query<
         (
             &RapierImpulseJointHandle,
             &ImpulseJoint,
+            Option<&JointDisabled>,
             Option<&PhysicsWorld>,
         ),
         Changed<ImpulseJoint>,
     >,
     changed_multibody_joints: Query<
         (
+            Entity,
             &RapierMultibodyJointHandle,
             &MultibodyJoint,
+            Option<&JointDisabled>,
             Option<&PhysicsWorld>,
         ),
         Changed<MultibodyJoint>,
     >,
+    disabled_impulse_joints: Query<
+        (&RapierImpulseJointHandle, Option<&PhysicsWorld>),
+        Changed<JointDisabled>,
+    >,
+    disabled_multibody_joints: Query<
+        (&RapierMultibodyJointHandle, Option<&PhysicsWorld>),
+        Changed<JointDisabled>,
+    >,
+    mut init_failures: EventWriter<JointInitFailedEvent>,
 ) {
     // TODO: right now, we only support propagating changes made to the joint data.
     //       Re-parenting the joint isn’t supported yet.
-    for (handle, changed_joint, world_within) in changed_impulse_joints.iter() {
+    for (handle, changed_joint, disabled, world_within) in changed_impulse_joints.iter() {
         let world = get_world(world_within, &mut context);
 
         if let Some(joint) = world.impulse_joints.get_mut(handle.0) {
             joint.data = changed_joint.data.into_rapier();
+            joint.data.set_enabled(disabled.is_none());
+        }
+    }
+
+    for (entity, handle, changed_joint, disabled, world_within) in changed_multibody_joints.iter() {
+        let world = get_world(world_within, &mut context);
+        let mut data = changed_joint.data.into_rapier();
+        data.set_enabled(disabled.is_none());
+
+        let Some(link) = world
+            .multibody_joints
+            .get_mut(handle.0)
+            .and_then(|(mb, link_id)| mb.link_mut(link_id))
+        else {
+            continue;
+        };
+
+        if link.joint.data.locked_axes == data.locked_axes {
+            link.joint.data = data;
+            continue;
+        }
+
+        // The degrees of freedom of the joint changed, so the multibody
+        // needs to be rebuilt by re-inserting the joint.
+        world.multibody_joints.remove(handle.0, true);
+        world.entity2multibody_joint.remove(&entity);
+
+        let (Some(source), Some(target)) = (
+            world.entity2body.get(&changed_joint.parent).copied(),
+            world.entity2body.get(&entity).copied(),
+        ) else {
+            // Let `init_joints` create the joint once its rigid-bodies exist.
+            commands
+                .entity(entity)
+                .remove::<RapierMultibodyJointHandle>();
+            continue;
+        };
+
+        if let Some(new_handle) = world.multibody_joints.insert(source, target, data, true) {
+            world.entity2multibody_joint.insert(entity, new_handle);
+            commands
+                .entity(entity)
+                .insert(RapierMultibodyJointHandle(new_handle));
+        } else {
+            error!("Failed to re-create multibody joint: loop detected.");
+            commands
+                .entity(entity)
+                .remove::<RapierMultibodyJointHandle>();
+            init_failures.send(JointInitFailedEvent {
+                entity,
+                reason: JointInitFailure::MultibodyLoop,
+            });
+        }
+    }
+
+    for (handle, world_within) in disabled_impulse_joints.iter() {
+        let world = get_world(world_within, &mut context);
+        set_impulse_joint_enabled(world, handle.0, false);
+    }
+
+    for (handle, world_within) in disabled_multibody_joints.iter() {
+        let world = get_world(world_within, &mut context);
+        set_multibody_joint_enabled(world, handle.0, false);
+    }
+}
+
+/// Enables or disables an impulse joint, waking up its rigid-bodies.
+pub(crate) fn set_impulse_joint_enabled(
+    world: &mut RapierWorld,
+    handle: ImpulseJointHandle,
+    enabled: bool,
+) {
+    if let Some(joint) = world.impulse_joints.get_mut(handle) {
+        joint.data.set_enabled(enabled);
+        let bodies = [joint.body1, joint.body2];
+
+        for body in bodies {
+            if let Some(rb) = world.bodies.get_mut(body) {
+                rb.wake_up(true);
+            }
+        }
+    }
+}
+
+/// Enables or disables a multibody joint, waking up its rigid-body.
+pub(crate) fn set_multibody_joint_enabled(
+    world: &mut RapierWorld,
+    handle: MultibodyJointHandle,
+    enabled: bool,
+) {
+    if let Some((mb, link_id)) = world.multibody_joints.get_mut(handle) {
+        if let Some(link) = mb.link_mut(link_id) {
+            link.joint.data.set_enabled(enabled);
+            let body = link.rigid_body_handle();
+
+            if let Some(rb) = world.bodies.get_mut(body) {
+                rb.wake_up(true);
+            }
+        }
+    }
+}
+
+/// System responsible for applying the [`JointMotor`] settings onto the physics joints.
+pub fn apply_joint_motors(
+    mut context: ResMut<RapierContext>,
+    impulse_joint_motors: Query<
+        (
+            &RapierImpulseJointHandle,
+            &JointMotor,
+            Option<&PhysicsWorld>,
+        ),
+        Or<(
+            Changed<JointMotor>,
+            Changed<ImpulseJoint>,
+            Changed<RapierImpulseJointHandle>,
+        )>,
+    >,
+    multibody_joint_motors: Query<
+        (
+            &RapierMultibodyJointHandle,
+            &JointMotor,
+            Option<&PhysicsWorld>,
+        ),
+        Or<(
+            Changed<JointMotor>,
+            Changed<MultibodyJoint>,
+            Changed<RapierMultibodyJointHandle>,
+        )>,
+    >,
+) {
+    // NOTE: changes to the `ImpulseJoint` or `MultibodyJoint` components overwrite the
+    //       whole joint data, so the motors have to be applied again afterward.
+    for (handle, motor, world_within) in impulse_joint_motors.iter() {
+        let world = get_world(world_within, &mut context);
+
+        if let Some(joint) = world.impulse_joints.get_mut(handle.0) {
+            motor.apply_to(&mut joint.data);
+            let bodies = [joint.body1, joint.body2];
+
+            // Wake up the bodies so sleeping joints react to the new targets.
+            for body in bodies {
+                if let Some(rb) = world.bodies.get_mut(body) {
+                    rb.wake_up(true);
+                }
+            }
         }
     }
 
-    for (handle, changed_joint, world_within) in changed_multibody_joints.iter() {
+    for (handle, motor, world_within) in multibody_joint_motors.iter() {
         let world = get_world(world_within, &mut context);
 
-        // TODO: not sure this will always work properly, e.g., if the number of Dofs is changed.
         if let Some((mb, link_id)) = world.multibody_joints.get_mut(handle.0) {
             if let Some(link) = mb.link_mut(link_id) {
-                link.joint.data = changed_joint.data.into_rapier();
+                motor.apply_to(&mut link.joint.data);
+                let body = link.rigid_body_handle();
+
+                if let Some(rb) = world.bodies.get_mut(body) {
+                    rb.wake_up(true);
+                }
+            }
+        }
+    }
+}
+
+/// System responsible for breaking the impulse joints which applied, during the last
+/// simulation step, a force or torque greater than their [`ImpulseJointBreakForce`].
+pub fn break_impulse_joints(
+    mut commands: Commands,
+    mut context: ResMut<RapierContext>,
+    config: Res<RapierConfiguration>,
+    breakable_joints: Query<(
+        Entity,
+        &RapierImpulseJointHandle,
+        &ImpulseJoint,
+        &ImpulseJointBreakForce,
+        Option<&PhysicsWorld>,
+    )>,
+    mut broken_joints: EventWriter<JointBrokenEvent>,
+) {
+    if !config.physics_pipeline_active {
+        // The joint impulses didn’t change since the last check.
+        return;
+    }
+
+    for (entity, handle, joint, break_force, world_within) in breakable_joints.iter() {
+        let world = get_world(world_within, &mut context);
+
+        let Some(raw_joint) = world.impulse_joints.get(handle.0) else {
+            continue;
+        };
+
+        let (impulse, angular_impulse) = joint_impulses(raw_joint);
+
+        // The impulses were applied during the last substep, convert them to forces.
+        let substep_dt = last_substep_dt(world, config.timestep_mode);
+        if substep_dt <= 0.0 {
+            continue;
+        }
+
+        let force = impulse.length() / substep_dt;
+        #[cfg(feature = "dim2")]
+        let torque = angular_impulse.abs() / substep_dt;
+        #[cfg(feature = "dim3")]
+        let torque = angular_impulse.length() / substep_dt;
+
+        if force > break_force.force || torque > break_force.torque {
+            // Remove the joint right away so it doesn’t affect the next step.
+            world.impulse_joints.remove(handle.0, true);
+            world.entity2impulse_joint.remove(&entity);
+            commands
+                .entity(entity)
+                .remove::<(ImpulseJoint, RapierImpulseJointHandle)>();
+
+            broken_joints.send(JointBrokenEvent {
+                entity,
+                parent: joint.parent,
+                impulse,
+                angular_impulse,
+            });
+        }
+    }
+}
+
+/// System responsible for welding the rigid-bodies with a [`WeldOnContact`] to the rigid-bodies
+/// they start touching.
+pub fn weld_on_contact(
+    mut commands: Commands,
+    context: Res<RapierContext>,
+    mut collision_events: EventReader<CollisionEvent>,
+    welding: Query<(), With<WeldOnContact>>,
+    mut welded: Local<HashSet<(Entity, Entity)>>,
+) {
+    welded.clear();
+
+    for event in collision_events.read() {
+        let CollisionEvent::Started(collider1, collider2, flags, world_id, _) = *event else {
+            continue;
+        };
+        if flags.contains(CollisionEventFlags::SENSOR) {
+            continue;
+        }
+        let Ok(world) = context.get_world(world_id) else {
+            continue;
+        };
+        let (Some(body1), Some(body2)) = (
+            world.collider_parent(collider1),
+            world.collider_parent(collider2),
+        ) else {
+            continue;
+        };
+
+        let welds = |collider, body| welding.contains(collider) || welding.contains(body);
+        if body1 == body2 || !(welds(collider1, body1) || welds(collider2, body2)) {
+            continue;
+        }
+
+        // Several colliders of the same rigid-bodies may start touching during the same step.
+        if welded.insert((body1, body2)) && !welded.contains(&(body2, body1)) {
+            let _ = context.weld_entities(world_id, body1, body2, &mut commands);
+        }
+    }
+}
+
+/// System responsible for writing the forces applied by impulse joints into the
+/// [`ReadImpulseJointForces`] component.
+pub fn writeback_impulse_joint_forces(
+    context: Res<RapierContext>,
+    config: Res<RapierConfiguration>,
+    mut joints: Query<(
+        &RapierImpulseJointHandle,
+        &mut ReadImpulseJointForces,
+        Option<&PhysicsWorld>,
+    )>,
+) {
+    if !config.physics_pipeline_active {
+        return;
+    }
+
+    for (handle, mut read_forces, world_within) in joints.iter_mut() {
+        let world_id = world_within.map_or(DEFAULT_WORLD_ID, |w| w.world_id);
+        let Ok(world) = context.get_world(world_id) else {
+            continue;
+        };
+
+        let Some(raw_joint) = world.impulse_joints.get(handle.0) else {
+            continue;
+        };
+
+        let substep_dt = last_substep_dt(world, config.timestep_mode);
+        if substep_dt <= 0.0 {
+            continue;
+        }
+
+        let (impulse, angular_impulse) = joint_impulses(raw_joint);
+        let new_forces = ReadImpulseJointForces {
+            force: impulse / substep_dt,
+            torque: angular_impulse / substep_dt,
+            motors: motor_states(world, raw_joint, substep_dt),
+        };
+
+        // NOTE: we write the new value only if there was an
+        //       actual change, in order to not trigger bevy’s
+        //       change tracking when the values didn’t change.
+        if *read_forces != new_forces {
+            *read_forces = new_forces;
+        }
+    }
+}
+
+/// The duration of the last substep performed by the given world.
+fn last_substep_dt(world: &RapierWorld, timestep_mode: TimestepMode) -> Real {
+    let dt = world.integration_parameters.dt;
+    match timestep_mode {
+        TimestepMode::Fixed { substeps, .. }
+        | TimestepMode::Variable { substeps, .. }
+        | TimestepMode::FixedSchedule { substeps }
+        | TimestepMode::Manual { substeps, .. } => dt / substeps as Real,
+        TimestepMode::Interpolated {
+            time_scale,
+            substeps,
+            ..
+        } => dt * time_scale / substeps as Real,
+    }
+}
+
+/// The linear and angular impulses applied by a joint during the last substep.
+#[cfg(feature = "dim2")]
+fn joint_impulses(joint: &rapier::dynamics::ImpulseJoint) -> (Vect, Real) {
+    let impulses = joint.impulses;
+    (Vect::new(impulses[0], impulses[1]), impulses[2])
+}
+
+/// The linear and angular impulses applied by a joint during the last substep.
+#[cfg(feature = "dim3")]
+fn joint_impulses(joint: &rapier::dynamics::ImpulseJoint) -> (Vect, Vect) {
+    let impulses = joint.impulses;
+    (
+        Vect::new(impulses[0], impulses[1], impulses[2]),
+        Vect::new(impulses[3], impulses[4], impulses[5]),
+    )
+}
+
+/// The target and actual state of every motorized axis of a joint.
+fn motor_states(
+    world: &RapierWorld,
+    joint: &rapier::dynamics::ImpulseJoint,
+    substep_dt: Real,
+) -> Vec<JointMotorState> {
+    let data = &joint.data;
+    if data.motor_axes.is_empty() {
+        return vec![];
+    }
+
+    let (Some(rb1), Some(rb2)) = (world.bodies.get(joint.body1), world.bodies.get(joint.body2))
+    else {
+        return vec![];
+    };
+
+    let frame1 = rb1.position() * data.local_frame1;
+    let frame2 = rb2.position() * data.local_frame2;
+    let relative_pos = frame1.inv_mul(&frame2);
+    let anchor2 = Point::from(frame2.translation.vector);
+    let linvel = frame1.rotation.inverse_transform_vector(
+        &(rb2.velocity_at_point(&anchor2) - rb1.velocity_at_point(&anchor2)),
+    );
+    #[cfg(feature = "dim2")]
+    let (angles, angvel) = (
+        [relative_pos.rotation.angle()],
+        [rb2.angvel() - rb1.angvel()],
+    );
+    #[cfg(feature = "dim3")]
+    let (angles, angvel) = (
+        relative_pos.rotation.scaled_axis(),
+        frame1
+            .rotation
+            .inverse_transform_vector(&(rb2.angvel() - rb1.angvel())),
+    );
+
+    let mut states = vec![];
+    for (i, motor) in data.motors.iter().enumerate() {
+        let axis = joint_axis(i);
+        if !data.motor_axes.contains(axis.into()) {
+            continue;
+        }
+
+        let (position, velocity) = if i < DIM {
+            (relative_pos.translation.vector[i], linvel[i])
+        } else {
+            (angles[i - DIM], angvel[i - DIM])
+        };
+
+        states.push(JointMotorState {
+            axis,
+            target_position: motor.target_pos,
+            target_velocity: motor.target_vel,
+            position,
+            velocity,
+            force: motor.impulse / substep_dt,
+        });
+    }
+
+    states
+}
+
+/// The joint axis corresponding to the given degree of freedom index.
+fn joint_axis(i: usize) -> JointAxis {
+    #[cfg(feature = "dim2")]
+    return [JointAxis::X, JointAxis::Y, JointAxis::AngX][i];
+    #[cfg(feature = "dim3")]
+    return [
+        JointAxis::X,
+        JointAxis::Y,
+        JointAxis::Z,
+        JointAxis::AngX,
+        JointAxis::AngY,
+        JointAxis::AngZ,
+    ][i];
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(feature = "dim3")]
+    use crate::dynamics::SphericalJointBuilder;
+    use crate::dynamics::{
+        AxisMotor, FixedJointBuilder, RevoluteJointBuilder, RigidBody, RopeJointBuilder,
+        SpringJointBuilder, Velocity,
+    };
+    use crate::geometry::Collider;
+    use crate::plugin::systems::tests::HeadlessRenderPlugin;
+    use crate::plugin::{NoUserData, RapierPhysicsPlugin, DEFAULT_WORLD_ID};
+    use bevy::ecs::event::Events;
+    use bevy::time::TimePlugin;
+
+    #[test]
+    fn overloaded_joint_breaks() {
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        let anchor = app
+            .world
+            .spawn((TransformBundle::default(), RigidBody::Fixed))
+            .id();
+
+        // A heavy box hanging from a joint that can barely hold any weight.
+        let joint = FixedJointBuilder::new().local_anchor1(-Vect::Y * 2.0);
+        #[cfg(feature = "dim2")]
+        let shape = Collider::cuboid(1.0, 1.0);
+        #[cfg(feature = "dim3")]
+        let shape = Collider::cuboid(1.0, 1.0, 1.0);
+        let heavy_box = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(0.0, -2.0, 0.0)),
+                RigidBody::Dynamic,
+                shape,
+                ImpulseJoint::new(anchor, joint),
+                ImpulseJointBreakForce::force(1.0),
+            ))
+            .id();
+
+        let mut broken = None;
+        for _ in 0..5 {
+            app.update();
+
+            let events = app.world.resource::<Events<JointBrokenEvent>>();
+            if let Some(event) = events.iter_current_update_events().next() {
+                broken = Some(*event);
+                break;
+            }
+        }
+
+        let broken = broken.expect("The joint should have broken.");
+        assert_eq!(broken.entity, heavy_box);
+        assert_eq!(broken.parent, anchor);
+        assert!(app.world.get::<ImpulseJoint>(heavy_box).is_none());
+
+        app.update();
+        let context = app.world.resource::<RapierContext>();
+        let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+        assert_eq!(world.impulse_joints.len(), 0);
+    }
+
+    #[test]
+    fn joint_forces_are_read_back() {
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        let anchor = app
+            .world
+            .spawn((TransformBundle::default(), RigidBody::Fixed))
+            .id();
+
+        // A box hanging from a fixed joint, which has to hold its whole weight.
+        let joint = FixedJointBuilder::new().local_anchor1(-Vect::Y * 2.0);
+        #[cfg(feature = "dim2")]
+        let (shape, mass) = (Collider::cuboid(1.0, 1.0), 4.0);
+        #[cfg(feature = "dim3")]
+        let (shape, mass) = (Collider::cuboid(1.0, 1.0, 1.0), 8.0);
+        let hanging_box = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(0.0, -2.0, 0.0)),
+                RigidBody::Dynamic,
+                shape,
+                ImpulseJoint::new(anchor, joint),
+                ReadImpulseJointForces::default(),
+            ))
+            .id();
+
+        for _ in 0..20 {
+            app.update();
+        }
+
+        let gravity = app
+            .world
+            .resource::<RapierContext>()
+            .get_world(DEFAULT_WORLD_ID)
+            .unwrap()
+            .gravity;
+        let forces = app
+            .world
+            .get::<ReadImpulseJointForces>(hanging_box)
+            .unwrap();
+        approx::assert_relative_eq!(
+            forces.force.length(),
+            mass * gravity.length(),
+            max_relative = 0.05
+        );
+        assert!(forces.motors.is_empty());
+    }
+
+    #[test]
+    fn joint_waits_for_delayed_parent() {
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        let parent = app.world.spawn(TransformBundle::default()).id();
+        let child = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                ImpulseJoint::new(parent, FixedJointBuilder::new()),
+            ))
+            .id();
+
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(app.world.get::<RapierImpulseJointHandle>(child).is_none());
+
+        app.world.entity_mut(parent).insert(RigidBody::Fixed);
+        app.update();
+
+        assert!(app.world.get::<RapierImpulseJointHandle>(child).is_some());
+        let failures = app.world.resource::<Events<JointInitFailedEvent>>();
+        assert!(failures.is_empty());
+    }
+
+    #[test]
+    fn joint_across_worlds_fails() {
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        let other_world = app
+            .world
+            .resource_mut::<RapierContext>()
+            .add_world(RapierWorld::default());
+
+        let parent = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Fixed,
+                PhysicsWorld {
+                    world_id: other_world,
+                },
+            ))
+            .id();
+        let child = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                ImpulseJoint::new(parent, FixedJointBuilder::new()),
+            ))
+            .id();
+
+        for _ in 0..JOINT_INIT_ATTEMPTS_BEFORE_FAILURE {
+            app.update();
+        }
+
+        assert!(app.world.get::<RapierImpulseJointHandle>(child).is_none());
+        let failures = app.world.resource::<Events<JointInitFailedEvent>>();
+        let failures: Vec<_> = failures.get_reader().read(failures).copied().collect();
+        assert_eq!(
+            failures,
+            vec![JointInitFailedEvent {
+                entity: child,
+                reason: JointInitFailure::DifferentWorlds {
+                    parent_world: other_world,
+                    child_world: DEFAULT_WORLD_ID,
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn joint_motor_drives_existing_joint() {
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        let anchor = app
+            .world
+            .spawn((TransformBundle::default(), RigidBody::Fixed))
+            .id();
+
+        #[cfg(feature = "dim2")]
+        let (joint, shape) = (RevoluteJointBuilder::new(), Collider::ball(0.5));
+        #[cfg(feature = "dim3")]
+        let (joint, shape) = (RevoluteJointBuilder::new(Vect::Z), Collider::ball(0.5));
+        let wheel = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Velocity::zero(),
+                shape,
+                ImpulseJoint::new(anchor, joint),
+                JointMotor::revolute(AxisMotor::velocity(2.0, 1000.0)),
+            ))
+            .id();
+
+        let angvel = |app: &App| {
+            let velocity = app.world.get::<Velocity>(wheel).unwrap();
+            #[cfg(feature = "dim2")]
+            return velocity.angvel;
+            #[cfg(feature = "dim3")]
+            return velocity.angvel.z;
+        };
+
+        for _ in 0..30 {
+            app.update();
+        }
+        approx::assert_relative_eq!(angvel(&app), 2.0, max_relative = 0.05);
+        let handle = app.world.get::<RapierImpulseJointHandle>(wheel).unwrap().0;
+
+        app.world
+            .get_mut::<JointMotor>(wheel)
+            .unwrap()
+            .axis_mut(JointAxis::AngX)
+            .unwrap()
+            .target_velocity = -1.0;
+        for _ in 0..30 {
+            app.update();
+        }
+        approx::assert_relative_eq!(angvel(&app), -1.0, max_relative = 0.05);
+        assert_eq!(
+            app.world.get::<RapierImpulseJointHandle>(wheel).unwrap().0,
+            handle
+        );
+    }
+
+    #[test]
+    fn disabled_joint_lets_body_fall() {
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        let anchor = app
+            .world
+            .spawn((TransformBundle::default(), RigidBody::Fixed))
+            .id();
+
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(0.0, -1.0, 0.0)),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                ImpulseJoint::new(anchor, RopeJointBuilder::new(2.0)),
+                JointDisabled,
+            ))
+            .id();
+
+        let distance_to_anchor = |app: &App| {
+            app.world
+                .get::<Transform>(body)
+                .unwrap()
+                .translation
+                .length()
+        };
+
+        for _ in 0..60 {
+            app.update();
+        }
+        assert!(distance_to_anchor(&app) > 3.0);
+
+        app.world.entity_mut(body).remove::<JointDisabled>();
+        for _ in 0..60 {
+            app.update();
+        }
+        assert!(distance_to_anchor(&app) < 2.1);
+    }
+
+    #[test]
+    fn fixed_joint_from_world_transforms_keeps_pose() {
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.world
+            .resource_mut::<RapierContext>()
+            .get_world_mut(DEFAULT_WORLD_ID)
+            .unwrap()
+            .gravity = Vect::ZERO;
+
+        #[cfg(feature = "dim2")]
+        let (parent_transform, child_transform, shape) = (
+            Transform::from_xyz(1.0, 2.0, 0.0).with_rotation(Quat::from_rotation_z(0.3)),
+            Transform::from_xyz(-2.0, 0.5, 0.0).with_rotation(Quat::from_rotation_z(-1.2)),
+            Collider::cuboid(0.5, 0.5),
+        );
+        #[cfg(feature = "dim3")]
+        let (parent_transform, child_transform, shape) = (
+            Transform::from_xyz(1.0, 2.0, 3.0).with_rotation(Quat::from_euler(
+                EulerRot::XYZ,
+                0.3,
+                -0.7,
+                1.1,
+            )),
+            Transform::from_xyz(-2.0, 0.5, 1.0).with_rotation(Quat::from_euler(
+                EulerRot::XYZ,
+                -1.2,
+                0.4,
+                0.2,
+            )),
+            Collider::cuboid(0.5, 0.5, 0.5),
+        );
+
+        let parent = app
+            .world
+            .spawn((
+                TransformBundle::from(parent_transform),
+                RigidBody::Dynamic,
+                shape.clone(),
+            ))
+            .id();
+        let joint = FixedJointBuilder::from_world_transforms(
+            &GlobalTransform::from(parent_transform),
+            &GlobalTransform::from(child_transform),
+        );
+        let child = app
+            .world
+            .spawn((
+                TransformBundle::from(child_transform),
+                RigidBody::Dynamic,
+                shape,
+                ImpulseJoint::new(parent, joint),
+            ))
+            .id();
+
+        for _ in 0..2 {
+            app.update();
+        }
+
+        for (entity, expected) in [(parent, parent_transform), (child, child_transform)] {
+            let transform = app.world.get::<Transform>(entity).unwrap();
+            assert!(transform
+                .translation
+                .abs_diff_eq(expected.translation, 1.0e-4));
+            assert!(transform.rotation.abs_diff_eq(expected.rotation, 1.0e-4));
+        }
+    }
+
+    #[test]
+    fn spring_joint_changes_are_applied() {
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        let anchor = app
+            .world
+            .spawn((TransformBundle::default(), RigidBody::Fixed))
+            .id();
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(0.0, -1.0, 0.0)),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                ImpulseJoint::new(anchor, SpringJointBuilder::new(1.0, 10.0, 0.5)),
+            ))
+            .id();
+
+        app.update();
+
+        let spring = SpringJointBuilder::new(1.0, 10.0, 0.5)
+            .rest_length(3.0)
+            .build();
+        app.world.get_mut::<ImpulseJoint>(body).unwrap().data = spring.into();
+        app.update();
+
+        let handle = app.world.get::<RapierImpulseJointHandle>(body).unwrap().0;
+        let context = app.world.resource::<RapierContext>();
+        let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+        let motor = world.impulse_joints.get(handle).unwrap().data.motors[0];
+        assert_eq!(motor.target_pos, 3.0);
+        assert_eq!(motor.stiffness, 10.0);
+        assert_eq!(motor.damping, 0.5);
+    }
+
+    #[test]
+    fn multibody_arm_is_moved_to_target_pose() {
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.world
+            .resource_mut::<RapierContext>()
+            .get_world_mut(DEFAULT_WORLD_ID)
+            .unwrap()
+            .gravity = Vect::ZERO;
+
+        #[cfg(feature = "dim2")]
+        let revolute = || RevoluteJointBuilder::new().local_anchor2(-Vect::X);
+        #[cfg(feature = "dim3")]
+        let revolute = || RevoluteJointBuilder::new(Vect::Z).local_anchor2(-Vect::X);
+
+        let root = app
+            .world
+            .spawn((TransformBundle::default(), RigidBody::Fixed))
+            .id();
+        let link1 = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(1.0, 0.0, 0.0)),
+                RigidBody::Dynamic,
+                Collider::ball(0.1),
+                MultibodyJoint::new(root, revolute()),
+            ))
+            .id();
+        let link2 = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(2.0, 0.0, 0.0)),
+                RigidBody::Dynamic,
+                Collider::ball(0.1),
+                MultibodyJoint::new(link1, revolute().local_anchor1(Vect::X)),
+            ))
+            .id();
+
+        app.update();
+
+        let mut context = app.world.resource_mut::<RapierContext>();
+        let world = context.get_world_mut(DEFAULT_WORLD_ID).unwrap();
+        assert!(world.set_multibody_joint_positions(link1, &[0.5]));
+        assert!(world.set_multibody_joint_positions(link2, &[-0.3]));
+        // A revolute joint has a single free axis.
+        assert!(!world.set_multibody_joint_positions(link2, &[-0.3, 0.1]));
+
+        app.update();
+
+        let context = app.world.resource::<RapierContext>();
+        let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+        for (link, angle) in [(link1, 0.5), (link2, -0.3)] {
+            let positions = world.multibody_joint_positions(link).unwrap();
+            assert_eq!(positions.len(), 1);
+            approx::assert_relative_eq!(positions[0], angle, epsilon = 1.0e-3);
+        }
+
+        // The end of the arm is where the joint angles put it.
+        let expected = Vec3::new(
+            0.5_f32.cos() + 0.2_f32.cos(),
+            0.5_f32.sin() + 0.2_f32.sin(),
+            0.0,
+        );
+        let translation = app.world.get::<Transform>(link2).unwrap().translation;
+        assert!(translation.abs_diff_eq(expected, 1.0e-3));
+        assert!(world.multibody_joint_positions(root).is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "dim3")]
+    fn spherical_multibody_joint_is_moved_to_target_rotation() {
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.world
+            .resource_mut::<RapierContext>()
+            .get_world_mut(DEFAULT_WORLD_ID)
+            .unwrap()
+            .gravity = Vect::ZERO;
+
+        let root = app
+            .world
+            .spawn((TransformBundle::default(), RigidBody::Fixed))
+            .id();
+        let link = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.1),
+                MultibodyJoint::new(root, SphericalJointBuilder::new()),
+            ))
+            .id();
+
+        app.update();
+
+        // Move the joint twice, so that the second target is reached from a rotated pose.
+        for target in [Vect::new(0.4, -0.2, 0.1), Vect::new(-0.3, 0.6, 0.5)] {
+            let mut context = app.world.resource_mut::<RapierContext>();
+            let world = context.get_world_mut(DEFAULT_WORLD_ID).unwrap();
+            assert!(world.set_multibody_joint_positions(link, &target.to_array()));
+            app.update();
+
+            let context = app.world.resource::<RapierContext>();
+            let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+            let positions = world.multibody_joint_positions(link).unwrap();
+            assert!(Vect::from_slice(&positions).abs_diff_eq(target, 1.0e-3));
+
+            let rotation = app.world.get::<Transform>(link).unwrap().rotation;
+            let expected = Quat::from_scaled_axis(target);
+            assert!(rotation.angle_between(expected) < 1.0e-3);
+        }
+    }
+
+    #[test]
+    fn multibody_joint_dofs_can_change() {
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.world
+            .resource_mut::<RapierContext>()
+            .get_world_mut(DEFAULT_WORLD_ID)
+            .unwrap()
+            .gravity = Vect::ZERO;
+
+        #[cfg(feature = "dim2")]
+        let (revolute, angvel) = (RevoluteJointBuilder::new(), 2.0);
+        #[cfg(feature = "dim3")]
+        let (revolute, angvel) = (RevoluteJointBuilder::new(Vect::Z), Vect::Z * 2.0);
+
+        let root = app
+            .world
+            .spawn((TransformBundle::default(), RigidBody::Fixed))
+            .id();
+        let child = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                Velocity::angular(angvel),
+                MultibodyJoint::new(root, revolute),
+            ))
+            .id();
+
+        for _ in 0..5 {
+            app.update();
+        }
+        let rotation = app.world.get::<Transform>(child).unwrap().rotation;
+        assert!(!rotation.abs_diff_eq(Quat::IDENTITY, 1.0e-3));
+        let old_handle = app
+            .world
+            .get::<RapierMultibodyJointHandle>(child)
+            .unwrap()
+            .0;
+
+        app.world.get_mut::<MultibodyJoint>(child).unwrap().data = FixedJointBuilder::new().into();
+        for _ in 0..2 {
+            app.update();
+        }
+
+        let new_handle = app
+            .world
+            .get::<RapierMultibodyJointHandle>(child)
+            .unwrap()
+            .0;
+        assert_ne!(old_handle, new_handle);
+        let stopped_rotation = app.world.get::<Transform>(child).unwrap().rotation;
+        for _ in 0..5 {
+            app.update();
+        }
+        let rotation = app.world.get::<Transform>(child).unwrap().rotation;
+        assert!(rotation.abs_diff_eq(stopped_rotation, 1.0e-3));
+
+        let context = app.world.resource::<RapierContext>();
+        let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+        assert_eq!(world.entity2multibody_joint.get(&child), Some(&new_handle));
+    }
+
+    #[test]
+    fn joint_scenes_spawned_twice_are_jointed_within_each_instance() {
+        use bevy::ecs::entity::EntityHashMap;
+        use bevy::scene::DynamicScene;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        let registry = app.world.resource::<AppTypeRegistry>().clone();
+
+        #[cfg(feature = "dim2")]
+        let joint = RevoluteJointBuilder::new();
+        #[cfg(feature = "dim3")]
+        let joint = RevoluteJointBuilder::new(Vect::Z);
+
+        let mut source = World::new();
+        source.insert_resource(registry);
+        let parent = source
+            .spawn((TransformBundle::default(), RigidBody::Fixed))
+            .id();
+        let child = source
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                ImpulseJoint::new(parent, joint),
+            ))
+            .id();
+        let link = source
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                MultibodyJoint::new(child, joint),
+            ))
+            .id();
+        let scene = DynamicScene::from_world(&source);
+
+        let entity_maps: Vec<EntityHashMap<Entity>> = (0..2)
+            .map(|_| {
+                let mut entity_map = EntityHashMap::default();
+                scene
+                    .write_to_world(&mut app.world, &mut entity_map)
+                    .unwrap();
+                entity_map
+            })
+            .collect();
+
+        app.update();
+
+        let context = app.world.resource::<RapierContext>();
+        let world = context
+            .get_world(DEFAULT_WORLD_ID)
+            .expect("The default world should exist.");
+        assert_eq!(world.impulse_joints.len(), 2);
+
+        for entity_map in &entity_maps {
+            let handle = world.entity2impulse_joint[&entity_map[&child]];
+            let joint = world.impulse_joints.get(handle).unwrap();
+            assert_eq!(joint.body1, world.entity2body[&entity_map[&parent]]);
+            assert_eq!(joint.body2, world.entity2body[&entity_map[&child]]);
+
+            let multibody_joint = app.world.get::<MultibodyJoint>(entity_map[&link]);
+            assert_eq!(multibody_joint.unwrap().parent, entity_map[&child]);
+            assert!(world
+                .entity2multibody_joint
+                .contains_key(&entity_map[&link]));
+        }
+    }
+
+    #[test]
+    fn stiffer_joints_stretch_less() {
+        use rapier::dynamics::IntegrationParameters;
+
+        fn chain_stretch(natural_frequency: Real, damping_ratio: Real) -> Real {
+            let mut app = App::new();
+            app.add_plugins((
+                HeadlessRenderPlugin,
+                TransformPlugin,
+                TimePlugin,
+                RapierPhysicsPlugin::<NoUserData>::default(),
+            ));
+            app.world
+                .resource_mut::<RapierContext>()
+                .get_world_mut(DEFAULT_WORLD_ID)
+                .unwrap()
+                .set_joint_stiffness(natural_frequency, damping_ratio);
+
+            #[cfg(feature = "dim2")]
+            let link_joint = || RevoluteJointBuilder::new().local_anchor1(-Vect::Y);
+            #[cfg(feature = "dim3")]
+            let link_joint = || RevoluteJointBuilder::new(Vect::Z).local_anchor1(-Vect::Y);
+
+            // A chain of ten links of length 1 hanging from a fixed anchor.
+            let mut parent = app
+                .world
+                .spawn((TransformBundle::default(), RigidBody::Fixed))
+                .id();
+            for i in 1..=10 {
+                parent = app
+                    .world
+                    .spawn((
+                        TransformBundle::from(Transform::from_xyz(0.0, -(i as f32), 0.0)),
+                        RigidBody::Dynamic,
+                        Collider::ball(0.25),
+                        ImpulseJoint::new(parent, link_joint()),
+                    ))
+                    .id();
             }
+
+            for _ in 0..120 {
+                app.update();
+            }
+
+            let translation = app.world.get::<Transform>(parent).unwrap().translation;
+            translation.length() - 10.0
         }
+
+        let default_params = IntegrationParameters::default();
+        let soft = chain_stretch(5.0, 1.0);
+        let stiff = chain_stretch(
+            default_params.joint_natural_frequency,
+            default_params.joint_damping_ratio,
+        );
+        assert!(stiff >= -1.0e-3);
+        assert!(soft > stiff + 0.1, "soft: {soft}, stiff: {stiff}");
     }
 }
@@ -1,28 +1,42 @@
 //! Systems responsible for interfacing our Bevy components with the Rapier physics engine.
 
+mod async_step;
 mod character_controller;
 mod collider;
+mod fluid;
 mod joint;
 mod remove;
 mod rigid_body;
+mod substep;
+#[cfg(feature = "dim3")]
+mod vehicle;
 mod worlds;
 mod writeback;
 
+pub use async_step::*;
 pub use character_controller::*;
 pub use collider::*;
+pub use fluid::*;
 pub use joint::*;
 pub use remove::*;
 pub use rigid_body::*;
+pub use substep::*;
+#[cfg(feature = "dim3")]
+pub use vehicle::*;
 pub use worlds::*;
 pub use writeback::*;
 
 use crate::dynamics::{RapierRigidBodyHandle, TransformInterpolation};
-use crate::pipeline::{CollisionEvent, ContactForceEvent};
-use crate::plugin::configuration::SimulationToRenderTime;
-use crate::plugin::{RapierConfiguration, RapierContext};
+use crate::pipeline::{
+    CollisionEvent, ContactForceEvent, DetailedCollisionEvent, PhysicsStepEvent,
+    PhysicsTimeDroppedEvent,
+};
+use crate::plugin::configuration::{QueryPipelineUpdateMode, SimulationToRenderTime, TimestepMode};
+use crate::plugin::{RapierConfiguration, RapierContext, RapierWorld, WorldId};
 use crate::prelude::{BevyPhysicsHooks, BevyPhysicsHooksAdapter};
 use bevy::ecs::system::{StaticSystemParam, SystemParamItem};
 use bevy::prelude::*;
+use bevy::utils::Instant;
 
 /// System responsible for advancing the physics simulation, and updating the internal state
 /// for scene queries.
@@ -35,40 +49,127 @@ pub fn step_simulation<Hooks>(
     mut sim_to_render_time: ResMut<SimulationToRenderTime>,
     mut collision_event_writer: EventWriter<CollisionEvent>,
     mut contact_force_event_writer: EventWriter<ContactForceEvent>,
+    mut detailed_collision_event_writer: EventWriter<DetailedCollisionEvent>,
+    mut time_dropped_event_writer: EventWriter<PhysicsTimeDroppedEvent>,
+    mut step_event_writer: EventWriter<PhysicsStepEvent>,
     mut interpolation_query: Query<(&RapierRigidBodyHandle, &mut TransformInterpolation)>,
+    async_step: Option<Res<AsyncPhysicsStep>>,
 ) where
     Hooks: 'static + BevyPhysicsHooks,
     for<'w, 's> SystemParamItem<'w, 's, Hooks>: BevyPhysicsHooks,
 {
-    let hooks_adapter = BevyPhysicsHooksAdapter::new(hooks.into_inner());
+    if async_step.is_some() && config.physics_pipeline_active {
+        // The step is started by `start_async_physics_step`, at the end of the frame.
+        return;
+    }
+
+    let mut hooks_adapter = BevyPhysicsHooksAdapter::new(hooks.into_inner());
+    let num_steps = num_steps_to_run(&mut context, &config);
+    let measure_step_time = context.measure_step_time;
 
     for (world_id, world) in context.worlds.iter_mut() {
-        if config.physics_pipeline_active {
-            world.step_simulation(
-                *world_id,
-                config.timestep_mode,
-                true,
-                &hooks_adapter,
-                &time,
-                &mut sim_to_render_time,
-                &mut Some(&mut interpolation_query),
-            );
+        world.physics_time.last_frame_steps = 0;
+        world.set_profiling_enabled(config.enable_profiling);
+        world.detailed_collision_events = config.detailed_collision_events;
+        hooks_adapter.set_world_id(*world_id);
+        let start_time = measure_step_time.then(Instant::now);
 
-            world.deleted_colliders.clear();
+        if num_steps > 0 {
+            for _ in 0..num_steps {
+                let first_step = world.physics_time.steps;
+                let dropped_seconds = world.step_simulation(
+                    *world_id,
+                    config.timestep_mode,
+                    true,
+                    &hooks_adapter,
+                    &time,
+                    &mut sim_to_render_time,
+                    &mut Some(&mut interpolation_query),
+                );
+
+                if dropped_seconds > 0.0 {
+                    time_dropped_event_writer.send(PhysicsTimeDroppedEvent {
+                        world_id: *world_id,
+                        dropped_seconds,
+                    });
+                }
 
-            world.send_bevy_events(&mut collision_event_writer, &mut contact_force_event_writer);
+                step_event_writer.send_batch(step_events(*world_id, world, first_step));
+            }
         } else {
             world.propagate_modified_body_positions_to_colliders();
         }
 
-        if config.query_pipeline_active {
+        // Flush the events even if the world wasn’t stepped, so the events emitted before the
+        // simulation was paused (or by a manual step) aren’t delayed until it resumes.
+        world.send_bevy_events(&mut collision_event_writer, &mut contact_force_event_writer);
+        world.send_detailed_collision_events(&mut detailed_collision_event_writer);
+
+        // The removed colliders are only needed until the next step emitted their events. An
+        // interpolated step may not have run any step this frame, even if one was requested.
+        if world.physics_time.last_frame_steps > 0 {
+            world.deleted_colliders.clear();
+        }
+
+        if let Some(start_time) = start_time {
+            world.last_step_duration = start_time.elapsed();
+        }
+
+        if config.update_query_pipeline_after_step() {
             world.update_query_pipeline();
         }
     }
 }
 
+/// System responsible for updating the query pipelines at the end of
+/// [`PhysicsSet::Writeback`](crate::plugin::PhysicsSet::Writeback), with
+/// [`QueryPipelineUpdateMode::AfterWriteback`].
+pub fn update_query_pipelines(
+    mut context: ResMut<RapierContext>,
+    config: Res<RapierConfiguration>,
+) {
+    if !config.query_pipeline_active
+        || config.query_pipeline_update_mode != QueryPipelineUpdateMode::AfterWriteback
+    {
+        return;
+    }
+
+    for world in context.worlds.values_mut() {
+        world.propagate_modified_body_positions_to_colliders();
+        world.update_query_pipeline();
+    }
+}
+
+/// The [`PhysicsStepEvent`]s of the steps executed by `world` after its first `first_step` steps.
+pub(crate) fn step_events(
+    world_id: WorldId,
+    world: &RapierWorld,
+    first_step: u64,
+) -> impl Iterator<Item = PhysicsStepEvent> {
+    let dt = world.physics_time.last_dt;
+    (first_step..world.physics_time.steps).map(move |step_index| PhysicsStepEvent {
+        world_id,
+        step_index,
+        dt,
+    })
+}
+
+/// The number of times the worlds must be stepped by the current run of the physics systems.
+///
+/// With [`TimestepMode::Manual`], this consumes the steps requested with
+/// [`RapierContext::request_step`].
+pub(crate) fn num_steps_to_run(context: &mut RapierContext, config: &RapierConfiguration) -> u32 {
+    if !config.physics_pipeline_active {
+        0
+    } else if let TimestepMode::Manual { .. } = config.timestep_mode {
+        context.take_requested_steps()
+    } else {
+        1
+    }
+}
+
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use bevy::{
         asset::AssetPlugin,
         ecs::event::Events,
@@ -108,6 +209,7 @@ mod tests {
             entity2,
             CollisionEventFlags::SENSOR,
             DEFAULT_WORLD_ID,
+            0,
         ));
 
         app.update();
@@ -153,6 +255,7 @@ mod tests {
             entity2,
             CollisionEventFlags::SENSOR,
             DEFAULT_WORLD_ID,
+            0,
         ));
 
         app.update();
@@ -321,6 +424,4128 @@ mod tests {
         }
     }
 
+    #[test]
+    fn kinematic_velocity_based_child_follows_its_parent() {
+        use crate::dynamics::{GravityScale, Velocity};
+        use crate::math::Vect;
+        use crate::plugin::TimestepMode;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .timestep_mode = TimestepMode::Fixed {
+            dt: 1.0 / 60.0,
+            substeps: 1,
+        };
+
+        let child = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(0.0, 2.0, 0.0)),
+                RigidBody::KinematicVelocityBased,
+                Collider::ball(0.5),
+                Velocity::zero(),
+            ))
+            .id();
+        let parent = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                GravityScale(0.0),
+                Velocity::linear(Vect::Y * 2.0),
+            ))
+            .push_children(&[child])
+            .id();
+
+        for _ in 0..30 {
+            app.update();
+
+            let child_transform = app.world.get::<Transform>(child).unwrap();
+            approx::assert_relative_eq!(child_transform.translation.y, 2.0, epsilon = 1.0e-4);
+            assert_eq!(
+                app.world.get::<Velocity>(child).unwrap().linvel,
+                Vect::ZERO,
+                "The velocity set by the user should be preserved."
+            );
+        }
+
+        let parent_y = app.world.get::<Transform>(parent).unwrap().translation.y;
+        assert!(parent_y > 0.5);
+
+        let context = app.world.resource::<RapierContext>();
+        let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+        let child_body = world.bodies.get(world.entity2body[&child]).unwrap();
+        approx::assert_relative_eq!(child_body.translation().y, parent_y + 2.0, epsilon = 1.0e-3);
+    }
+
+    #[test]
+    fn child_rotation_tracks_rigid_body() {
+        use crate::dynamics::{GravityScale, Velocity};
+        #[cfg(feature = "dim3")]
+        use crate::math::Vect;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        #[cfg(feature = "dim2")]
+        let velocity = Velocity::angular(3.0);
+        #[cfg(feature = "dim3")]
+        let velocity = Velocity::angular(Vect::new(0.0, 1.0, 3.0));
+        let child = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(2.0, 0.0, 0.0)),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                GravityScale(0.0),
+                velocity,
+            ))
+            .id();
+        app.world
+            .spawn((
+                TransformBundle::from(
+                    Transform::from_xyz(1.0, 2.0, 0.0).with_rotation(Quat::from_rotation_z(0.5)),
+                ),
+                RigidBody::Fixed,
+            ))
+            .push_children(&[child]);
+
+        for _ in 0..10 {
+            app.update();
+
+            let global_transform = app
+                .world
+                .get::<GlobalTransform>(child)
+                .unwrap()
+                .compute_transform();
+            let context = app.world.resource::<RapierContext>();
+            let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+            let child_body = world.bodies.get(world.entity2body[&child]).unwrap();
+            let body_transform = utils::iso_to_transform(child_body.position());
+
+            approx::assert_relative_eq!(
+                global_transform.translation,
+                body_transform.translation,
+                epsilon = 1.0e-4
+            );
+            // Compare the rotation angles, to account for the quaternion’s double covering.
+            assert!(
+                global_transform
+                    .rotation
+                    .angle_between(body_transform.rotation)
+                    < 1.0e-3,
+                "The child should be rotated like its rigid-body."
+            );
+        }
+
+        let global_rotation = app
+            .world
+            .get::<GlobalTransform>(child)
+            .unwrap()
+            .compute_transform()
+            .rotation;
+        assert!(global_rotation.angle_between(Quat::IDENTITY) > 0.1);
+    }
+
+    #[test]
+    fn sync_vel_includes_parent_angular_velocity() {
+        use crate::dynamics::{GravityScale, Velocity};
+        #[cfg(feature = "dim3")]
+        use crate::math::Vect;
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        #[cfg(feature = "dim2")]
+        let spin = Velocity::angular(1.0);
+        #[cfg(feature = "dim3")]
+        let spin = Velocity::angular(Vect::Z);
+        let child = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(5.0, 0.0, 0.0)),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                GravityScale(0.0),
+                Velocity::zero(),
+            ))
+            .id();
+        app.world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                GravityScale(0.0),
+                spin,
+            ))
+            .push_children(&[child]);
+
+        app.update();
+
+        let child_linvel = |app: &App| {
+            let context = app.world.resource::<RapierContext>();
+            let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+            let child_body = world.bodies.get(world.entity2body[&child]).unwrap();
+            child_body.linvel().norm()
+        };
+
+        // After the writeback, the velocity of the child is relative to its parent.
+        approx::assert_relative_eq!(child_linvel(&app), 0.0, epsilon = 1.0e-2);
+
+        // Before the simulation step, the child moves along with the spinning parent.
+        app.world.run_system_once(sync_vel);
+        approx::assert_relative_eq!(child_linvel(&app), 5.0, epsilon = 1.0e-2);
+    }
+
+    #[test]
+    fn child_keeps_its_offset_from_a_spinning_parent() {
+        use crate::dynamics::{GravityScale, Velocity};
+        #[cfg(feature = "dim3")]
+        use crate::math::Vect;
+        use crate::plugin::TimestepMode;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .timestep_mode = TimestepMode::Fixed {
+            dt: 1.0 / 60.0,
+            substeps: 1,
+        };
+
+        #[cfg(feature = "dim2")]
+        let spin = Velocity::angular(1.0);
+        #[cfg(feature = "dim3")]
+        let spin = Velocity::angular(Vect::Z);
+        let child = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(2.0, 0.0, 0.0)),
+                RigidBody::Dynamic,
+                Collider::ball(0.25),
+                GravityScale(0.0),
+                Velocity::zero(),
+            ))
+            .id();
+        let parent = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.25),
+                GravityScale(0.0),
+                spin,
+            ))
+            .push_children(&[child])
+            .id();
+
+        for _ in 0..60 {
+            app.update();
+        }
+
+        // The parent made a sixth of a turn, and the child turned along with it.
+        let parent_rotation = app.world.get::<Transform>(parent).unwrap().rotation;
+        approx::assert_relative_eq!(
+            parent_rotation.angle_between(Quat::IDENTITY),
+            1.0,
+            epsilon = 2.0e-2
+        );
+        let local_offset = app.world.get::<Transform>(child).unwrap().translation;
+        assert!(
+            local_offset.abs_diff_eq(Vec3::new(2.0, 0.0, 0.0), 2.0e-2),
+            "{local_offset}"
+        );
+        let global_offset = app
+            .world
+            .get::<GlobalTransform>(child)
+            .unwrap()
+            .translation();
+        assert!(
+            global_offset.abs_diff_eq(parent_rotation * Vec3::new(2.0, 0.0, 0.0), 2.0e-2),
+            "{global_offset}"
+        );
+    }
+
+    #[test]
+    fn independent_children_ignore_the_parent_motion() {
+        use crate::dynamics::{GravityScale, PhysicsHierarchyMode, Velocity};
+        use crate::math::Vect;
+        use crate::plugin::TimestepMode;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .timestep_mode = TimestepMode::Fixed {
+            dt: 1.0 / 60.0,
+            substeps: 1,
+        };
+
+        let synced = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(2.0, 0.0, 0.0)),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                GravityScale(0.0),
+                Velocity::zero(),
+            ))
+            .id();
+        let independent = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(-2.0, 0.0, 0.0)),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                GravityScale(0.0),
+                Velocity::zero(),
+                PhysicsHierarchyMode::Independent,
+            ))
+            .id();
+        let parent = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                GravityScale(0.0),
+                Velocity::linear(Vect::Y * 2.0),
+            ))
+            .push_children(&[synced, independent])
+            .id();
+
+        for _ in 0..30 {
+            app.update();
+        }
+
+        let parent_y = app.world.get::<Transform>(parent).unwrap().translation.y;
+        assert!(parent_y > 0.5);
+
+        // The synced child is carried by its parent.
+        let synced_transform = app.world.get::<Transform>(synced).unwrap();
+        approx::assert_relative_eq!(synced_transform.translation.y, 0.0, epsilon = 1.0e-3);
+        let synced_global = app.world.get::<GlobalTransform>(synced).unwrap();
+        approx::assert_relative_eq!(synced_global.translation().y, parent_y, epsilon = 1.0e-3);
+
+        // The independent child stays where it was in world-space.
+        let independent_transform = app.world.get::<Transform>(independent).unwrap();
+        approx::assert_relative_eq!(
+            independent_transform.translation.y,
+            -parent_y,
+            epsilon = 1.0e-3
+        );
+        let independent_global = app.world.get::<GlobalTransform>(independent).unwrap();
+        approx::assert_relative_eq!(independent_global.translation().y, 0.0, epsilon = 1.0e-3);
+        assert_eq!(
+            app.world.get::<Velocity>(independent).unwrap().linvel,
+            Vect::ZERO
+        );
+
+        let context = app.world.resource::<RapierContext>();
+        let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+        let independent_body = world.bodies.get(world.entity2body[&independent]).unwrap();
+        approx::assert_relative_eq!(independent_body.translation().y, 0.0, epsilon = 1.0e-3);
+    }
+
+    #[test]
+    fn resting_child_bodies_fall_asleep() {
+        use crate::dynamics::{GravityScale, Velocity};
+        use crate::plugin::TimestepMode;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .timestep_mode = TimestepMode::Fixed {
+            dt: 1.0 / 60.0,
+            substeps: 1,
+        };
+
+        let children: Vec<_> = (0..10)
+            .map(|i| {
+                app.world
+                    .spawn((
+                        TransformBundle::from(Transform::from_xyz(i as f32 * 2.0, 0.0, 0.0)),
+                        RigidBody::Dynamic,
+                        Collider::ball(0.5),
+                        GravityScale(0.0),
+                        Velocity::zero(),
+                    ))
+                    .id()
+            })
+            .collect();
+        app.world
+            .spawn((TransformBundle::default(), RigidBody::Fixed))
+            .push_children(&children);
+
+        // Rigid-bodies fall asleep after resting for two seconds.
+        for _ in 0..180 {
+            app.update();
+        }
+
+        let context = app.world.resource::<RapierContext>();
+        let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+        for child in children {
+            let body = world.bodies.get(world.entity2body[&child]).unwrap();
+            assert!(body.is_sleeping());
+        }
+    }
+
+    #[test]
+    fn scaled_shape_queries_match_simulation() {
+        use crate::geometry::{ActiveCollisionTypes, ShapeCastOptions};
+        use crate::math::Vect;
+        use crate::pipeline::QueryFilter;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .scaled_shape_subdivision = 7;
+
+        #[cfg(feature = "dim2")]
+        let (ground_shape, non_uniform, rotation) =
+            (Collider::cuboid(10.0, 1.0), Vect::new(2.0, 1.0), 0.5);
+        #[cfg(feature = "dim3")]
+        let (ground_shape, non_uniform, rotation) = (
+            Collider::cuboid(10.0, 1.0, 10.0),
+            Vect::new(2.0, 1.0, 1.0),
+            Quat::from_rotation_z(0.5),
+        );
+        let ground = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(0.0, -1.0, 0.0)),
+                ground_shape,
+            ))
+            .id();
+
+        // A non-uniformly scaled ball gets approximated by a convex shape. It is tilted, so the
+        // approximation touches the ground on one of its vertices.
+        let ball = app
+            .world
+            .spawn((
+                TransformBundle::from(
+                    Transform::from_xyz(0.0, 0.9, 0.0)
+                        .with_rotation(Quat::from_rotation_z(0.5))
+                        .with_scale(Vec3::new(2.0, 1.0, 1.0)),
+                ),
+                RigidBody::KinematicPositionBased,
+                Collider::ball(1.0),
+                ActiveCollisionTypes::all(),
+            ))
+            .id();
+
+        app.update();
+        app.update();
+
+        let context = app.world.resource::<RapierContext>();
+        let world = context
+            .get_world(DEFAULT_WORLD_ID)
+            .expect("The default world should exist.");
+        assert_eq!(world.scaled_shape_subdivision, 7);
+
+        let (_, deepest) = world
+            .contact_pair(ground, ball)
+            .and_then(|pair| pair.find_deepest_contact())
+            .expect("The scaled ball should penetrate the ground.");
+
+        // The query shape was approximated with other subdivisions, it is approximated again
+        // like the ball of the simulation.
+        let mut shape = Collider::ball(1.0);
+        shape.set_scale(non_uniform, 30);
+        let (hit_entity, hit) = world
+            .cast_shape(
+                Vect::Y * 5.9,
+                rotation,
+                -Vect::Y,
+                &shape,
+                ShapeCastOptions::default(),
+                QueryFilter::default().exclude_collider(ball),
+            )
+            .expect("The shape-cast should hit the ground.");
+        assert_eq!(hit_entity, ground);
+        approx::assert_relative_eq!(hit.time_of_impact, 5.0 + deepest.dist(), epsilon = 1.0e-3);
+    }
+
+    #[test]
+    fn off_center_impulse_spins_rigid_body() {
+        use crate::dynamics::{ExternalImpulseAtPoint, GravityScale, Velocity};
+        use crate::math::Vect;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        #[cfg(feature = "dim2")]
+        let (collider, impulse, point) = (Collider::cuboid(0.5, 0.5), Vect::Y, Vect::new(1.5, 0.0));
+        #[cfg(feature = "dim3")]
+        let (collider, impulse, point) = (
+            Collider::cuboid(0.5, 0.5, 0.5),
+            Vect::Y,
+            Vect::new(1.5, 0.0, 0.0),
+        );
+
+        // A unit cube of mass 1, centered at `x = 1`.
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(1.0, 0.0, 0.0)),
+                RigidBody::Dynamic,
+                collider,
+                GravityScale(0.0),
+                Velocity::zero(),
+                ExternalImpulseAtPoint::new(impulse, point),
+            ))
+            .id();
+
+        for _ in 0..3 {
+            app.update();
+        }
+
+        // The angular impulse is `0.5`, and the angular inertia around `z` is `1 / 6`.
+        let velocity = app.world.get::<Velocity>(body).unwrap();
+        approx::assert_relative_eq!(velocity.linvel, impulse, epsilon = 1.0e-4);
+        #[cfg(feature = "dim2")]
+        approx::assert_relative_eq!(velocity.angvel, 3.0, epsilon = 1.0e-4);
+        #[cfg(feature = "dim3")]
+        approx::assert_relative_eq!(velocity.angvel, Vect::Z * 3.0, epsilon = 1.0e-4);
+
+        let impulse = app.world.get::<ExternalImpulseAtPoint>(body).unwrap();
+        assert_eq!(*impulse, ExternalImpulseAtPoint::default());
+    }
+
+    #[test]
+    fn velocity_at_point_of_spinning_body() {
+        use crate::dynamics::{GravityScale, Velocity};
+        use crate::math::Vect;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        // A body spinning around its center-of-mass, at `x = 1`, while moving along `y`.
+        #[cfg(feature = "dim2")]
+        let (collider, velocity) = (
+            Collider::ball(0.5),
+            Velocity {
+                linvel: Vect::Y,
+                angvel: 2.0,
+            },
+        );
+        #[cfg(feature = "dim3")]
+        let (collider, velocity) = (
+            Collider::ball(0.5),
+            Velocity {
+                linvel: Vect::Y,
+                angvel: Vect::Z * 2.0,
+            },
+        );
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(1.0, 0.0, 0.0)),
+                RigidBody::Dynamic,
+                collider,
+                GravityScale(0.0),
+                velocity,
+            ))
+            .id();
+
+        app.update();
+
+        let translation = app.world.get::<Transform>(body).unwrap().translation;
+        #[cfg(feature = "dim2")]
+        let center_of_mass = translation.truncate();
+        #[cfg(feature = "dim3")]
+        let center_of_mass = translation;
+        #[cfg(feature = "dim2")]
+        let point = center_of_mass + Vect::new(0.0, 0.5);
+        #[cfg(feature = "dim3")]
+        let point = center_of_mass + Vect::new(0.0, 0.5, 0.0);
+
+        // `ω × r` for a point `0.5` above the center-of-mass is `-1` along `x`.
+        let expected = Vect::Y - Vect::X;
+
+        let context = app.world.resource::<RapierContext>();
+        let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+        approx::assert_relative_eq!(
+            world.velocity_at_point(body, point).unwrap(),
+            expected,
+            epsilon = 1.0e-4
+        );
+        approx::assert_relative_eq!(
+            context.velocity_at_point(body, point).unwrap(),
+            expected,
+            epsilon = 1.0e-4
+        );
+        approx::assert_relative_eq!(
+            velocity.linear_velocity_at_point(point, center_of_mass),
+            expected,
+            epsilon = 1.0e-4
+        );
+        assert!(world
+            .velocity_at_point(Entity::PLACEHOLDER, point)
+            .is_none());
+    }
+
+    #[test]
+    fn gravity_override_replaces_world_gravity() {
+        use crate::dynamics::{GravityOverride, GravityScale, Velocity};
+        use crate::geometry::ColliderMassProperties;
+        use crate::math::Vect;
+        use crate::plugin::TimestepMode;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        let dt = 1.0 / 60.0;
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .timestep_mode = TimestepMode::Fixed { dt, substeps: 1 };
+
+        let up = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                ColliderMassProperties::Density(3.0),
+                GravityOverride(Vect::Y * 5.0),
+                Velocity::zero(),
+            ))
+            .id();
+        let side = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(10.0, 0.0, 0.0)),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                GravityScale(2.0),
+                GravityOverride(Vect::X * 2.0),
+                Velocity::zero(),
+            ))
+            .id();
+
+        let linvel = |app: &App, entity| app.world.get::<Velocity>(entity).unwrap().linvel;
+        let steps = 10;
+        let elapsed = steps as f32 * dt;
+
+        app.update();
+        let (up_start, side_start) = (linvel(&app, up), linvel(&app, side));
+        for _ in 0..steps {
+            app.update();
+        }
+
+        // The acceleration doesn’t depend on the mass nor on the gravity scale.
+        approx::assert_relative_eq!(
+            linvel(&app, up) - up_start,
+            Vect::Y * 5.0 * elapsed,
+            epsilon = 1.0e-3
+        );
+        approx::assert_relative_eq!(
+            linvel(&app, side) - side_start,
+            Vect::X * 2.0 * elapsed,
+            epsilon = 1.0e-3
+        );
+
+        // Once the override is removed, the world gravity applies again.
+        app.world.entity_mut(up).remove::<GravityOverride>();
+        app.update();
+        let up_start = linvel(&app, up);
+        for _ in 0..steps {
+            app.update();
+        }
+
+        let gravity = app
+            .world
+            .resource::<RapierContext>()
+            .get_world(DEFAULT_WORLD_ID)
+            .unwrap()
+            .gravity;
+        approx::assert_relative_eq!(
+            linvel(&app, up) - up_start,
+            gravity * elapsed,
+            epsilon = 1.0e-3
+        );
+    }
+
+    #[test]
+    fn max_velocity_clamps_speeds() {
+        use crate::dynamics::{GravityScale, MaxVelocity, Velocity};
+        use crate::math::Vect;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        #[cfg(feature = "dim2")]
+        let velocity = Velocity {
+            linvel: Vect::new(1.0e5, 1.0e5),
+            angvel: 1.0e5,
+        };
+        #[cfg(feature = "dim3")]
+        let velocity = Velocity {
+            linvel: Vect::new(1.0e5, 1.0e5, 0.0),
+            angvel: Vect::new(0.0, 1.0e5, 1.0e5),
+        };
+        let clamped = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                GravityScale(0.0),
+                velocity,
+                MaxVelocity {
+                    linear: 10.0,
+                    angular: 2.0,
+                },
+            ))
+            .id();
+        let unclamped = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(0.0, -1.0e3, 0.0)),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                GravityScale(0.0),
+                velocity,
+                MaxVelocity::default(),
+            ))
+            .id();
+
+        app.update();
+
+        let clamped = app.world.get::<Velocity>(clamped).unwrap();
+        approx::assert_relative_eq!(clamped.linvel.length(), 10.0, epsilon = 1.0e-3);
+        approx::assert_relative_eq!(
+            clamped.linvel.normalize(),
+            velocity.linvel.normalize(),
+            epsilon = 1.0e-5
+        );
+        #[cfg(feature = "dim2")]
+        approx::assert_relative_eq!(clamped.angvel, 2.0, epsilon = 1.0e-3);
+        #[cfg(feature = "dim3")]
+        approx::assert_relative_eq!(clamped.angvel.length(), 2.0, epsilon = 1.0e-3);
+
+        let unclamped = app.world.get::<Velocity>(unclamped).unwrap();
+        approx::assert_relative_eq!(unclamped.linvel, velocity.linvel, max_relative = 1.0e-3);
+    }
+
+    #[test]
+    fn soft_ccd_prevents_tunneling() {
+        use crate::dynamics::{GravityScale, SoftCcd, Velocity};
+        use crate::math::Vect;
+        use crate::plugin::TimestepMode;
+
+        /// Shoots a small ball at a thin wall, and returns its final position along `x`.
+        fn final_position(soft_ccd: Option<SoftCcd>) -> f32 {
+            let mut app = App::new();
+            app.add_plugins((
+                HeadlessRenderPlugin,
+                TransformPlugin,
+                TimePlugin,
+                RapierPhysicsPlugin::<NoUserData>::default(),
+            ));
+            app.world
+                .resource_mut::<RapierConfiguration>()
+                .timestep_mode = TimestepMode::Fixed {
+                dt: 1.0 / 60.0,
+                substeps: 1,
+            };
+
+            #[cfg(feature = "dim2")]
+            let wall = Collider::cuboid(0.05, 5.0);
+            #[cfg(feature = "dim3")]
+            let wall = Collider::cuboid(0.05, 5.0, 5.0);
+            app.world.spawn((
+                TransformBundle::from(Transform::from_xyz(3.0, 0.0, 0.0)),
+                wall,
+            ));
+
+            // The ball moves by 5 units per step.
+            let mut ball = app.world.spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.1),
+                GravityScale(0.0),
+                Velocity::linear(Vect::X * 300.0),
+            ));
+            if let Some(soft_ccd) = soft_ccd {
+                ball.insert(soft_ccd);
+            }
+            let ball = ball.id();
+
+            for _ in 0..5 {
+                app.update();
+            }
+
+            app.world.get::<Transform>(ball).unwrap().translation.x
+        }
+
+        assert!(final_position(None) > 3.0);
+        assert!(final_position(Some(SoftCcd { prediction: 10.0 })) < 3.0);
+    }
+
+    #[test]
+    fn sleep_events_control_the_rigid_body() {
+        use crate::dynamics::{PutBodyToSleep, Sleeping, WakeUpBody};
+        use crate::plugin::TimestepMode;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .timestep_mode = TimestepMode::Fixed {
+            dt: 1.0 / 60.0,
+            substeps: 1,
+        };
+
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(0.0, 10.0, 0.0)),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                Sleeping::default(),
+            ))
+            .id();
+        app.update();
+
+        let is_sleeping = |app: &App| {
+            let context = app.world.resource::<RapierContext>();
+            let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+            let handle = world.entity2body.get(&body).copied().unwrap();
+            world.bodies.get(handle).unwrap().is_sleeping()
+        };
+        let height = |app: &App| app.world.get::<Transform>(body).unwrap().translation.y;
+
+        // A sleeping body doesn’t fall, even though it is affected by gravity.
+        app.world.send_event(PutBodyToSleep(body));
+        app.update();
+        let asleep_height = height(&app);
+        for _ in 0..10 {
+            app.update();
+        }
+        assert!(is_sleeping(&app));
+        assert!(app.world.get::<Sleeping>(body).unwrap().sleeping);
+        assert_eq!(height(&app), asleep_height);
+
+        app.world.send_event(WakeUpBody(body));
+        for _ in 0..10 {
+            app.update();
+        }
+        assert!(!is_sleeping(&app));
+        assert!(!app.world.get::<Sleeping>(body).unwrap().sleeping);
+        assert!(height(&app) < asleep_height);
+    }
+
+    #[test]
+    fn migrating_bodies_between_worlds_does_not_leak() {
+        use crate::dynamics::PhysicsWorld;
+        use crate::plugin::RapierWorld;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        let other_world_id = app
+            .world
+            .resource_mut::<RapierContext>()
+            .add_world(RapierWorld::default());
+
+        let entities: Vec<_> = (0..1000)
+            .map(|i| {
+                app.world
+                    .spawn((
+                        TransformBundle::from(Transform::from_xyz(i as f32 * 2.0, 0.0, 0.0)),
+                        RigidBody::Dynamic,
+                        Collider::ball(0.5),
+                    ))
+                    .id()
+            })
+            .collect();
+        app.update();
+
+        let map_sizes = |app: &App| {
+            let context = app.world.resource::<RapierContext>();
+            let mut sizes: Vec<_> = context
+                .worlds
+                .iter()
+                .map(|(id, world)| {
+                    assert_eq!(world.last_body_transform_set.len(), world.entity2body.len());
+                    assert!(world.last_body_transforms_are_live());
+                    (*id, world.last_body_transform_set.len())
+                })
+                .collect();
+            sizes.sort();
+            sizes
+        };
+
+        for i in 0..10 {
+            let world_id = if i % 2 == 0 {
+                other_world_id
+            } else {
+                DEFAULT_WORLD_ID
+            };
+
+            for entity in &entities {
+                app.world
+                    .entity_mut(*entity)
+                    .insert(PhysicsWorld { world_id });
+            }
+            app.update();
+            app.update();
+
+            let total: usize = map_sizes(&app).iter().map(|(_, size)| size).sum();
+            assert_eq!(total, entities.len());
+            assert!(map_sizes(&app).contains(&(world_id, entities.len())));
+        }
+    }
+
+    #[test]
+    fn validate_reports_corrupted_maps() {
+        use crate::plugin::ConsistencyError;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+            ))
+            .id();
+        let other = app.world.spawn(TransformBundle::default()).id();
+        app.update();
+
+        let mut context = app.world.resource_mut::<RapierContext>();
+        assert!(context.validate_all().is_empty());
+
+        let world = context.get_world_mut(DEFAULT_WORLD_ID).unwrap();
+        let body_handle = world.entity2body[&body];
+        let collider_handle = world.entity2collider[&body];
+
+        world.entity2body.insert(other, body_handle);
+        world.colliders.remove(
+            collider_handle,
+            &mut world.islands,
+            &mut world.bodies,
+            false,
+        );
+        let errors = world.validate();
+        assert!(errors.contains(&ConsistencyError::RigidBodyEntityMismatch {
+            entity: other,
+            handle: body_handle,
+            owner: Some(body),
+        }));
+        assert!(errors.iter().any(|error| matches!(
+            error,
+            ConsistencyError::SharedRigidBodyHandle { handle, .. } if *handle == body_handle
+        )));
+        assert!(errors.contains(&ConsistencyError::MissingCollider {
+            entity: body,
+            handle: collider_handle,
+        }));
+
+        world.entity2body.remove(&body);
+        world.bodies.remove(
+            body_handle,
+            &mut world.islands,
+            &mut world.colliders,
+            &mut world.impulse_joints,
+            &mut world.multibody_joints,
+            true,
+        );
+        world.entity2body.remove(&other);
+        world.entity2collider.remove(&body);
+        assert_eq!(
+            context.validate_all(),
+            vec![(
+                DEFAULT_WORLD_ID,
+                ConsistencyError::StaleLastBodyTransform {
+                    handle: body_handle
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn writeback_skips_sleeping_bodies() {
+        use crate::dynamics::{GravityScale, Sleeping, Velocity};
+        use crate::math::Vect;
+        use crate::plugin::TimestepMode;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .timestep_mode = TimestepMode::Fixed {
+            dt: 1.0 / 60.0,
+            substeps: 1,
+        };
+
+        let sleeping = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                Sleeping {
+                    sleeping: true,
+                    ..Default::default()
+                },
+            ))
+            .id();
+        let moving = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(10.0, 0.0, 0.0)),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                GravityScale(0.0),
+                Velocity::linear(Vect::X),
+            ))
+            .id();
+
+        for _ in 0..3 {
+            app.update();
+        }
+
+        // Move the sleeping rigid-body behind the plugin’s back, without waking it up. Since it
+        // isn’t active, the writeback shouldn’t even look at it.
+        let mut context = app.world.resource_mut::<RapierContext>();
+        let world = context.get_world_mut(DEFAULT_WORLD_ID).unwrap();
+        let handle = world.entity2body[&sleeping];
+        let rb = world.bodies.get_mut(handle).unwrap();
+        let mut position = *rb.position();
+        position.translation.vector.y = 5.0;
+        rb.set_position(position, false);
+
+        let moving_x = app.world.get::<Transform>(moving).unwrap().translation.x;
+        app.update();
+
+        assert_eq!(
+            app.world.get::<Transform>(sleeping).unwrap().translation.y,
+            0.0
+        );
+        assert!(app.world.get::<Transform>(moving).unwrap().translation.x > moving_x);
+    }
+
+    #[test]
+    fn async_step_matches_sync_step_with_one_frame_latency() {
+        use crate::geometry::ActiveEvents;
+        use crate::plugin::TimestepMode;
+        use bevy::tasks::{AsyncComputeTaskPool, TaskPool};
+
+        AsyncComputeTaskPool::get_or_init(TaskPool::default);
+
+        fn setup_app(async_step: bool) -> (App, Entity) {
+            let mut app = App::new();
+            app.add_plugins((
+                HeadlessRenderPlugin,
+                TransformPlugin,
+                TimePlugin,
+                RapierPhysicsPlugin::<NoUserData>::default().with_async_step(async_step),
+            ));
+            app.world
+                .resource_mut::<RapierConfiguration>()
+                .timestep_mode = TimestepMode::Fixed {
+                dt: 1.0 / 60.0,
+                substeps: 1,
+            };
+
+            #[cfg(feature = "dim2")]
+            let ground = Collider::cuboid(10.0, 0.1);
+            #[cfg(feature = "dim3")]
+            let ground = Collider::cuboid(10.0, 0.1, 10.0);
+            app.world.spawn((TransformBundle::default(), ground));
+            let ball = app
+                .world
+                .spawn((
+                    TransformBundle::from(Transform::from_xyz(0.0, 1.0, 0.0)),
+                    RigidBody::Dynamic,
+                    Collider::ball(0.5),
+                    ActiveEvents::COLLISION_EVENTS,
+                ))
+                .id();
+
+            (app, ball)
+        }
+
+        fn run(app: &mut App, updates: usize) -> usize {
+            let mut reader = app.world.resource::<Events<CollisionEvent>>().get_reader();
+            let mut num_events = 0;
+
+            for _ in 0..updates {
+                app.update();
+                let events = app.world.resource::<Events<CollisionEvent>>();
+                num_events += reader.read(events).count();
+            }
+
+            num_events
+        }
+
+        let (mut sync_app, sync_ball) = setup_app(false);
+        let (mut async_app, async_ball) = setup_app(true);
+        assert!(async_app.world.contains_resource::<AsyncPhysicsStep>());
+
+        const NUM_UPDATES: usize = 60;
+        let sync_events = run(&mut sync_app, NUM_UPDATES);
+        // The results of a step are only written back during the following frame.
+        let async_events = run(&mut async_app, NUM_UPDATES + 1);
+
+        let sync_pos = sync_app.world.get::<Transform>(sync_ball).unwrap();
+        let async_pos = async_app.world.get::<Transform>(async_ball).unwrap();
+        assert!(sync_pos.translation.y < 1.0);
+        assert_eq!(sync_pos.translation, async_pos.translation);
+        // The ball landed on the ground, and the event was only delivered once.
+        assert_eq!(sync_events, 1);
+        assert_eq!(async_events, sync_events);
+    }
+
+    #[test]
+    fn fixed_schedule_interpolates_between_fixed_steps() {
+        use crate::dynamics::{GravityScale, TransformInterpolation, Velocity};
+        use crate::math::Vect;
+        use crate::plugin::TimestepMode;
+        use bevy::time::TimeUpdateStrategy;
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default().in_fixed_schedule(),
+        ))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            1.0 / 60.0,
+        )))
+        .insert_resource(Time::<Fixed>::from_hz(20.0));
+
+        assert_eq!(
+            app.world.resource::<RapierConfiguration>().timestep_mode,
+            TimestepMode::FixedSchedule { substeps: 1 }
+        );
+
+        let bundle = (
+            TransformBundle::default(),
+            RigidBody::Dynamic,
+            Collider::ball(0.5),
+            GravityScale(0.0),
+            Velocity::linear(Vect::X),
+        );
+        let interpolated = app
+            .world
+            .spawn((bundle.clone(), TransformInterpolation::default()))
+            .id();
+        let raw = app.world.spawn(bundle).id();
+
+        for _ in 0..20 {
+            app.update();
+        }
+
+        let context = app.world.resource::<RapierContext>();
+        let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+        approx::assert_relative_eq!(world.integration_parameters.dt, 1.0 / 20.0);
+        let body_x = |entity| world.bodies[world.entity2body[&entity]].translation().x;
+        let (interpolated_body_x, raw_body_x) = (body_x(interpolated), body_x(raw));
+        assert!(raw_body_x > 0.0);
+
+        let transform_x = |entity| app.world.get::<Transform>(entity).unwrap().translation.x;
+        assert_eq!(transform_x(raw), raw_body_x);
+        // The interpolated transform lags at most one fixed step behind the simulation.
+        let interpolated_x = transform_x(interpolated);
+        assert!(interpolated_x <= interpolated_body_x);
+        assert!(interpolated_x >= interpolated_body_x - 1.0 / 20.0 - 1.0e-5);
+    }
+
+    #[test]
+    fn interpolated_mode_drops_time_beyond_max_steps() {
+        use crate::dynamics::{GravityScale, Velocity};
+        use crate::math::Vect;
+        use crate::plugin::TimestepMode;
+        use bevy::time::TimeUpdateStrategy;
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ))
+        // Simulate a hitch of one second on every frame.
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+        app.world
+            .resource_mut::<Time<Virtual>>()
+            .set_max_delta(Duration::from_secs(10));
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .timestep_mode = TimestepMode::Interpolated {
+            dt: 1.0 / 60.0,
+            time_scale: 1.0,
+            substeps: 1,
+            max_steps_per_frame: 4,
+        };
+
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                GravityScale(0.0),
+                Velocity::linear(Vect::X),
+            ))
+            .id();
+
+        // The first update has a zero delta time.
+        app.update();
+        app.update();
+
+        let context = app.world.resource::<RapierContext>();
+        let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+        let x = world.bodies[world.entity2body[&body]].translation().x;
+        // Only 4 steps were executed instead of 60.
+        approx::assert_relative_eq!(x, 4.0 / 60.0, epsilon = 1.0e-5);
+
+        let events = app.world.resource::<Events<PhysicsTimeDroppedEvent>>();
+        let dropped: Vec<_> = events.get_reader().read(events).copied().collect();
+        assert_eq!(dropped.len(), 1);
+        assert_eq!(dropped[0].world_id, DEFAULT_WORLD_ID);
+        approx::assert_relative_eq!(
+            dropped[0].dropped_seconds,
+            1.0 - 4.0 / 60.0,
+            epsilon = 1.0e-5
+        );
+    }
+
+    #[test]
+    fn manual_mode_only_steps_when_requested() {
+        use crate::dynamics::{GravityScale, Velocity};
+        use crate::math::Vect;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .timestep_mode = TimestepMode::Manual {
+            dt: 1.0 / 60.0,
+            substeps: 1,
+        };
+
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                GravityScale(0.0),
+                Velocity::linear(Vect::X),
+            ))
+            .id();
+
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(app.world.get::<Transform>(body).unwrap().translation.x, 0.0);
+
+        app.world.resource_mut::<RapierContext>().request_step(2);
+        app.update();
+        let x = app.world.get::<Transform>(body).unwrap().translation.x;
+        approx::assert_relative_eq!(x, 2.0 / 60.0, epsilon = 1.0e-5);
+        assert_eq!(app.world.resource::<RapierContext>().requested_steps(), 0);
+
+        // The requested steps were all consumed.
+        app.update();
+        let new_x = app.world.get::<Transform>(body).unwrap().translation.x;
+        assert_eq!(new_x, x);
+    }
+
+    #[test]
+    fn physics_time_counts_steps_and_elapsed_time() {
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        const DT: f32 = 1.0 / 60.0;
+        const SUBSTEPS: usize = 4;
+        const FRAMES: usize = 10;
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .timestep_mode = TimestepMode::Fixed {
+            dt: DT,
+            substeps: SUBSTEPS,
+        };
+
+        let mut reader = app
+            .world
+            .resource::<Events<PhysicsStepEvent>>()
+            .get_reader();
+        let mut step_events = vec![];
+
+        for _ in 0..FRAMES {
+            app.update();
+            let events = app.world.resource::<Events<PhysicsStepEvent>>();
+            step_events.extend(reader.read(events).copied());
+        }
+
+        let context = app.world.resource::<RapierContext>();
+        let time = context.get_world(DEFAULT_WORLD_ID).unwrap().physics_time;
+        assert_eq!(time.steps, (FRAMES * SUBSTEPS) as u64);
+        assert_eq!(time.last_frame_steps, SUBSTEPS as u32);
+        approx::assert_relative_eq!(time.last_dt, DT / SUBSTEPS as f32);
+        approx::assert_relative_eq!(
+            time.elapsed,
+            time.steps as f64 * time.last_dt as f64,
+            epsilon = 1.0e-6
+        );
+
+        // One event per step, in order.
+        assert_eq!(step_events.len(), FRAMES * SUBSTEPS);
+        for (i, event) in step_events.iter().enumerate() {
+            assert_eq!(event.world_id, DEFAULT_WORLD_ID);
+            assert_eq!(event.step_index, i as u64);
+        }
+    }
+
+    #[test]
+    fn auto_inserted_interpolation_smooths_transforms() {
+        use crate::dynamics::{GravityScale, TransformInterpolation, Velocity};
+        use crate::math::Vect;
+        use bevy::time::TimeUpdateStrategy;
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ))
+        // Render faster than the physics steps.
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            1.0 / 144.0,
+        )));
+        let mut config = app.world.resource_mut::<RapierConfiguration>();
+        config.auto_insert_interpolation = true;
+        config.timestep_mode = TimestepMode::interpolated(1.0 / 60.0, 1.0, 1);
+
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                GravityScale(0.0),
+                Velocity::linear(Vect::X),
+            ))
+            .id();
+
+        for _ in 0..5 {
+            app.update();
+        }
+        assert!(app.world.get::<TransformInterpolation>(body).is_some());
+
+        // Without interpolation, the body would only move on the frames where a step is executed.
+        let mut prev_x = app.world.get::<Transform>(body).unwrap().translation.x;
+        for _ in 0..20 {
+            app.update();
+            let x = app.world.get::<Transform>(body).unwrap().translation.x;
+            assert!(x > prev_x);
+            prev_x = x;
+        }
+
+        app.world.entity_mut(body).remove::<RigidBody>();
+        app.update();
+        app.update();
+        assert!(app.world.get::<TransformInterpolation>(body).is_none());
+    }
+
+    #[test]
+    fn extrapolated_bodies_lead_the_simulation() {
+        use crate::dynamics::{GravityScale, InterpolationMode, TransformInterpolation, Velocity};
+        use crate::math::Vect;
+        use bevy::time::TimeUpdateStrategy;
+        use std::time::Duration;
+
+        const DT: f32 = 1.0 / 60.0;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            1.0 / 144.0,
+        )));
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .timestep_mode = TimestepMode::interpolated(DT, 1.0, 1);
+
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                GravityScale(0.0),
+                Velocity::linear(Vect::X * 2.0),
+                TransformInterpolation {
+                    mode: InterpolationMode::Extrapolate,
+                    ..Default::default()
+                },
+            ))
+            .id();
+
+        for _ in 0..20 {
+            app.update();
+
+            let diff = app.world.resource::<SimulationToRenderTime>().diff;
+            let context = app.world.resource::<RapierContext>();
+            let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+            let raw_x = world.bodies[world.entity2body[&body]].translation().x;
+            let x = app.world.get::<Transform>(body).unwrap().translation.x;
+
+            // The body is rendered where it will be at the render time, not one step behind.
+            approx::assert_relative_eq!(x, raw_x + 2.0 * (DT + diff), epsilon = 1.0e-5);
+        }
+    }
+
+    #[test]
+    fn diagnostics_are_recorded_for_each_world() {
+        use crate::plugin::{RapierDiagnostic, RapierDiagnosticsPlugin};
+        use bevy::diagnostic::DiagnosticsStore;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+            RapierDiagnosticsPlugin,
+        ));
+        app.finish();
+
+        for i in 0..3 {
+            app.world.spawn((
+                TransformBundle::from(Transform::from_xyz(i as f32 * 3.0, 0.0, 0.0)),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+            ));
+        }
+
+        for _ in 0..3 {
+            app.update();
+        }
+
+        let store = app.world.resource::<DiagnosticsStore>();
+        for world_id in [Some(DEFAULT_WORLD_ID), None] {
+            let value = |diagnostic: RapierDiagnostic| {
+                store
+                    .get(diagnostic.id(world_id))
+                    .and_then(|diagnostic| diagnostic.value())
+                    .unwrap()
+            };
+
+            assert_eq!(value(RapierDiagnostic::Bodies), 3.0);
+            assert_eq!(value(RapierDiagnostic::ActiveBodies), 3.0);
+            assert_eq!(value(RapierDiagnostic::Colliders), 3.0);
+            assert_eq!(value(RapierDiagnostic::Joints), 0.0);
+            // The falling balls don’t touch each other.
+            assert_eq!(value(RapierDiagnostic::ContactPairs), 0.0);
+            assert_eq!(value(RapierDiagnostic::Islands), 3.0);
+            assert!(value(RapierDiagnostic::StepTimeMs) > 0.0);
+        }
+    }
+
+    #[test]
+    fn diagnostics_count_multibody_joints_as_one_island() {
+        use crate::dynamics::{FixedJointBuilder, MultibodyJoint};
+        use crate::plugin::{RapierDiagnostic, RapierDiagnosticsPlugin};
+        use bevy::diagnostic::DiagnosticsStore;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+            RapierDiagnosticsPlugin,
+        ));
+        app.finish();
+
+        let mut spawn_ball = |x: f32| {
+            app.world
+                .spawn((
+                    TransformBundle::from(Transform::from_xyz(x, 0.0, 0.0)),
+                    RigidBody::Dynamic,
+                    Collider::ball(0.5),
+                ))
+                .id()
+        };
+        let root = spawn_ball(0.0);
+        let link = spawn_ball(3.0);
+        spawn_ball(6.0);
+        app.world.entity_mut(link).insert(MultibodyJoint::new(
+            root,
+            FixedJointBuilder::new().local_anchor1(Vect::X * 3.0),
+        ));
+
+        for _ in 0..3 {
+            app.update();
+        }
+
+        let store = app.world.resource::<DiagnosticsStore>();
+        let value = |diagnostic: RapierDiagnostic| {
+            store
+                .get(diagnostic.id(Some(DEFAULT_WORLD_ID)))
+                .and_then(|diagnostic| diagnostic.value())
+                .unwrap()
+        };
+
+        assert_eq!(value(RapierDiagnostic::Joints), 1.0);
+        // The two linked balls form a single island, the third one is alone.
+        assert_eq!(value(RapierDiagnostic::Islands), 2.0);
+    }
+
+    #[test]
+    fn step_counters_are_populated_when_profiling() {
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        #[cfg(feature = "dim2")]
+        let ground = Collider::cuboid(10.0, 1.0);
+        #[cfg(feature = "dim3")]
+        let ground = Collider::cuboid(10.0, 1.0, 10.0);
+        app.world.spawn((
+            TransformBundle::from(Transform::from_xyz(0.0, -1.0, 0.0)),
+            ground,
+        ));
+        app.world.spawn((
+            TransformBundle::from(Transform::from_xyz(0.0, 0.4, 0.0)),
+            RigidBody::Dynamic,
+            Collider::ball(0.5),
+        ));
+
+        app.update();
+        app.update();
+        let counters =
+            app.world.resource::<RapierContext>().worlds[&DEFAULT_WORLD_ID].step_counters();
+        assert_eq!(counters, crate::plugin::StepCounters::default());
+
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .enable_profiling = true;
+        app.update();
+        app.update();
+        let counters =
+            app.world.resource::<RapierContext>().worlds[&DEFAULT_WORLD_ID].step_counters();
+        assert!(counters.step_time > 0.0);
+        assert!(counters.collision_detection_time > 0.0);
+        assert!(counters.step_time >= counters.solver_time);
+        assert_eq!(counters.num_contact_pairs, 1);
+    }
+
+    #[test]
+    fn cast_ray_from_screen_hits_the_collider_under_the_cursor() {
+        use crate::pipeline::QueryFilter;
+        use bevy::window::PrimaryWindow;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        #[cfg(feature = "dim2")]
+        let (cameras, target, cursor_offset) = (
+            vec![app.world.spawn(Camera2dBundle::default()).id()],
+            app.world
+                .spawn((
+                    TransformBundle::from(Transform::from_xyz(100.0, 0.0, 0.0)),
+                    Collider::ball(10.0),
+                ))
+                .id(),
+            Vec2::new(100.0, 0.0),
+        );
+        #[cfg(feature = "dim3")]
+        let (cameras, target, cursor_offset) = {
+            let transform = Transform::from_xyz(0.0, 0.0, 10.0).looking_at(Vec3::ZERO, Vec3::Y);
+            let perspective = app
+                .world
+                .spawn(Camera3dBundle {
+                    transform,
+                    ..default()
+                })
+                .id();
+            let orthographic = app
+                .world
+                .spawn(Camera3dBundle {
+                    transform,
+                    camera: Camera {
+                        order: 1,
+                        ..default()
+                    },
+                    projection: OrthographicProjection::default().into(),
+                    ..default()
+                })
+                .id();
+            let target = app
+                .world
+                .spawn((TransformBundle::default(), Collider::cuboid(0.5, 0.5, 0.5)))
+                .id();
+            (vec![perspective, orthographic], target, Vec2::ZERO)
+        };
+
+        // Compute the camera matrices, and add the collider to the query pipeline.
+        app.update();
+
+        let window = app
+            .world
+            .query_filtered::<&Window, With<PrimaryWindow>>()
+            .single(&app.world);
+        let center = Vec2::new(window.width(), window.height()) / 2.0;
+
+        for camera in cameras {
+            let (camera, camera_transform) = app
+                .world
+                .query::<(&Camera, &GlobalTransform)>()
+                .get(&app.world, camera)
+                .unwrap();
+            let context = app.world.resource::<RapierContext>();
+            let cast = |screen_pos| {
+                context
+                    .cast_ray_from_screen(
+                        DEFAULT_WORLD_ID,
+                        camera,
+                        camera_transform,
+                        screen_pos,
+                        100.0,
+                        true,
+                        QueryFilter::default(),
+                    )
+                    .unwrap()
+            };
+
+            let (entity, _intersection) = cast(center + cursor_offset).unwrap();
+            assert_eq!(entity, target);
+            #[cfg(feature = "dim3")]
+            {
+                assert!((_intersection.time_of_impact - 9.5).abs() < 1.0e-3);
+                assert!((_intersection.normal - Vec3::Z).length() < 1.0e-3);
+            }
+
+            assert!(cast(center + cursor_offset + Vec2::new(100.0, 100.0)).is_none());
+        }
+    }
+
+    #[test]
+    fn physics_hooks_receive_the_stepped_world() {
+        use crate::dynamics::PhysicsWorld;
+        use crate::geometry::ActiveHooks;
+        use crate::pipeline::PairFilterContextView;
+        use crate::plugin::RapierWorld;
+        use bevy::ecs::system::SystemParam;
+        use rapier::prelude::SolverFlags;
+        use std::sync::Mutex;
+
+        #[derive(Resource, Default)]
+        struct FilteredPairs(Mutex<Vec<(WorldId, Entity)>>);
+
+        #[derive(SystemParam)]
+        struct RecordWorldHooks<'w> {
+            pairs: Res<'w, FilteredPairs>,
+        }
+
+        impl BevyPhysicsHooks for RecordWorldHooks<'_> {
+            fn filter_contact_pair(&self, context: PairFilterContextView) -> Option<SolverFlags> {
+                let mut pairs = self.pairs.0.lock().unwrap();
+                pairs.push((context.world_id(), context.collider1()));
+                pairs.push((context.world_id(), context.collider2()));
+                Some(SolverFlags::COMPUTE_IMPULSES)
+            }
+        }
+
+        let mut app = App::new();
+        app.init_resource::<FilteredPairs>().add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<RecordWorldHooks>::default(),
+        ));
+        let other_world_id = app
+            .world
+            .resource_mut::<RapierContext>()
+            .add_world(RapierWorld::default());
+
+        #[cfg(feature = "dim2")]
+        let ground_collider = Collider::cuboid(10.0, 1.0);
+        #[cfg(feature = "dim3")]
+        let ground_collider = Collider::cuboid(10.0, 1.0, 10.0);
+
+        let mut entities = vec![];
+        for world_id in [DEFAULT_WORLD_ID, other_world_id] {
+            let ground = app
+                .world
+                .spawn((
+                    TransformBundle::from(Transform::from_xyz(0.0, -1.0, 0.0)),
+                    ground_collider.clone(),
+                    PhysicsWorld { world_id },
+                ))
+                .id();
+            let ball = app
+                .world
+                .spawn((
+                    TransformBundle::from(Transform::from_xyz(0.0, 0.4, 0.0)),
+                    RigidBody::Dynamic,
+                    Collider::ball(0.5),
+                    ActiveHooks::FILTER_CONTACT_PAIRS,
+                    PhysicsWorld { world_id },
+                ))
+                .id();
+            entities.push((world_id, ground));
+            entities.push((world_id, ball));
+        }
+
+        for _ in 0..3 {
+            app.update();
+        }
+
+        let pairs = app.world.resource::<FilteredPairs>().0.lock().unwrap();
+        assert!(!pairs.is_empty());
+        for pair in pairs.iter() {
+            assert!(
+                entities.contains(pair),
+                "{pair:?} filtered in the wrong world"
+            );
+        }
+        for world_id in [DEFAULT_WORLD_ID, other_world_id] {
+            assert!(pairs.iter().any(|(id, _)| *id == world_id));
+        }
+    }
+
+    #[test]
+    fn one_way_platform_hook_only_stops_falling_bodies() {
+        use crate::dynamics::Velocity;
+        use crate::geometry::ActiveHooks;
+        use crate::math::Vect;
+        use crate::pipeline::ContactModificationContextView;
+        use crate::plugin::TimestepMode;
+        use bevy::ecs::system::SystemParam;
+
+        #[derive(Component)]
+        struct OneWayPlatform;
+
+        #[derive(SystemParam)]
+        struct OneWayPlatformHooks<'w, 's> {
+            platforms: Query<'w, 's, (), With<OneWayPlatform>>,
+        }
+
+        impl BevyPhysicsHooks for OneWayPlatformHooks<'_, '_> {
+            fn modify_solver_contacts(&self, mut context: ContactModificationContextView) {
+                let velocity = if self.platforms.contains(context.collider1()) {
+                    context.velocity2()
+                } else if self.platforms.contains(context.collider2()) {
+                    context.velocity1()
+                } else {
+                    return;
+                };
+
+                // Decide once per contact manifold, so a body resting on the platform isn’t
+                // dropped because of small upward corrections.
+                if context.user_data() == 0 {
+                    context.set_user_data(if velocity.linvel.y > 0.0 { 1 } else { 2 });
+                }
+
+                if context.user_data() == 1 {
+                    context.clear_solver_contacts();
+                }
+            }
+        }
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<OneWayPlatformHooks>::default(),
+        ));
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .timestep_mode = TimestepMode::Fixed {
+            dt: 1.0 / 60.0,
+            substeps: 1,
+        };
+
+        #[cfg(feature = "dim2")]
+        let platform = Collider::cuboid(5.0, 0.1);
+        #[cfg(feature = "dim3")]
+        let platform = Collider::cuboid(5.0, 0.1, 5.0);
+        app.world.spawn((
+            TransformBundle::default(),
+            platform,
+            OneWayPlatform,
+            ActiveHooks::MODIFY_SOLVER_CONTACTS,
+        ));
+
+        let from_below = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(-2.0, -2.0, 0.0)),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                Velocity::linear(Vect::Y * 10.0),
+            ))
+            .id();
+        let from_above = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(2.0, 2.0, 0.0)),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+            ))
+            .id();
+
+        let height = |app: &App, entity| app.world.get::<Transform>(entity).unwrap().translation.y;
+        let mut max_height = f32::MIN;
+        for _ in 0..300 {
+            app.update();
+            max_height = max_height.max(height(&app, from_below));
+        }
+
+        // The first ball went through the platform, then landed on it like the second one.
+        assert!(max_height > 2.0);
+        assert!((height(&app, from_below) - 0.6).abs() < 0.05);
+        assert!((height(&app, from_above) - 0.6).abs() < 0.05);
+    }
+
+    #[test]
+    fn conveyor_belt_transports_resting_bodies() {
+        use crate::dynamics::Velocity;
+        use crate::geometry::ConveyorBelt;
+        use crate::math::Vect;
+        use crate::plugin::TimestepMode;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .timestep_mode = TimestepMode::Fixed {
+            dt: 1.0 / 60.0,
+            substeps: 1,
+        };
+
+        // The belt is rotated, so its local velocity along `X` moves the bodies along `-Z` in
+        // 3D, and along `-X` in 2D.
+        #[cfg(feature = "dim2")]
+        let (belt, rotation, expected) = (
+            Collider::cuboid(10.0, 0.1),
+            Quat::from_rotation_z(PI),
+            Vect::new(-2.0, 0.0),
+        );
+        #[cfg(feature = "dim3")]
+        let (belt, rotation, expected) = (
+            Collider::cuboid(10.0, 0.1, 10.0),
+            Quat::from_rotation_y(PI / 2.0),
+            Vect::new(0.0, 0.0, -2.0),
+        );
+        app.world.spawn((
+            TransformBundle::from(Transform::from_rotation(rotation)),
+            belt,
+            ConveyorBelt {
+                velocity: Vect::X * 2.0,
+            },
+        ));
+
+        #[cfg(feature = "dim2")]
+        let body = Collider::cuboid(0.5, 0.5);
+        #[cfg(feature = "dim3")]
+        let body = Collider::cuboid(0.5, 0.5, 0.5);
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(0.0, 0.6, 0.0)),
+                RigidBody::Dynamic,
+                body,
+                Velocity::zero(),
+            ))
+            .id();
+
+        for _ in 0..120 {
+            app.update();
+        }
+
+        let velocity = app.world.get::<Velocity>(body).unwrap().linvel;
+        assert!(
+            (velocity - expected).length() < 0.1,
+            "unexpected steady-state velocity {velocity:?}"
+        );
+    }
+
+    #[test]
+    fn conveyor_belt_active_hooks_are_removed_with_the_belt() {
+        use crate::geometry::{ActiveHooks, ConveyorBelt, RapierColliderHandle};
+        use crate::math::Vect;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        #[cfg(feature = "dim2")]
+        let belt = Collider::cuboid(10.0, 0.1);
+        #[cfg(feature = "dim3")]
+        let belt = Collider::cuboid(10.0, 0.1, 10.0);
+        let belt_components = (
+            TransformBundle::default(),
+            belt,
+            ConveyorBelt { velocity: Vect::X },
+        );
+        let auto = app.world.spawn(belt_components.clone()).id();
+        let user = app
+            .world
+            .spawn((
+                belt_components,
+                ActiveHooks::MODIFY_SOLVER_CONTACTS | ActiveHooks::FILTER_CONTACT_PAIRS,
+            ))
+            .id();
+
+        app.update();
+        for entity in [auto, user] {
+            let hooks = *app.world.get::<ActiveHooks>(entity).unwrap();
+            assert!(hooks.contains(ActiveHooks::MODIFY_SOLVER_CONTACTS));
+        }
+
+        app.world.entity_mut(auto).remove::<ConveyorBelt>();
+        app.world.entity_mut(user).remove::<ConveyorBelt>();
+        app.update();
+        app.update();
+
+        // The flag added by the belt is removed, but the one set by the user is kept.
+        let hooks = |entity| {
+            let handle = app.world.get::<RapierColliderHandle>(entity).unwrap().0;
+            let context = app.world.resource::<RapierContext>();
+            let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+            (
+                *app.world.get::<ActiveHooks>(entity).unwrap(),
+                world.colliders.get(handle).unwrap().active_hooks(),
+            )
+        };
+        let (auto_hooks, auto_raw_hooks) = hooks(auto);
+        assert_eq!(auto_hooks, ActiveHooks::empty());
+        assert!(!auto_raw_hooks.contains(rapier::pipeline::ActiveHooks::MODIFY_SOLVER_CONTACTS));
+        let (user_hooks, user_raw_hooks) = hooks(user);
+        assert_eq!(
+            user_hooks,
+            ActiveHooks::MODIFY_SOLVER_CONTACTS | ActiveHooks::FILTER_CONTACT_PAIRS
+        );
+        assert!(user_raw_hooks.contains(rapier::pipeline::ActiveHooks::MODIFY_SOLVER_CONTACTS));
+    }
+
+    #[test]
+    fn detailed_collision_events_describe_the_impact() {
+        use crate::geometry::ActiveEvents;
+        use crate::math::Vect;
+        use crate::plugin::TimestepMode;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        let mut config = app.world.resource_mut::<RapierConfiguration>();
+        config.timestep_mode = TimestepMode::Fixed {
+            dt: 1.0 / 60.0,
+            substeps: 1,
+        };
+        config.detailed_collision_events = true;
+
+        #[cfg(feature = "dim2")]
+        let floor = Collider::cuboid(10.0, 1.0);
+        #[cfg(feature = "dim3")]
+        let floor = Collider::cuboid(10.0, 1.0, 10.0);
+        let floor = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(0.0, -1.0, 0.0)),
+                floor,
+            ))
+            .id();
+
+        // The ball falls from a height of 2 before touching the floor.
+        let height = 2.0;
+        let ball = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(0.0, height + 0.5, 0.0)),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                ActiveEvents::COLLISION_EVENTS,
+            ))
+            .id();
+
+        let mut reader = app
+            .world
+            .resource::<Events<DetailedCollisionEvent>>()
+            .get_reader();
+        let mut events = vec![];
+        for _ in 0..60 {
+            app.update();
+            let detailed_events = app.world.resource::<Events<DetailedCollisionEvent>>();
+            events.extend(reader.read(detailed_events).copied());
+        }
+
+        assert_eq!(events.len(), 1);
+        let event = events[0];
+        assert_eq!(event.world_id, DEFAULT_WORLD_ID);
+
+        // Orient the contact from the floor toward the ball.
+        let (normal, relative_velocity) = if event.collider1 == floor {
+            assert_eq!(event.collider2, ball);
+            (event.data.normal, event.data.relative_velocity)
+        } else {
+            assert_eq!((event.collider1, event.collider2), (ball, floor));
+            (-event.data.normal, -event.data.relative_velocity)
+        };
+
+        assert!((normal - Vect::Y).length() < 1.0e-3, "{normal:?}");
+        assert!(relative_velocity.y < 0.0);
+        let expected_speed = (2.0 * 9.81 * height).sqrt();
+        assert!(
+            (event.data.impact_speed() - expected_speed).abs() < 0.3,
+            "{} != {expected_speed}",
+            event.data.impact_speed()
+        );
+    }
+
+    #[test]
+    fn pending_collision_events_are_sent_while_paused() {
+        use crate::geometry::{ActiveEvents, Sensor};
+        use crate::plugin::{SimulationToRenderTime, TimestepMode};
+        use crate::prelude::GravityScale;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        let timestep_mode = TimestepMode::Fixed {
+            dt: 1.0 / 60.0,
+            substeps: 1,
+        };
+        let mut config = app.world.resource_mut::<RapierConfiguration>();
+        config.timestep_mode = timestep_mode;
+        config.physics_pipeline_active = false;
+
+        let sensor = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                Collider::ball(1.0),
+                Sensor,
+                ActiveEvents::COLLISION_EVENTS,
+            ))
+            .id();
+        let ball = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                GravityScale(0.0),
+            ))
+            .id();
+
+        let mut reader = app.world.resource::<Events<CollisionEvent>>().get_reader();
+        let mut update = |app: &mut App| {
+            app.update();
+            let events = app.world.resource::<Events<CollisionEvent>>();
+            reader.read(events).copied().collect::<Vec<_>>()
+        };
+        assert!(update(&mut app).is_empty());
+
+        // Step the paused world manually, like a custom stepping system would do.
+        app.world
+            .resource_mut::<RapierContext>()
+            .get_world_mut(DEFAULT_WORLD_ID)
+            .unwrap()
+            .step_simulation(
+                DEFAULT_WORLD_ID,
+                timestep_mode,
+                true,
+                &(),
+                &Time::default(),
+                &mut SimulationToRenderTime::default(),
+                &mut None,
+            );
+
+        let events = update(&mut app);
+        assert_eq!(events.len(), 1, "{events:?}");
+        let CollisionEvent::Started(e1, e2, _, world_id, step_index) = events[0] else {
+            panic!("unexpected event {:?}", events[0]);
+        };
+        assert!((e1, e2) == (sensor, ball) || (e1, e2) == (ball, sensor));
+        assert_eq!(world_id, DEFAULT_WORLD_ID);
+        assert_eq!(step_index, 0);
+        assert!(update(&mut app).is_empty());
+
+        // The colliders are still intersecting, so resuming doesn’t send the event again.
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .physics_pipeline_active = true;
+        for _ in 0..5 {
+            assert!(update(&mut app).is_empty());
+        }
+    }
+
+    #[test]
+    fn removed_colliders_are_reported_when_frames_skip_steps() {
+        use crate::geometry::{ActiveEvents, Sensor};
+        use crate::plugin::TimestepMode;
+        use crate::prelude::GravityScale;
+        use bevy::time::TimeUpdateStrategy;
+        use rapier::geometry::CollisionEventFlags;
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ))
+        // Only one frame out of four runs a step.
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            1.0 / 240.0,
+        )));
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .timestep_mode = TimestepMode::Interpolated {
+            dt: 1.0 / 60.0,
+            time_scale: 1.0,
+            substeps: 1,
+        };
+
+        let sensor = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                Collider::ball(1.0),
+                Sensor,
+                ActiveEvents::COLLISION_EVENTS,
+            ))
+            .id();
+        let ball = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                GravityScale(0.0),
+            ))
+            .id();
+
+        let mut reader = app.world.resource::<Events<CollisionEvent>>().get_reader();
+        let mut events = vec![];
+        for _ in 0..8 {
+            app.update();
+            let new_events = app.world.resource::<Events<CollisionEvent>>();
+            events.extend(reader.read(new_events).copied());
+        }
+        assert!(matches!(events[..], [CollisionEvent::Started(..)]));
+
+        // Despawn the ball right after a frame which ran a step, so the next frames don’t.
+        loop {
+            app.update();
+            let context = app.world.resource::<RapierContext>();
+            let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+            if world.physics_time.last_frame_steps > 0 {
+                break;
+            }
+        }
+        app.world.despawn(ball);
+
+        events.clear();
+        for _ in 0..8 {
+            app.update();
+            let new_events = app.world.resource::<Events<CollisionEvent>>();
+            events.extend(reader.read(new_events).copied());
+        }
+        let [CollisionEvent::Stopped(e1, e2, flags, ..)] = events[..] else {
+            panic!("unexpected events {events:?}");
+        };
+        assert!((e1, e2) == (sensor, ball) || (e1, e2) == (ball, sensor));
+        assert!(flags.contains(CollisionEventFlags::REMOVED));
+    }
+
+    #[test]
+    fn custom_event_handler_chained_with_bevy_events() {
+        use crate::geometry::ActiveEvents;
+        use crate::math::Real;
+        use crate::plugin::TimestepMode;
+        use rapier::dynamics::RigidBodySet;
+        use rapier::geometry::{ColliderSet, CollisionEvent as RapierCollisionEvent, ContactPair};
+        use rapier::pipeline::EventHandler;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        struct CountingHandler(Arc<AtomicUsize>);
+
+        impl EventHandler for CountingHandler {
+            fn handle_collision_event(
+                &self,
+                _bodies: &RigidBodySet,
+                _colliders: &ColliderSet,
+                _event: RapierCollisionEvent,
+                _contact_pair: Option<&ContactPair>,
+            ) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+
+            fn handle_contact_force_event(
+                &self,
+                _dt: Real,
+                _bodies: &RigidBodySet,
+                _colliders: &ColliderSet,
+                _contact_pair: &ContactPair,
+                _total_force_magnitude: Real,
+            ) {
+            }
+        }
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .timestep_mode = TimestepMode::Fixed {
+            dt: 1.0 / 60.0,
+            substeps: 1,
+        };
+
+        let count = Arc::new(AtomicUsize::new(0));
+        let mut context = app.world.resource_mut::<RapierContext>();
+        context
+            .set_event_handler(DEFAULT_WORLD_ID, Box::new(CountingHandler(count.clone())))
+            .unwrap();
+        context
+            .get_world_mut(DEFAULT_WORLD_ID)
+            .unwrap()
+            .chain_bevy_events = true;
+
+        #[cfg(feature = "dim2")]
+        let floor = Collider::cuboid(10.0, 1.0);
+        #[cfg(feature = "dim3")]
+        let floor = Collider::cuboid(10.0, 1.0, 10.0);
+        app.world.spawn((
+            TransformBundle::from(Transform::from_xyz(0.0, -1.0, 0.0)),
+            floor,
+        ));
+        app.world.spawn((
+            TransformBundle::from(Transform::from_xyz(0.0, 1.0, 0.0)),
+            RigidBody::Dynamic,
+            Collider::ball(0.5),
+            ActiveEvents::COLLISION_EVENTS,
+        ));
+
+        let mut reader = app.world.resource::<Events<CollisionEvent>>().get_reader();
+        let mut num_bevy_events = 0;
+        for _ in 0..60 {
+            app.update();
+            num_bevy_events += reader
+                .read(app.world.resource::<Events<CollisionEvent>>())
+                .count();
+        }
+
+        assert!(num_bevy_events > 0);
+        assert_eq!(count.load(Ordering::SeqCst), num_bevy_events);
+
+        // Without chaining, the custom handler replaces the Bevy events.
+        app.world
+            .resource_mut::<RapierContext>()
+            .get_world_mut(DEFAULT_WORLD_ID)
+            .unwrap()
+            .chain_bevy_events = false;
+        app.world.spawn((
+            TransformBundle::from(Transform::from_xyz(3.0, 1.0, 0.0)),
+            RigidBody::Dynamic,
+            Collider::ball(0.5),
+            ActiveEvents::COLLISION_EVENTS,
+        ));
+
+        let count_before = count.load(Ordering::SeqCst);
+        for _ in 0..60 {
+            app.update();
+            assert_eq!(
+                reader
+                    .read(app.world.resource::<Events<CollisionEvent>>())
+                    .count(),
+                0
+            );
+        }
+        assert!(count.load(Ordering::SeqCst) > count_before);
+    }
+
+    #[test]
+    fn physics_components_survive_a_dynamic_scene_round_trip() {
+        use crate::math::Vect;
+        use crate::prelude::Velocity;
+        use bevy::ecs::entity::EntityHashMap;
+        use bevy::scene::DynamicScene;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        let registry = app.world.resource::<AppTypeRegistry>().clone();
+
+        let mut source = World::new();
+        source.insert_resource(registry.clone());
+        let entity = source
+            .spawn((
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                Velocity::linear(Vect::X),
+            ))
+            .id();
+        let scene = DynamicScene::from_world(&source);
+
+        let mut destination = World::new();
+        destination.insert_resource(registry);
+        let mut entity_map = EntityHashMap::default();
+        scene
+            .write_to_world(&mut destination, &mut entity_map)
+            .unwrap();
+        let copy = entity_map[&entity];
+
+        assert_eq!(
+            destination.get::<RigidBody>(copy),
+            Some(&RigidBody::Dynamic)
+        );
+        assert_eq!(
+            destination.get::<Velocity>(copy),
+            Some(&Velocity::linear(Vect::X))
+        );
+        let collider = destination.get::<Collider>(copy).unwrap();
+        assert_eq!(collider.as_ball().map(|ball| ball.radius()), Some(0.5));
+    }
+
+    #[test]
+    fn colliders_are_created_from_shape_descs() {
+        use crate::prelude::{ColliderShapeDesc, RapierColliderHandle};
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        let ball = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                ColliderShapeDesc::Ball { radius: 0.5 },
+            ))
+            .id();
+        let unserializable = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                ColliderShapeDesc::Unserializable,
+            ))
+            .id();
+
+        app.update();
+
+        let collider = app.world.get::<Collider>(ball).unwrap();
+        assert_eq!(
+            collider.shape_desc(),
+            ColliderShapeDesc::Ball { radius: 0.5 }
+        );
+        assert!(app.world.get::<RapierColliderHandle>(ball).is_some());
+        assert!(app.world.get::<Collider>(unserializable).is_none());
+    }
+
+    #[test]
+    fn shape_descs_are_inserted_for_colliders() {
+        use crate::prelude::ColliderShapeDesc;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        let entity = app
+            .world
+            .spawn((TransformBundle::default(), Collider::ball(0.5)))
+            .id();
+        app.update();
+        assert_eq!(
+            app.world.get::<ColliderShapeDesc>(entity),
+            Some(&ColliderShapeDesc::Ball { radius: 0.5 })
+        );
+
+        *app.world.get_mut::<Collider>(entity).unwrap() = Collider::ball(1.0);
+        app.update();
+        assert_eq!(
+            app.world.get::<ColliderShapeDesc>(entity),
+            Some(&ColliderShapeDesc::Ball { radius: 1.0 })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde-serialize")]
+    fn collider_shape_descs_survive_a_ron_scene_round_trip() {
+        use crate::math::{Rot, Vect};
+        use crate::prelude::{ColliderShapeDesc, RapierColliderHandle};
+        use bevy::ecs::entity::EntityHashMap;
+        use bevy::scene::serde::SceneDeserializer;
+        use bevy::scene::{ron, DynamicScene};
+        use serde::de::DeserializeSeed;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        let registry = app.world.resource::<AppTypeRegistry>().clone();
+
+        let descs = [
+            ColliderShapeDesc::Ball { radius: 0.5 },
+            ColliderShapeDesc::Cuboid {
+                half_extents: Vect::splat(1.0),
+            },
+            ColliderShapeDesc::Compound {
+                shapes: vec![
+                    (
+                        Vect::X,
+                        Rot::default(),
+                        ColliderShapeDesc::Ball { radius: 0.25 },
+                    ),
+                    (
+                        -Vect::X,
+                        Rot::default(),
+                        ColliderShapeDesc::Segment {
+                            a: Vect::ZERO,
+                            b: Vect::Y * 2.0,
+                        },
+                    ),
+                ],
+            },
+        ];
+
+        let mut source = World::new();
+        source.insert_resource(registry.clone());
+        let entities: Vec<_> = descs
+            .iter()
+            .map(|desc| source.spawn((Transform::default(), desc.clone())).id())
+            .collect();
+        let ron = DynamicScene::from_world(&source)
+            .serialize_ron(&registry)
+            .unwrap();
+
+        let scene = SceneDeserializer {
+            type_registry: &registry.read(),
+        }
+        .deserialize(&mut ron::de::Deserializer::from_str(&ron).unwrap())
+        .unwrap();
+        let mut entity_map = EntityHashMap::default();
+        scene
+            .write_to_world(&mut app.world, &mut entity_map)
+            .unwrap();
+        for entity in &entities {
+            app.world
+                .entity_mut(entity_map[entity])
+                .insert(GlobalTransform::default());
+        }
+
+        app.update();
+
+        let context = app.world.resource::<RapierContext>();
+        let world = context
+            .get_world(DEFAULT_WORLD_ID)
+            .expect("The default world should exist.");
+        for (entity, desc) in entities.iter().zip(&descs) {
+            let handle = app
+                .world
+                .get::<RapierColliderHandle>(entity_map[entity])
+                .unwrap();
+            let shape = world.colliders.get(handle.0).unwrap().shared_shape();
+            assert_eq!(Collider::from(shape.clone()).shape_desc(), *desc);
+        }
+    }
+
+    #[test]
+    fn state_hash_detects_diverging_simulations() {
+        use crate::dynamics::Velocity;
+        use crate::math::Vect;
+        use crate::plugin::TimestepMode;
+
+        fn simulate(initial_velocity: Vect) -> u64 {
+            let mut app = App::new();
+            app.add_plugins((
+                HeadlessRenderPlugin,
+                TransformPlugin,
+                TimePlugin,
+                RapierPhysicsPlugin::<NoUserData>::default(),
+            ));
+            app.world
+                .resource_mut::<RapierConfiguration>()
+                .timestep_mode = TimestepMode::Fixed {
+                dt: 1.0 / 60.0,
+                substeps: 1,
+            };
+
+            #[cfg(feature = "dim2")]
+            let ground = Collider::cuboid(10.0, 0.5);
+            #[cfg(feature = "dim3")]
+            let ground = Collider::cuboid(10.0, 0.5, 10.0);
+            app.world.spawn((TransformBundle::default(), ground));
+            app.world.spawn((
+                TransformBundle::from(Transform::from_xyz(0.0, 2.0, 0.0)),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                Velocity::linear(initial_velocity),
+            ));
+
+            for _ in 0..30 {
+                app.update();
+            }
+
+            app.world.resource::<RapierContext>().state_hash()
+        }
+
+        let hash = simulate(Vect::ZERO);
+        assert_eq!(hash, simulate(Vect::ZERO));
+        assert_ne!(hash, simulate(Vect::X * 1.0e-3));
+    }
+
+    /// Steps a canned scene and compares the bit-exact final positions of its rigid-bodies with
+    /// the snapshot stored next to the manifest of the crate.
+    ///
+    /// Run with the `BLESS_DETERMINISM_SNAPSHOT` environment variable set to record a new
+    /// snapshot after a change that is expected to alter the simulation.
+    #[test]
+    #[cfg(feature = "enhanced-determinism")]
+    fn canned_scene_matches_the_determinism_snapshot() {
+        use crate::dynamics::Velocity;
+        use crate::math::Vect;
+        use crate::plugin::TimestepMode;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .timestep_mode = TimestepMode::Fixed {
+            dt: 1.0 / 60.0,
+            substeps: 1,
+        };
+
+        #[cfg(feature = "dim2")]
+        let (ground, cube) = (Collider::cuboid(20.0, 0.5), Collider::cuboid(0.5, 0.5));
+        #[cfg(feature = "dim3")]
+        let (ground, cube) = (
+            Collider::cuboid(20.0, 0.5, 20.0),
+            Collider::cuboid(0.5, 0.5, 0.5),
+        );
+        app.world.spawn((TransformBundle::default(), ground));
+
+        let mut bodies = vec![];
+        for i in 0..5 {
+            for j in 0..(5 - i) {
+                let x = j as f32 * 1.1 + i as f32 * 0.55 - 2.5;
+                let y = i as f32 * 1.05 + 1.0;
+                bodies.push(
+                    app.world
+                        .spawn((
+                            TransformBundle::from(Transform::from_xyz(x, y, 0.0)),
+                            RigidBody::Dynamic,
+                            cube.clone(),
+                        ))
+                        .id(),
+                );
+            }
+        }
+        for k in 0..3 {
+            bodies.push(
+                app.world
+                    .spawn((
+                        TransformBundle::from(Transform::from_xyz(k as f32 * 2.0 - 8.0, 3.0, 0.0)),
+                        RigidBody::Dynamic,
+                        Collider::ball(0.4),
+                        Velocity::linear(Vect::X * 6.0),
+                    ))
+                    .id(),
+            );
+        }
+
+        for _ in 0..600 {
+            app.update();
+        }
+
+        let snapshot: String = bodies
+            .iter()
+            .map(|entity| {
+                let transform = app.world.get::<Transform>(*entity).unwrap();
+                let bits: Vec<_> = transform
+                    .translation
+                    .to_array()
+                    .into_iter()
+                    .chain(transform.rotation.to_array())
+                    .map(|value| format!("{:08x}", value.to_bits()))
+                    .collect();
+                bits.join(" ") + "\n"
+            })
+            .collect();
+
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/determinism_snapshot.txt");
+        if std::env::var_os("BLESS_DETERMINISM_SNAPSHOT").is_some() {
+            std::fs::write(path, snapshot).unwrap();
+        } else {
+            let expected = std::fs::read_to_string(path).unwrap_or_else(|_| {
+                panic!("No snapshot found at {path}, run with `BLESS_DETERMINISM_SNAPSHOT=1`.")
+            });
+            assert_eq!(snapshot, expected);
+        }
+    }
+
+    #[test]
+    fn resimulating_with_corrected_inputs_matches_the_reference() {
+        use crate::math::{Real, Vect};
+        use crate::plugin::RapierWorld;
+        use rapier::prelude::{ColliderBuilder, RigidBodyBuilder, RigidBodyHandle};
+
+        const DT: Real = 1.0 / 60.0;
+
+        fn setup() -> (RapierWorld, RigidBodyHandle) {
+            let mut world = RapierWorld::default();
+            #[cfg(feature = "dim2")]
+            let ground = ColliderBuilder::cuboid(10.0, 0.5);
+            #[cfg(feature = "dim3")]
+            let ground = ColliderBuilder::cuboid(10.0, 0.5, 10.0);
+            world.colliders.insert(ground);
+
+            let body = world
+                .bodies
+                .insert(RigidBodyBuilder::dynamic().translation(Vect::Y.into()));
+            world
+                .colliders
+                .insert_with_parent(ColliderBuilder::ball(0.5), body, &mut world.bodies);
+            (world, body)
+        }
+
+        // The inputs applied to the body at each step.
+        fn push(world: &mut RapierWorld, body: RigidBodyHandle, strength: Real) {
+            world.bodies[body].apply_impulse((Vect::X * strength).into(), true);
+        }
+
+        let (mut reference, body) = setup();
+        reference.resimulate(30, DT, &(), |world, _| push(world, body, 0.1));
+
+        let (mut predicted, body) = setup();
+        predicted.resimulate(10, DT, &(), |world, _| push(world, body, 0.1));
+        let checkpoint = (
+            predicted.islands.clone(),
+            predicted.broad_phase.clone(),
+            predicted.narrow_phase.clone(),
+            predicted.bodies.clone(),
+            predicted.colliders.clone(),
+            predicted.impulse_joints.clone(),
+            predicted.multibody_joints.clone(),
+            predicted.ccd_solver.clone(),
+        );
+
+        // The prediction misses the inputs of the last steps.
+        predicted.resimulate(20, DT, &(), |world, step| {
+            push(world, body, if step < 5 { 0.1 } else { 0.0 })
+        });
+        assert_ne!(predicted.state_hash(), reference.state_hash());
+
+        (
+            predicted.islands,
+            predicted.broad_phase,
+            predicted.narrow_phase,
+            predicted.bodies,
+            predicted.colliders,
+            predicted.impulse_joints,
+            predicted.multibody_joints,
+            predicted.ccd_solver,
+        ) = checkpoint;
+        predicted.resimulate(20, DT, &(), |world, _| push(world, body, 0.1));
+
+        assert_eq!(predicted.state_hash(), reference.state_hash());
+        assert_eq!(predicted.physics_time.steps, 50);
+    }
+
+    #[test]
+    fn headless_app_without_writeback_steps_without_touching_transforms() {
+        use crate::plugin::TimestepMode;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default()
+                .without_writeback()
+                .without_async_colliders(),
+        ));
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .timestep_mode = TimestepMode::Fixed {
+            dt: 1.0 / 60.0,
+            substeps: 1,
+        };
+
+        let start = Transform::from_xyz(0.0, 5.0, 0.0);
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::from(start),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+            ))
+            .id();
+
+        let body_height = |app: &App| {
+            let context = app.world.resource::<RapierContext>();
+            let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+            world.bodies[world.entity2body[&body]].translation().y
+        };
+
+        for _ in 0..30 {
+            app.update();
+        }
+
+        assert_eq!(app.world.get::<Transform>(body), Some(&start));
+        assert!(body_height(&app) < 4.5);
+
+        // Setting the same transform again still teleports the body back.
+        *app.world.get_mut::<Transform>(body).unwrap() = start;
+        app.update();
+
+        assert!(body_height(&app) > 4.9);
+    }
+
+    #[test]
+    fn substep_schedule_runs_after_each_substep() {
+        use crate::plugin::{PhysicsSubstepSchedule, SubstepTime, TimestepMode};
+
+        #[derive(Resource, Default)]
+        struct SeenSubsteps(Vec<(SubstepTime, u64)>);
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ))
+        .init_resource::<SeenSubsteps>()
+        .add_systems(
+            PhysicsSubstepSchedule,
+            |substep: Res<SubstepTime>,
+             context: Res<RapierContext>,
+             mut seen: ResMut<SeenSubsteps>| {
+                let steps = context
+                    .get_world(DEFAULT_WORLD_ID)
+                    .unwrap()
+                    .physics_time
+                    .steps;
+                seen.0.push((*substep, steps));
+            },
+        );
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .timestep_mode = TimestepMode::Fixed {
+            dt: 1.0 / 60.0,
+            substeps: 4,
+        };
+
+        app.update();
+
+        let seen = &app.world.resource::<SeenSubsteps>().0;
+        let expected: Vec<_> = (0..4)
+            .map(|index| {
+                let substep = SubstepTime {
+                    dt: 1.0 / 60.0 / 4.0,
+                    index,
+                    substeps: 4,
+                };
+                (substep, index as u64 + 1)
+            })
+            .collect();
+        assert_eq!(*seen, expected);
+    }
+
+    #[test]
+    fn one_frame_force_is_applied_to_a_single_step() {
+        use crate::dynamics::{ExternalForce, ForceMode, GravityScale, Velocity};
+        use crate::geometry::ColliderMassProperties;
+        use crate::math::Vect;
+        use crate::plugin::TimestepMode;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        let dt = 1.0 / 60.0;
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .timestep_mode = TimestepMode::Fixed { dt, substeps: 1 };
+
+        let mut spawn_body = |x: f32, mode: ForceMode| {
+            app.world
+                .spawn((
+                    TransformBundle::from(Transform::from_xyz(x, 0.0, 0.0)),
+                    RigidBody::Dynamic,
+                    Collider::ball(0.5),
+                    ColliderMassProperties::Mass(2.0),
+                    GravityScale(0.0),
+                    Velocity::zero(),
+                    mode,
+                ))
+                .id()
+        };
+        let persistent = spawn_body(0.0, ForceMode::Persistent);
+        let one_frame = spawn_body(10.0, ForceMode::OneFrame);
+        app.update();
+
+        // An acceleration of `1` along `X`.
+        let force = ExternalForce {
+            force: Vect::X * 2.0,
+            ..Default::default()
+        };
+        app.world.entity_mut(persistent).insert(force);
+        app.world.entity_mut(one_frame).insert(force);
+
+        let linvel = |app: &App, entity| app.world.get::<Velocity>(entity).unwrap().linvel;
+        for _ in 0..4 {
+            app.update();
+        }
+
+        approx::assert_relative_eq!(
+            linvel(&app, persistent),
+            Vect::X * 4.0 * dt,
+            epsilon = 1.0e-5
+        );
+        approx::assert_relative_eq!(linvel(&app, one_frame), Vect::X * dt, epsilon = 1.0e-5);
+        assert_eq!(*app.world.get::<ExternalForce>(persistent).unwrap(), force);
+        assert_eq!(
+            *app.world.get::<ExternalForce>(one_frame).unwrap(),
+            ExternalForce::default()
+        );
+
+        // Setting the force again applies it to one more step.
+        *app.world.get_mut::<ExternalForce>(one_frame).unwrap() = force;
+        for _ in 0..3 {
+            app.update();
+        }
+
+        approx::assert_relative_eq!(
+            linvel(&app, one_frame),
+            Vect::X * 2.0 * dt,
+            epsilon = 1.0e-5
+        );
+    }
+
+    #[test]
+    fn removing_locked_axes_unlocks_rigid_body() {
+        use crate::dynamics::{ExternalForce, GravityScale, LockedAxes, Velocity};
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        #[cfg(feature = "dim2")]
+        let (collider, torque) = (Collider::ball(0.5), 1.0);
+        #[cfg(feature = "dim3")]
+        let (collider, torque) = (Collider::ball(0.5), crate::math::Vect::Z);
+
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                collider,
+                GravityScale(0.0),
+                Velocity::zero(),
+                LockedAxes::ROTATION_LOCKED,
+                ExternalForce {
+                    torque,
+                    ..Default::default()
+                },
+            ))
+            .id();
+
+        let angvel = |app: &App| app.world.get::<Velocity>(body).unwrap().angvel;
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(angvel(&app), Velocity::zero().angvel);
+
+        app.world.entity_mut(body).remove::<LockedAxes>();
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_ne!(angvel(&app), Velocity::zero().angvel);
+    }
+
+    #[test]
+    fn removing_dominance_and_friction_resets_them() {
+        use crate::dynamics::{CoefficientCombineRule, Dominance};
+        use crate::geometry::{Friction, Restitution};
+        use rapier::dynamics::CoefficientCombineRule as RapierCoefficientCombineRule;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                Dominance::group(3),
+                Friction {
+                    coefficient: 0.1,
+                    combine_rule: CoefficientCombineRule::Max,
+                },
+                Restitution {
+                    coefficient: 0.9,
+                    combine_rule: CoefficientCombineRule::Min,
+                },
+            ))
+            .id();
+        app.update();
+
+        app.world
+            .entity_mut(body)
+            .remove::<(Dominance, Friction, Restitution)>();
+        app.update();
+
+        let context = app.world.resource::<RapierContext>();
+        let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+        let rb = &world.bodies[world.entity2body[&body]];
+        assert_eq!(rb.dominance_group(), 0);
+
+        let co = &world.colliders[world.entity2collider[&body]];
+        assert_eq!(co.friction(), Friction::default().coefficient);
+        assert_eq!(
+            co.friction_combine_rule(),
+            RapierCoefficientCombineRule::from(Friction::default().combine_rule)
+        );
+        assert_eq!(co.restitution(), Restitution::default().coefficient);
+        assert_eq!(
+            co.restitution_combine_rule(),
+            RapierCoefficientCombineRule::from(Restitution::default().combine_rule)
+        );
+    }
+
+    #[test]
+    fn collision_group_change_wakes_resting_bodies() {
+        use crate::dynamics::Velocity;
+        use crate::geometry::{CollisionGroups, Group};
+        use crate::plugin::TimestepMode;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .timestep_mode = TimestepMode::Fixed {
+            dt: 1.0 / 60.0,
+            substeps: 1,
+        };
+
+        #[cfg(feature = "dim2")]
+        let (ground_collider, box_collider) =
+            (Collider::cuboid(10.0, 0.5), Collider::cuboid(0.5, 0.5));
+        #[cfg(feature = "dim3")]
+        let (ground_collider, box_collider) = (
+            Collider::cuboid(10.0, 0.5, 10.0),
+            Collider::cuboid(0.5, 0.5, 0.5),
+        );
+
+        let ground = app
+            .world
+            .spawn((TransformBundle::default(), ground_collider))
+            .id();
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(0.0, 1.0, 0.0)),
+                RigidBody::Dynamic,
+                box_collider,
+                Velocity::zero(),
+            ))
+            .id();
+
+        let is_sleeping = |app: &App| {
+            let context = app.world.resource::<RapierContext>();
+            let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+            world.bodies[world.entity2body[&body]].is_sleeping()
+        };
+        for _ in 0..1000 {
+            app.update();
+            if is_sleeping(&app) {
+                break;
+            }
+        }
+        assert!(is_sleeping(&app), "The box should rest on the ground.");
+
+        app.world
+            .entity_mut(ground)
+            .insert(CollisionGroups::new(Group::GROUP_2, Group::NONE));
+        app.update();
+        app.update();
+
+        assert!(!is_sleeping(&app));
+        assert!(app.world.get::<Velocity>(body).unwrap().linvel.y < 0.0);
+    }
+
+    #[test]
+    fn collider_multiple_group_change_wakes_resting_bodies() {
+        use crate::dynamics::Velocity;
+        use crate::geometry::{ColliderMultiple, CollisionGroups, Group};
+        use crate::plugin::TimestepMode;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .timestep_mode = TimestepMode::Fixed {
+            dt: 1.0 / 60.0,
+            substeps: 1,
+        };
+
+        #[cfg(feature = "dim2")]
+        let (ground_collider, box_collider) =
+            (Collider::cuboid(10.0, 0.5), Collider::cuboid(0.5, 0.5));
+        #[cfg(feature = "dim3")]
+        let (ground_collider, box_collider) = (
+            Collider::cuboid(10.0, 0.5, 10.0),
+            Collider::cuboid(0.5, 0.5, 0.5),
+        );
+
+        let ground = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                ColliderMultiple(vec![(Transform::IDENTITY, ground_collider)]),
+            ))
+            .id();
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(0.0, 1.0, 0.0)),
+                RigidBody::Dynamic,
+                box_collider,
+                Velocity::zero(),
+            ))
+            .id();
+
+        let is_sleeping = |app: &App| {
+            let context = app.world.resource::<RapierContext>();
+            let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+            world.bodies[world.entity2body[&body]].is_sleeping()
+        };
+        for _ in 0..1000 {
+            app.update();
+            if is_sleeping(&app) {
+                break;
+            }
+        }
+        assert!(is_sleeping(&app), "The box should rest on the ground.");
+
+        app.world
+            .entity_mut(ground)
+            .insert(CollisionGroups::new(Group::GROUP_2, Group::NONE));
+        app.update();
+        app.update();
+
+        assert!(!is_sleeping(&app));
+        assert!(app.world.get::<Velocity>(body).unwrap().linvel.y < 0.0);
+    }
+
+    #[test]
+    fn toggling_sensor_while_touching_switches_the_pair_type() {
+        use crate::geometry::{ActiveEvents, Sensor};
+        use crate::plugin::TimestepMode;
+        use crate::prelude::GravityScale;
+
+        // Returns the app, the ground and the ball resting on it (or inside it if it isn’t
+        // affected by gravity).
+        fn setup(ground_is_sensor: bool) -> (App, Entity, Entity) {
+            let mut app = App::new();
+            app.add_plugins((
+                HeadlessRenderPlugin,
+                TransformPlugin,
+                TimePlugin,
+                RapierPhysicsPlugin::<NoUserData>::default(),
+            ));
+            app.world
+                .resource_mut::<RapierConfiguration>()
+                .timestep_mode = TimestepMode::Fixed {
+                dt: 1.0 / 60.0,
+                substeps: 1,
+            };
+
+            #[cfg(feature = "dim2")]
+            let ground_collider = Collider::cuboid(10.0, 0.5);
+            #[cfg(feature = "dim3")]
+            let ground_collider = Collider::cuboid(10.0, 0.5, 10.0);
+
+            let ground = app
+                .world
+                .spawn((
+                    TransformBundle::default(),
+                    ground_collider,
+                    ActiveEvents::COLLISION_EVENTS,
+                ))
+                .id();
+            let ball = app
+                .world
+                .spawn((
+                    TransformBundle::from(Transform::from_xyz(0.0, 0.9, 0.0)),
+                    RigidBody::Dynamic,
+                    Collider::ball(0.5),
+                ))
+                .id();
+
+            if ground_is_sensor {
+                app.world.entity_mut(ground).insert(Sensor);
+                app.world.entity_mut(ball).insert(GravityScale(0.0));
+            }
+
+            (app, ground, ball)
+        }
+
+        // Updates the app, and returns the collision events as `(started, sensor)` pairs.
+        fn update(app: &mut App, ground: Entity, ball: Entity, frames: usize) -> Vec<(bool, bool)> {
+            let mut reader = app.world.resource::<Events<CollisionEvent>>().get_reader();
+            for _ in 0..frames {
+                app.update();
+            }
+
+            let events = app.world.resource::<Events<CollisionEvent>>();
+            reader
+                .read(events)
+                .map(|event| {
+                    let (e1, e2, flags, started) = match *event {
+                        CollisionEvent::Started(e1, e2, flags, ..) => (e1, e2, flags, true),
+                        CollisionEvent::Stopped(e1, e2, flags, ..) => (e1, e2, flags, false),
+                    };
+                    assert!((e1, e2) == (ground, ball) || (e1, e2) == (ball, ground));
+                    (started, flags.contains(CollisionEventFlags::SENSOR))
+                })
+                .collect()
+        }
+
+        // From contact to intersection.
+        let (mut app, ground, ball) = setup(false);
+        assert_eq!(update(&mut app, ground, ball, 10), [(true, false)]);
+        app.world.entity_mut(ground).insert(Sensor);
+        let events = update(&mut app, ground, ball, 2);
+        assert!(events.len() >= 2, "{events:?}");
+        assert_eq!(events[..2], [(false, false), (true, true)]);
+
+        // From intersection to contact.
+        let (mut app, ground, ball) = setup(true);
+        assert_eq!(update(&mut app, ground, ball, 10), [(true, true)]);
+        app.world.entity_mut(ground).remove::<Sensor>();
+        let events = update(&mut app, ground, ball, 2);
+        assert!(events.len() >= 2, "{events:?}");
+        assert_eq!(events[..2], [(false, true), (true, false)]);
+    }
+
+    #[test]
+    fn removing_active_events_stops_collision_events() {
+        use crate::dynamics::Velocity;
+        use crate::geometry::ActiveEvents;
+        use crate::plugin::TimestepMode;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .timestep_mode = TimestepMode::Fixed {
+            dt: 1.0 / 60.0,
+            substeps: 1,
+        };
+
+        #[cfg(feature = "dim2")]
+        let ground_collider = Collider::cuboid(10.0, 0.5);
+        #[cfg(feature = "dim3")]
+        let ground_collider = Collider::cuboid(10.0, 0.5, 10.0);
+
+        app.world
+            .spawn((TransformBundle::default(), ground_collider));
+        let ball = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(0.0, 2.0, 0.0)),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+                Velocity::zero(),
+                ActiveEvents::COLLISION_EVENTS,
+            ))
+            .id();
+
+        let mut reader = app.world.resource::<Events<CollisionEvent>>().get_reader();
+        let mut update = |app: &mut App, frames: usize| {
+            for _ in 0..frames {
+                app.update();
+            }
+            let events = app.world.resource::<Events<CollisionEvent>>();
+            reader.read(events).count()
+        };
+        assert_eq!(
+            update(&mut app, 120),
+            1,
+            "The ball should land on the ground."
+        );
+
+        // Drop the ball again, after it stopped reporting its collisions.
+        app.world.entity_mut(ball).remove::<ActiveEvents>();
+        update(&mut app, 1);
+        *app.world.get_mut::<Transform>(ball).unwrap() = Transform::from_xyz(0.0, 2.0, 0.0);
+        *app.world.get_mut::<Velocity>(ball).unwrap() = Velocity::zero();
+        assert_eq!(update(&mut app, 120), 0);
+
+        let context = app.world.resource::<RapierContext>();
+        let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+        let co = &world.colliders[world.entity2collider[&ball]];
+        assert!(co.active_events().is_empty());
+    }
+
+    #[test]
+    fn disabled_markers_follow_their_presence() {
+        use crate::geometry::ColliderDisabled;
+        use crate::math::Vect;
+        use crate::pipeline::QueryFilter;
+        use crate::prelude::RigidBodyDisabled;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        let mut spawn_body = |x: f32| {
+            app.world
+                .spawn((
+                    TransformBundle::from(Transform::from_xyz(x, 0.0, 0.0)),
+                    RigidBody::Fixed,
+                    Collider::ball(0.5),
+                ))
+                .id()
+        };
+        let spawned_disabled = spawn_body(0.0);
+        let disabled_later = spawn_body(10.0);
+        let toggled = spawn_body(20.0);
+        app.world
+            .entity_mut(spawned_disabled)
+            .insert(RigidBodyDisabled);
+        app.update();
+
+        let states = |app: &App| {
+            let context = app.world.resource::<RapierContext>();
+            let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+            [spawned_disabled, disabled_later, toggled].map(|entity| {
+                let body_enabled = world.bodies[world.entity2body[&entity]].is_enabled();
+                let collider_enabled = world.colliders[world.entity2collider[&entity]].is_enabled();
+                // Cast a ray through the ball, from above.
+                let ray_origin =
+                    app.world.get::<Transform>(entity).unwrap().translation + Vect::Y * 2.0;
+                let hit = world
+                    .cast_ray(ray_origin, -Vect::Y, 4.0, true, QueryFilter::default())
+                    .is_some();
+                (body_enabled, collider_enabled, hit)
+            })
+        };
+        assert_eq!(
+            states(&app),
+            [
+                (false, false, false),
+                (true, true, true),
+                (true, true, true)
+            ]
+        );
+
+        // Disabling later, and toggling both markers several times during a frame.
+        app.world
+            .entity_mut(disabled_later)
+            .insert(RigidBodyDisabled);
+        app.world
+            .entity_mut(toggled)
+            .insert((RigidBodyDisabled, ColliderDisabled))
+            .remove::<(RigidBodyDisabled, ColliderDisabled)>()
+            .insert(ColliderDisabled);
+        app.update();
+        assert_eq!(
+            states(&app),
+            [
+                (false, false, false),
+                (false, false, false),
+                (true, false, false)
+            ]
+        );
+
+        // Enabling again.
+        app.world
+            .entity_mut(spawned_disabled)
+            .remove::<RigidBodyDisabled>();
+        app.world
+            .entity_mut(toggled)
+            .remove::<ColliderDisabled>()
+            .insert(ColliderDisabled)
+            .remove::<ColliderDisabled>();
+        app.update();
+        assert_eq!(
+            states(&app),
+            [
+                (true, true, true),
+                (false, false, false),
+                (true, true, true)
+            ]
+        );
+    }
+
+    #[test]
+    fn global_transform_without_transform_tracks_simulation() {
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .timestep_mode = TimestepMode::Fixed {
+            dt: 1.0 / 60.0,
+            substeps: 1,
+        };
+
+        let entity = app
+            .world
+            .spawn((
+                GlobalTransform::from(Transform::from_xyz(0.0, 10.0, 0.0)),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+            ))
+            .id();
+
+        for _ in 0..30 {
+            app.update();
+        }
+
+        assert!(app.world.get::<Transform>(entity).is_none());
+        let translation = app
+            .world
+            .get::<GlobalTransform>(entity)
+            .unwrap()
+            .translation();
+        let context = app.world.resource::<RapierContext>();
+        let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+        let body = &world.bodies[world.entity2body[&entity]];
+        assert!(translation.y < 9.0, "the body didn’t fall: {translation}");
+        assert!((translation.y - body.translation().y).abs() < 1.0e-5);
+    }
+
+    #[test]
+    fn radial_impulse_pushes_near_bodies_harder() {
+        use crate::dynamics::{Falloff, GravityScale, RadialImpulseFlags};
+        use crate::math::Vect;
+        use crate::pipeline::QueryFilter;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        #[cfg(feature = "dim2")]
+        let (cube, wall) = (Collider::cuboid(0.5, 0.5), Collider::cuboid(0.1, 5.0));
+        #[cfg(feature = "dim3")]
+        let (cube, wall) = (
+            Collider::cuboid(0.5, 0.5, 0.5),
+            Collider::cuboid(0.1, 5.0, 5.0),
+        );
+        let mut spawn_box = |x: f32| {
+            app.world
+                .spawn((
+                    TransformBundle::from(Transform::from_xyz(x, 0.0, 0.0)),
+                    RigidBody::Dynamic,
+                    GravityScale(0.0),
+                    cube.clone(),
+                ))
+                .id()
+        };
+        let near = spawn_box(2.0);
+        let far = spawn_box(6.0);
+        let hidden = spawn_box(-6.0);
+        app.world.spawn((
+            TransformBundle::from(Transform::from_xyz(-3.0, 0.0, 0.0)),
+            wall,
+        ));
+        app.update();
+
+        let mut context = app.world.resource_mut::<RapierContext>();
+        let mut pushed = context
+            .apply_radial_impulse(
+                DEFAULT_WORLD_ID,
+                Vect::ZERO,
+                10.0,
+                5.0,
+                Falloff::Linear,
+                RadialImpulseFlags::OCCLUSION,
+                QueryFilter::default(),
+            )
+            .unwrap();
+        pushed.sort();
+        let mut expected = vec![near, far];
+        expected.sort();
+        assert_eq!(pushed, expected);
+
+        let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+        let linvel =
+            |entity| -> Vect { (*world.bodies[world.entity2body[&entity]].linvel()).into() };
+        assert!(linvel(near).x > linvel(far).x);
+        assert!(linvel(far).x > 0.0);
+        assert_eq!(linvel(hidden), Vect::ZERO);
+        // Without spin since the impulse is applied at the center-of-mass.
+        assert_eq!(linvel(near).y, 0.0);
+    }
+
+    #[test]
+    fn query_filter_excluded_entities_compose_with_predicate() {
+        use crate::math::{Real, Vect};
+        use crate::pipeline::QueryFilter;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        #[cfg(feature = "dim2")]
+        let cube = Collider::cuboid(0.5, 0.5);
+        #[cfg(feature = "dim3")]
+        let cube = Collider::cuboid(0.5, 0.5, 0.5);
+
+        // Colliders lined up along `-X`, the closest first.
+        let collider = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(-2.0, 0.0, 0.0)),
+                cube.clone(),
+            ))
+            .id();
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(-4.0, 0.0, 0.0)),
+                RigidBody::Fixed,
+            ))
+            .with_children(|children| {
+                children.spawn((TransformBundle::default(), cube.clone()));
+            })
+            .id();
+        let rejected = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(-6.0, 0.0, 0.0)),
+                cube.clone(),
+            ))
+            .id();
+        let target = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(-8.0, 0.0, 0.0)),
+                cube,
+            ))
+            .id();
+
+        app.update();
+
+        let context = app.world.resource::<RapierContext>();
+        let world = context
+            .get_world(DEFAULT_WORLD_ID)
+            .expect("The default world should exist.");
+        let cast = |filter| {
+            world
+                .cast_ray(Vect::ZERO, -Vect::X, Real::MAX, true, filter)
+                .map(|(entity, _)| entity)
+        };
+
+        assert_eq!(cast(QueryFilter::default()), Some(collider));
+
+        let excluded = [collider, body];
+        assert_eq!(
+            cast(QueryFilter::default().exclude_entities(&excluded)),
+            Some(rejected)
+        );
+
+        let predicate = |entity: Entity| entity != rejected;
+        assert_eq!(
+            cast(
+                QueryFilter::default()
+                    .exclude_entities(&excluded)
+                    .predicate(&predicate)
+            ),
+            Some(target)
+        );
+    }
+
+    #[test]
+    fn query_pipeline_update_modes() {
+        use crate::math::{Real, Vect};
+        use crate::pipeline::QueryFilter;
+        use crate::plugin::{PhysicsSet, QueryPipelineUpdateMode};
+
+        /// Teleports a rigid-body along `X` through the [`RapierContext`], after the step.
+        #[derive(Resource, Default)]
+        struct WritebackTeleport(Option<(Entity, Real)>);
+
+        fn teleport_during_writeback(
+            mut teleport: ResMut<WritebackTeleport>,
+            mut context: ResMut<RapierContext>,
+        ) {
+            let Some((entity, x)) = teleport.0.take() else {
+                return;
+            };
+            let world = context.get_world_mut(DEFAULT_WORLD_ID).unwrap();
+            let handle = world.entity2body[&entity];
+            let body = world.bodies.get_mut(handle).unwrap();
+            let mut position = *body.position();
+            position.translation.x = x;
+            body.set_position(position, true);
+        }
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ))
+        .init_resource::<WritebackTeleport>()
+        .add_systems(
+            PostUpdate,
+            teleport_during_writeback
+                .in_set(PhysicsSet::Writeback)
+                .before(update_query_pipelines),
+        );
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .query_pipeline_update_mode = QueryPipelineUpdateMode::AfterWriteback;
+
+        #[cfg(feature = "dim2")]
+        let cube = Collider::cuboid(0.5, 0.5);
+        #[cfg(feature = "dim3")]
+        let cube = Collider::cuboid(0.5, 0.5, 0.5);
+        let collider = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(5.0, 0.0, 0.0)),
+                cube.clone(),
+            ))
+            .id();
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(5.0, 10.0, 0.0)),
+                RigidBody::Fixed,
+                cube,
+            ))
+            .id();
+
+        app.update();
+
+        let cast_ray_at = |app: &App, y: Real| {
+            app.world
+                .resource::<RapierContext>()
+                .cast_ray(
+                    DEFAULT_WORLD_ID,
+                    Vect::Y * y,
+                    -Vect::X,
+                    Real::MAX,
+                    true,
+                    QueryFilter::default(),
+                )
+                .unwrap()
+                .map(|(entity, _)| entity)
+        };
+        let cast_ray = |app: &App| cast_ray_at(app, 0.0);
+        assert_eq!(cast_ray(&app), None);
+        assert_eq!(cast_ray_at(&app, 10.0), None);
+
+        // The changes made to the worlds after the step are only seen by the scene queries of the
+        // following frame with `AfterWriteback`.
+        for mode in [
+            QueryPipelineUpdateMode::AfterStep,
+            QueryPipelineUpdateMode::AfterWriteback,
+        ] {
+            app.world
+                .resource_mut::<RapierConfiguration>()
+                .query_pipeline_update_mode = mode;
+            app.world.resource_mut::<WritebackTeleport>().0 = Some((body, -5.0));
+            app.update();
+            let expected = (mode == QueryPipelineUpdateMode::AfterWriteback).then_some(body);
+            assert_eq!(cast_ray_at(&app, 10.0), expected, "{mode:?}");
+
+            app.world.resource_mut::<WritebackTeleport>().0 = Some((body, 5.0));
+            app.update();
+            app.update();
+            assert_eq!(cast_ray_at(&app, 10.0), None, "{mode:?}");
+        }
+
+        // Teleport the collider in front of the ray.
+        app.world
+            .get_mut::<Transform>(collider)
+            .unwrap()
+            .translation
+            .x = -5.0;
+        app.update();
+        assert_eq!(cast_ray(&app), Some(collider));
+
+        // In manual mode, the scene queries only see the teleportation once requested.
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .query_pipeline_update_mode = QueryPipelineUpdateMode::Manual;
+        app.world
+            .get_mut::<Transform>(collider)
+            .unwrap()
+            .translation
+            .x = 5.0;
+        app.update();
+        assert_eq!(cast_ray(&app), Some(collider));
+
+        app.world
+            .resource_mut::<RapierContext>()
+            .update_query_pipeline();
+        assert_eq!(cast_ray(&app), None);
+    }
+
+    #[test]
+    fn kinematic_target_is_reached_without_overshoot() {
+        use crate::dynamics::KinematicTarget;
+        use crate::math::Vect;
+        use crate::plugin::TimestepMode;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .timestep_mode = TimestepMode::Fixed {
+            dt: 1.0 / 60.0,
+            substeps: 1,
+        };
+
+        #[cfg(feature = "dim2")]
+        let (platform, target_rotation, expected_rotation) = (
+            Collider::cuboid(2.0, 0.2),
+            PI / 2.0,
+            Quat::from_rotation_z(PI / 2.0),
+        );
+        #[cfg(feature = "dim3")]
+        let (platform, target_rotation, expected_rotation) = (
+            Collider::cuboid(2.0, 0.2, 2.0),
+            Quat::from_rotation_y(PI / 2.0),
+            Quat::from_rotation_y(PI / 2.0),
+        );
+
+        // Moves by one unit per step.
+        let mover = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                RigidBody::KinematicVelocityBased,
+                platform.clone(),
+                KinematicTarget::translation(Vect::X * 4.0).with_max_speeds(60.0, f32::INFINITY),
+            ))
+            .id();
+        // Rotates without any speed limit.
+        let rotator = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(0.0, 5.0, 0.0)),
+                RigidBody::KinematicVelocityBased,
+                platform,
+                KinematicTarget::rotation(target_rotation),
+            ))
+            .id();
+
+        for step in 1..=10 {
+            app.update();
+
+            let transform = *app.world.get::<Transform>(mover).unwrap();
+            let expected = (step as f32).min(4.0);
+            approx::assert_relative_eq!(transform.translation.x, expected, epsilon = 1.0e-4);
+
+            let transform = *app.world.get::<Transform>(rotator).unwrap();
+            assert!(transform.translation.distance(Vec3::Y * 5.0) < 1.0e-4);
+            assert!(transform.rotation.abs_diff_eq(expected_rotation, 1.0e-4));
+        }
+
+        let context = app.world.resource::<RapierContext>();
+        let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+        let body = world.bodies.get(world.entity2body[&mover]).unwrap();
+        assert_eq!(Vect::from(*body.linvel()), Vect::ZERO);
+    }
+
+    #[test]
+    fn weld_on_contact_moves_bodies_as_one() {
+        use crate::dynamics::{
+            ExternalImpulse, GravityScale, ImpulseJoint, RapierImpulseJointHandle, Velocity,
+            WeldOnContact,
+        };
+        use crate::geometry::ActiveEvents;
+        use crate::math::Vect;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        #[cfg(feature = "dim2")]
+        let cube = Collider::cuboid(0.5, 0.5);
+        #[cfg(feature = "dim3")]
+        let cube = Collider::cuboid(0.5, 0.5, 0.5);
+
+        let moving = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(-2.0, 0.0, 0.0)),
+                RigidBody::Dynamic,
+                cube.clone(),
+                GravityScale(0.0),
+                Velocity {
+                    linvel: Vect::X * 5.0,
+                    ..default()
+                },
+            ))
+            .id();
+        let sticky = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(2.0, 0.0, 0.0)),
+                RigidBody::Dynamic,
+                cube,
+                GravityScale(0.0),
+                WeldOnContact,
+                ActiveEvents::COLLISION_EVENTS,
+            ))
+            .id();
+
+        let mut welded = false;
+        for _ in 0..120 {
+            app.update();
+            welded = app
+                .world
+                .query::<(&ImpulseJoint, &RapierImpulseJointHandle)>()
+                .iter(&app.world)
+                .any(|(joint, _)| joint.parent == moving);
+            if welded {
+                break;
+            }
+        }
+        assert!(welded, "the boxes weren’t welded");
+
+        let translation = |app: &App, entity| {
+            app.world
+                .get::<GlobalTransform>(entity)
+                .unwrap()
+                .translation()
+        };
+        let offset = translation(&app, sticky) - translation(&app, moving);
+
+        // Push the first box sideways: the second one must follow it, keeping their offset.
+        app.world.entity_mut(moving).insert(ExternalImpulse {
+            impulse: Vect::Y * 2.0,
+            ..default()
+        });
+        for _ in 0..60 {
+            app.update();
+        }
+
+        let new_offset = translation(&app, sticky) - translation(&app, moving);
+        assert!(translation(&app, sticky).y > 0.5);
+        assert!(
+            (new_offset.length() - offset.length()).abs() < 1.0e-2,
+            "{offset} != {new_offset}"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "dim3")]
+    fn driven_vehicle_moves_forward() {
+        use crate::control::{RaycastVehicle, RaycastVehicleOutput, VehicleWheel};
+        use crate::math::Vect;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        app.world.spawn((
+            TransformBundle::from(Transform::from_xyz(0.0, -0.1, 0.0)),
+            Collider::cuboid(50.0, 0.1, 50.0),
+        ));
+        let chassis = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(0.0, 1.0, 0.0)),
+                RigidBody::Dynamic,
+                Collider::cuboid(1.0, 0.25, 0.5),
+                RaycastVehicle {
+                    engine_force: 10.0,
+                    ..default()
+                },
+            ))
+            .with_children(|children| {
+                for (x, z) in [(0.8, 0.5), (0.8, -0.5), (-0.8, 0.5), (-0.8, -0.5)] {
+                    children.spawn((
+                        TransformBundle::default(),
+                        VehicleWheel::new(Vect::new(x, -0.25, z), 0.3)
+                            .suspension(0.3, 20.0, 2.0)
+                            .driven(),
+                    ));
+                }
+            })
+            .id();
+
+        for _ in 0..120 {
+            app.update();
+        }
+
+        let translation = app.world.get::<Transform>(chassis).unwrap().translation;
+        let output = *app.world.get::<RaycastVehicleOutput>(chassis).unwrap();
+        assert_eq!(output.wheels_in_contact, 4);
+        assert!(output.current_speed > 0.0);
+        assert!(translation.x > 0.5, "{translation}");
+        assert!(translation.y > 0.25, "{translation}");
+    }
+
+    #[test]
+    #[cfg(feature = "dim3")]
+    fn vehicle_is_only_updated_by_frames_that_step() {
+        use crate::control::{RaycastVehicle, RaycastVehicleOutput, VehicleWheel};
+        use crate::math::Vect;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .timestep_mode = TimestepMode::Manual {
+            dt: 1.0 / 60.0,
+            substeps: 1,
+        };
+
+        app.world.spawn((
+            TransformBundle::from(Transform::from_xyz(0.0, -0.1, 0.0)),
+            Collider::cuboid(50.0, 0.1, 50.0),
+        ));
+        let chassis = app
+            .world
+            .spawn((
+                TransformBundle::from(Transform::from_xyz(0.0, 0.5, 0.0)),
+                RigidBody::Dynamic,
+                Collider::cuboid(1.0, 0.25, 0.5),
+                RaycastVehicle {
+                    engine_force: 10.0,
+                    ..default()
+                },
+            ))
+            .with_children(|children| {
+                for (x, z) in [(0.8, 0.5), (0.8, -0.5), (-0.8, 0.5), (-0.8, -0.5)] {
+                    children.spawn((
+                        TransformBundle::default(),
+                        VehicleWheel::new(Vect::new(x, -0.25, z), 0.3)
+                            .suspension(0.3, 20.0, 2.0)
+                            .driven(),
+                    ));
+                }
+            })
+            .id();
+
+        let linvel = |app: &App| {
+            let handle = app.world.get::<RapierRigidBodyHandle>(chassis).unwrap().0;
+            let context = app.world.resource::<RapierContext>();
+            let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+            Vect::from(*world.bodies.get(handle).unwrap().linvel())
+        };
+
+        // The wheels don’t push the chassis while the simulation isn’t stepped.
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(linvel(&app), Vect::ZERO);
+        assert!(app.world.get::<RaycastVehicleOutput>(chassis).is_none());
+
+        app.world.resource_mut::<RapierContext>().request_step(1);
+        app.update();
+        let last_changed = app
+            .world
+            .entity(chassis)
+            .get_ref::<RaycastVehicleOutput>()
+            .unwrap()
+            .last_changed();
+
+        app.update();
+        let output = app
+            .world
+            .entity(chassis)
+            .get_ref::<RaycastVehicleOutput>()
+            .unwrap();
+        assert_eq!(output.last_changed(), last_changed);
+    }
+
+    #[test]
+    fn reserved_world_maps_are_not_reallocated_by_spawning() {
+        const NUM_BODIES: usize = 64;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.world
+            .resource_mut::<RapierContext>()
+            .get_world_mut(DEFAULT_WORLD_ID)
+            .unwrap()
+            .reserve(NUM_BODIES, NUM_BODIES);
+
+        let capacities = |app: &App| {
+            let context = app.world.resource::<RapierContext>();
+            let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+            [
+                world.entity2body.capacity(),
+                world.entity2collider.capacity(),
+                world.entity2colliders.capacity(),
+                world.entity2impulse_joint.capacity(),
+                world.entity2multibody_joint.capacity(),
+                world.last_body_transform_set.capacity(),
+            ]
+        };
+        let reserved = capacities(&app);
+        assert!(reserved.iter().all(|capacity| *capacity >= NUM_BODIES));
+
+        for i in 0..NUM_BODIES {
+            app.world.spawn((
+                TransformBundle::from(Transform::from_xyz(i as f32 * 2.0, 0.0, 0.0)),
+                RigidBody::Dynamic,
+                Collider::ball(0.5),
+            ));
+        }
+        app.update();
+
+        let context = app.world.resource::<RapierContext>();
+        let world = context.get_world(DEFAULT_WORLD_ID).unwrap();
+        assert_eq!(world.entity2body.len(), NUM_BODIES);
+        assert_eq!(world.entity2collider.len(), NUM_BODIES);
+        assert_eq!(capacities(&app), reserved);
+    }
+
+    /// An app generating the colliders of the `AsyncCollider`s on the async compute task pool.
+    #[cfg(feature = "async-collider")]
+    pub fn async_collider_test_app() -> App {
+        use crate::geometry::AsyncColliderFailedEvent;
+        use bevy::tasks::{AsyncComputeTaskPool, TaskPool};
+
+        AsyncComputeTaskPool::get_or_init(TaskPool::default);
+
+        let mut app = App::new();
+        app.add_plugins(HeadlessRenderPlugin)
+            // The events aren’t cleared, so the tests can read all of them.
+            .init_resource::<Events<AsyncColliderFailedEvent>>()
+            .add_systems(
+                Update,
+                (poll_collider_generation_tasks, init_async_colliders).chain(),
+            );
+        app
+    }
+
     // Allows run tests for systems containing rendering related things without GPU
     pub struct HeadlessRenderPlugin;
 
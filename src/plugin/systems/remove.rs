@@ -1,16 +1,28 @@
 use crate::dynamics::ImpulseJoint;
+use crate::dynamics::JointDisabled;
+use crate::dynamics::MassChangeCause;
 use crate::dynamics::MultibodyJoint;
 use crate::dynamics::RapierImpulseJointHandle;
 use crate::dynamics::RapierMultibodyJointHandle;
 use crate::dynamics::RapierRigidBodyHandle;
 use crate::dynamics::RigidBody;
+use crate::dynamics::{
+    Dominance, ExternalForce, ExternalForceAtPoint, GravityOverride, GravityScale, LockedAxes,
+    SoftCcd,
+};
 use crate::geometry::Collider;
-use crate::geometry::ColliderDisabled;
+use crate::geometry::ContactSkin;
 use crate::geometry::RapierColliderHandle;
-use crate::plugin::find_item_and_world;
+use crate::geometry::{ActiveCollisionTypes, ActiveEvents, ActiveHooks};
+use crate::geometry::{ColliderMultiple, RapierColliderHandles};
+use crate::geometry::{ConveyorBelt, ConveyorBeltActiveHooks};
+use crate::geometry::{Friction, Restitution};
+use crate::plugin::systems::{
+    set_impulse_joint_enabled, set_multibody_joint_enabled, set_rigid_body_forces,
+};
 use crate::plugin::RapierContext;
+use crate::plugin::{find_item_and_world, find_item_and_world_id};
 use crate::prelude::MassModifiedEvent;
-use crate::prelude::RigidBodyDisabled;
 use crate::prelude::Sensor;
 use bevy::prelude::*;
 
@@ -22,19 +34,60 @@ pub fn sync_removals(
     mut context: ResMut<RapierContext>,
     mut removed_bodies: RemovedComponents<RapierRigidBodyHandle>,
     mut removed_colliders: RemovedComponents<RapierColliderHandle>,
+    mut removed_collider_multiples: RemovedComponents<RapierColliderHandles>,
     mut removed_impulse_joints: RemovedComponents<RapierImpulseJointHandle>,
     mut removed_multibody_joints: RemovedComponents<RapierMultibodyJointHandle>,
     orphan_bodies: Query<Entity, (With<RapierRigidBodyHandle>, Without<RigidBody>)>,
     orphan_colliders: Query<Entity, (With<RapierColliderHandle>, Without<Collider>)>,
+    orphan_collider_multiples: Query<
+        Entity,
+        (With<RapierColliderHandles>, Without<ColliderMultiple>),
+    >,
     orphan_impulse_joints: Query<Entity, (With<RapierImpulseJointHandle>, Without<ImpulseJoint>)>,
     orphan_multibody_joints: Query<
         Entity,
         (With<RapierMultibodyJointHandle>, Without<MultibodyJoint>),
     >,
 
-    mut removed_sensors: RemovedComponents<Sensor>,
-    mut removed_rigid_body_disabled: RemovedComponents<RigidBodyDisabled>,
-    mut removed_colliders_disabled: RemovedComponents<ColliderDisabled>,
+    (
+        mut removed_sensors,
+        mut removed_contact_skins,
+        mut removed_joints_disabled,
+        mut removed_soft_ccds,
+        mut removed_conveyor_belts,
+        mut removed_active_events,
+        mut removed_active_hooks,
+        mut removed_active_collision_types,
+    ): (
+        RemovedComponents<Sensor>,
+        RemovedComponents<ContactSkin>,
+        RemovedComponents<JointDisabled>,
+        RemovedComponents<SoftCcd>,
+        RemovedComponents<ConveyorBelt>,
+        RemovedComponents<ActiveEvents>,
+        RemovedComponents<ActiveHooks>,
+        RemovedComponents<ActiveCollisionTypes>,
+    ),
+    mut belt_active_hooks: Query<&mut ActiveHooks, With<ConveyorBeltActiveHooks>>,
+    (mut removed_gravity_overrides, remaining_forces): (
+        RemovedComponents<GravityOverride>,
+        Query<(
+            Option<&GravityScale>,
+            Option<&ExternalForce>,
+            Option<&ExternalForceAtPoint>,
+        )>,
+    ),
+    (
+        mut removed_locked_axes,
+        mut removed_dominances,
+        mut removed_frictions,
+        mut removed_restitutions,
+    ): (
+        RemovedComponents<LockedAxes>,
+        RemovedComponents<Dominance>,
+        RemovedComponents<Friction>,
+        RemovedComponents<Restitution>,
+    ),
 
     mut mass_modified: EventWriter<MassModifiedEvent>,
 ) {
@@ -42,35 +95,11 @@ pub fn sync_removals(
      * Rigid-bodies removal detection.
      */
     for entity in removed_bodies.read() {
-        if let Some((world, handle)) =
-            find_item_and_world(&mut context, |world| world.entity2body.remove(&entity))
-        {
-            let _ = world.last_body_transform_set.remove(&handle);
-            world.bodies.remove(
-                handle,
-                &mut world.islands,
-                &mut world.colliders,
-                &mut world.impulse_joints,
-                &mut world.multibody_joints,
-                false,
-            );
-        }
+        find_item_and_world(&mut context, |world| world.remove_rigid_body_entity(entity));
     }
 
     for entity in orphan_bodies.iter() {
-        if let Some((world, handle)) =
-            find_item_and_world(&mut context, |world| world.entity2body.remove(&entity))
-        {
-            let _ = world.last_body_transform_set.remove(&handle);
-            world.bodies.remove(
-                handle,
-                &mut world.islands,
-                &mut world.colliders,
-                &mut world.impulse_joints,
-                &mut world.multibody_joints,
-                false,
-            );
-        }
+        find_item_and_world(&mut context, |world| world.remove_rigid_body_entity(entity));
         commands.entity(entity).remove::<RapierRigidBodyHandle>();
     }
 
@@ -78,36 +107,87 @@ pub fn sync_removals(
      * Collider removal detection.
      */
     for entity in removed_colliders.read() {
-        if let Some((world, handle)) =
-            find_item_and_world(&mut context, |world| world.entity2collider.remove(&entity))
+        if let Some((world_id, world, handle)) =
+            find_item_and_world_id(&mut context, |world| world.entity2collider.remove(&entity))
         {
-            if let Some(parent) = world.collider_parent(entity) {
-                mass_modified.send(parent.into());
-            }
+            let parent = world
+                .colliders
+                .get(handle)
+                .and_then(|co| co.parent())
+                .and_then(|h| world.rigid_body_entity(h));
 
             world
                 .colliders
                 .remove(handle, &mut world.islands, &mut world.bodies, true);
             world.deleted_colliders.insert(handle, entity);
+
+            if let Some(event) = parent.and_then(|parent| {
+                world.mass_modified_event(world_id, parent, MassChangeCause::ColliderRemoved)
+            }) {
+                mass_modified.send(event);
+            }
         }
     }
 
     for entity in orphan_colliders.iter() {
-        if let Some((world, handle)) =
-            find_item_and_world(&mut context, |world| world.entity2collider.remove(&entity))
+        if let Some((world_id, world, handle)) =
+            find_item_and_world_id(&mut context, |world| world.entity2collider.remove(&entity))
         {
-            if let Some(parent) = world.collider_parent(entity) {
-                mass_modified.send(parent.into());
-            }
+            let parent = world
+                .colliders
+                .get(handle)
+                .and_then(|co| co.parent())
+                .and_then(|h| world.rigid_body_entity(h));
 
             world
                 .colliders
                 .remove(handle, &mut world.islands, &mut world.bodies, true);
             world.deleted_colliders.insert(handle, entity);
+
+            if let Some(event) = parent.and_then(|parent| {
+                world.mass_modified_event(world_id, parent, MassChangeCause::ColliderRemoved)
+            }) {
+                mass_modified.send(event);
+            }
         }
         commands.entity(entity).remove::<RapierColliderHandle>();
     }
 
+    /*
+     * Collider multiple removal detection.
+     */
+    for entity in removed_collider_multiples
+        .read()
+        .chain(orphan_collider_multiples.iter())
+    {
+        if let Some((world_id, world, handles)) =
+            find_item_and_world_id(&mut context, |world| world.entity2colliders.remove(&entity))
+        {
+            let parent = handles
+                .first()
+                .and_then(|h| world.colliders.get(*h))
+                .and_then(|co| co.parent())
+                .and_then(|h| world.rigid_body_entity(h));
+
+            for handle in handles {
+                world
+                    .colliders
+                    .remove(handle, &mut world.islands, &mut world.bodies, true);
+                world.deleted_colliders.insert(handle, entity);
+            }
+
+            if let Some(event) = parent.and_then(|parent| {
+                world.mass_modified_event(world_id, parent, MassChangeCause::ColliderRemoved)
+            }) {
+                mass_modified.send(event);
+            }
+        }
+    }
+
+    for entity in orphan_collider_multiples.iter() {
+        commands.entity(entity).remove::<RapierColliderHandles>();
+    }
+
     /*
      * Impulse joint removal detection.
      */
@@ -154,34 +234,213 @@ pub fn sync_removals(
      * Marker components removal detection.
      */
     for entity in removed_sensors.read() {
+        if let Some((world_id, world, handle)) = find_item_and_world_id(&mut context, |world| {
+            world.entity2collider.get(&entity).copied()
+        }) {
+            world.set_collider_sensor(world_id, handle, false);
+        }
+
+        if let Some((world_id, world, handles)) = find_item_and_world_id(&mut context, |world| {
+            world.entity2colliders.get(&entity).cloned()
+        }) {
+            for handle in handles {
+                world.set_collider_sensor(world_id, handle, false);
+            }
+        }
+    }
+
+    for entity in removed_contact_skins.read() {
+        if let Some((world, handle)) = find_item_and_world(&mut context, |world| {
+            world.entity2collider.get(&entity).copied()
+        }) {
+            if let Some(co) = world.colliders.get_mut(handle) {
+                co.set_contact_skin(0.0);
+            }
+        }
+
+        set_collider_multiple(&mut context, entity, |co| co.set_contact_skin(0.0));
+    }
+
+    // The flags are reset to the ones of colliders created without these components.
+    for entity in removed_active_events.read() {
+        let reset = |co: &mut rapier::geometry::Collider| {
+            co.set_active_events(ActiveEvents::default().into())
+        };
+
+        if let Some((world, handle)) = find_item_and_world(&mut context, |world| {
+            world.entity2collider.get(&entity).copied()
+        }) {
+            if let Some(co) = world.colliders.get_mut(handle) {
+                reset(co);
+            }
+        }
+
+        set_collider_multiple(&mut context, entity, reset);
+    }
+
+    for entity in removed_active_hooks.read() {
+        let reset = |co: &mut rapier::geometry::Collider| {
+            co.set_active_hooks(ActiveHooks::default().into())
+        };
+
+        if let Some((world, handle)) = find_item_and_world(&mut context, |world| {
+            world.entity2collider.get(&entity).copied()
+        }) {
+            if let Some(co) = world.colliders.get_mut(handle) {
+                reset(co);
+            }
+        }
+
+        set_collider_multiple(&mut context, entity, reset);
+    }
+
+    for entity in removed_active_collision_types.read() {
+        let reset = |co: &mut rapier::geometry::Collider| {
+            co.set_active_collision_types(ActiveCollisionTypes::default().into())
+        };
+
+        if let Some((world, handle)) = find_item_and_world(&mut context, |world| {
+            world.entity2collider.get(&entity).copied()
+        }) {
+            if let Some(co) = world.colliders.get_mut(handle) {
+                reset(co);
+            }
+        }
+
+        set_collider_multiple(&mut context, entity, reset);
+    }
+
+    for entity in removed_soft_ccds.read() {
+        if let Some((world, handle)) = find_item_and_world(&mut context, |world| {
+            world.entity2body.get(&entity).copied()
+        }) {
+            if let Some(rb) = world.bodies.get_mut(handle) {
+                rb.set_soft_ccd_prediction(0.0);
+            }
+        }
+    }
+
+    for entity in removed_locked_axes.read() {
+        if let Some((world, handle)) = find_item_and_world(&mut context, |world| {
+            world.entity2body.get(&entity).copied()
+        }) {
+            if let Some(rb) = world.bodies.get_mut(handle) {
+                rb.set_locked_axes(LockedAxes::empty().into(), true);
+            }
+        }
+    }
+
+    for entity in removed_dominances.read() {
+        if let Some((world, handle)) = find_item_and_world(&mut context, |world| {
+            world.entity2body.get(&entity).copied()
+        }) {
+            if let Some(rb) = world.bodies.get_mut(handle) {
+                rb.set_dominance_group(Dominance::default().groups);
+            }
+        }
+    }
+
+    // Both the coefficients and the combine rules are reset to the ones of colliders created
+    // without these components.
+    let friction = Friction::default();
+    for entity in removed_frictions.read() {
+        let reset_friction = |co: &mut rapier::geometry::Collider| {
+            co.set_friction(friction.coefficient);
+            co.set_friction_combine_rule(friction.combine_rule.into());
+        };
+
         if let Some((world, handle)) = find_item_and_world(&mut context, |world| {
             world.entity2collider.get(&entity).copied()
         }) {
             if let Some(co) = world.colliders.get_mut(handle) {
-                co.set_sensor(false);
+                reset_friction(co);
             }
         }
+
+        set_collider_multiple(&mut context, entity, reset_friction);
     }
 
-    for entity in removed_colliders_disabled.read() {
+    let restitution = Restitution::default();
+    for entity in removed_restitutions.read() {
+        let reset_restitution = |co: &mut rapier::geometry::Collider| {
+            co.set_restitution(restitution.coefficient);
+            co.set_restitution_combine_rule(restitution.combine_rule.into());
+        };
+
         if let Some((world, handle)) = find_item_and_world(&mut context, |world| {
             world.entity2collider.get(&entity).copied()
         }) {
             if let Some(co) = world.colliders.get_mut(handle) {
-                co.set_enabled(true);
+                reset_restitution(co);
             }
         }
+
+        set_collider_multiple(&mut context, entity, reset_restitution);
+    }
+
+    for entity in removed_conveyor_belts.read() {
+        for world in context.worlds.values_mut() {
+            world.conveyor_belts.remove(&entity);
+        }
+
+        // Only remove the flag if it was added by the belt, not by the user.
+        if let Ok(mut active_hooks) = belt_active_hooks.get_mut(entity) {
+            active_hooks.remove(ActiveHooks::MODIFY_SOLVER_CONTACTS);
+            commands.entity(entity).remove::<ConveyorBeltActiveHooks>();
+        }
     }
 
-    for entity in removed_rigid_body_disabled.read() {
+    for entity in removed_gravity_overrides.read() {
         if let Some((world, handle)) = find_item_and_world(&mut context, |world| {
             world.entity2body.get(&entity).copied()
         }) {
             if let Some(rb) = world.bodies.get_mut(handle) {
-                rb.set_enabled(true);
+                let (gravity_scale, forces, forces_at_point) =
+                    remaining_forces.get(entity).unwrap_or_default();
+                rb.set_gravity_scale(gravity_scale.map(|scale| scale.0).unwrap_or(1.0), true);
+                set_rigid_body_forces(rb, &world.colliders, forces, forces_at_point, None);
             }
         }
     }
 
+    for entity in removed_joints_disabled.read() {
+        if let Some((world, handle)) = find_item_and_world(&mut context, |world| {
+            world.entity2impulse_joint.get(&entity).copied()
+        }) {
+            set_impulse_joint_enabled(world, handle, true);
+        }
+
+        if let Some((world, handle)) = find_item_and_world(&mut context, |world| {
+            world.entity2multibody_joint.get(&entity).copied()
+        }) {
+            set_multibody_joint_enabled(world, handle, true);
+        }
+    }
+
     // TODO: what about removing forces?
+
+    debug_assert!(
+        context
+            .worlds
+            .values()
+            .all(|world| world.last_body_transforms_are_live()),
+        "`last_body_transform_set` contains handles of removed rigid-bodies."
+    );
+}
+
+/// Applies `f` to all the colliders of the `ColliderMultiple` attached to `entity`.
+fn set_collider_multiple(
+    context: &mut RapierContext,
+    entity: Entity,
+    f: impl Fn(&mut rapier::geometry::Collider),
+) {
+    if let Some((world, handles)) = find_item_and_world(context, |world| {
+        world.entity2colliders.get(&entity).cloned()
+    }) {
+        for handle in handles {
+            if let Some(co) = world.colliders.get_mut(handle) {
+                f(co);
+            }
+        }
+    }
 }
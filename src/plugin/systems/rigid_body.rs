@@ -1,11 +1,18 @@
 use crate::dynamics::RapierRigidBodyHandle;
-use crate::plugin::get_world;
 use crate::plugin::{configuration::TimestepMode, RapierConfiguration, RapierContext};
+use crate::plugin::{find_item_and_world, get_world};
 use crate::{dynamics::RigidBody, plugin::configuration::SimulationToRenderTime};
 use crate::{prelude::*, utils};
 use bevy::prelude::*;
+use bevy::utils::{HashMap, HashSet};
 use rapier::dynamics::{RigidBodyBuilder, RigidBodyHandle, RigidBodyType};
-use std::collections::HashMap;
+use rapier::geometry::{ColliderSet, NarrowPhase};
+use rapier::math::Isometry;
+
+use super::AsyncPhysicsStep;
+
+/// The distance, or angle, below which a child rigid-body isn’t moved to match its `Transform`.
+const SET_POSITION_EPSILON: f32 = 1.0e-5;
 
 /// Components that will be updated after a physics step.
 pub type RigidBodyWritebackComponents<'a> = (
@@ -16,6 +23,9 @@ pub type RigidBodyWritebackComponents<'a> = (
     Option<&'a mut Sleeping>,
     Option<&'a PhysicsWorld>,
     Option<&'a RigidBody>,
+    Option<&'a PhysicsHierarchyMode>,
+    Option<&'a mut GlobalTransform>,
+    Option<&'a mut ReadMassProperties>,
 );
 
 /// Components related to rigid-bodies.
@@ -74,25 +84,41 @@ pub fn apply_rigid_body_user_changes(
         (&RapierRigidBodyHandle, &LockedAxes, Option<&PhysicsWorld>),
         Changed<LockedAxes>,
     >,
-    changed_forces: Query<
-        (
-            &RapierRigidBodyHandle,
-            &ExternalForce,
-            Option<&PhysicsWorld>,
-        ),
-        Changed<ExternalForce>,
-    >,
-    mut changed_impulses: Query<
-        (
-            &RapierRigidBodyHandle,
-            &mut ExternalImpulse,
-            Option<&PhysicsWorld>,
-        ),
-        Changed<ExternalImpulse>,
-    >,
+    (changed_forces, mut changed_impulses, mut changed_impulses_at_point): (
+        Query<
+            (
+                &RapierRigidBodyHandle,
+                Option<&ExternalForce>,
+                Option<&ExternalForceAtPoint>,
+                Option<&GravityOverride>,
+                Option<&PhysicsWorld>,
+            ),
+            Or<(
+                Changed<ExternalForce>,
+                Changed<ExternalForceAtPoint>,
+                Changed<GravityOverride>,
+            )>,
+        >,
+        Query<
+            (
+                &RapierRigidBodyHandle,
+                &mut ExternalImpulse,
+                Option<&PhysicsWorld>,
+            ),
+            Changed<ExternalImpulse>,
+        >,
+        Query<
+            (
+                &RapierRigidBodyHandle,
+                &mut ExternalImpulseAtPoint,
+                Option<&PhysicsWorld>,
+            ),
+            Changed<ExternalImpulseAtPoint>,
+        >,
+    ),
     changed_gravity_scale: Query<
         (&RapierRigidBodyHandle, &GravityScale, Option<&PhysicsWorld>),
-        Changed<GravityScale>,
+        (Changed<GravityScale>, Without<GravityOverride>),
     >,
     (changed_ccd, changed_soft_ccd): (
         Query<(&RapierRigidBodyHandle, &Ccd, Option<&PhysicsWorld>), Changed<Ccd>>,
@@ -110,24 +136,14 @@ pub fn apply_rigid_body_user_changes(
         (&RapierRigidBodyHandle, &Damping, Option<&PhysicsWorld>),
         Changed<Damping>,
     >,
-    (changed_disabled, changed_additional_solver_iterations): (
-        Query<
-            (
-                &RapierRigidBodyHandle,
-                &RigidBodyDisabled,
-                Option<&PhysicsWorld>,
-            ),
-            Changed<RigidBodyDisabled>,
-        >,
-        Query<
-            (
-                &RapierRigidBodyHandle,
-                &AdditionalSolverIterations,
-                Option<&PhysicsWorld>,
-            ),
-            Changed<AdditionalSolverIterations>,
-        >,
-    ),
+    changed_additional_solver_iterations: Query<
+        (
+            &RapierRigidBodyHandle,
+            &AdditionalSolverIterations,
+            Option<&PhysicsWorld>,
+        ),
+        Changed<AdditionalSolverIterations>,
+    >,
 
     mut mass_modified: EventWriter<MassModifiedEvent>,
 ) {
@@ -266,6 +282,7 @@ pub fn apply_rigid_body_user_changes(
     }
 
     for (entity, handle, mprops, world_within) in changed_additional_mass_props.iter() {
+        let world_id = world_within.map_or(DEFAULT_WORLD_ID, |w| w.world_id);
         let world = get_world(world_within, &mut context);
 
         if let Some(rb) = world.bodies.get_mut(handle.0) {
@@ -278,7 +295,13 @@ pub fn apply_rigid_body_user_changes(
                 }
             }
 
-            mass_modified.send(entity.into());
+            if let Some(event) = world.mass_modified_event(
+                world_id,
+                entity,
+                MassChangeCause::AdditionalMassPropertiesChanged,
+            ) {
+                mass_modified.send(event);
+            }
         }
     }
 
@@ -300,15 +323,17 @@ pub fn apply_rigid_body_user_changes(
         }
     }
 
-    for (handle, forces, world_within) in changed_forces.iter() {
+    for (handle, forces, forces_at_point, gravity_override, world_within) in changed_forces.iter() {
         let world = get_world(world_within, &mut context);
 
         if let Some(rb) = world.bodies.get_mut(handle.0) {
-            rb.reset_forces(true);
-            rb.reset_torques(true);
-            rb.add_force(forces.force.into(), true);
-            #[allow(clippy::useless_conversion)] // Need to convert if dim3 enabled
-            rb.add_torque(forces.torque.into(), true);
+            set_rigid_body_forces(
+                rb,
+                &world.colliders,
+                forces,
+                forces_at_point,
+                gravity_override,
+            );
         }
     }
 
@@ -323,6 +348,17 @@ pub fn apply_rigid_body_user_changes(
         }
     }
 
+    for (handle, mut impulse, world_within) in changed_impulses_at_point.iter_mut() {
+        let world = get_world(world_within, &mut context);
+
+        if let Some(rb) = world.bodies.get_mut(handle.0) {
+            // Make sure the center-of-mass is up-to-date.
+            rb.recompute_mass_properties_from_colliders(&world.colliders);
+            rb.apply_impulse_at_point(impulse.impulse.into(), impulse.point.into(), true);
+            impulse.reset();
+        }
+    }
+
     for (handle, gravity_scale, world_within) in changed_gravity_scale.iter() {
         let world = get_world(world_within, &mut context);
 
@@ -363,53 +399,324 @@ pub fn apply_rigid_body_user_changes(
             rb.set_angular_damping(damping.angular_damping);
         }
     }
+}
+
+/// System responsible for enabling or disabling the Rapier rigid-bodies depending on the
+/// presence of the [`RigidBodyDisabled`] component.
+///
+/// The state is derived from the components whenever the marker is inserted or removed, or the
+/// rigid-body is (re)created, so that toggling the marker several times during a frame is
+/// idempotent.
+pub fn sync_disabled_rigid_bodies(
+    mut context: ResMut<RapierContext>,
+    mut removed_disabled: RemovedComponents<RigidBodyDisabled>,
+    changed: Query<Entity, Or<(Changed<RapierRigidBodyHandle>, Changed<RigidBodyDisabled>)>>,
+    bodies: Query<(
+        &RapierRigidBodyHandle,
+        Has<RigidBodyDisabled>,
+        Option<&PhysicsWorld>,
+    )>,
+) {
+    for entity in changed.iter().chain(removed_disabled.read()) {
+        let Ok((handle, disabled, world_within)) = bodies.get(entity) else {
+            continue;
+        };
 
-    for (handle, _, world_within) in changed_disabled.iter() {
         let world = get_world(world_within, &mut context);
+        if let Some(rb) = world.bodies.get_mut(handle.0) {
+            if rb.is_enabled() == disabled {
+                rb.set_enabled(!disabled);
+            }
+        }
+    }
+}
+
+/// Replaces the forces applied to a rigid-body by the given external forces and gravity
+/// override.
+pub(crate) fn set_rigid_body_forces(
+    rb: &mut rapier::dynamics::RigidBody,
+    colliders: &ColliderSet,
+    forces: Option<&ExternalForce>,
+    forces_at_point: Option<&ExternalForceAtPoint>,
+    gravity_override: Option<&GravityOverride>,
+) {
+    rb.reset_forces(true);
+    rb.reset_torques(true);
+
+    if let Some(forces) = forces {
+        rb.add_force(forces.force.into(), true);
+        #[allow(clippy::useless_conversion)] // Need to convert if dim3 enabled
+        rb.add_torque(forces.torque.into(), true);
+    }
+
+    if forces_at_point.is_some() || gravity_override.is_some() {
+        // Make sure the mass and center-of-mass are up-to-date.
+        rb.recompute_mass_properties_from_colliders(colliders);
+    }
 
-        if let Some(co) = world.bodies.get_mut(handle.0) {
-            co.set_enabled(false);
+    if let Some(forces_at_point) = forces_at_point {
+        rb.add_force_at_point(
+            forces_at_point.force.into(),
+            forces_at_point.point.into(),
+            true,
+        );
+    }
+
+    if let Some(gravity_override) = gravity_override {
+        // The world gravity is entirely replaced by the override.
+        rb.set_gravity_scale(0.0, true);
+        rb.add_force((gravity_override.0 * rb.mass()).into(), true);
+    }
+}
+
+/// System responsible for keeping the force applied by a [`GravityOverride`] proportional to the
+/// mass of its rigid-body.
+pub fn apply_gravity_override_mass_changes(
+    mut context: ResMut<RapierContext>,
+    mut mass_modified: EventReader<MassModifiedEvent>,
+    gravity_overrides: Query<(
+        &RapierRigidBodyHandle,
+        Option<&ExternalForce>,
+        Option<&ExternalForceAtPoint>,
+        &GravityOverride,
+        Option<&PhysicsWorld>,
+    )>,
+) {
+    for event in mass_modified.read() {
+        let Ok((handle, forces, forces_at_point, gravity_override, world_within)) =
+            gravity_overrides.get(event.entity)
+        else {
+            continue;
+        };
+
+        let world = get_world(world_within, &mut context);
+
+        if let Some(rb) = world.bodies.get_mut(handle.0) {
+            set_rigid_body_forces(
+                rb,
+                &world.colliders,
+                forces,
+                forces_at_point,
+                Some(gravity_override),
+            );
+        }
+    }
+}
+
+/// System responsible for applying the [`WakeUpBody`] and [`PutBodyToSleep`] events to the
+/// physics rigid-bodies.
+///
+/// This runs after [`apply_rigid_body_user_changes`] so the events take precedence over any
+/// modification of the [`Sleeping`] component made during the same frame.
+pub fn apply_sleep_events(
+    mut context: ResMut<RapierContext>,
+    mut wake_up_events: EventReader<WakeUpBody>,
+    mut sleep_events: EventReader<PutBodyToSleep>,
+) {
+    for WakeUpBody(entity) in wake_up_events.read() {
+        if let Some((world, handle)) =
+            find_item_and_world(&mut context, |world| world.entity2body.get(entity).copied())
+        {
+            if let Some(rb) = world.bodies.get_mut(handle) {
+                rb.wake_up(true);
+            }
+        }
+    }
+
+    for PutBodyToSleep(entity) in sleep_events.read() {
+        if let Some((world, handle)) =
+            find_item_and_world(&mut context, |world| world.entity2body.get(entity).copied())
+        {
+            if let Some(rb) = world.bodies.get_mut(handle) {
+                rb.sleep();
+            }
+        }
+    }
+}
+
+/// System responsible for clamping the velocities of the rigid-bodies with a [`MaxVelocity`]
+/// component, right after the simulation step.
+pub fn clamp_velocities(
+    mut context: ResMut<RapierContext>,
+    max_velocities: Query<(&RapierRigidBodyHandle, &MaxVelocity, Option<&PhysicsWorld>)>,
+) {
+    for (handle, max_velocity, world_within) in max_velocities.iter() {
+        let world = get_world(world_within, &mut context);
+
+        if let Some(rb) = world.bodies.get_mut(handle.0) {
+            let linvel = *rb.linvel();
+            let speed = linvel.norm();
+            if speed > max_velocity.linear {
+                rb.set_linvel(linvel * (max_velocity.linear / speed), false);
+            }
+
+            #[cfg(feature = "dim2")]
+            {
+                let angvel = rb.angvel();
+                if angvel.abs() > max_velocity.angular {
+                    rb.set_angvel(
+                        angvel.clamp(-max_velocity.angular, max_velocity.angular),
+                        false,
+                    );
+                }
+            }
+            #[cfg(feature = "dim3")]
+            {
+                let angvel = *rb.angvel();
+                let angular_speed = angvel.norm();
+                if angular_speed > max_velocity.angular {
+                    rb.set_angvel(angvel * (max_velocity.angular / angular_speed), false);
+                }
+            }
+        }
+    }
+}
+
+/// System responsible for resetting the [`ExternalForce`] and [`ExternalForceAtPoint`] of the
+/// rigid-bodies with [`ForceMode::OneFrame`], once a simulation step applied them.
+///
+/// Both the components and the forces of the Rapier rigid-bodies are reset. The components are
+/// modified through change detection, so [`apply_rigid_body_user_changes`] sees any force set
+/// again afterward, even if it has the same value as before the reset.
+pub fn reset_one_frame_forces(
+    mut context: ResMut<RapierContext>,
+    async_step: Option<Res<AsyncPhysicsStep>>,
+    mut one_frame_forces: Query<(
+        &RapierRigidBodyHandle,
+        &ForceMode,
+        Option<&mut ExternalForce>,
+        Option<&mut ExternalForceAtPoint>,
+        Option<&GravityOverride>,
+        Option<&PhysicsWorld>,
+    )>,
+) {
+    for (handle, mode, forces, forces_at_point, gravity_override, world_within) in
+        one_frame_forces.iter_mut()
+    {
+        if *mode != ForceMode::OneFrame {
+            continue;
+        }
+
+        let world = get_world(world_within, &mut context);
+        if world.physics_time.last_frame_steps == 0 {
+            // The forces weren’t applied yet.
+            continue;
+        }
+
+        // With the asynchronous step, this runs before the forces modified during this frame
+        // are given to the simulation.
+        if async_step.is_some()
+            && (forces.as_ref().is_some_and(|f| f.is_changed())
+                || forces_at_point.as_ref().is_some_and(|f| f.is_changed()))
+        {
+            continue;
+        }
+
+        let mut reset = false;
+        let forces = forces.map(|mut forces| {
+            reset |= forces.set_if_neq(ExternalForce::default());
+            *forces
+        });
+        let forces_at_point = forces_at_point.map(|mut forces_at_point| {
+            reset |= forces_at_point.set_if_neq(ExternalForceAtPoint::default());
+            *forces_at_point
+        });
+
+        if !reset {
+            continue;
+        }
+
+        if let Some(rb) = world.bodies.get_mut(handle.0) {
+            // The gravity override is a persistent force, it must be kept.
+            set_rigid_body_forces(
+                rb,
+                &world.colliders,
+                forces.as_ref(),
+                forces_at_point.as_ref(),
+                gravity_override,
+            );
         }
     }
 }
 
 /// System responsible for writing the result of the last simulation step into our `bevy_rapier`
 /// components and the [`GlobalTransform`] component.
+///
+/// Only the hierarchies containing a rigid-body that was recently active are written back, so
+/// the cost of this system scales with the number of moving rigid-bodies rather than with the
+/// total number of rigid-bodies.
 pub fn writeback_rigid_bodies(
     mut context: ResMut<RapierContext>,
     config: Res<RapierConfiguration>,
     sim_to_render_time: Res<SimulationToRenderTime>,
-    top_entities: Query<Entity, Without<Parent>>,
+    parents: Query<&Parent>,
     mut writeback: Query<RigidBodyWritebackComponents, Without<RigidBodyDisabled>>,
     children_query: Query<&Children>,
+    mut warned_missing_interpolation: Local<bool>,
+    mut warned_missing_transform: Local<bool>,
 ) {
     if !config.physics_pipeline_active {
         return;
     }
 
-    for entity in top_entities.iter() {
-        let (transform, delta_transform, velocity, world_offset) = if let Ok((
+    // The roots of the hierarchies containing a rigid-body moved by the simulation. The
+    // rigid-bodies that were active before the last steps are included so that the final
+    // state of the bodies that just fell asleep is written back too.
+    let mut top_entities = HashSet::default();
+    for world in context.worlds.values() {
+        let active_bodies = world
+            .islands
+            .active_dynamic_bodies()
+            .iter()
+            .chain(world.islands.active_kinematic_bodies())
+            .copied()
+            .chain(world.recently_active_bodies.iter());
+
+        for handle in active_bodies {
+            if let Some(mut entity) = world.rigid_body_entity(handle) {
+                while let Ok(parent) = parents.get(entity) {
+                    entity = parent.get();
+                }
+                top_entities.insert(entity);
+            }
+        }
+    }
+
+    for entity in top_entities {
+        let (transform, delta_transform, velocity, center_of_mass, world_offset) = if let Ok((
             entity,
             transform,
             mut interpolation,
             mut velocity,
             mut sleeping,
             world_within,
+            rigid_body,
             _,
-        )) = writeback.get_mut(entity)
+            global_transform,
+            mut read_mprops,
+        )) =
+            writeback.get_mut(entity)
         {
+            if interpolation.is_none()
+                && rigid_body == Some(&RigidBody::Dynamic)
+                && matches!(config.timestep_mode, TimestepMode::Interpolated { .. })
+                && !*warned_missing_interpolation
+            {
+                *warned_missing_interpolation = true;
+                warn!("The dynamic rigid-body {entity:?} has no `TransformInterpolation`, so it is rendered at the rate of the physics steps. Add the component, or enable `RapierConfiguration::auto_insert_interpolation`.");
+            }
+
             let mut my_new_global_transform = Transform::IDENTITY;
             let mut parent_delta = Transform::IDENTITY;
             let mut my_velocity = Velocity::default();
+            let mut my_center_of_mass = Vect::ZERO;
             let mut world_offset = Vec3::ZERO;
 
             let world = get_world(world_within, &mut context);
 
-            // TODO: do this the other way round: iterate through Rapier’s RigidBodySet on the active bodies,
-            // and update the components accordingly. That way, we don’t have to iterate through the entities that weren’t changed
-            // by physics (for example because they are sleeping).
             if let Some(handle) = world.entity2body.get(&entity).copied() {
                 if let Some(rb) = world.bodies.get(handle) {
+                    my_center_of_mass = rb.center_of_mass().coords.into();
                     let mut interpolated_pos = utils::iso_to_transform(rb.position());
 
                     if let TimestepMode::Interpolated { dt, .. } = config.timestep_mode {
@@ -418,14 +725,26 @@ pub fn writeback_rigid_bodies(
                                 interpolation.end = Some(*rb.position());
                             }
 
-                            if let Some(interpolated) =
-                                interpolation.lerp_slerp((dt + sim_to_render_time.diff) / dt)
-                            {
+                            if let Some(interpolated) = interpolated_position(
+                                &world.narrow_phase,
+                                rb,
+                                interpolation,
+                                (dt + sim_to_render_time.diff) / dt,
+                                dt,
+                            ) {
                                 interpolated_pos = utils::iso_to_transform(&interpolated);
                             }
                         }
                     }
 
+                    if let TimestepMode::FixedSchedule { .. } = config.timestep_mode {
+                        // The transform is interpolated right before rendering, by
+                        // `interpolate_fixed_schedule_transforms`.
+                        if let Some(interpolation) = interpolation.as_deref_mut() {
+                            interpolation.end.get_or_insert(*rb.position());
+                        }
+                    }
+
                     if let Some(mut transform) = transform {
                         // NOTE: Rapier's `RigidBody` doesn't know its own scale as it is encoded
                         //       directly within its collider, so we have to retrieve it from
@@ -488,6 +807,14 @@ pub fn writeback_rigid_bodies(
                                     .with_rotation(interpolated_pos.rotation),
                             ),
                         );
+                    } else if let Some(mut global_transform) = global_transform {
+                        warn_missing_transform(entity, &mut warned_missing_transform);
+                        my_new_global_transform =
+                            writeback_global_transform(&mut global_transform, interpolated_pos);
+                        world_offset = my_new_global_transform.translation;
+                        world
+                            .last_body_transform_set
+                            .insert(handle, *global_transform);
                     }
 
                     if let Some(velocity) = &mut velocity {
@@ -517,6 +844,14 @@ pub fn writeback_rigid_bodies(
                             sleeping.sleeping = rb.is_sleeping();
                         }
                     }
+
+                    if let Some(read_mprops) = &mut read_mprops {
+                        // The world-space center-of-mass and inertia follow the body.
+                        let new_mprops = ReadMassProperties::from_rapier(rb.mass_properties());
+                        if **read_mprops != new_mprops {
+                            **read_mprops = new_mprops;
+                        }
+                    }
                 }
             }
 
@@ -524,6 +859,7 @@ pub fn writeback_rigid_bodies(
                 my_new_global_transform,
                 parent_delta,
                 my_velocity,
+                my_center_of_mass,
                 world_offset,
             )
         } else {
@@ -531,6 +867,7 @@ pub fn writeback_rigid_bodies(
                 Transform::IDENTITY,
                 Transform::IDENTITY,
                 Velocity::default(),
+                Vect::ZERO,
                 Vec3::ZERO,
             )
         };
@@ -543,13 +880,16 @@ pub fn writeback_rigid_bodies(
             transform,
             delta_transform,
             velocity,
+            center_of_mass,
             &children_query,
             entity,
             world_offset,
+            &mut warned_missing_transform,
         );
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn recurse_child_transforms(
     context: &mut RapierContext,
     config: &RapierConfiguration,
@@ -558,9 +898,11 @@ fn recurse_child_transforms(
     parent_global_transform: Transform,
     parent_delta: Transform,
     parent_velocity: Velocity,
+    parent_center_of_mass: Vect,
     children_query: &Query<&Children>,
     parent_entity: Entity,
     world_offset: Vec3,
+    warned_missing_transform: &mut bool,
 ) {
     let Ok(children) = children_query.get(parent_entity) else {
         return;
@@ -569,7 +911,7 @@ fn recurse_child_transforms(
     for child in children.iter().copied() {
         let mut world_offset = world_offset;
 
-        let (transform, delta_transform, velocity) = if let Ok((
+        let (transform, delta_transform, velocity, center_of_mass) = if let Ok((
             entity,
             transform,
             mut interpolation,
@@ -577,17 +919,20 @@ fn recurse_child_transforms(
             mut sleeping,
             world_within,
             rb_type,
-        )) = writeback.get_mut(child)
+            hierarchy_mode,
+            global_transform,
+            mut read_mprops,
+        )) =
+            writeback.get_mut(child)
         {
+            let independent = hierarchy_mode == Some(&PhysicsHierarchyMode::Independent);
             let mut my_new_global_transform = parent_global_transform;
             let mut delta_transform = parent_delta;
             let mut my_velocity = parent_velocity;
+            let mut my_center_of_mass = parent_center_of_mass;
 
             let world = get_world(world_within, context);
 
-            // TODO: do this the other way round: iterate through Rapier’s RigidBodySet on the active bodies,
-            // and update the components accordingly. That way, we don’t have to iterate through the entities that weren’t changed
-            // by physics (for example because they are sleeping).
             if let Some(handle) = world.entity2body.get(&entity).copied() {
                 if let Some(rb) = world.bodies.get_mut(handle) {
                     let mut interpolated_pos = utils::iso_to_transform(rb.position());
@@ -598,14 +943,26 @@ fn recurse_child_transforms(
                                 interpolation.end = Some(*rb.position());
                             }
 
-                            if let Some(interpolated) =
-                                interpolation.lerp_slerp((dt + sim_to_render_time.diff) / dt)
-                            {
+                            if let Some(interpolated) = interpolated_position(
+                                &world.narrow_phase,
+                                rb,
+                                interpolation,
+                                (dt + sim_to_render_time.diff) / dt,
+                                dt,
+                            ) {
                                 interpolated_pos = utils::iso_to_transform(&interpolated);
                             }
                         }
                     }
 
+                    if let TimestepMode::FixedSchedule { .. } = config.timestep_mode {
+                        // The transform is interpolated right before rendering, by
+                        // `interpolate_fixed_schedule_transforms`.
+                        if let Some(interpolation) = interpolation.as_deref_mut() {
+                            interpolation.end.get_or_insert(*rb.position());
+                        }
+                    }
+
                     if let Some(mut transform) = transform {
                         // We need to compute the new local transform such that:
                         // curr_parent_global_transform * new_transform * parent_delta_pos = interpolated_pos
@@ -614,27 +971,47 @@ fn recurse_child_transforms(
 
                         let inverse_parent_rotation = parent_global_transform.rotation.inverse();
 
-                        interpolated_pos.translation -= world_offset;
-
-                        let new_rotation = Quat::IDENTITY; //inverse_parent_rotation * interpolated_pos.rotation;
-
                         // has to be mut in 2d mode
                         #[allow(unused_mut)]
-                        let mut new_translation;
-
-                        let translation_offset =
-                            if rb_type.copied().unwrap_or(RigidBody::Fixed) == RigidBody::Dynamic {
-                                // The parent's velocity will have already moved them
-                                parent_delta.translation
-                            } else {
-                                Vec3::ZERO
-                            };
-
-                        let rotated_interpolation = inverse_parent_rotation
-                            * (parent_delta.rotation
-                                * (interpolated_pos.translation - translation_offset));
-
-                        new_translation = rotated_interpolation;
+                        let (new_rotation, mut new_translation) = if independent {
+                            // The body is simulated as if it had no parent, so its local transform
+                            // is just its global transform relative to the parent’s.
+                            (
+                                inverse_parent_rotation * interpolated_pos.rotation,
+                                inverse_parent_rotation
+                                    * (interpolated_pos.translation
+                                        - parent_global_transform.translation)
+                                    / parent_global_transform.scale,
+                            )
+                        } else if matches!(
+                            rb_type,
+                            Some(RigidBody::Dynamic | RigidBody::KinematicVelocityBased)
+                        ) {
+                            // The velocity of the parent at this body, including the contribution
+                            // of its angular velocity, was added by `sync_vel`: the simulation
+                            // already moved the body along with its parent, so its translation is
+                            // relative to the parent’s new pose. Only the rotation of the parent
+                            // isn’t part of its simulated motion. In 2D, all these rotations are
+                            // about `Z` so the result is a rotation about `Z` too.
+                            (
+                                inverse_parent_rotation
+                                    * (parent_delta.rotation * interpolated_pos.rotation),
+                                inverse_parent_rotation
+                                    * (interpolated_pos.translation
+                                        - parent_global_transform.translation)
+                                    / parent_global_transform.scale,
+                            )
+                        } else {
+                            // The body didn’t move along with its parent during the step: the
+                            // motion of the parent since the last step is applied to it.
+                            interpolated_pos.translation -= world_offset;
+                            (
+                                inverse_parent_rotation
+                                    * (parent_delta.rotation * interpolated_pos.rotation),
+                                inverse_parent_rotation
+                                    * (parent_delta.rotation * interpolated_pos.translation),
+                            )
+                        };
 
                         // In 2D, preserve the transform `z` component that may have been set by the user
                         #[cfg(feature = "dim2")]
@@ -654,36 +1031,79 @@ fn recurse_child_transforms(
                             transform.translation = new_translation;
                         }
 
-                        let inv_old_transform = Transform {
-                            scale: old_transform.scale,
-                            rotation: old_transform.rotation.inverse(),
-                            translation: -old_transform.translation,
-                        };
-
-                        delta_transform = transform.mul_transform(inv_old_transform);
-
                         // NOTE: we need to compute the result of the next transform propagation
                         //       to make sure that our change detection for transforms is exact
                         //       despite rounding errors.
 
                         my_new_global_transform = parent_global_transform.mul_transform(*transform);
+
+                        // The motion of this body since the last step, in global space, like the
+                        // `parent_delta` computed for top-level bodies.
+                        let old_global_transform =
+                            parent_global_transform.mul_transform(old_transform);
+                        delta_transform = Transform {
+                            translation: my_new_global_transform.translation
+                                - old_global_transform.translation,
+                            rotation: my_new_global_transform.rotation
+                                * old_global_transform.rotation.inverse(),
+                            scale: transform.scale,
+                        };
                         world_offset = my_new_global_transform.translation;
 
                         world
                             .last_body_transform_set
                             .insert(handle, GlobalTransform::from(my_new_global_transform));
 
-                        rb.set_position(utils::transform_to_iso(&my_new_global_transform), false);
-                    }
+                        // NOTE: only move the rigid-body if it actually moved, otherwise modifying
+                        //       it every frame would prevent whole hierarchies from sleeping.
+                        let parent_moved = parent_delta.translation.length_squared()
+                            > SET_POSITION_EPSILON * SET_POSITION_EPSILON
+                            || parent_delta.rotation.angle_between(Quat::IDENTITY)
+                                > SET_POSITION_EPSILON;
 
-                    if let Some(velocity) = &mut velocity {
-                        let old_linvel = *rb.linvel();
+                        if !independent && (parent_moved || !rb.is_sleeping()) {
+                            let new_position = utils::transform_to_iso(&my_new_global_transform);
 
-                        my_velocity.linvel = old_linvel.into();
+                            if (new_position.translation.vector - rb.translation()).norm()
+                                > SET_POSITION_EPSILON
+                                || new_position.rotation.angle_to(rb.rotation())
+                                    > SET_POSITION_EPSILON
+                            {
+                                rb.set_position(new_position, false);
+                            }
+                        }
+                    } else if let Some(mut global_transform) = global_transform {
+                        // Without a local transform, the body can’t follow its parent: its
+                        // global pose is simply the one computed by the simulation.
+                        warn_missing_transform(entity, warned_missing_transform);
+                        my_new_global_transform =
+                            writeback_global_transform(&mut global_transform, interpolated_pos);
+                        delta_transform = Transform::IDENTITY;
+                        world_offset = my_new_global_transform.translation;
+                        world
+                            .last_body_transform_set
+                            .insert(handle, *global_transform);
+                    }
 
-                        rb.set_linvel(parent_velocity.linvel.into(), false);
-                        rb.set_linvel(old_linvel - rb.linvel(), false);
+                    my_center_of_mass = rb.center_of_mass().coords.into();
+                    let old_linvel: Vect = (*rb.linvel()).into();
+                    my_velocity = Velocity {
+                        linvel: old_linvel,
+                        #[cfg(feature = "dim3")]
+                        angvel: (*rb.angvel()).into(),
+                        #[cfg(feature = "dim2")]
+                        angvel: rb.angvel(),
+                    };
+
+                    if !independent {
+                        // Remove the velocity added by `sync_vel`, so the velocity of the
+                        // rigid-body is relative to its parent again.
+                        let parent_point_velocity = parent_velocity
+                            .linear_velocity_at_point(my_center_of_mass, parent_center_of_mass);
+                        rb.set_linvel((old_linvel - parent_point_velocity).into(), false);
+                    }
 
+                    if let Some(velocity) = &mut velocity {
                         let new_vel = Velocity {
                             linvel: (*rb.linvel()).into(),
                             #[cfg(feature = "dim3")]
@@ -708,12 +1128,30 @@ fn recurse_child_transforms(
                             sleeping.sleeping = rb.is_sleeping();
                         }
                     }
+
+                    if let Some(read_mprops) = &mut read_mprops {
+                        // The world-space center-of-mass and inertia follow the body.
+                        let new_mprops = ReadMassProperties::from_rapier(rb.mass_properties());
+                        if **read_mprops != new_mprops {
+                            **read_mprops = new_mprops;
+                        }
+                    }
                 }
             }
 
-            (my_new_global_transform, delta_transform, my_velocity)
+            (
+                my_new_global_transform,
+                delta_transform,
+                my_velocity,
+                my_center_of_mass,
+            )
         } else {
-            (parent_global_transform, parent_delta, parent_velocity)
+            (
+                parent_global_transform,
+                parent_delta,
+                parent_velocity,
+                parent_center_of_mass,
+            )
         };
 
         recurse_child_transforms(
@@ -724,22 +1162,316 @@ fn recurse_child_transforms(
             transform,
             delta_transform,
             velocity,
+            center_of_mass,
             children_query,
             child,
             world_offset,
+            warned_missing_transform,
+        );
+    }
+}
+
+/// System responsible for interpolating the transforms of the rigid-bodies with a
+/// [`TransformInterpolation`] between the last two steps of the fixed schedule, using
+/// `Time<Fixed>::overstep_fraction()`.
+///
+/// This only does something with [`TimestepMode::FixedSchedule`], and runs in `PostUpdate`
+/// right before the transform propagation.
+pub fn interpolate_fixed_schedule_transforms(
+    mut context: ResMut<RapierContext>,
+    config: Res<RapierConfiguration>,
+    fixed_time: Res<Time<Fixed>>,
+    global_transforms: Query<&GlobalTransform>,
+    mut interpolated: Query<
+        (
+            &RapierRigidBodyHandle,
+            &TransformInterpolation,
+            &mut Transform,
+            Option<&Parent>,
+            Option<&PhysicsWorld>,
+        ),
+        Without<RigidBodyDisabled>,
+    >,
+) {
+    if !config.physics_pipeline_active
+        || !matches!(config.timestep_mode, TimestepMode::FixedSchedule { .. })
+    {
+        return;
+    }
+
+    let t = fixed_time.overstep_fraction();
+    let dt = fixed_time.timestep().as_secs_f32();
+
+    for (handle, interpolation, mut transform, parent, world_within) in interpolated.iter_mut() {
+        let world = get_world(world_within, &mut context);
+        let Some(interpolated) = world
+            .bodies
+            .get(handle.0)
+            .and_then(|rb| interpolated_position(&world.narrow_phase, rb, interpolation, t, dt))
+        else {
+            continue;
+        };
+        let interpolated_pos = utils::iso_to_transform(&interpolated);
+
+        #[allow(unused_mut)] // mut is needed in 2D but not in 3D.
+        let mut new_translation = interpolated_pos.translation;
+
+        // In 2D, preserve the transform `z` component that may have been set by the user
+        #[cfg(feature = "dim2")]
+        {
+            new_translation.z = transform.translation.z;
+        }
+
+        let new_global_transform = Transform::from_translation(new_translation)
+            .with_rotation(interpolated_pos.rotation)
+            .with_scale(transform.scale);
+        let new_transform = match parent.and_then(|p| global_transforms.get(p.get()).ok()) {
+            Some(parent_global_transform) => {
+                GlobalTransform::from(new_global_transform).reparented_to(parent_global_transform)
+            }
+            None => new_global_transform,
+        };
+
+        // NOTE: we write the new value only if there was an
+        //       actual change, in order to not trigger bevy’s
+        //       change tracking when the values didn’t change.
+        if *transform != new_transform {
+            *transform = new_transform;
+        }
+
+        // Make sure the next fixed step doesn’t see the interpolated transform as a user change.
+        world.last_body_transform_set.insert(
+            handle.0,
+            GlobalTransform::from(
+                Transform::from_translation(new_translation)
+                    .with_rotation(interpolated_pos.rotation),
+            ),
         );
     }
 }
 
+/// Writes the pose computed by the simulation into the [`GlobalTransform`] of a rigid-body
+/// without [`Transform`], keeping its scale (and its `z` coordinate in 2D).
+///
+/// Bevy’s transform propagation ignores the entities without [`Transform`], so this
+/// [`GlobalTransform`] isn’t overwritten by [`crate::plugin::RapierTransformPropagateSet`].
+fn writeback_global_transform(
+    global_transform: &mut GlobalTransform,
+    pose: Transform,
+) -> Transform {
+    let current = global_transform.compute_transform();
+    #[allow(unused_mut)] // mut is needed in 2D but not in 3D.
+    let mut new_transform = pose.with_scale(current.scale);
+
+    // In 2D, preserve the transform `z` component that may have been set by the user
+    #[cfg(feature = "dim2")]
+    {
+        new_transform.translation.z = current.translation.z;
+    }
+
+    // NOTE: we write the new value only if there was an
+    //       actual change, in order to not trigger bevy’s
+    //       change tracking when the values didn’t change.
+    let new_global_transform = GlobalTransform::from(new_transform);
+    if *global_transform != new_global_transform {
+        *global_transform = new_global_transform;
+    }
+
+    new_transform
+}
+
+fn warn_missing_transform(entity: Entity, warned: &mut bool) {
+    if !*warned {
+        *warned = true;
+        warn!("The rigid-body {entity:?} has a `GlobalTransform` but no `Transform`, so the simulation writes its `GlobalTransform` directly and it doesn’t follow its parent. Add a `Transform` to the entity to position it relative to its parent.");
+    }
+}
+
+/// The position at which a rigid-body should be rendered, `t * dt` seconds after the second to
+/// last physics step, `dt` being the length of the physics steps.
+fn interpolated_position(
+    narrow_phase: &NarrowPhase,
+    rb: &rapier::dynamics::RigidBody,
+    interpolation: &TransformInterpolation,
+    t: f32,
+    dt: f32,
+) -> Option<Isometry<f32>> {
+    let ahead = match interpolation.mode {
+        InterpolationMode::Interpolate => return interpolation.lerp_slerp(t),
+        InterpolationMode::Extrapolate => t * dt,
+        InterpolationMode::ExtrapolateClamped(max_ahead) => (t * dt).min(max_ahead),
+    };
+
+    // Extrapolating through an obstacle looks worse than lagging behind.
+    let in_contact = rb.colliders().iter().any(|handle| {
+        narrow_phase
+            .contact_pairs_with(*handle)
+            .any(|pair| pair.has_any_active_contact)
+    });
+
+    if in_contact {
+        interpolation.lerp_slerp(t)
+    } else {
+        Some(rb.predict_position_using_velocity_and_forces(ahead))
+    }
+}
+
+/// System responsible for inserting a [`TransformInterpolation`] on the rigid-bodies, and for
+/// removing it from the removed rigid-bodies, if
+/// [`RapierConfiguration::auto_insert_interpolation`] is enabled.
+pub fn insert_transform_interpolations(
+    mut commands: Commands,
+    config: Res<RapierConfiguration>,
+    new_bodies: Query<
+        Entity,
+        (
+            Added<RapierRigidBodyHandle>,
+            With<Transform>,
+            Without<TransformInterpolation>,
+        ),
+    >,
+    mut removed_bodies: RemovedComponents<RapierRigidBodyHandle>,
+    interpolated_non_bodies: Query<
+        (),
+        (With<TransformInterpolation>, Without<RapierRigidBodyHandle>),
+    >,
+) {
+    if !config.auto_insert_interpolation {
+        removed_bodies.clear();
+        return;
+    }
+
+    for entity in new_bodies.iter() {
+        commands
+            .entity(entity)
+            .insert(TransformInterpolation::default());
+    }
+
+    for entity in removed_bodies.read() {
+        if interpolated_non_bodies.contains(entity) {
+            commands.entity(entity).remove::<TransformInterpolation>();
+        }
+    }
+}
+
+/// System responsible for setting the velocities of the [`RigidBody::KinematicVelocityBased`]
+/// rigid-bodies with a [`KinematicTarget`], so they reach it during the next simulation steps.
+pub fn apply_kinematic_targets(
+    mut context: ResMut<RapierContext>,
+    config: Res<RapierConfiguration>,
+    time: Res<Time>,
+    sim_to_render_time: Res<SimulationToRenderTime>,
+    targets: Query<(
+        &RapierRigidBodyHandle,
+        &KinematicTarget,
+        Option<&PhysicsWorld>,
+    )>,
+) {
+    let duration = simulated_duration(&config, &context, &time, &sim_to_render_time);
+    if duration <= 0.0 {
+        return;
+    }
+
+    for (handle, target, world_within) in targets.iter() {
+        let world = get_world(world_within, &mut context);
+        let Some(rb) = world.bodies.get_mut(handle.0) else {
+            continue;
+        };
+        if rb.body_type() != RigidBodyType::KinematicVelocityBased {
+            continue;
+        }
+
+        let current = utils::iso_to_transform(rb.position());
+
+        #[cfg(feature = "dim2")]
+        let (target_rotation, angvel) = {
+            use std::f32::consts::{PI, TAU};
+            let angle = rb.rotation().angle();
+            let target_angle = target.rotation.unwrap_or(angle);
+            let delta = (target_angle - angle + PI).rem_euclid(TAU) - PI;
+            let angvel =
+                (delta / duration).clamp(-target.max_angular_speed, target.max_angular_speed);
+            (Quat::from_rotation_z(target_angle), angvel)
+        };
+        #[cfg(feature = "dim3")]
+        let (target_rotation, angvel) = {
+            let target_rotation = target.rotation.unwrap_or(current.rotation);
+            // Take the shortest path to the target rotation.
+            let mut delta = target_rotation * current.rotation.inverse();
+            if delta.w < 0.0 {
+                delta = -delta;
+            }
+            let (axis, angle) = delta.to_axis_angle();
+            let angvel = (axis * angle / duration).clamp_length_max(target.max_angular_speed);
+            (target_rotation, angvel)
+        };
+
+        // The rigid-body rotates around its center-of-mass, so its center-of-mass is the point
+        // that must reach its target position.
+        let target_pose = utils::transform_to_iso(&Transform {
+            translation: target.translation.unwrap_or(current.translation),
+            rotation: target_rotation,
+            ..default()
+        });
+        let target_com = target_pose * rb.mass_properties().local_mprops.local_com;
+        let linvel = Vect::from((target_com - rb.center_of_mass()) / duration)
+            .clamp_length_max(target.max_linear_speed);
+
+        rb.set_linvel(linvel.into(), true);
+        #[cfg(feature = "dim2")]
+        rb.set_angvel(angvel, true);
+        #[cfg(feature = "dim3")]
+        rb.set_angvel(angvel.into(), true);
+    }
+}
+
+/// The total duration the simulation will be advanced by during the current run of the physics
+/// systems, planned like the steps of [`super::step_simulation_in_substeps`].
+pub(crate) fn simulated_duration(
+    config: &RapierConfiguration,
+    context: &RapierContext,
+    time: &Time,
+    sim_to_render_time: &SimulationToRenderTime,
+) -> f32 {
+    let num_steps = if !config.physics_pipeline_active {
+        0
+    } else if let TimestepMode::Manual { .. } = config.timestep_mode {
+        context.requested_steps()
+    } else {
+        1
+    };
+    if num_steps == 0 {
+        return 0.0;
+    }
+
+    // Plan the steps on a copy of the time difference, which is only consumed by the step.
+    let (num_steps, substep_dt, substeps) = super::substep::plan_steps(
+        num_steps,
+        config.timestep_mode,
+        time,
+        &mut SimulationToRenderTime {
+            diff: sim_to_render_time.diff,
+        },
+        &mut 0.0,
+    );
+    num_steps as f32 * substep_dt * substeps as f32
+}
+
 /// Syncs up child velocities with their parents in the physics simulation.
 /// This is done to avoid child components getting hit by their parent and rapier
 /// assuming the child is hit by the full velocity of the parent instead of `parent vel - child vel`.
 ///
-/// This will not change the bevy component's velocity.
+/// This will not change the bevy component's velocity: the [`Velocity`] of a child rigid-body,
+/// including a [`RigidBody::KinematicVelocityBased`] one, is relative to its parent. Children
+/// with [`PhysicsHierarchyMode::Independent`] are left untouched.
 pub fn sync_vel(
     top_ents: Query<Entity, Without<Parent>>,
     vel_query: Query<&Velocity>,
-    query: Query<(&RapierRigidBodyHandle, Option<&PhysicsWorld>)>,
+    query: Query<(
+        &RapierRigidBodyHandle,
+        Option<&PhysicsWorld>,
+        Option<&PhysicsHierarchyMode>,
+    )>,
     children_query: Query<&Children>,
     mut context: ResMut<RapierContext>,
 ) {
@@ -750,9 +1482,24 @@ pub fn sync_vel(
             Velocity::default()
         };
 
+        let mut center_of_mass = Vect::ZERO;
+        if let Ok((handle, world_within, _)) = query.get(ent) {
+            let world = get_world(world_within, &mut context);
+            if let Some(rb) = world.bodies.get(handle.0) {
+                center_of_mass = rb.center_of_mass().coords.into();
+            }
+        }
+
         if let Ok(children) = children_query.get(ent) {
             for child in children.iter().copied() {
-                sync_velocity_recursively(child, &query, &children_query, vel, &mut context);
+                sync_velocity_recursively(
+                    child,
+                    &query,
+                    &children_query,
+                    vel,
+                    center_of_mass,
+                    &mut context,
+                );
             }
         }
     }
@@ -760,38 +1507,50 @@ pub fn sync_vel(
 
 fn sync_velocity_recursively(
     ent: Entity,
-    query: &Query<(&RapierRigidBodyHandle, Option<&PhysicsWorld>)>,
+    query: &Query<(
+        &RapierRigidBodyHandle,
+        Option<&PhysicsWorld>,
+        Option<&PhysicsHierarchyMode>,
+    )>,
     children_query: &Query<&Children>,
     parent_vel: Velocity,
+    parent_center_of_mass: Vect,
     context: &mut RapierContext,
 ) {
-    let vel = if let Ok((handle, world_within)) = query.get(ent) {
+    let (vel, center_of_mass) = if let Ok((handle, world_within, hierarchy_mode)) = query.get(ent) {
         let world = get_world(world_within, context);
         if let Some(rb) = world.bodies.get_mut(handle.0) {
-            #[cfg(feature = "dim3")]
-            let old_linvel = Vec3::from(*rb.linvel());
-            #[cfg(feature = "dim2")]
-            let old_linvel = Vec2::from(*rb.linvel());
-
-            rb.set_linvel((old_linvel + (parent_vel.linvel)).into(), false);
-
-            Velocity {
-                linvel: (*rb.linvel()).into(),
-                #[cfg(feature = "dim3")]
-                angvel: (*rb.angvel()).into(),
-                #[cfg(feature = "dim2")]
-                angvel: rb.angvel(),
+            let old_linvel = Vect::from(*rb.linvel());
+            let center_of_mass: Vect = rb.center_of_mass().coords.into();
+
+            if hierarchy_mode != Some(&PhysicsHierarchyMode::Independent) {
+                // The child moves along with the point of the parent it is attached to, which
+                // includes the contribution of the parent’s angular velocity.
+                let parent_point_vel =
+                    parent_vel.linear_velocity_at_point(center_of_mass, parent_center_of_mass);
+                rb.set_linvel((old_linvel + parent_point_vel).into(), false);
             }
+
+            (
+                Velocity {
+                    linvel: (*rb.linvel()).into(),
+                    #[cfg(feature = "dim3")]
+                    angvel: (*rb.angvel()).into(),
+                    #[cfg(feature = "dim2")]
+                    angvel: rb.angvel(),
+                },
+                center_of_mass,
+            )
         } else {
-            parent_vel
+            (parent_vel, parent_center_of_mass)
         }
     } else {
-        parent_vel
+        (parent_vel, parent_center_of_mass)
     };
 
     if let Ok(children) = children_query.get(ent) {
         for child in children.iter().copied() {
-            sync_velocity_recursively(child, query, children_query, vel, context);
+            sync_velocity_recursively(child, query, children_query, vel, center_of_mass, context);
         }
     }
 }
@@ -900,7 +1659,7 @@ pub fn init_rigid_bodies(
             .entity(entity)
             .insert(RapierRigidBodyHandle(handle));
 
-        world.entity2body.insert(entity, handle);
+        world.insert_rigid_body_entity(entity, handle);
 
         if let Some(transform) = transform {
             world.last_body_transform_set.insert(handle, *transform);
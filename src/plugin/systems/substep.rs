@@ -0,0 +1,244 @@
+//! Systems responsible for stepping the simulation one substep at a time, running the
+//! [`PhysicsSubstepSchedule`] between the substeps.
+
+use crate::dynamics::{RapierRigidBodyHandle, TransformInterpolation};
+use crate::math::Real;
+use crate::pipeline::{
+    CollisionEvent, ContactForceEvent, DetailedCollisionEvent, PhysicsStepEvent,
+    PhysicsTimeDroppedEvent,
+};
+use crate::plugin::configuration::{SimulationToRenderTime, SubstepTime, TimestepMode};
+use crate::plugin::{PhysicsSubstepSchedule, RapierConfiguration, RapierContext};
+use crate::prelude::{BevyPhysicsHooks, BevyPhysicsHooksAdapter};
+use bevy::ecs::system::{StaticSystemParam, SystemParamItem, SystemState};
+use bevy::prelude::*;
+use bevy::utils::Instant;
+use std::time::Duration;
+
+use super::AsyncPhysicsStep;
+
+type PhysicsEventWriters = (
+    EventWriter<'static, CollisionEvent>,
+    EventWriter<'static, ContactForceEvent>,
+    EventWriter<'static, DetailedCollisionEvent>,
+    EventWriter<'static, PhysicsTimeDroppedEvent>,
+    EventWriter<'static, PhysicsStepEvent>,
+);
+
+/// Run condition checking if systems were added to the [`PhysicsSubstepSchedule`].
+pub fn has_substep_schedule(schedules: Res<Schedules>) -> bool {
+    schedules.contains(PhysicsSubstepSchedule)
+}
+
+/// System responsible for advancing the physics simulation like [`super::step_simulation`],
+/// but one substep at a time, running the [`PhysicsSubstepSchedule`] after each substep.
+///
+/// The substeps of all the worlds are interleaved: the schedule runs once per substep, after
+/// every world executed it. It is used instead of [`super::step_simulation`] if systems were
+/// added to the [`PhysicsSubstepSchedule`].
+///
+/// The [`RapierContext`] is removed from the app while the worlds are stepped, so the custom
+/// physics hooks can’t access it.
+pub fn step_simulation_in_substeps<Hooks>(
+    world: &mut World,
+    hooks: &mut SystemState<StaticSystemParam<'static, 'static, Hooks>>,
+    event_writers: &mut SystemState<PhysicsEventWriters>,
+    interpolation_query: &mut QueryState<(&RapierRigidBodyHandle, &mut TransformInterpolation)>,
+) where
+    Hooks: 'static + BevyPhysicsHooks,
+    for<'w, 's> SystemParamItem<'w, 's, Hooks>: BevyPhysicsHooks,
+{
+    let config = *world.resource::<RapierConfiguration>();
+    if world.contains_resource::<AsyncPhysicsStep>() && config.physics_pipeline_active {
+        // The step is started by `start_async_physics_step`, at the end of the frame.
+        return;
+    }
+
+    let time = world.resource::<Time>().clone();
+    let mut context = world.resource_mut::<RapierContext>();
+    let num_steps = super::num_steps_to_run(&mut context, &config);
+    let measure_step_time = context.measure_step_time;
+
+    for rapier_world in context.worlds.values_mut() {
+        rapier_world.physics_time.last_frame_steps = 0;
+        rapier_world.set_profiling_enabled(config.enable_profiling);
+        rapier_world.detailed_collision_events = config.detailed_collision_events;
+        rapier_world.last_step_duration = Duration::ZERO;
+    }
+
+    let mut dropped_seconds = 0.0;
+    if num_steps > 0 {
+        let (num_steps, substep_dt, substeps) = plan_steps(
+            num_steps,
+            config.timestep_mode,
+            &time,
+            &mut world.resource_mut::<SimulationToRenderTime>(),
+            &mut dropped_seconds,
+        );
+
+        for step in 0..num_steps {
+            // Record the previous state of the bodies before the last step, like
+            // `RapierWorld::step_simulation` does.
+            if step + 1 == num_steps
+                && matches!(config.timestep_mode, TimestepMode::Interpolated { .. })
+            {
+                world.resource_scope(|world, context: Mut<RapierContext>| {
+                    record_interpolation_start(world, &context, interpolation_query);
+                });
+            }
+
+            for index in 0..substeps {
+                world.resource_scope(|world, mut context: Mut<RapierContext>| {
+                    let mut hooks_adapter =
+                        BevyPhysicsHooksAdapter::new(hooks.get_mut(world).into_inner());
+
+                    for (world_id, rapier_world) in context.worlds.iter_mut() {
+                        hooks_adapter.set_world_id(*world_id);
+                        let start_time = measure_step_time.then(Instant::now);
+                        rapier_world.step_simulation(
+                            *world_id,
+                            TimestepMode::Manual {
+                                dt: substep_dt,
+                                substeps: 1,
+                            },
+                            true,
+                            &hooks_adapter,
+                            &time,
+                            &mut SimulationToRenderTime::default(),
+                            &mut None,
+                        );
+
+                        if let Some(start_time) = start_time {
+                            rapier_world.last_step_duration += start_time.elapsed();
+                        }
+                    }
+                });
+
+                world.insert_resource(SubstepTime {
+                    dt: substep_dt,
+                    index: index as u32,
+                    substeps: substeps as u32,
+                });
+                world.run_schedule(PhysicsSubstepSchedule);
+            }
+        }
+    }
+
+    world.resource_scope(|world, mut context: Mut<RapierContext>| {
+        let (
+            mut collision_event_writer,
+            mut contact_force_event_writer,
+            mut detailed_collision_event_writer,
+            mut time_dropped_event_writer,
+            mut step_event_writer,
+        ) = event_writers.get_mut(world);
+
+        for (world_id, rapier_world) in context.worlds.iter_mut() {
+            if dropped_seconds > 0.0 {
+                time_dropped_event_writer.send(PhysicsTimeDroppedEvent {
+                    world_id: *world_id,
+                    dropped_seconds,
+                });
+            }
+
+            let physics_time = rapier_world.physics_time;
+            let first_step = physics_time.steps - physics_time.last_frame_steps as u64;
+            step_event_writer.send_batch(super::step_events(*world_id, rapier_world, first_step));
+
+            if num_steps == 0 {
+                rapier_world.propagate_modified_body_positions_to_colliders();
+            }
+
+            // Flush the events even if the world wasn’t stepped, like `step_simulation`.
+            rapier_world
+                .send_bevy_events(&mut collision_event_writer, &mut contact_force_event_writer);
+            rapier_world.send_detailed_collision_events(&mut detailed_collision_event_writer);
+
+            // The planned steps may be zero even if a step was requested, like `step_simulation`.
+            if rapier_world.physics_time.last_frame_steps > 0 {
+                rapier_world.deleted_colliders.clear();
+            }
+
+            if config.update_query_pipeline_after_step() {
+                rapier_world.update_query_pipeline();
+            }
+        }
+    });
+}
+
+/// The number of steps to execute during this frame, and the length and number of their
+/// substeps.
+pub(crate) fn plan_steps(
+    num_steps: u32,
+    timestep_mode: TimestepMode,
+    time: &Time,
+    sim_to_render_time: &mut SimulationToRenderTime,
+    dropped_seconds: &mut Real,
+) -> (u32, Real, usize) {
+    match timestep_mode {
+        TimestepMode::Interpolated {
+            dt,
+            time_scale,
+            substeps,
+            max_steps_per_frame,
+        } => {
+            sim_to_render_time.diff += time.delta_seconds();
+
+            let max_diff = max_steps_per_frame as Real * dt;
+            if sim_to_render_time.diff > max_diff {
+                *dropped_seconds = sim_to_render_time.diff - max_diff;
+                sim_to_render_time.diff = max_diff;
+            }
+
+            let mut num_steps = 0;
+            while sim_to_render_time.diff > 0.0 && num_steps < max_steps_per_frame {
+                num_steps += 1;
+                sim_to_render_time.diff -= dt;
+            }
+
+            (
+                num_steps as u32,
+                dt / (substeps as Real) * time_scale,
+                substeps,
+            )
+        }
+        TimestepMode::Variable {
+            max_dt,
+            time_scale,
+            substeps,
+        } => (
+            num_steps,
+            (time.delta_seconds() * time_scale).min(max_dt) / (substeps as Real),
+            substeps,
+        ),
+        TimestepMode::Fixed { dt, substeps } | TimestepMode::Manual { dt, substeps } => {
+            (num_steps, dt / (substeps as Real), substeps)
+        }
+        TimestepMode::FixedSchedule { substeps } => (
+            num_steps,
+            time.delta_seconds() / (substeps as Real),
+            substeps,
+        ),
+    }
+}
+
+fn record_interpolation_start(
+    world: &mut World,
+    context: &RapierContext,
+    interpolation_query: &mut QueryState<(&RapierRigidBodyHandle, &mut TransformInterpolation)>,
+) {
+    for rapier_world in context.worlds.values() {
+        let active = rapier_world.islands.active_dynamic_bodies().iter().copied();
+        for handle in active.chain(rapier_world.recently_active_bodies.iter()) {
+            let Some(body) = rapier_world.bodies.get(handle) else {
+                continue;
+            };
+
+            let entity = Entity::from_bits(body.user_data as u64);
+            if let Ok((_, mut interpolation)) = interpolation_query.get_mut(world, entity) {
+                interpolation.start = Some(*body.position());
+                interpolation.end = None;
+            }
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use crate::control::{RapierVehicleController, RaycastVehicle, RaycastVehicleOutput, VehicleWheel};
+use crate::dynamics::RapierRigidBodyHandle;
+use crate::math::Vect;
+use crate::plugin::configuration::SimulationToRenderTime;
+use crate::plugin::{get_world, RapierConfiguration, RapierContext};
+use crate::prelude::PhysicsWorld;
+use bevy::prelude::*;
+use rapier::pipeline::QueryFilter;
+
+/// System responsible for applying the forces of the wheels of each [`RaycastVehicle`] to its
+/// chassis, and for moving the wheel entities accordingly.
+///
+/// The vehicles are updated once per frame, over the total duration the simulation will be
+/// advanced by during this frame, and aren’t updated if the simulation won’t be stepped.
+#[allow(clippy::too_many_arguments)]
+pub fn update_vehicles(
+    mut commands: Commands,
+    config: Res<RapierConfiguration>,
+    time: Res<Time>,
+    sim_to_render_time: Res<SimulationToRenderTime>,
+    mut context: ResMut<RapierContext>,
+    mut vehicles: Query<(
+        Entity,
+        &RaycastVehicle,
+        &RapierRigidBodyHandle,
+        Option<&Children>,
+        Option<&PhysicsWorld>,
+        Option<&mut RapierVehicleController>,
+        Option<&mut RaycastVehicleOutput>,
+    )>,
+    mut wheels: Query<(&VehicleWheel, &mut Transform)>,
+) {
+    let duration =
+        super::rigid_body::simulated_duration(&config, &context, &time, &sim_to_render_time);
+    if duration <= 0.0 {
+        return;
+    }
+
+    for (entity, vehicle, handle, children, world_within, controller, output) in vehicles.iter_mut()
+    {
+        let world = get_world(world_within, &mut context);
+
+        let vehicle_wheels: Vec<_> = children
+            .into_iter()
+            .flat_map(|children| children.iter())
+            .filter_map(|child| Some((*child, *wheels.get(*child).ok()?.0)))
+            .collect();
+
+        let mut inserted = None;
+        let controller = match controller {
+            Some(controller) => controller.into_inner(),
+            None => inserted.insert(RapierVehicleController::new(handle.0, &vehicle_wheels)),
+        };
+        if controller.controller.chassis != handle.0
+            || !controller
+                .wheels
+                .iter()
+                .eq(vehicle_wheels.iter().map(|(entity, _)| entity))
+        {
+            *controller = RapierVehicleController::new(handle.0, &vehicle_wheels);
+        }
+
+        let raw_controller = &mut controller.controller;
+        raw_controller.index_up_axis = vehicle.up_axis;
+        raw_controller.index_forward_axis = vehicle.forward_axis;
+
+        for (raw_wheel, (_, wheel)) in raw_controller
+            .wheels_mut()
+            .iter_mut()
+            .zip(vehicle_wheels.iter())
+        {
+            raw_wheel.chassis_connection_point_cs = wheel.connection_point.into();
+            raw_wheel.direction_cs = wheel.direction.into();
+            raw_wheel.axle_cs = wheel.axle.into();
+            raw_wheel.suspension_rest_length = wheel.suspension_rest_length;
+            raw_wheel.max_suspension_travel = wheel.max_suspension_travel;
+            raw_wheel.suspension_stiffness = wheel.suspension_stiffness;
+            raw_wheel.damping_compression = wheel.suspension_compression;
+            raw_wheel.damping_relaxation = wheel.suspension_damping;
+            raw_wheel.max_suspension_force = wheel.max_suspension_force;
+            raw_wheel.radius = wheel.radius;
+            raw_wheel.friction_slip = wheel.friction_slip;
+            raw_wheel.side_friction_stiffness = wheel.side_friction_stiffness;
+            raw_wheel.engine_force = if wheel.driven {
+                vehicle.engine_force
+            } else {
+                0.0
+            };
+            raw_wheel.steering = if wheel.steered { vehicle.steering } else { 0.0 };
+            raw_wheel.brake = vehicle.brake;
+        }
+
+        // The wheel forces are applied as impulses, which don’t wake up the chassis.
+        if vehicle.engine_force != 0.0 {
+            if let Some(rb) = world.bodies.get_mut(handle.0) {
+                rb.wake_up(true);
+            }
+        }
+
+        raw_controller.update_vehicle(
+            duration,
+            &mut world.bodies,
+            &world.colliders,
+            &world.query_pipeline,
+            QueryFilter::new().exclude_rigid_body(handle.0),
+        );
+
+        // Move the wheel entities to the poses of the wheels, relative to the chassis.
+        for (raw_wheel, (wheel_entity, wheel)) in
+            raw_controller.wheels().iter().zip(vehicle_wheels.iter())
+        {
+            let Ok((_, mut transform)) = wheels.get_mut(*wheel_entity) else {
+                continue;
+            };
+
+            let suspension_length = raw_wheel.raycast_info().suspension_length;
+            let translation = wheel.connection_point + wheel.direction * suspension_length;
+            let steering = Quat::from_axis_angle(-wheel.direction.normalize(), raw_wheel.steering);
+            let spin = Quat::from_axis_angle(wheel.axle.normalize(), raw_wheel.rotation);
+            let new_transform = transform
+                .with_translation(translation)
+                .with_rotation(steering * spin);
+            transform.set_if_neq(new_transform);
+        }
+
+        let new_output = RaycastVehicleOutput {
+            current_speed: raw_controller.current_vehicle_speed(),
+            wheels_in_contact: raw_controller
+                .wheels()
+                .iter()
+                .filter(|wheel| wheel.raycast_info().is_in_contact)
+                .count(),
+        };
+        match output {
+            Some(mut output) => {
+                output.set_if_neq(new_output);
+            }
+            None => {
+                commands.entity(entity).insert(new_output);
+            }
+        }
+
+        if let Some(controller) = inserted {
+            commands.entity(entity).insert(controller);
+        }
+    }
+}
@@ -3,8 +3,8 @@
 use crate::dynamics::{
     RapierImpulseJointHandle, RapierMultibodyJointHandle, RapierRigidBodyHandle,
 };
-use crate::geometry::RapierColliderHandle;
-use crate::plugin::RapierContext;
+use crate::geometry::{RapierColliderHandle, RapierColliderHandles};
+use crate::plugin::{RapierConfiguration, RapierContext, RapierWorld};
 use crate::prelude::PhysicsWorld;
 use bevy::prelude::*;
 
@@ -36,6 +36,7 @@ fn remove_old_physics(entity: Entity, commands: &mut Commands) {
     commands
         .entity(entity)
         .remove::<RapierColliderHandle>()
+        .remove::<RapierColliderHandles>()
         .remove::<RapierRigidBodyHandle>()
         .remove::<RapierMultibodyJointHandle>()
         .remove::<RapierImpulseJointHandle>();
@@ -60,6 +61,7 @@ pub fn on_change_world(
                 x.entity2impulse_joint.contains_key(&entity)
                     || x.entity2multibody_joint.contains_key(&entity)
                     || x.entity2collider.contains_key(&entity)
+                    || x.entity2colliders.contains_key(&entity)
                     || x.entity2body.contains_key(&entity)
             })
             .unwrap_or(false)
@@ -109,3 +111,36 @@ fn bubble_down_world_change(
         );
     });
 }
+
+/// Logs every inconsistency found between the entity-to-handle maps of each world and their
+/// Rapier sets, see [`RapierContext::validate_all`].
+///
+/// This is a debugging aid, added after [`PhysicsSet::Writeback`](crate::plugin::PhysicsSet::Writeback)
+/// when the `debug-validate` feature is enabled. It can also be added manually to any schedule.
+pub fn log_consistency_errors(context: Res<RapierContext>) {
+    for (world_id, error) in context.validate_all() {
+        log::error!("Inconsistent physics world {world_id}: {error}");
+    }
+}
+
+/// Copies [`RapierConfiguration::scaled_shape_subdivision`] into every [`RapierWorld`](crate::plugin::RapierWorld)
+/// so scene queries approximate scaled shapes the same way the simulation colliders do.
+pub fn sync_scaled_shape_subdivision(
+    mut context: ResMut<RapierContext>,
+    config: Res<RapierConfiguration>,
+) {
+    // NOTE: this also runs when the configuration didn’t change, to catch worlds added
+    //       after the last configuration change. The context is only borrowed mutably if a
+    //       world is out of sync, to not trigger its change detection every frame.
+    let out_of_sync =
+        |world: &RapierWorld| world.scaled_shape_subdivision != config.scaled_shape_subdivision;
+    if !context.worlds.values().any(out_of_sync) {
+        return;
+    }
+
+    for world in context.worlds.values_mut() {
+        if out_of_sync(world) {
+            world.scaled_shape_subdivision = config.scaled_shape_subdivision;
+        }
+    }
+}
@@ -1,11 +1,19 @@
 use crate::dynamics::MassProperties;
 use crate::dynamics::ReadMassProperties;
+use crate::dynamics::{RapierRigidBodyHandle, ReadContactForces};
+use crate::geometry::{
+    Collider, ColliderMassProperties, RapierColliderHandle, ReadColliderMassProperties,
+};
 use crate::plugin::RapierConfiguration;
 use crate::plugin::RapierContext;
-use crate::prelude::MassModifiedEvent;
+use crate::plugin::DEFAULT_WORLD_ID;
+use crate::prelude::{MassModifiedEvent, PhysicsWorld};
 use bevy::prelude::*;
 
 /// System responsible for writing updated mass properties back into the [`ReadMassProperties`] component.
+///
+/// The world-space mass properties of the rigid-bodies moved by the simulation are also updated
+/// by `writeback_rigid_bodies`.
 pub fn writeback_mass_properties(
     context: Res<RapierContext>,
     config: Res<RapierConfiguration>,
@@ -17,28 +25,351 @@ pub fn writeback_mass_properties(
         return;
     }
 
-    for (_, world) in context.worlds.iter() {
-        for entity in mass_modified.read() {
-            let Some(handle) = world.entity2body.get(entity).copied() else {
-                continue;
-            };
+    for event in mass_modified.read() {
+        let Ok(mut mass_props) = mass_props.get_mut(event.entity) else {
+            continue;
+        };
+        let Some(rb) = context.get_world(event.world_id).ok().and_then(|world| {
+            world
+                .entity2body
+                .get(&event.entity)
+                .and_then(|handle| world.bodies.get(*handle))
+        }) else {
+            continue;
+        };
 
-            let Some(rb) = world.bodies.get(handle) else {
-                continue;
-            };
+        // NOTE: we write the new value only if there was an
+        //       actual change, in order to not trigger bevy’s
+        //       change tracking when the values didn’t change.
+        let new_mass_props = ReadMassProperties::from_rapier(rb.mass_properties());
+        if *mass_props != new_mass_props {
+            *mass_props = new_mass_props;
+        }
+    }
+}
+
+/// System responsible for writing updated collider mass properties back into the
+/// [`ReadColliderMassProperties`] component.
+pub fn writeback_collider_mass_properties(
+    context: Res<RapierContext>,
+    config: Res<RapierConfiguration>,
+
+    mut mass_props: Query<
+        (&RapierColliderHandle, &mut ReadColliderMassProperties),
+        Or<(
+            Changed<RapierColliderHandle>,
+            Changed<Collider>,
+            Changed<ColliderMassProperties>,
+            Added<ReadColliderMassProperties>,
+        )>,
+    >,
+) {
+    if !config.physics_pipeline_active {
+        return;
+    }
+
+    for (handle, mut mass_props) in mass_props.iter_mut() {
+        let Some(co) = context
+            .worlds
+            .values()
+            .find_map(|world| world.colliders.get(handle.0))
+        else {
+            continue;
+        };
+
+        let new_mass_props = MassProperties::from_rapier(co.mass_properties());
+
+        // NOTE: we write the new value only if there was an
+        //       actual change, in order to not trigger bevy’s
+        //       change tracking when the values didn’t change.
+        if mass_props.get() != &new_mass_props {
+            mass_props.set(new_mass_props);
+        }
+    }
+}
+
+/// System responsible for writing the contact forces of the last simulation step into the
+/// [`ReadContactForces`] component.
+pub fn writeback_contact_forces(
+    context: Res<RapierContext>,
+    config: Res<RapierConfiguration>,
+
+    mut contact_forces: Query<(
+        &RapierRigidBodyHandle,
+        &mut ReadContactForces,
+        Option<&PhysicsWorld>,
+    )>,
+) {
+    if !config.physics_pipeline_active {
+        return;
+    }
+
+    for (handle, mut contact_forces, world_within) in contact_forces.iter_mut() {
+        let world_id = world_within.map_or(DEFAULT_WORLD_ID, |world| world.world_id);
+        let Ok(world) = context.get_world(world_id) else {
+            continue;
+        };
+        let Some(rb) = world.bodies.get(handle.0) else {
+            continue;
+        };
+
+        let dt = world.physics_time.last_dt;
+        let mut new_contact_forces = ReadContactForces::default();
+
+        if dt > 0.0 {
+            for collider in rb.colliders() {
+                for pair in world.narrow_phase.contact_pairs_with(*collider) {
+                    if !pair.has_any_active_contact {
+                        continue;
+                    }
+
+                    let force = pair.total_impulse_magnitude() / dt;
+                    new_contact_forces.total_force += force;
+                    new_contact_forces.max_force = new_contact_forces.max_force.max(force);
+                    new_contact_forces.contact_count += pair
+                        .manifolds
+                        .iter()
+                        .map(|manifold| manifold.data.solver_contacts.len() as u32)
+                        .sum::<u32>();
+                }
+            }
+        }
+
+        // NOTE: we write the new value only if there was an
+        //       actual change, in order to not trigger bevy’s
+        //       change tracking when the values didn’t change.
+        if *contact_forces != new_contact_forces {
+            *contact_forces = new_contact_forces;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::plugin::systems::tests::HeadlessRenderPlugin;
+    use crate::plugin::{NoUserData, RapierPhysicsPlugin};
+    use bevy::time::TimePlugin;
+
+    #[test]
+    fn collider_mass_properties_are_read_back() {
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+
+        #[cfg(feature = "dim2")]
+        let collider = Collider::cuboid(0.5, 0.5);
+        #[cfg(feature = "dim3")]
+        let collider = Collider::cuboid(0.5, 0.5, 0.5);
+        let entity = app
+            .world
+            .spawn((
+                TransformBundle::default(),
+                collider,
+                ColliderMassProperties::Density(1.0),
+                ReadColliderMassProperties::default(),
+            ))
+            .id();
+
+        let read_mass = |app: &App| {
+            app.world
+                .get::<ReadColliderMassProperties>(entity)
+                .unwrap()
+                .mass
+        };
+
+        app.update();
+        approx::assert_relative_eq!(read_mass(&app), 1.0, epsilon = 1.0e-5);
+
+        *app.world.get_mut::<ColliderMassProperties>(entity).unwrap() =
+            ColliderMassProperties::Density(2.0);
+        app.update();
+        approx::assert_relative_eq!(read_mass(&app), 2.0, epsilon = 1.0e-5);
+
+        // Doubling the scale multiplies the area by 4 in 2D, and the volume by 8 in 3D.
+        app.world.get_mut::<Transform>(entity).unwrap().scale = Vec3::splat(2.0);
+        app.update();
+        #[cfg(feature = "dim2")]
+        approx::assert_relative_eq!(read_mass(&app), 8.0, epsilon = 1.0e-4);
+        #[cfg(feature = "dim3")]
+        approx::assert_relative_eq!(read_mass(&app), 16.0, epsilon = 1.0e-4);
+    }
+
+    #[test]
+    fn world_space_mass_properties_are_read_back() {
+        use crate::dynamics::{GravityScale, LockedAxes, RigidBody, Velocity};
+        use crate::math::Vect;
+        use crate::plugin::TimestepMode;
+        use std::f32::consts::{FRAC_PI_2, PI};
 
-            let Ok(mut mass_props) = mass_props.get_mut(**entity) else {
-                continue;
-            };
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .timestep_mode = TimestepMode::Fixed {
+            dt: 1.0 / 60.0,
+            substeps: 1,
+        };
 
-            let new_mass_props = MassProperties::from_rapier(rb.mass_properties().local_mprops);
+        // A ball offset by one unit along the local `X` axis of a body rotated by 90 degrees,
+        // so its center-of-mass is one unit above the body’s origin.
+        let body = app
+            .world
+            .spawn((
+                TransformBundle::from(
+                    Transform::from_xyz(1.0, 2.0, 0.0)
+                        .with_rotation(Quat::from_rotation_z(FRAC_PI_2)),
+                ),
+                RigidBody::Dynamic,
+                GravityScale(0.0),
+                LockedAxes::TRANSLATION_LOCKED_Y,
+                Velocity::linear(Vect::X),
+                ReadMassProperties::default(),
+            ))
+            .with_children(|children| {
+                children.spawn((
+                    TransformBundle::from(Transform::from_xyz(1.0, 0.0, 0.0)),
+                    Collider::ball(0.5),
+                ));
+            })
+            .id();
 
-            // NOTE: we write the new value only if there was an
-            //       actual change, in order to not trigger bevy’s
-            //       change tracking when the values didn’t change.
-            if mass_props.get() != &new_mass_props {
-                mass_props.set(new_mass_props);
+        #[cfg(feature = "dim2")]
+        let (mass, inertia) = {
+            let mass = PI * 0.25;
+            (mass, mass * 0.25 / 2.0)
+        };
+        #[cfg(feature = "dim3")]
+        let (mass, inertia) = {
+            let mass = 4.0 / 3.0 * PI * 0.125;
+            (mass, mass * 0.25 * 2.0 / 5.0)
+        };
+
+        for _ in 0..10 {
+            app.update();
+
+            let mprops = *app.world.get::<ReadMassProperties>(body).unwrap();
+            let translation = app.world.get::<Transform>(body).unwrap().translation;
+            approx::assert_relative_eq!(mprops.mass, mass, epsilon = 1.0e-4);
+
+            #[cfg(feature = "dim2")]
+            {
+                let expected_com = translation.truncate() + Vect::Y;
+                approx::assert_relative_eq!(
+                    mprops.world_center_of_mass,
+                    expected_com,
+                    epsilon = 1.0e-4
+                );
+                approx::assert_relative_eq!(
+                    mprops.effective_inv_mass,
+                    Vect::new(1.0 / mass, 0.0),
+                    epsilon = 1.0e-3
+                );
+                approx::assert_relative_eq!(
+                    mprops.effective_inv_angular_inertia,
+                    1.0 / inertia,
+                    epsilon = 1.0e-2
+                );
+            }
+            #[cfg(feature = "dim3")]
+            {
+                let expected_com = translation + Vect::Y;
+                approx::assert_relative_eq!(
+                    mprops.world_center_of_mass,
+                    expected_com,
+                    epsilon = 1.0e-4
+                );
+                approx::assert_relative_eq!(
+                    mprops.effective_inv_mass,
+                    Vect::new(1.0 / mass, 0.0, 1.0 / mass),
+                    epsilon = 1.0e-3
+                );
+                approx::assert_relative_eq!(
+                    mprops.effective_inv_angular_inertia,
+                    Mat3::from_diagonal(Vec3::splat(1.0 / inertia)),
+                    epsilon = 1.0e-2
+                );
             }
         }
+
+        // The body moved along `X`, and the center-of-mass followed it.
+        let mprops = app.world.get::<ReadMassProperties>(body).unwrap();
+        assert!(mprops.world_center_of_mass.x > 1.1);
+    }
+
+    #[test]
+    fn stacked_boxes_read_the_weight_they_support() {
+        use crate::dynamics::RigidBody;
+        use crate::plugin::TimestepMode;
+
+        let mut app = App::new();
+        app.add_plugins((
+            HeadlessRenderPlugin,
+            TransformPlugin,
+            TimePlugin,
+            RapierPhysicsPlugin::<NoUserData>::default(),
+        ));
+        app.world
+            .resource_mut::<RapierConfiguration>()
+            .timestep_mode = TimestepMode::Fixed {
+            dt: 1.0 / 60.0,
+            substeps: 1,
+        };
+
+        #[cfg(feature = "dim2")]
+        let (ground, cube) = (Collider::cuboid(10.0, 0.5), Collider::cuboid(0.5, 0.5));
+        #[cfg(feature = "dim3")]
+        let (ground, cube) = (
+            Collider::cuboid(10.0, 0.5, 10.0),
+            Collider::cuboid(0.5, 0.5, 0.5),
+        );
+        app.world.spawn((
+            TransformBundle::from(Transform::from_xyz(0.0, -0.5, 0.0)),
+            ground,
+        ));
+
+        // A column of three boxes with a unit mass.
+        let boxes: Vec<_> = (0..3)
+            .map(|i| {
+                app.world
+                    .spawn((
+                        TransformBundle::from(Transform::from_xyz(0.0, 0.5 + i as f32, 0.0)),
+                        RigidBody::Dynamic,
+                        cube.clone(),
+                        ColliderMassProperties::Mass(1.0),
+                        ReadContactForces::default(),
+                    ))
+                    .id()
+            })
+            .collect();
+
+        for _ in 0..120 {
+            app.update();
+        }
+
+        let gravity = app
+            .world
+            .resource::<RapierContext>()
+            .get_world(DEFAULT_WORLD_ID)
+            .unwrap()
+            .gravity
+            .length();
+        let forces = |i: usize| *app.world.get::<ReadContactForces>(boxes[i]).unwrap();
+
+        // The top box only rests on the middle one.
+        approx::assert_relative_eq!(forces(2).total_force, gravity, max_relative = 0.1);
+        // The bottom box supports the two boxes above it, and is supported by the ground.
+        approx::assert_relative_eq!(forces(0).max_force, 3.0 * gravity, max_relative = 0.1);
+        approx::assert_relative_eq!(forces(0).total_force, 5.0 * gravity, max_relative = 0.1);
+        assert!(forces(0).contact_count > 0);
     }
 }
@@ -1,8 +1,14 @@
+use crate::geometry::Group;
+use crate::math::Vect;
+use crate::parry::bounding_volume::Aabb;
+use crate::pipeline::{DebugCast, DebugCastBuffer};
 use crate::plugin::context::RapierWorld;
-use crate::plugin::RapierContext;
+use crate::plugin::{RapierContext, WorldId};
 use bevy::prelude::*;
 use bevy::transform::TransformSystem;
-use rapier::math::{Point, Real};
+use rapier::dynamics::{GenericJoint, JointLimits, RigidBodySet};
+use rapier::geometry::{ColliderBuilder, ColliderSet};
+use rapier::math::{Isometry, Point, Real, Rotation, Vector, DIM, SPATIAL_DIM};
 use rapier::pipeline::{DebugRenderBackend, DebugRenderObject, DebugRenderPipeline};
 pub use rapier::pipeline::{DebugRenderMode, DebugRenderStyle};
 use std::fmt::Debug;
@@ -15,6 +21,31 @@ use std::fmt::Debug;
 #[derive(Copy, Clone, Component, PartialEq, Debug)]
 pub struct ColliderDebugColor(pub Color);
 
+/// Marker component hiding the collider, rigid-body, and joint of an entity from the
+/// debug-renderer.
+#[derive(Copy, Clone, Default, Component, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Component)]
+pub struct DebugRenderExclude;
+
+/// Marker component restricting the debug-renderer to the entities with this component.
+///
+/// As long as at least one entity has this component, the colliders, rigid-bodies, and joints
+/// of all the other entities are hidden.
+#[derive(Copy, Clone, Default, Component, PartialEq, Eq, Debug, Reflect)]
+#[reflect(Component)]
+pub struct DebugRenderOnly;
+
+/// Selects which parts of the physics scene are drawn by the debug-renderer.
+///
+/// This is checked in addition to the [`DebugRenderExclude`] and [`DebugRenderOnly`] markers.
+#[derive(Copy, Clone, Default, PartialEq, Debug, Reflect)]
+pub struct DebugRenderFilter {
+    /// If set, only the colliders with memberships intersecting this mask are drawn.
+    pub groups: Option<Group>,
+    /// If set, only the objects of this world are drawn.
+    pub world: Option<WorldId>,
+}
+
 /// Plugin rensponsible for rendering (using lines) what Rapier "sees" when performing
 /// its physics simulation. This is typically useful to check proper
 /// alignment between colliders and your own visual assets.
@@ -25,6 +56,13 @@ pub struct RapierDebugRenderPlugin {
     pub style: DebugRenderStyle,
     /// Flags to select what part of physics scene is rendered (by default
     /// everything is rendered).
+    ///
+    /// [`DebugRenderMode::CONTACTS`] is disabled by default. When enabled, a cross is drawn at
+    /// each solver contact point, along with its normal and its penetration depth.
+    ///
+    /// With [`DebugRenderMode::IMPULSE_JOINTS`] and [`DebugRenderMode::MULTIBODY_JOINTS`], both
+    /// anchors of each joint are drawn, along with the line connecting them, the free axes of the
+    /// joint, and its limits.
     pub mode: DebugRenderMode,
 }
 
@@ -69,6 +107,31 @@ pub struct DebugRenderContext {
     /// to modify the set of rendered elements, and modify the default coloring rules.
     #[reflect(ignore)]
     pub pipeline: DebugRenderPipeline,
+    /// The color of the limits of a joint that are currently exceeded, in HSLA like the colors
+    /// of `pipeline.style`.
+    pub violated_joint_limit_color: [f32; 4],
+    /// Filters the objects to draw.
+    pub filter: DebugRenderFilter,
+    /// If set, the nodes of the bounding volume hierarchy of the query pipeline are drawn, down
+    /// to this depth. See [`RapierWorld::visit_query_pipeline_bvh`].
+    ///
+    /// The bounding boxes of the colliders themselves are drawn with
+    /// [`DebugRenderMode::COLLIDER_AABBS`].
+    pub bvh_max_depth: Option<usize>,
+    /// The color of the nodes of the bounding volume hierarchy, in HSLA.
+    pub bvh_color: [f32; 4],
+    /// The color of the casts recorded in the [`DebugCastBuffer`] that hit a collider, in HSLA.
+    pub cast_hit_color: [f32; 4],
+    /// The color of the casts recorded in the [`DebugCastBuffer`] that didn’t hit anything, in
+    /// HSLA.
+    pub cast_miss_color: [f32; 4],
+    /// The maximum length of the casts drawn, so that the casts that didn’t hit anything with an
+    /// unbounded maximum time-of-impact can still be drawn.
+    pub cast_max_length: Real,
+    /// If set, the colliders attached to sleeping rigid-bodies are drawn with this color, in HSLA.
+    ///
+    /// Otherwise, their color is dimmed with `pipeline.style.sleep_color_multiplier`.
+    pub sleeping_body_color: Option<[f32; 4]>,
 }
 
 impl Default for DebugRenderContext {
@@ -76,53 +139,152 @@ impl Default for DebugRenderContext {
         Self {
             enabled: true,
             pipeline: DebugRenderPipeline::default(),
+            violated_joint_limit_color: DEFAULT_VIOLATED_JOINT_LIMIT_COLOR,
+            filter: DebugRenderFilter::default(),
+            bvh_max_depth: None,
+            bvh_color: [30.0, 1.0, 0.5, 1.0],
+            cast_hit_color: [120.0, 1.0, 0.4, 1.0],
+            cast_miss_color: [0.0, 1.0, 0.5, 1.0],
+            cast_max_length: 1000.0,
+            sleeping_body_color: None,
         }
     }
 }
 
+const DEFAULT_VIOLATED_JOINT_LIMIT_COLOR: [f32; 4] = [0.0, 1.0, 0.5, 1.0];
+
 impl Plugin for RapierDebugRenderPlugin {
     fn build(&self, app: &mut App) {
-        app.register_type::<DebugRenderContext>();
+        app.register_type::<DebugRenderContext>()
+            .register_type::<DebugRenderExclude>()
+            .register_type::<DebugRenderOnly>();
 
         app.insert_resource(DebugRenderContext {
             enabled: self.enabled,
             pipeline: DebugRenderPipeline::new(self.style, self.mode),
+            ..Default::default()
+        })
+        .insert_resource(DebugCastBuffer {
+            recording: true,
+            ..Default::default()
         })
         .add_systems(
             PostUpdate,
-            debug_render_scene.after(TransformSystem::TransformPropagate),
+            (debug_render_scene, debug_render_casts).after(TransformSystem::TransformPropagate),
         );
     }
 }
 
+type DebugRenderMarkers<'world, 'state> =
+    Query<'world, 'state, (Has<DebugRenderExclude>, Has<DebugRenderOnly>)>;
+
+/// Is `object` of `world` drawn, given the `filter` and the debug-render `markers` of the
+/// entities? `has_only` indicates if any entity has a [`DebugRenderOnly`] marker.
+fn is_object_visible(
+    world: &RapierWorld,
+    object: DebugRenderObject,
+    filter: &DebugRenderFilter,
+    markers: &DebugRenderMarkers,
+    has_only: bool,
+) -> bool {
+    let entity_visible = |user_data: u128| match markers.get(Entity::from_bits(user_data as u64)) {
+        Ok((excluded, only)) => !excluded && (only || !has_only),
+        Err(_) => !has_only,
+    };
+    let collider_visible = |handle| {
+        world.colliders.get(handle).map_or(true, |co| {
+            let in_groups = filter.groups.map_or(true, |groups| {
+                co.collision_groups().memberships.bits() & groups.bits() != 0
+            });
+            in_groups && entity_visible(co.user_data)
+        })
+    };
+    // The joints are attached to the entity of their second rigid-body.
+    let body_visible = |handle| {
+        world
+            .bodies
+            .get(handle)
+            .map_or(true, |rb| entity_visible(rb.user_data))
+    };
+
+    match object {
+        DebugRenderObject::Collider(handle, ..) => collider_visible(handle),
+        DebugRenderObject::RigidBody(handle, ..) => body_visible(handle),
+        DebugRenderObject::ImpulseJoint(handle, ..) => world
+            .impulse_joints
+            .get(handle)
+            .map_or(true, |joint| body_visible(joint.body2)),
+        DebugRenderObject::MultibodyJoint(handle, ..) => world
+            .multibody_joints
+            .get(handle)
+            .and_then(|(mb, link_id)| mb.link(link_id))
+            .map_or(true, |link| body_visible(link.rigid_body_handle())),
+        DebugRenderObject::ContactPair(pair, ..) => {
+            collider_visible(pair.collider1) || collider_visible(pair.collider2)
+        }
+        #[allow(unreachable_patterns)]
+        _ => true,
+    }
+}
+
 struct BevyLinesRenderBackend<'world, 'state, 'a, 'b> {
     custom_colors: Query<'world, 'state, &'a ColliderDebugColor>,
+    markers: DebugRenderMarkers<'world, 'state>,
+    has_only: bool,
+    filter: DebugRenderFilter,
+    sleeping_body_color: Option<[f32; 4]>,
+    sleep_color_multiplier: [f32; 4],
     world: Option<&'b RapierWorld>,
     gizmos: Gizmos<'world, 'state>,
 }
 
 impl<'world, 'state, 'a, 'b> BevyLinesRenderBackend<'world, 'state, 'a, 'b> {
     fn object_color(&self, object: DebugRenderObject, default: [f32; 4]) -> [f32; 4] {
-        let color = match object {
-            DebugRenderObject::Collider(h, ..) => self
-                .world
-                .expect("World not set before triggering debug render")
-                .colliders
-                .get(h)
-                .and_then(|co| {
-                    self.custom_colors
-                        .get(Entity::from_bits(co.user_data as u64))
-                        .map(|co| co.0)
-                        .ok()
-                }),
-            _ => None,
+        let DebugRenderObject::Collider(h, ..) = object else {
+            return default;
+        };
+        let world = self
+            .world
+            .expect("World not set before triggering debug render");
+        let Some(co) = world.colliders.get(h) else {
+            return default;
         };
 
-        color.map(|co| co.as_hsla_f32()).unwrap_or(default)
+        let sleeping = co
+            .parent()
+            .and_then(|parent| world.bodies.get(parent))
+            .is_some_and(|rb| rb.is_sleeping());
+        if let (true, Some(color)) = (sleeping, self.sleeping_body_color) {
+            return color;
+        }
+
+        match self
+            .custom_colors
+            .get(Entity::from_bits(co.user_data as u64))
+        {
+            // Rapier already dimmed the default color of sleeping bodies, but not custom colors.
+            Ok(custom_color) if sleeping => {
+                let color = custom_color.0.as_hsla_f32();
+                std::array::from_fn(|i| color[i] * self.sleep_color_multiplier[i])
+            }
+            Ok(custom_color) => custom_color.0.as_hsla_f32(),
+            Err(_) => default,
+        }
     }
 }
 
 impl<'world, 'state, 'a, 'b> DebugRenderBackend for BevyLinesRenderBackend<'world, 'state, 'a, 'b> {
+    fn filter_object(&self, object: DebugRenderObject) -> bool {
+        is_object_visible(
+            self.world
+                .expect("World not set before triggering debug render"),
+            object,
+            &self.filter,
+            &self.markers,
+            self.has_only,
+        )
+    }
+
     #[cfg(feature = "dim2")]
     fn draw_line(
         &mut self,
@@ -161,6 +323,8 @@ fn debug_render_scene(
     mut render_context: ResMut<DebugRenderContext>,
     gizmos: Gizmos,
     custom_colors: Query<&ColliderDebugColor>,
+    markers: DebugRenderMarkers,
+    only: Query<(), With<DebugRenderOnly>>,
 ) {
     if !render_context.enabled {
         return;
@@ -168,13 +332,34 @@ fn debug_render_scene(
 
     let mut backend = BevyLinesRenderBackend {
         custom_colors,
+        markers,
+        has_only: !only.is_empty(),
+        filter: render_context.filter,
+        sleeping_body_color: render_context.sleeping_body_color,
+        sleep_color_multiplier: render_context.pipeline.style.sleep_color_multiplier,
         world: None,
         gizmos,
     };
 
-    for (_, world) in rapier_context.worlds.iter() {
+    for (world_id, world) in rapier_context.worlds.iter() {
+        if render_context
+            .filter
+            .world
+            .is_some_and(|filtered| filtered != *world_id)
+        {
+            continue;
+        }
+
         backend.world = Some(world);
 
+        // The contacts and joints are rendered by `render_contacts` and `render_joints` instead
+        // of Rapier.
+        let mode = render_context.pipeline.mode;
+        render_context
+            .pipeline
+            .mode
+            .remove(DebugRenderMode::CONTACTS | DebugRenderMode::JOINTS);
+
         let unscaled_style = render_context.pipeline.style;
         render_context.pipeline.render(
             &mut backend,
@@ -185,5 +370,515 @@ fn debug_render_scene(
             &world.narrow_phase,
         );
         render_context.pipeline.style = unscaled_style;
+        render_context.pipeline.mode = mode;
+
+        if mode.contains(DebugRenderMode::CONTACTS) {
+            render_contacts(&mut backend, world, &render_context.pipeline.style);
+        }
+
+        if mode.intersects(DebugRenderMode::JOINTS) {
+            render_joints(&mut backend, world, mode, &render_context);
+        }
+
+        if let Some(max_depth) = render_context.bvh_max_depth {
+            let color = hsla(render_context.bvh_color);
+            world.visit_query_pipeline_bvh(max_depth, |_, aabb| {
+                draw_aabb(&mut backend.gizmos, aabb, color);
+            });
+        }
+    }
+}
+
+fn hsla(color: [f32; 4]) -> Color {
+    Color::hsla(color[0], color[1], color[2], color[3])
+}
+
+#[cfg(feature = "dim2")]
+fn to_vec3(v: Vect) -> Vec3 {
+    v.extend(0.0)
+}
+
+#[cfg(feature = "dim3")]
+fn to_vec3(v: Vect) -> Vec3 {
+    v
+}
+
+fn draw_aabb(gizmos: &mut Gizmos, aabb: &Aabb, color: Color) {
+    let center = aabb.center();
+    let extents = aabb.extents();
+    #[cfg(feature = "dim2")]
+    gizmos.rect_2d(
+        Vec2::new(center.x, center.y),
+        0.0,
+        Vec2::new(extents.x, extents.y),
+        color,
+    );
+    #[cfg(feature = "dim3")]
+    gizmos.cuboid(
+        Transform::from_xyz(center.x, center.y, center.z)
+            .with_scale(Vec3::new(extents.x, extents.y, extents.z)),
+        color,
+    );
+}
+
+/// Draws the lines of the shapes of casts with a single color.
+struct CastRenderBackend<'a, 'world, 'state> {
+    gizmos: &'a mut Gizmos<'world, 'state>,
+    color: Color,
+}
+
+impl<'a, 'world, 'state> DebugRenderBackend for CastRenderBackend<'a, 'world, 'state> {
+    fn draw_line(
+        &mut self,
+        _object: DebugRenderObject,
+        a: Point<Real>,
+        b: Point<Real>,
+        _color: [f32; 4],
+    ) {
+        self.gizmos.line(
+            to_vec3(a.coords.into()),
+            to_vec3(b.coords.into()),
+            self.color,
+        );
+    }
+}
+
+/// Draws the casts recorded in the [`DebugCastBuffer`] during this frame, then clears it.
+///
+/// Shapes are drawn at their initial position and at their time of impact. The casts are drawn
+/// up to [`DebugRenderContext::cast_max_length`].
+fn debug_render_casts(
+    mut buffer: ResMut<DebugCastBuffer>,
+    render_context: Res<DebugRenderContext>,
+    mut gizmos: Gizmos,
+) {
+    if !render_context.enabled {
+        buffer.casts.clear();
+        return;
+    }
+
+    let style = &render_context.pipeline.style;
+    let mut shape_pipeline = DebugRenderPipeline::new(*style, DebugRenderMode::COLLIDER_SHAPES);
+    // The end of a cast, with its length clamped so that unbounded casts can be drawn.
+    let cast_end = |start: Vect, dir: Vect, time_of_impact: Real| {
+        let length = (dir.length() * time_of_impact).min(render_context.cast_max_length);
+        start + dir.normalize_or_zero() * length
+    };
+    let color = |hit: bool| {
+        hsla(if hit {
+            render_context.cast_hit_color
+        } else {
+            render_context.cast_miss_color
+        })
+    };
+
+    for cast in buffer.casts.drain(..) {
+        match cast {
+            DebugCast::Ray {
+                origin,
+                dir,
+                time_of_impact,
+                hit,
+            } => {
+                let end = cast_end(origin, dir, time_of_impact);
+                gizmos.line(to_vec3(origin), to_vec3(end), color(hit.is_some()));
+
+                if let Some((point, normal)) = hit {
+                    let point = to_vec3(point);
+                    let half_size = style.contact_normal_length * 0.1;
+                    for axis in [Vec3::X, Vec3::Y, Vec3::Z].into_iter().take(DIM) {
+                        gizmos.line(
+                            point - axis * half_size,
+                            point + axis * half_size,
+                            color(true),
+                        );
+                    }
+                    gizmos.line(
+                        point,
+                        point + to_vec3(normal) * style.contact_normal_length,
+                        hsla(style.contact_normal_color),
+                    );
+                }
+            }
+            DebugCast::Shape {
+                shape,
+                start,
+                vel,
+                time_of_impact,
+                rotation,
+                hit,
+            } => {
+                let end = cast_end(start, vel, time_of_impact);
+                let color = color(hit);
+                gizmos.line(to_vec3(start), to_vec3(end), color);
+
+                // Let the debug-render pipeline draw the shape, as if it was a collider at both
+                // ends of the cast.
+                let mut colliders = ColliderSet::new();
+                for pos in [start, end] {
+                    colliders.insert(
+                        ColliderBuilder::new(shape.raw.clone())
+                            .position((pos, rotation).into())
+                            .build(),
+                    );
+                }
+                shape_pipeline.render_colliders(
+                    &mut CastRenderBackend {
+                        gizmos: &mut gizmos,
+                        color,
+                    },
+                    &RigidBodySet::new(),
+                    &colliders,
+                );
+            }
+        }
+    }
+}
+
+fn draw_cross(
+    backend: &mut BevyLinesRenderBackend,
+    object: DebugRenderObject,
+    point: Point<Real>,
+    half_size: Real,
+    color: [f32; 4],
+) {
+    for i in 0..DIM {
+        let mut offset = Vector::zeros();
+        offset[i] = half_size;
+        backend.draw_line(object, point - offset, point + offset, color);
+    }
+}
+
+/// Draws a cross at each solver contact point of `world`, a line along its normal, and a
+/// segment along the normal as long as the penetration depth.
+fn render_contacts(
+    backend: &mut BevyLinesRenderBackend,
+    world: &RapierWorld,
+    style: &DebugRenderStyle,
+) {
+    let cross_half_size = style.contact_normal_length * 0.1;
+
+    for pair in world.narrow_phase.contact_pairs() {
+        let (Some(co1), Some(co2)) = (
+            world.colliders.get(pair.collider1),
+            world.colliders.get(pair.collider2),
+        ) else {
+            continue;
+        };
+        let object = DebugRenderObject::ContactPair(pair, co1, co2);
+        if !backend.filter_object(object) {
+            continue;
+        }
+
+        for manifold in &pair.manifolds {
+            let normal = manifold.data.normal;
+
+            for contact in &manifold.data.solver_contacts {
+                let point = contact.point;
+
+                draw_cross(
+                    backend,
+                    object,
+                    point,
+                    cross_half_size,
+                    style.contact_normal_color,
+                );
+
+                backend.draw_line(
+                    object,
+                    point,
+                    point + normal * style.contact_normal_length,
+                    style.contact_normal_color,
+                );
+
+                let depth = -contact.dist;
+                if depth > 0.0 {
+                    backend.draw_line(
+                        object,
+                        point,
+                        point + normal * depth,
+                        style.contact_depth_color,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Draws the impulse and multibody joints of `world`, depending on `mode`.
+fn render_joints(
+    backend: &mut BevyLinesRenderBackend,
+    world: &RapierWorld,
+    mode: DebugRenderMode,
+    render_context: &DebugRenderContext,
+) {
+    let style = &render_context.pipeline.style;
+
+    if mode.contains(DebugRenderMode::IMPULSE_JOINTS) {
+        for (handle, joint) in world.impulse_joints.iter() {
+            let (Some(rb1), Some(rb2)) =
+                (world.bodies.get(joint.body1), world.bodies.get(joint.body2))
+            else {
+                continue;
+            };
+
+            let object = DebugRenderObject::ImpulseJoint(handle, joint);
+            if !backend.filter_object(object) {
+                continue;
+            }
+
+            render_joint(
+                backend,
+                object,
+                &joint.data,
+                rb1.position() * joint.data.local_frame1,
+                rb2.position() * joint.data.local_frame2,
+                [
+                    style.impulse_joint_anchor_color,
+                    style.impulse_joint_separation_color,
+                    render_context.violated_joint_limit_color,
+                ],
+                style.rigid_body_axes_length,
+            );
+        }
+    }
+
+    if mode.contains(DebugRenderMode::MULTIBODY_JOINTS) {
+        for handle in world.entity2multibody_joint.values() {
+            let Some((mb, link_id)) = world.multibody_joints.get(*handle) else {
+                continue;
+            };
+            let Some(link) = mb.link(link_id) else {
+                continue;
+            };
+            let Some(rb2) = world.bodies.get(link.rigid_body_handle()) else {
+                continue;
+            };
+
+            let data = &link.joint.data;
+            let pos2 = rb2.position();
+            let parent_pos = pos2 * link.local_to_parent().inverse();
+
+            let object = DebugRenderObject::MultibodyJoint(*handle, mb, link);
+            if !backend.filter_object(object) {
+                continue;
+            }
+
+            render_joint(
+                backend,
+                object,
+                data,
+                parent_pos * data.local_frame1,
+                pos2 * data.local_frame2,
+                [
+                    style.multibody_joint_anchor_color,
+                    style.multibody_joint_separation_color,
+                    render_context.violated_joint_limit_color,
+                ],
+                style.rigid_body_axes_length,
+            );
+        }
+    }
+}
+
+/// Draws the anchors of a joint with the world-space frames `frame1` and `frame2`, the line
+/// between them, the free axes of the joint, and its limits.
+///
+/// The `colors` are the colors of the anchors and axes, of the line between the anchors, and
+/// of the limits that are currently exceeded.
+fn render_joint(
+    backend: &mut BevyLinesRenderBackend,
+    object: DebugRenderObject,
+    data: &GenericJoint,
+    frame1: Isometry<Real>,
+    frame2: Isometry<Real>,
+    [anchor_color, separation_color, violated_color]: [[f32; 4]; 3],
+    length: Real,
+) {
+    // Accept small violations, which are expected with a soft solver.
+    const LIMIT_TOLERANCE: Real = 1.0e-3;
+    const ARC_SUBDIVISIONS: usize = 16;
+
+    let anchor1 = Point::from(frame1.translation.vector);
+    let anchor2 = Point::from(frame2.translation.vector);
+    draw_cross(backend, object, anchor1, length * 0.1, anchor_color);
+    draw_cross(backend, object, anchor2, length * 0.1, anchor_color);
+    backend.draw_line(object, anchor1, anchor2, separation_color);
+
+    // The joint’s position, relative to its first frame.
+    let joint_pos = frame1.inv_mul(&frame2);
+    #[cfg(feature = "dim2")]
+    let angles = [joint_pos.rotation.angle()];
+    #[cfg(feature = "dim3")]
+    let angles = joint_pos.rotation.scaled_axis();
+
+    for i in (0..SPATIAL_DIM).filter(|i| data.locked_axes.bits() & (1 << i) == 0) {
+        let limits = (data.limit_axes.bits() & (1 << i) != 0).then_some(data.limits[i]);
+        let limit_color = |limits: JointLimits<Real>, value: Real| {
+            if value < limits.min - LIMIT_TOLERANCE || value > limits.max + LIMIT_TOLERANCE {
+                violated_color
+            } else {
+                anchor_color
+            }
+        };
+
+        if i < DIM {
+            let dir = frame1.rotation * Vector::ith(i, length);
+            backend.draw_line(object, anchor1 - dir, anchor1 + dir, anchor_color);
+
+            if let Some(limits) = limits {
+                let color = limit_color(limits, joint_pos.translation.vector[i]);
+                let min = anchor1 + dir * (limits.min / length);
+                let max = anchor1 + dir * (limits.max / length);
+                backend.draw_line(object, min, max, color);
+                draw_cross(backend, object, min, length * 0.1, color);
+                draw_cross(backend, object, max, length * 0.1, color);
+            }
+        } else {
+            #[cfg(feature = "dim2")]
+            let (rotation_about_axis, start) = (Rotation::new, Vector::x());
+            #[cfg(feature = "dim3")]
+            let (rotation_about_axis, start) = {
+                let axis = Vector::ith(i - DIM, 1.0);
+                backend.draw_line(
+                    object,
+                    anchor1 - frame1.rotation * axis * length,
+                    anchor1 + frame1.rotation * axis * length,
+                    anchor_color,
+                );
+                (
+                    move |angle: Real| Rotation::new(axis * angle),
+                    Vector::ith((i - DIM + 1) % DIM, 1.0),
+                )
+            };
+
+            if let Some(limits) = limits {
+                let color = limit_color(limits, angles[i - DIM]);
+                let arc_point = |angle: Real| {
+                    anchor1 + frame1.rotation * (rotation_about_axis(angle) * start) * length
+                };
+
+                backend.draw_line(object, anchor1, arc_point(limits.min), color);
+                backend.draw_line(object, anchor1, arc_point(limits.max), color);
+
+                for k in 0..ARC_SUBDIVISIONS {
+                    let t0 = k as Real / ARC_SUBDIVISIONS as Real;
+                    let t1 = (k + 1) as Real / ARC_SUBDIVISIONS as Real;
+                    let angle0 = limits.min + (limits.max - limits.min) * t0;
+                    let angle1 = limits.min + (limits.max - limits.min) * t1;
+                    backend.draw_line(object, arc_point(angle0), arc_point(angle1), color);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::DEFAULT_WORLD_ID;
+    use bevy::ecs::system::RunSystemOnce;
+    use rapier::geometry::InteractionGroups;
+
+    /// Records the entities of the colliders drawn by the debug-renderer.
+    struct MockRenderBackend<'a, 'world, 'state> {
+        world: &'a RapierWorld,
+        filter: DebugRenderFilter,
+        markers: &'a DebugRenderMarkers<'world, 'state>,
+        has_only: bool,
+        drawn: Vec<Entity>,
+    }
+
+    impl<'a, 'world, 'state> DebugRenderBackend for MockRenderBackend<'a, 'world, 'state> {
+        fn filter_object(&self, object: DebugRenderObject) -> bool {
+            is_object_visible(
+                self.world,
+                object,
+                &self.filter,
+                self.markers,
+                self.has_only,
+            )
+        }
+
+        fn draw_line(
+            &mut self,
+            object: DebugRenderObject,
+            _a: Point<Real>,
+            _b: Point<Real>,
+            _color: [f32; 4],
+        ) {
+            if let DebugRenderObject::Collider(handle, ..) = object {
+                let entity = Entity::from_bits(self.world.colliders[handle].user_data as u64);
+                if !self.drawn.contains(&entity) {
+                    self.drawn.push(entity);
+                }
+            }
+        }
+    }
+
+    fn drawn_colliders(
+        context: Res<RapierContext>,
+        render_context: Res<DebugRenderContext>,
+        markers: DebugRenderMarkers,
+        only: Query<(), With<DebugRenderOnly>>,
+    ) -> Vec<Entity> {
+        let world = &context.worlds[&DEFAULT_WORLD_ID];
+        let mut backend = MockRenderBackend {
+            world,
+            filter: render_context.filter,
+            markers: &markers,
+            has_only: !only.is_empty(),
+            drawn: vec![],
+        };
+
+        DebugRenderPipeline::new(Default::default(), DebugRenderMode::COLLIDER_SHAPES).render(
+            &mut backend,
+            &world.bodies,
+            &world.colliders,
+            &world.impulse_joints,
+            &world.multibody_joints,
+            &world.narrow_phase,
+        );
+
+        backend.drawn
+    }
+
+    #[test]
+    fn debug_render_skips_filtered_colliders() {
+        let mut app = App::new();
+        let plain = app.world.spawn_empty().id();
+        let excluded = app.world.spawn(DebugRenderExclude).id();
+        let grouped = app.world.spawn_empty().id();
+
+        let mut context = RapierContext::default();
+        let world = context.worlds.get_mut(&DEFAULT_WORLD_ID).unwrap();
+        for (entity, memberships) in [
+            (plain, Group::GROUP_1),
+            (excluded, Group::GROUP_1),
+            (grouped, Group::GROUP_2),
+        ] {
+            let groups = InteractionGroups::new(
+                rapier::geometry::Group::from_bits_truncate(memberships.bits()),
+                rapier::geometry::Group::ALL,
+            );
+            world.colliders.insert(
+                ColliderBuilder::ball(0.5)
+                    .user_data(entity.to_bits() as u128)
+                    .collision_groups(groups),
+            );
+        }
+        app.insert_resource(context)
+            .insert_resource(DebugRenderContext::default());
+
+        let mut drawn = app.world.run_system_once(drawn_colliders);
+        drawn.sort();
+        assert_eq!(drawn, vec![plain, grouped]);
+
+        app.world.resource_mut::<DebugRenderContext>().filter.groups = Some(Group::GROUP_2);
+        assert_eq!(app.world.run_system_once(drawn_colliders), vec![grouped]);
+
+        app.world.resource_mut::<DebugRenderContext>().filter.groups = None;
+        app.world.entity_mut(plain).insert(DebugRenderOnly);
+        assert_eq!(app.world.run_system_once(drawn_colliders), vec![plain]);
     }
 }